@@ -8,7 +8,7 @@ use super_table::*;
 /// Create a dynamic 10x500 Table with width 300 and unevenly distributed content.
 /// There are no constraint, the content simply has to be formatted to fit as good as possible into
 /// the given space.
-fn build_huge_table() {
+fn huge_table() -> Table {
     let mut table = Table::new();
     table
         .load_preset(UTF8_FULL)
@@ -70,12 +70,28 @@ fn build_huge_table() {
         table.add_row(row);
     }
 
+    table
+}
+
+fn build_huge_table() {
+    let table = huge_table();
+
     // Build the table.
-    let _ = table.lines();
+    // Lines are drawn lazily, so all of them need to be consumed.
+    let _ = table.lines().count();
 }
 
 pub fn build_tables(crit: &mut Criterion) {
     crit.bench_function("Huge table", |b| b.iter(build_huge_table));
+
+    // Only measure the rendering, without generating the random content.
+    let table = huge_table();
+    crit.bench_function("Render huge table", |b| {
+        b.iter(|| table.lines().map(|line| line.len()).sum::<usize>())
+    });
+    crit.bench_function("First line of huge table", |b| {
+        b.iter(|| table.lines().next())
+    });
 }
 
 criterion_group!(benches, build_tables);
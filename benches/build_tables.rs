@@ -48,7 +48,8 @@ fn build_readme_table() {
         ]);
 
     // Build the table.
-    let _ = table.lines();
+    // Lines are drawn lazily, so all of them need to be consumed.
+    let _ = table.lines().count();
 }
 
 #[cfg(not(feature = "tty"))]
@@ -87,7 +88,8 @@ fn build_readme_table() {
         ]);
 
     // Build the table.
-    let _ = table.lines();
+    // Lines are drawn lazily, so all of them need to be consumed.
+    let _ = table.lines().count();
 }
 
 /// Create a dynamic 10x10 Table with width 400 and unevenly distributed content.
@@ -141,7 +143,8 @@ fn build_big_table() {
     ]);
 
     // Build the table.
-    let _ = table.lines();
+    // Lines are drawn lazily, so all of them need to be consumed.
+    let _ = table.lines().count();
 }
 
 pub fn build_tables(crit: &mut Criterion) {
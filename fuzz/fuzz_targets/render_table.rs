@@ -0,0 +1,205 @@
+//! Build random tables and make sure they render without panicking.
+//!
+//! Run it with `cargo +nightly fuzz run render_table` from the repository root.
+//! All lines of a table with valid spans must have the same display width, which is checked
+//! with `Table::assert_rectangular`.
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use super_table::presets::{ASCII_MARKDOWN, NOTHING, UTF8_BORDERS_ONLY, UTF8_FULL};
+use super_table::{
+    Cell, CellAlignment, ColumnConstraint, ContentArrangement, Table, VerticalAlignment, Width,
+};
+
+/// Limits that keep single runs fast, while still covering interesting layouts.
+const MAX_ROWS: usize = 12;
+const MAX_CELLS: usize = 8;
+const MAX_SPAN: u16 = 4;
+const MAX_CONTENT: usize = 48;
+
+#[derive(Arbitrary, Debug)]
+struct FuzzTable {
+    arrangement: Arrangement,
+    width: Option<u8>,
+    preset: Preset,
+    header: Option<Vec<FuzzCell>>,
+    rows: Vec<Vec<FuzzCell>>,
+    columns: Vec<FuzzColumn>,
+}
+
+#[derive(Arbitrary, Debug)]
+enum Arrangement {
+    Disabled,
+    Dynamic,
+    DynamicFullWidth,
+}
+
+#[derive(Arbitrary, Debug)]
+enum Preset {
+    Utf8Full,
+    Utf8BordersOnly,
+    AsciiMarkdown,
+    Nothing,
+}
+
+#[derive(Arbitrary, Debug)]
+struct FuzzCell {
+    content: String,
+    colspan: u8,
+    rowspan: u8,
+    alignment: Option<Alignment>,
+    vertical_alignment: Option<VerticalPosition>,
+    no_wrap: bool,
+}
+
+#[derive(Arbitrary, Debug)]
+enum Alignment {
+    Left,
+    Center,
+    Right,
+}
+
+#[derive(Arbitrary, Debug)]
+enum VerticalPosition {
+    Top,
+    Middle,
+    Bottom,
+}
+
+#[derive(Arbitrary, Debug)]
+struct FuzzColumn {
+    constraint: Option<Constraint>,
+    padding: (u8, u8),
+}
+
+#[derive(Arbitrary, Debug)]
+enum Constraint {
+    Hidden,
+    ContentWidth,
+    Absolute(FuzzWidth),
+    LowerBoundary(FuzzWidth),
+    UpperBoundary(FuzzWidth),
+    Boundaries { lower: FuzzWidth, upper: FuzzWidth },
+}
+
+#[derive(Arbitrary, Debug)]
+enum FuzzWidth {
+    Fixed(u8),
+    Percentage(u8),
+}
+
+impl FuzzWidth {
+    fn width(&self) -> Width {
+        match self {
+            FuzzWidth::Fixed(width) => Width::Fixed(u16::from(*width)),
+            FuzzWidth::Percentage(percent) => Width::Percentage(u16::from(*percent % 101)),
+        }
+    }
+}
+
+impl Constraint {
+    fn constraint(&self) -> ColumnConstraint {
+        match self {
+            Constraint::Hidden => ColumnConstraint::Hidden,
+            Constraint::ContentWidth => ColumnConstraint::ContentWidth,
+            Constraint::Absolute(width) => ColumnConstraint::Absolute(width.width()),
+            Constraint::LowerBoundary(width) => ColumnConstraint::LowerBoundary(width.width()),
+            Constraint::UpperBoundary(width) => ColumnConstraint::UpperBoundary(width.width()),
+            Constraint::Boundaries { lower, upper } => ColumnConstraint::Boundaries {
+                lower: lower.width(),
+                upper: upper.width(),
+            },
+        }
+    }
+}
+
+impl FuzzCell {
+    fn cell(&self) -> Cell {
+        // Control characters don't have a display width, so they can't be laid out.
+        let content: String = self
+            .content
+            .chars()
+            .filter(|character| !character.is_control() || *character == '\n')
+            .take(MAX_CONTENT)
+            .collect();
+
+        let mut cell = Cell::new(content)
+            .set_colspan(1 + u16::from(self.colspan) % MAX_SPAN)
+            .set_rowspan(1 + u16::from(self.rowspan) % MAX_SPAN);
+        if let Some(alignment) = &self.alignment {
+            cell = cell.set_alignment(match alignment {
+                Alignment::Left => CellAlignment::Left,
+                Alignment::Center => CellAlignment::Center,
+                Alignment::Right => CellAlignment::Right,
+            });
+        }
+        if let Some(alignment) = &self.vertical_alignment {
+            cell = cell.set_vertical_alignment(match alignment {
+                VerticalPosition::Top => VerticalAlignment::Top,
+                VerticalPosition::Middle => VerticalAlignment::Middle,
+                VerticalPosition::Bottom => VerticalAlignment::Bottom,
+            });
+        }
+        if self.no_wrap {
+            cell = cell.set_no_wrap();
+        }
+        cell
+    }
+}
+
+fn cells(cells: &[FuzzCell]) -> Vec<Cell> {
+    cells.iter().take(MAX_CELLS).map(FuzzCell::cell).collect()
+}
+
+fn build(input: &FuzzTable) -> Table {
+    let mut table = Table::new();
+    table
+        .load_preset(match input.preset {
+            Preset::Utf8Full => UTF8_FULL,
+            Preset::Utf8BordersOnly => UTF8_BORDERS_ONLY,
+            Preset::AsciiMarkdown => ASCII_MARKDOWN,
+            Preset::Nothing => NOTHING,
+        })
+        .set_content_arrangement(match input.arrangement {
+            Arrangement::Disabled => ContentArrangement::Disabled,
+            Arrangement::Dynamic => ContentArrangement::Dynamic,
+            Arrangement::DynamicFullWidth => ContentArrangement::DynamicFullWidth,
+        });
+    if let Some(width) = input.width {
+        table.set_width(u16::from(width));
+    }
+    if let Some(header) = &input.header {
+        table.set_header(cells(header));
+    }
+    for row in input.rows.iter().take(MAX_ROWS) {
+        table.add_row(cells(row));
+    }
+
+    for (index, fuzz_column) in input.columns.iter().enumerate() {
+        let Some(column) = table.column_mut(index) else {
+            break;
+        };
+        if let Some(constraint) = &fuzz_column.constraint {
+            column.set_constraint(constraint.constraint());
+        }
+        let (left, right) = fuzz_column.padding;
+        column.set_padding((u16::from(left % 4), u16::from(right % 4)));
+    }
+
+    table
+}
+
+fuzz_target!(|input: FuzzTable| {
+    let table = build(&input);
+
+    // Tables are always rendered, even if their spans are invalid.
+    let rendered = table.to_string();
+
+    // Tables that can be rendered properly have lines of the same width.
+    let Ok(lines) = table.try_lines() else {
+        return;
+    };
+    assert_eq!(rendered, lines.join("\n"));
+    table.assert_rectangular();
+});
@@ -0,0 +1,100 @@
+use std::collections::BTreeMap;
+use std::sync::{Mutex, PoisonError};
+
+use crate::row::Row;
+use crate::table::Table;
+
+/// A thread-safe collector for rows, which are produced concurrently.
+///
+/// The builder is `Send + Sync`, so it can be shared between threads or tasks via a reference
+/// or an [Arc](std::sync::Arc) without wrapping it in an external mutex.
+/// Each row is added with an insertion key, which determines the position of the row in the
+/// final table. Rows with the same key keep the order in which they've been added.
+///
+/// The table passed to [TableBuilder::new] is used as a template.
+/// Its header, styling and already existing rows are kept.
+///
+/// ```
+/// use super_table::{Table, TableBuilder};
+///
+/// let mut table = Table::new();
+/// table.set_header(vec!["Shard", "Status"]);
+///
+/// let builder = TableBuilder::new(table);
+/// std::thread::scope(|scope| {
+///     for shard in 0..4 {
+///         let builder = &builder;
+///         scope.spawn(move || {
+///             builder.add_row(shard, vec![shard.to_string(), "done".to_string()]);
+///         });
+///     }
+/// });
+///
+/// let table = builder.build();
+/// assert_eq!(table.row_count(), 4);
+/// assert_eq!(table.row(0).unwrap().cell_iter().next().unwrap().content(), "0");
+/// ```
+#[derive(Debug, Default)]
+pub struct TableBuilder {
+    table: Table,
+    rows: Mutex<BTreeMap<usize, Vec<Row>>>,
+}
+
+impl TableBuilder {
+    /// Create a new builder, which appends its rows to the given table.
+    pub fn new(table: Table) -> Self {
+        Self {
+            table,
+            rows: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Add a row, which will be positioned in the final table by its `key`.
+    ///
+    /// This only takes `&self`, so it can be called from multiple threads at the same time.
+    pub fn add_row<T: Into<Row>>(&self, key: usize, row: T) -> &Self {
+        let row = row.into();
+        self.lock_rows().entry(key).or_default().push(row);
+
+        self
+    }
+
+    /// Get the amount of rows that have been added to this builder so far.
+    pub fn row_count(&self) -> usize {
+        self.lock_rows().values().map(Vec::len).sum()
+    }
+
+    /// Returns whether no rows have been added to this builder so far.
+    pub fn is_empty(&self) -> bool {
+        self.lock_rows().is_empty()
+    }
+
+    /// Finalize the builder into a [Table].
+    ///
+    /// All rows are appended to the template table, ordered by their insertion key.
+    pub fn build(self) -> Table {
+        let mut table = self.table;
+        let rows = self
+            .rows
+            .into_inner()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        for row in rows.into_values().flatten() {
+            table.add_row(row);
+        }
+
+        table
+    }
+
+    /// A panic of another thread cannot leave the rows in an inconsistent state,
+    /// as the lock is only ever held for a single insertion or read.
+    fn lock_rows(&self) -> std::sync::MutexGuard<'_, BTreeMap<usize, Vec<Row>>> {
+        self.rows.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl From<Table> for TableBuilder {
+    fn from(table: Table) -> Self {
+        Self::new(table)
+    }
+}
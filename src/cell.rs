@@ -1,7 +1,15 @@
+use std::any::Any;
+
 #[cfg(feature = "tty")]
-use crate::{Attribute, Color};
+use crate::{Attribute, Color, Style};
 
-use crate::style::{CellAlignment, VerticalAlignment};
+use crate::style::{
+    CellAlignment, CellRole, EffectiveStyle, FillPolicy, Orientation, VerticalAlignment,
+};
+use crate::table::Table;
+use crate::user_data::UserData;
+use crate::utils::templates::Template;
+use crate::value::CellValue;
 
 /// A stylable table cell with content.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -10,21 +18,49 @@ pub struct Cell {
     /// This is done to make working with newlines more easily.\
     /// When creating a new [Cell], the given content is split by newline.
     pub(crate) content: Vec<String>,
+    /// The typed value of this cell, if one has been provided.
+    pub(crate) value: Option<CellValue>,
     /// The delimiter which is used to split the text into consistent pieces.\
     /// The default is ` `.
     pub(crate) delimiter: Option<char>,
     pub(crate) alignment: Option<CellAlignment>,
+    /// The alignment of each line of the content, which overwrites the alignment of the cell.
+    pub(crate) line_alignments: Vec<CellAlignment>,
     pub(crate) vertical_alignment: Option<VerticalAlignment>,
+    /// The direction in which the content is written.
+    pub(crate) orientation: Orientation,
+    /// The character that fills the space between the content and the opposite alignment edge.
+    pub(crate) fill_char: Option<char>,
+    /// Text that's displayed in front of the content, without being part of it.
+    pub(crate) prefix: Option<String>,
+    /// Text that's displayed after the content, without being part of it.
+    pub(crate) suffix: Option<String>,
     #[cfg(feature = "tty")]
     pub(crate) fg: Option<Color>,
     #[cfg(feature = "tty")]
     pub(crate) bg: Option<Color>,
     #[cfg(feature = "tty")]
     pub(crate) attributes: Vec<Attribute>,
+    /// The colors at the start and the end of each line, which replace the foreground color.
+    #[cfg(feature = "tty")]
+    pub(crate) gradient: Option<(Color, Color)>,
     /// Number of columns this cell spans (default: 1)
     pub(crate) colspan: Option<u16>,
+    /// Whether this cell spans all remaining columns, resolved when rendering.
+    pub(crate) colspan_rest: bool,
     /// Number of rows this cell spans (default: 1)
     pub(crate) rowspan: Option<u16>,
+    pub(crate) rowspan_fill: FillPolicy,
+    /// Whether the words of this cell must not be broken by the dynamic arrangement.
+    pub(crate) no_wrap: bool,
+    /// The name of the semantic style of this cell, which is looked up in the table's style map.
+    pub(crate) semantic_style: Option<String>,
+    /// The structural role of this cell, if it differs from the one given by its row.
+    pub(crate) role: Option<CellRole>,
+    /// Arbitrary data that's attached to this cell.
+    pub(crate) user_data: Option<UserData>,
+    /// The template, that's filled with the user data of the row.
+    pub(crate) template: Option<Template>,
 }
 
 impl Cell {
@@ -45,25 +81,163 @@ impl Cell {
 
         Self {
             content: split_content,
+            value: None,
             delimiter: None,
             alignment: None,
+            line_alignments: Vec::new(),
             vertical_alignment: None,
+            orientation: Orientation::Horizontal,
+            fill_char: None,
+            prefix: None,
+            suffix: None,
             #[cfg(feature = "tty")]
             fg: None,
             #[cfg(feature = "tty")]
             bg: None,
             #[cfg(feature = "tty")]
             attributes: Vec::new(),
+            #[cfg(feature = "tty")]
+            gradient: None,
             colspan: None,
+            colspan_rest: false,
             rowspan: None,
+            rowspan_fill: FillPolicy::Blank,
+            no_wrap: false,
+            semantic_style: None,
+            role: None,
+            user_data: None,
+            template: None,
         }
     }
 
+    /// Create a new Cell from a typed value.
+    ///
+    /// The display content of the cell is the value's
+    /// [string representation](CellValue::to_display_string).
+    /// The typed value is kept alongside and can be accessed via [Cell::value].
+    ///
+    /// ```
+    /// use super_table::{Cell, CellValue};
+    ///
+    /// let cell = Cell::from_value(1.5);
+    /// assert_eq!(cell.content(), "1.5");
+    /// assert_eq!(cell.value(), Some(&CellValue::Float(1.5)));
+    /// ```
+    pub fn from_value<T: Into<CellValue>>(value: T) -> Self {
+        let value = value.into();
+        let mut cell = Self::new_owned(value.to_display_string());
+        cell.value = Some(value);
+
+        cell
+    }
+
+    /// Create a new Cell, whose content is filled in from the
+    /// [user data](crate::Row::set_user_data) of its row.
+    ///
+    /// Placeholders are written as `{name}`, use `{{` and `}}` for literal braces.
+    /// They're filled from user data that maps names to values, which is either a
+    /// `HashMap<String, String>` or a `BTreeMap<String, String>`.
+    /// If the user data doesn't have a field for a placeholder, the value from `values` is used.
+    /// Placeholders without any value are displayed as they are.
+    /// That way, a cell can be defined once and is filled for each row, e.g. when rows are
+    /// updated continuously.
+    ///
+    /// The content of the cell is the template filled with `values`, until it's part of a row
+    /// with user data. The template is filled again whenever the table is rendered or exported.
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// use super_table::{Cell, Row, Table};
+    ///
+    /// let status = Cell::templated("{name} ({count})", [("count", "0")]);
+    /// assert_eq!(status.content(), "{name} (0)");
+    ///
+    /// let mut table = Table::new();
+    /// for (name, count) in [("db-1", Some(3)), ("db-2", None)] {
+    ///     let mut fields = HashMap::from([("name".to_string(), name.to_string())]);
+    ///     if let Some(count) = count {
+    ///         fields.insert("count".to_string(), count.to_string());
+    ///     }
+    ///     let mut row = Row::from(vec![status.clone()]);
+    ///     row.set_user_data(fields);
+    ///     table.add_row(row);
+    /// }
+    ///
+    /// let cell = table.row(0).unwrap().cell_iter().next().unwrap();
+    /// assert_eq!(cell.content(), "db-1 (3)");
+    ///
+    /// let expected = "
+    /// +----------+
+    /// | db-1 (3) |
+    /// |----------|
+    /// | db-2 (0) |
+    /// +----------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn templated<I, K, V>(template: &str, values: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: ToString,
+        V: ToString,
+    {
+        let template = Template {
+            text: template.to_string(),
+            defaults: values
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+        };
+        let mut cell = Self::new_owned(template.fill(None));
+        cell.template = Some(template);
+
+        cell
+    }
+
     /// Return a copy of the content contained in this cell.
     pub fn content(&self) -> String {
         self.content.join("\n")
     }
 
+    /// Attach a typed value to this cell, without changing the displayed content.
+    ///
+    /// This is useful if the displayed content is a formatted version of the actual data.
+    /// ```
+    /// use super_table::{Cell, CellValue};
+    ///
+    /// let cell = Cell::new("1.2k").set_value(1200);
+    /// assert_eq!(cell.content(), "1.2k");
+    /// assert_eq!(cell.value(), Some(&CellValue::Int(1200)));
+    /// ```
+    #[must_use]
+    pub fn set_value<T: Into<CellValue>>(mut self, value: T) -> Self {
+        self.value = Some(value.into());
+
+        self
+    }
+
+    /// Get the typed value of this cell, if one has been set via [Cell::from_value] or
+    /// [Cell::set_value].
+    pub fn value(&self) -> Option<&CellValue> {
+        self.value.as_ref()
+    }
+
+    /// Get the typed value of this cell.
+    ///
+    /// If no value has been explicitly set, the value is [parsed](CellValue::parse) from the
+    /// content of the cell.
+    /// ```
+    /// use super_table::{Cell, CellValue};
+    ///
+    /// assert_eq!(Cell::new("12").typed_value(), CellValue::Int(12));
+    /// ```
+    pub fn typed_value(&self) -> CellValue {
+        match &self.value {
+            Some(value) => value.clone(),
+            None => CellValue::parse(&self.content()),
+        }
+    }
+
     /// Set the delimiter used to split text for this cell. \
     /// Normal text uses spaces (` `) as delimiters. This is necessary to help super-table
     /// understand the concept of _words_.
@@ -92,6 +266,45 @@ impl Cell {
         self
     }
 
+    /// Set the horizontal alignment of each line of the content, e.g. to center a title above
+    /// its details.
+    ///
+    /// The first alignment applies to the first line of the content and so on.
+    /// Lines that are wrapped keep the alignment of the line they belong to.
+    /// Lines without an alignment in the list use the [alignment](Cell::set_alignment) of the
+    /// cell or its column.
+    ///
+    /// ```
+    /// use super_table::{Cell, CellAlignment, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec![
+    ///     Cell::new("Summary\nrequests: 1024\nerrors: 3")
+    ///         .set_line_alignments(vec![CellAlignment::Center]),
+    /// ]);
+    ///
+    /// let expected = "
+    /// +----------------+
+    /// |     Summary    |
+    /// | requests: 1024 |
+    /// | errors: 3      |
+    /// +----------------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    #[must_use]
+    pub fn set_line_alignments(mut self, alignments: Vec<CellAlignment>) -> Self {
+        self.line_alignments = alignments;
+
+        self
+    }
+
+    /// Get the alignments of the lines of this cell.
+    ///
+    /// See [Cell::set_line_alignments].
+    pub fn line_alignments(&self) -> &[CellAlignment] {
+        &self.line_alignments
+    }
+
     /// Set the vertical alignment of content for this cell.
     ///
     /// This controls where the content is positioned vertically when the cell's
@@ -114,6 +327,119 @@ impl Cell {
         self
     }
 
+    /// Set the direction in which the content of this cell is written.
+    ///
+    /// With [Orientation::Vertical], each grapheme is displayed on its own line, so the width of
+    /// the cell is the width of its widest grapheme.
+    /// The content of the cell itself isn't changed, so exports keep working with the raw data.
+    /// ```
+    /// use super_table::{Cell, Orientation, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec![Cell::new("Read").set_orientation(Orientation::Vertical)]);
+    ///
+    /// let expected = "
+    /// +---+
+    /// | R |
+    /// | e |
+    /// | a |
+    /// | d |
+    /// +---+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    #[must_use]
+    pub fn set_orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+
+        self
+    }
+
+    /// Get the [Orientation] of this cell.
+    pub fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    /// Fill the space between the content and the opposite edge of its alignment with a character,
+    /// instead of spaces.
+    ///
+    /// This is useful for leader lines, as they're used in a table of contents.
+    /// The padding of the column still consists of spaces and empty lines aren't filled.
+    /// The character is expected to be a single column wide.
+    /// ```
+    /// use super_table::{Cell, CellAlignment, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec![
+    ///     Cell::new("Chapter 1").set_fill_char('.'),
+    ///     Cell::new("12").set_alignment(CellAlignment::Right),
+    /// ])
+    /// .add_row(vec!["Chapter 10 - The end", "140"]);
+    ///
+    /// assert_eq!(table.lines().nth(1).unwrap(), "| Chapter 1........... |  12 |");
+    /// ```
+    #[must_use]
+    pub fn set_fill_char(mut self, fill_char: char) -> Self {
+        self.fill_char = Some(fill_char);
+
+        self
+    }
+
+    /// Get the fill character of this cell, if one has been set via [Cell::set_fill_char].
+    pub fn fill_char(&self) -> Option<char> {
+        self.fill_char
+    }
+
+    /// Display some text in front of the content of this cell, for instance a status icon or a
+    /// currency symbol.
+    ///
+    /// The prefix is only added when the table is rendered.
+    /// It isn't part of the [content](Cell::content) of the cell, so exports and comparisons
+    /// keep working with the raw data.
+    /// ```
+    /// use super_table::{Cell, Table};
+    ///
+    /// let cell = Cell::new("12.50").set_prefix("$");
+    /// assert_eq!(cell.content(), "12.50");
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec![cell]);
+    /// assert_eq!(table.lines().nth(1).unwrap(), "| $12.50 |");
+    /// ```
+    #[must_use]
+    pub fn set_prefix(mut self, prefix: &str) -> Self {
+        self.prefix = Some(prefix.to_string());
+
+        self
+    }
+
+    /// Get the prefix of this cell, if one has been set via [Cell::set_prefix].
+    pub fn prefix(&self) -> Option<&str> {
+        self.prefix.as_deref()
+    }
+
+    /// Display some text after the content of this cell, for instance a unit.
+    ///
+    /// Just like the [prefix](Cell::set_prefix), the suffix is only added when the table
+    /// is rendered and isn't part of the [content](Cell::content) of the cell.
+    /// ```
+    /// use super_table::{Cell, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec![Cell::new("backup").set_prefix("✅ ").set_suffix(" (2h ago)")]);
+    /// assert_eq!(table.lines().nth(1).unwrap(), "| ✅ backup (2h ago) |");
+    /// ```
+    #[must_use]
+    pub fn set_suffix(mut self, suffix: &str) -> Self {
+        self.suffix = Some(suffix.to_string());
+
+        self
+    }
+
+    /// Get the suffix of this cell, if one has been set via [Cell::set_suffix].
+    pub fn suffix(&self) -> Option<&str> {
+        self.suffix.as_deref()
+    }
+
     /// Set the foreground text color for this cell.
     ///
     /// Look at [Color](crate::Color) for a list of all possible Colors.
@@ -178,6 +504,120 @@ impl Cell {
         self
     }
 
+    /// Color the text of this cell with a gradient, that goes from `start` to `end` on each line.
+    ///
+    /// Each character gets its own 24-bit color, so gradients are only displayed, if the
+    /// terminal [supports true color](crate::Table::supports_truecolor).
+    /// Otherwise, the cell is displayed with its regular [foreground color](Cell::fg).
+    /// The background and the attributes of the cell are kept. Lines with
+    /// [highlights](crate::Table::highlight_matches) don't get a gradient.
+    ///
+    /// ```
+    /// use super_table::{Cell, Color};
+    ///
+    /// let cell = Cell::new("Release notes").set_gradient(
+    ///     Color::Rgb { r: 255, g: 0, b: 128 },
+    ///     Color::Rgb { r: 0, g: 128, b: 255 },
+    /// );
+    /// ```
+    #[cfg(feature = "tty")]
+    #[must_use]
+    pub fn set_gradient(mut self, start: Color, end: Color) -> Self {
+        self.gradient = Some((start, end));
+
+        self
+    }
+
+    /// Get the start and the end color of the [gradient](Cell::set_gradient) of this cell.
+    #[cfg(feature = "tty")]
+    pub fn gradient(&self) -> Option<(Color, Color)> {
+        self.gradient
+    }
+
+    /// Attach arbitrary data to this cell, for instance the domain object it displays.
+    ///
+    /// The data isn't displayed, but it's available wherever the cell is, e.g. in
+    /// [render hooks](crate::render::RenderEvent::CellFormatted).
+    /// Setting new data replaces any previous data.
+    ///
+    /// ```
+    /// use super_table::Cell;
+    ///
+    /// struct Measurement {
+    ///     value: f64,
+    /// }
+    ///
+    /// let cell = Cell::new("12.5 °C").set_user_data(Measurement { value: 12.5 });
+    /// assert_eq!(cell.user_data::<Measurement>().unwrap().value, 12.5);
+    /// assert!(cell.user_data::<String>().is_none());
+    /// ```
+    #[must_use]
+    pub fn set_user_data<T: Any + Send + Sync>(mut self, data: T) -> Self {
+        self.user_data = Some(UserData::new(data));
+
+        self
+    }
+
+    /// Get the data that's attached to this cell, if it's of type `T`.
+    ///
+    /// See [Cell::set_user_data].
+    pub fn user_data<T: Any>(&self) -> Option<&T> {
+        self.user_data.as_ref().and_then(UserData::get)
+    }
+
+    /// Get the styling of this cell, once the settings of its column, row and table are applied.
+    ///
+    /// See [EffectiveStyle] for the order in which settings are resolved.
+    /// The cell has to be a reference into the given table, for instance one that's returned
+    /// by [Table::row] or [Table::header]. Otherwise, `None` is returned.
+    ///
+    /// ```
+    /// use super_table::{CellAlignment, Row, Table};
+    ///
+    /// let mut row = Row::from(vec!["One", "Two"]);
+    /// row.set_cell_alignment(CellAlignment::Right);
+    /// let mut table = Table::new();
+    /// table.add_row(row);
+    /// table.column_mut(1).unwrap().set_cell_alignment(CellAlignment::Center);
+    ///
+    /// let row = table.row(0).unwrap();
+    /// let alignments: Vec<CellAlignment> = row
+    ///     .cell_iter()
+    ///     .map(|cell| cell.effective_style(&table).unwrap().alignment)
+    ///     .collect();
+    /// assert_eq!(alignments, vec![CellAlignment::Right, CellAlignment::Center]);
+    /// ```
+    pub fn effective_style(&self, table: &Table) -> Option<EffectiveStyle> {
+        let (row, column_index) = table.locate_cell(self)?;
+        let columns = table.columns.get(column_index..).unwrap_or_default();
+        let colspan = usize::from(self.colspan()).min(columns.len());
+
+        // The defaults of the header only apply to header cells.
+        let header_defaults = table.header_defaults.as_ref().filter(|_| {
+            table
+                .header
+                .as_ref()
+                .is_some_and(|header| std::ptr::eq(header, row))
+        });
+
+        Some(EffectiveStyle::resolve(
+            self,
+            row,
+            &columns[..colspan],
+            header_defaults,
+        ))
+    }
+
+    /// The combined styling of this cell.
+    #[cfg(feature = "tty")]
+    pub(crate) fn style(&self) -> Style {
+        Style {
+            fg: self.fg,
+            bg: self.bg,
+            attributes: self.attributes.clone(),
+        }
+    }
+
     /// Set the number of columns this cell spans.
     ///
     /// By default, a cell spans 1 column. Setting a colspan greater than 1
@@ -212,9 +652,150 @@ impl Cell {
     #[must_use]
     pub fn set_colspan(mut self, cols: u16) -> Self {
         self.colspan = Some(cols);
+        self.colspan_rest = false;
+        self
+    }
+
+    /// Let this cell span all remaining columns of its row.
+    ///
+    /// The actual colspan is resolved when the table is rendered, after all columns are known.
+    /// This is useful for full-width section rows, which otherwise break as soon as another
+    /// column is added to the table.
+    ///
+    /// Until the table is rendered, the cell is considered to span a single column.
+    /// The span stops early at columns that are occupied by a rowspan from a previous row.
+    ///
+    /// ```
+    /// use super_table::{Cell, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Name", "Age", "City"])
+    ///     .add_row(vec![Cell::new("Section 1").set_colspan_rest()])
+    ///     .add_row(vec!["Alice", "30", "Berlin"]);
+    /// ```
+    #[must_use]
+    pub fn set_colspan_rest(mut self) -> Self {
+        self.colspan = None;
+        self.colspan_rest = true;
+        self
+    }
+
+    /// Returns whether this cell spans all remaining columns of its row.
+    ///
+    /// See [Cell::set_colspan_rest].
+    pub fn spans_rest(&self) -> bool {
+        self.colspan_rest
+    }
+
+    /// Never break the words of this cell.
+    ///
+    /// When the content is [arranged dynamically](crate::ContentArrangement::Dynamic),
+    /// the longest word of this cell is a hard minimum for the width of its column.
+    /// If the cell spans multiple columns, the spanned columns are at least as wide as the word
+    /// together. This is useful for identifiers like UUIDs or paths, which are useless once broken.
+    ///
+    /// The content is still split at its delimiter, so only single words are kept intact.
+    /// The minimum is applied even if the table gets wider than its width,
+    /// but columns with a [constraint](crate::ColumnConstraint) keep their width.
+    ///
+    /// ```
+    /// use super_table::{Cell, ContentArrangement, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_content_arrangement(ContentArrangement::Dynamic)
+    ///     .set_width(20)
+    ///     .add_row(vec![Cell::new("id: 9f3c2a1e-77b4-4d").set_no_wrap()]);
+    ///
+    /// assert!(table.lines().any(|line| line.contains("9f3c2a1e-77b4-4d")));
+    /// ```
+    #[must_use]
+    pub fn set_no_wrap(mut self) -> Self {
+        self.no_wrap = true;
         self
     }
 
+    /// Returns whether the words of this cell are never broken.
+    ///
+    /// See [Cell::set_no_wrap].
+    pub fn is_no_wrap(&self) -> bool {
+        self.no_wrap
+    }
+
+    /// Set the semantic style of this cell, like `error`, `warning` or `muted`.
+    ///
+    /// The name describes the meaning of the content, while the [StyleMap](crate::StyleMap) of
+    /// the table defines how it's displayed in each output format.
+    /// Colors and attributes of the cell itself take precedence over its semantic style.
+    ///
+    /// ```
+    /// use super_table::{Cell, HtmlOptions, StyleMap, StyleTarget, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_style_map(StyleMap::new().add("error", StyleTarget::HtmlClass("error".into())))
+    ///     .add_row(vec![Cell::new("failed").set_semantic_style("error")]);
+    ///
+    /// let html = table.to_html(HtmlOptions::new());
+    /// assert!(html.contains("<td class=\"error\">failed</td>"));
+    /// ```
+    #[must_use]
+    pub fn set_semantic_style(mut self, name: impl Into<String>) -> Self {
+        self.semantic_style = Some(name.into());
+        self
+    }
+
+    /// Get the name of the semantic style of this cell.
+    ///
+    /// See [Cell::set_semantic_style].
+    pub fn semantic_style(&self) -> Option<&str> {
+        self.semantic_style.as_deref()
+    }
+
+    /// The name, that's looked up in the [StyleMap](crate::StyleMap) of a table.
+    ///
+    /// Cells without a semantic style use the name of their role.
+    pub(crate) fn style_name(&self) -> Option<&str> {
+        self.semantic_style
+            .as_deref()
+            .or(self.role.map(CellRole::name))
+    }
+
+    /// Set the structural role of this cell, like a row header in the body or a total.
+    ///
+    /// - [Table::to_html](crate::Table::to_html) writes header cells as `<th>` and all other
+    ///   cells as `<td>`. Header cells outside of the header row get `scope="row"`, so screen
+    ///   readers announce them for the rest of their row. Trailing rows, which only consist of
+    ///   footer cells, are written to a `<tfoot>`.
+    /// - Cells without a [semantic style](Cell::set_semantic_style) are styled by the
+    ///   [name](CellRole::name) of their role, so a [StyleMap](crate::StyleMap) can style all
+    ///   summaries of a table at once.
+    ///
+    /// ```
+    /// use super_table::{Cell, CellRole, HtmlOptions, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Host", "Requests"])
+    ///     .add_row(vec![Cell::new("web-1").set_role(CellRole::Header), Cell::new("1024")]);
+    ///
+    /// let html = table.to_html(HtmlOptions::new());
+    /// assert!(html.contains("<tr><th scope=\"row\">web-1</th><td>1024</td></tr>"));
+    /// ```
+    #[must_use]
+    pub fn set_role(mut self, role: CellRole) -> Self {
+        self.role = Some(role);
+        self
+    }
+
+    /// Get the role of this cell, if one has been set.
+    ///
+    /// See [Cell::set_role].
+    pub fn role(&self) -> Option<CellRole> {
+        self.role
+    }
+
     /// Set the number of rows this cell spans.
     ///
     /// By default, a cell spans 1 row. Setting a rowspan greater than 1
@@ -246,7 +827,9 @@ impl Cell {
     ///
     /// - When using rowspan, subsequent rows should have fewer cells than
     ///   the number of columns, as the rowspan cell occupies space in those rows.
-    /// - Rowspan content appears only in the starting row of the span.
+    /// - Rowspan content is positioned within all lines of the spanned rows, depending on the
+    ///   cell's [VerticalAlignment]. If the content is taller than the spanned rows, the starting
+    ///   row grows to fit the content.
     /// - Rowspan works with all table features including styling, alignment,
     ///   and multi-line content.
     /// - You can combine rowspan with colspan to create cells that span
@@ -257,6 +840,28 @@ impl Cell {
         self
     }
 
+    /// Set how the lines of a rowspan cell are filled, which aren't covered by its content.
+    ///
+    /// Default is [FillPolicy::Blank]. This has no effect on cells that don't span multiple rows.
+    ///
+    /// ```
+    /// use super_table::{Cell, FillPolicy};
+    ///
+    /// let cell = Cell::new("Spans 3 rows")
+    ///     .set_rowspan(3)
+    ///     .set_rowspan_fill(FillPolicy::Repeat('.'));
+    /// ```
+    #[must_use]
+    pub fn set_rowspan_fill(mut self, fill: FillPolicy) -> Self {
+        self.rowspan_fill = fill;
+        self
+    }
+
+    /// Get the [FillPolicy] of this cell.
+    pub fn rowspan_fill(&self) -> FillPolicy {
+        self.rowspan_fill
+    }
+
     /// Get the number of columns this cell spans.
     ///
     /// Returns 1 if no colspan is set (default behavior).
@@ -331,6 +936,19 @@ impl<T: ToString> From<T> for Cell {
     }
 }
 
+/// Convert a [CellValue] to a new [Cell], keeping the typed value.
+///
+/// ```
+/// # use super_table::{Cell, CellValue};
+/// let cell: Cell = CellValue::Bool(true).into();
+/// assert_eq!(cell.content(), "true");
+/// ```
+impl From<CellValue> for Cell {
+    fn from(value: CellValue) -> Self {
+        Self::from_value(value)
+    }
+}
+
 /// A simple wrapper type for a `Vec<Cell>`.
 ///
 /// This wrapper is needed to support generic conversions between iterables and `Vec<Cell>`.
@@ -1,4 +1,13 @@
-use crate::style::{CellAlignment, ColumnConstraint, VerticalAlignment};
+#[cfg(feature = "tty")]
+use std::collections::HashMap;
+
+use crate::style::{
+    BoolFormat, CellAlignment, ColumnConstraint, DecimalPrecision, MaskPolicy, NegativeFormat,
+    Overflow, SortOrder, TextTransform, VerticalAlignment,
+};
+#[cfg(feature = "tty")]
+use crate::style::{Color, Style};
+use crate::value::ColumnType;
 
 /// A representation of a table's column.
 /// Useful for styling and specifying constraints how big a column should be.
@@ -41,6 +50,51 @@ pub struct Column {
     /// Define the [VerticalAlignment] for all cells of this column
     pub(crate) vertical_alignment: Option<VerticalAlignment>,
     pub(crate) constraint: Option<ColumnConstraint>,
+    /// The type of data in this column, if it has been inferred.
+    pub(crate) column_type: Option<ColumnType>,
+    /// The sort indicator that's displayed in the header cell of this column.
+    pub(crate) sort_indicator: Option<SortOrder>,
+    /// The glyphs used to display ascending/descending sort indicators.
+    pub(crate) sort_indicator_glyphs: (String, String),
+    /// Hide the content of this column's cells when rendering.
+    pub(crate) mask: Option<MaskPolicy>,
+    /// Change the casing of this column's cells when rendering.
+    pub(crate) text_transform: Option<TextTransform>,
+    /// Change the casing of this column's header cell when rendering.
+    pub(crate) header_text_transform: Option<TextTransform>,
+    /// Columns with a lower priority are shrunk and hidden first, if space runs out.
+    pub(crate) truncation_priority: u8,
+    /// What happens to content, that's wider than this column.
+    pub(crate) overflow: Option<Overflow>,
+    /// The amount of spaces, that lines are indented by after they've been wrapped.
+    pub(crate) hanging_indent: u16,
+    /// Whether this column is repeated in every chunk of a table, that's split by its columns.
+    pub(crate) frozen: bool,
+    /// Whether the column is displayed as a placeholder instead of its content.
+    pub(crate) collapsed: bool,
+    /// Whether values, that repeat the value of the row above, are displayed as the dedup marker.
+    pub(crate) dedup_display: bool,
+    /// The content, that's displayed instead of a repeated value.
+    pub(crate) dedup_marker: String,
+    /// A description of the column's content, that travels with the table.
+    pub(crate) description: Option<String>,
+    /// The unit of the column's values, which is displayed below the header.
+    pub(crate) unit: Option<String>,
+    /// The amount of decimal places, that numbers of this column are displayed with.
+    pub(crate) decimal_precision: Option<DecimalPrecision>,
+    /// How negative numbers of this column are displayed.
+    pub(crate) negative_format: NegativeFormat,
+    /// The style of negative numbers of this column.
+    #[cfg(feature = "tty")]
+    pub(crate) negative_style: Option<Style>,
+    /// How booleans of this column are displayed.
+    pub(crate) bool_format: Option<BoolFormat>,
+    /// The foreground colors of cells by their content.
+    #[cfg(feature = "tty")]
+    pub(crate) category_colors: HashMap<String, Color>,
+    /// The index of the row, that all other cells of this column are compared to.
+    #[cfg(feature = "tty")]
+    pub(crate) baseline_row: Option<usize>,
 }
 
 impl Column {
@@ -52,6 +106,30 @@ impl Column {
             constraint: None,
             cell_alignment: None,
             vertical_alignment: None,
+            column_type: None,
+            sort_indicator: None,
+            sort_indicator_glyphs: ("▲".to_string(), "▼".to_string()),
+            mask: None,
+            text_transform: None,
+            header_text_transform: None,
+            truncation_priority: 0,
+            overflow: None,
+            hanging_indent: 0,
+            frozen: false,
+            collapsed: false,
+            dedup_display: false,
+            dedup_marker: String::new(),
+            description: None,
+            unit: None,
+            decimal_precision: None,
+            negative_format: NegativeFormat::default(),
+            #[cfg(feature = "tty")]
+            negative_style: None,
+            bool_format: None,
+            #[cfg(feature = "tty")]
+            category_colors: HashMap::new(),
+            #[cfg(feature = "tty")]
+            baseline_row: None,
         }
     }
 
@@ -101,13 +179,293 @@ impl Column {
         self
     }
 
+    /// Set the priority of this column, when the table has to be shrunk to fit into its width.
+    ///
+    /// With a [dynamic content arrangement](crate::ContentArrangement::Dynamic), all columns
+    /// that don't fit are usually shrunk by the same amount.
+    /// If columns have different priorities, columns with a higher priority keep their natural
+    /// width, as long as every column with a lower priority can still display a single character.
+    /// If there's not even that much space left, columns with the lowest priority are hidden.
+    ///
+    /// The default priority is `0`.
+    ///
+    /// ```
+    /// use super_table::{ContentArrangement, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_width(40)
+    ///     .set_content_arrangement(ContentArrangement::Dynamic)
+    ///     .add_row(vec![
+    ///         "A long description, that may be wrapped",
+    ///         "The important part, that should not be wrapped",
+    ///     ]);
+    /// table.column_mut(1).unwrap().set_truncation_priority(10);
+    ///
+    /// assert!(table.to_string().contains("The important part"));
+    /// ```
+    pub fn set_truncation_priority(&mut self, priority: u8) -> &mut Self {
+        self.truncation_priority = priority;
+
+        self
+    }
+
+    /// Get the truncation priority of this column.
+    ///
+    /// See [Column::set_truncation_priority].
+    pub fn truncation_priority(&self) -> u8 {
+        self.truncation_priority
+    }
+
+    /// Set what happens to content, that's wider than this column.
+    ///
+    /// By default, content is [wrapped](Overflow::Wrap).
+    /// [Table::infer_column_types](crate::Table::infer_column_types) picks an overflow by the type
+    /// of the column, unless one has been set.
+    /// The header is always wrapped.
+    ///
+    /// ```
+    /// use super_table::{ColumnConstraint, Overflow, Table, Width};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Commit"])
+    ///     .add_row(vec!["9f3c2a1e77b44d0c"]);
+    /// let column = table.column_mut(0).unwrap();
+    /// column.set_overflow(Overflow::TruncateMiddle);
+    /// column.set_constraint(ColumnConstraint::Absolute(Width::Fixed(11)));
+    ///
+    /// let expected = "
+    /// +-----------+
+    /// | Commit    |
+    /// +===========+
+    /// | 9f3...d0c |
+    /// +-----------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_overflow(&mut self, overflow: Overflow) -> &mut Self {
+        self.overflow = Some(overflow);
+
+        self
+    }
+
+    /// Get the overflow of this column, if one has been set.
+    ///
+    /// See [Column::set_overflow].
+    pub fn overflow(&self) -> Option<Overflow> {
+        self.overflow
+    }
+
+    /// Indent the lines of left-aligned cells by `indent` spaces, if they've been wrapped.
+    ///
+    /// The first line keeps its position, so structured content like `key: value` stays
+    /// readable, when the value is wrapped.
+    /// Lines that are separated by a newline in the content start without an indent.
+    /// The header and cells that span multiple columns aren't indented.
+    /// The indent is ignored, if the column isn't wider than the indent.
+    ///
+    /// ```
+    /// use super_table::{ColumnConstraint, Table, Width};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Notes"])
+    ///     .add_row(vec!["cause: the disk of the primary database ran full"]);
+    /// let column = table.column_mut(0).unwrap();
+    /// column.set_hanging_indent(7);
+    /// column.set_constraint(ColumnConstraint::Absolute(Width::Fixed(26)));
+    ///
+    /// let expected = "
+    /// +--------------------------+
+    /// | Notes                    |
+    /// +==========================+
+    /// | cause: the disk of the   |
+    /// |        primary database  |
+    /// |        ran full          |
+    /// +--------------------------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_hanging_indent(&mut self, indent: u16) -> &mut Self {
+        self.hanging_indent = indent;
+
+        self
+    }
+
+    /// Get the indent of wrapped lines.
+    ///
+    /// See [Column::set_hanging_indent].
+    pub fn hanging_indent(&self) -> u16 {
+        self.hanging_indent
+    }
+
+    /// Repeat this column in every chunk, when the table is split via
+    /// [Table::column_chunks](crate::Table::column_chunks).
+    ///
+    /// This is usually done for columns that identify a row, like its name or an index.
+    /// Any number of columns can be frozen. They keep their position in each chunk.
+    ///
+    /// ```
+    /// use super_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.set_header(vec!["Host", "CPU", "Memory"]);
+    /// table.column_mut(0).unwrap().set_frozen(true);
+    /// assert!(table.column(0).unwrap().is_frozen());
+    /// ```
+    pub fn set_frozen(&mut self, frozen: bool) -> &mut Self {
+        self.frozen = frozen;
+
+        self
+    }
+
+    /// Returns whether this column is repeated in every chunk.
+    ///
+    /// See [Column::set_frozen].
+    pub fn is_frozen(&self) -> bool {
+        self.frozen
+    }
+
     /// Returns weather the columns is hidden via [ColumnConstraint::Hidden].
     pub fn is_hidden(&self) -> bool {
         matches!(self.constraint, Some(ColumnConstraint::Hidden))
     }
 
+    /// Hide or show this column.
+    ///
+    /// This is the same as setting or removing the [ColumnConstraint::Hidden] constraint.
+    /// Showing a column, that isn't hidden, keeps its constraint.
+    ///
+    /// ```
+    /// use super_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.set_header(vec!["Name", "Token"]);
+    ///
+    /// let column = table.column_mut(1).unwrap();
+    /// column.set_hidden(true);
+    /// assert!(column.is_hidden());
+    /// column.set_hidden(false);
+    /// assert!(column.constraint().is_none());
+    /// ```
+    pub fn set_hidden(&mut self, hidden: bool) -> &mut Self {
+        if hidden {
+            self.constraint = Some(ColumnConstraint::Hidden);
+        } else if self.is_hidden() {
+            self.constraint = None;
+        }
+
+        self
+    }
+
+    /// Display this column with the width of a single character, with a `…` placeholder in
+    /// every cell that has content.
+    ///
+    /// In contrast to a [hidden](Column::set_hidden) column, the reader can still see, that
+    /// there's data in this column. The constraint of a collapsed column is ignored, but kept
+    /// for when it's expanded again. Hidden columns stay hidden.
+    ///
+    /// ```
+    /// use super_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Name", "Details"])
+    ///     .add_row(vec!["db-1", "Primary database in eu-west"])
+    ///     .add_row(vec!["db-2", ""]);
+    /// table.column_mut(1).unwrap().set_collapsed(true);
+    ///
+    /// let expected = "
+    /// +------+---+
+    /// | Name | … |
+    /// +==========+
+    /// | db-1 | … |
+    /// |------+---|
+    /// | db-2 |   |
+    /// +------+---+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_collapsed(&mut self, collapsed: bool) -> &mut Self {
+        self.collapsed = collapsed;
+
+        self
+    }
+
+    /// Returns whether this column is displayed as a placeholder.
+    ///
+    /// See [Column::set_collapsed].
+    pub fn is_collapsed(&self) -> bool {
+        self.collapsed
+    }
+
+    /// Display cells, that have the same content as the cell above them, as blank.
+    ///
+    /// This groups rows by the values of this column, without merging cells via
+    /// [rowspans](crate::Cell::set_rowspan).
+    /// Only the displayed content changes, the content of the cells stays the same and is still
+    /// used by exports of the [original content](crate::ExportContent::Original).
+    /// The header, footer rows and cells that span multiple rows or columns are never blanked.
+    /// [Annotations](crate::Table::add_annotation_row) and
+    /// [section breaks](crate::Table::add_section_break) start a new group.
+    ///
+    /// Use [Column::set_dedup_marker] to display something else than a blank cell.
+    ///
+    /// ```
+    /// use super_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Region", "Host"])
+    ///     .add_row(vec!["eu-west", "db-1"])
+    ///     .add_row(vec!["eu-west", "db-2"])
+    ///     .add_row(vec!["us-east", "db-3"]);
+    /// table.column_mut(0).unwrap().set_dedup_display(true);
+    ///
+    /// let expected = "
+    /// +---------+------+
+    /// | Region  | Host |
+    /// +================+
+    /// | eu-west | db-1 |
+    /// |---------+------|
+    /// |         | db-2 |
+    /// |---------+------|
+    /// | us-east | db-3 |
+    /// +---------+------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// assert_eq!(table.row(1).unwrap().cell_iter().next().unwrap().content(), "eu-west");
+    /// ```
+    pub fn set_dedup_display(&mut self, dedup: bool) -> &mut Self {
+        self.dedup_display = dedup;
+
+        self
+    }
+
+    /// Returns whether repeated values of this column are blanked.
+    ///
+    /// See [Column::set_dedup_display].
+    pub fn dedup_display(&self) -> bool {
+        self.dedup_display
+    }
+
+    /// Set the content, that's displayed instead of repeated values, e.g. a ditto mark `"`.
+    ///
+    /// This only has an effect, if [Column::set_dedup_display] is enabled.
+    /// By default, repeated values are displayed as blank cells.
+    pub fn set_dedup_marker(&mut self, marker: &str) -> &mut Self {
+        self.dedup_marker = marker.to_string();
+
+        self
+    }
+
+    /// Get the content, that's displayed instead of repeated values.
+    ///
+    /// See [Column::set_dedup_marker].
+    pub fn dedup_marker(&self) -> &str {
+        &self.dedup_marker
+    }
+
     /// Set the horizontal alignment for content inside of cells for this column.\
     /// **Note:** Alignment on a cell will always overwrite the column's setting.
+    /// The column's setting overwrites the alignment of a [row](crate::Row::set_cell_alignment).
     pub fn set_cell_alignment(&mut self, alignment: CellAlignment) {
         self.cell_alignment = Some(alignment);
     }
@@ -117,6 +475,468 @@ impl Column {
     pub fn set_vertical_alignment(&mut self, alignment: VerticalAlignment) {
         self.vertical_alignment = Some(alignment);
     }
+
+    /// Display a sort indicator in the header cell of this column.
+    ///
+    /// The indicator is appended to the first line of the header cell, separated by a space.
+    /// It's considered when calculating the width of the column, but doesn't change the
+    /// content of the header cell.
+    ///
+    /// ```
+    /// use super_table::{SortOrder, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table.set_header(vec!["Name", "Age"]);
+    /// table
+    ///     .column_mut(1)
+    ///     .unwrap()
+    ///     .set_sort_indicator(SortOrder::Descending);
+    ///
+    /// assert!(table.to_string().contains("Age ▼"));
+    /// ```
+    pub fn set_sort_indicator(&mut self, order: SortOrder) -> &mut Self {
+        self.sort_indicator = Some(order);
+
+        self
+    }
+
+    /// Remove the sort indicator from the header cell of this column.
+    pub fn remove_sort_indicator(&mut self) -> &mut Self {
+        self.sort_indicator = None;
+
+        self
+    }
+
+    /// Get the sort indicator of this column.
+    pub fn sort_indicator(&self) -> Option<SortOrder> {
+        self.sort_indicator
+    }
+
+    /// Set the glyphs that are used to display the sort indicator.
+    ///
+    /// The default is `▲` for [SortOrder::Ascending] and `▼` for [SortOrder::Descending].
+    pub fn set_sort_indicator_glyphs(&mut self, ascending: &str, descending: &str) -> &mut Self {
+        self.sort_indicator_glyphs = (ascending.to_string(), descending.to_string());
+
+        self
+    }
+
+    /// The glyph of the currently active sort indicator.
+    pub(crate) fn sort_indicator_glyph(&self) -> Option<&str> {
+        match self.sort_indicator? {
+            SortOrder::Ascending => Some(&self.sort_indicator_glyphs.0),
+            SortOrder::Descending => Some(&self.sort_indicator_glyphs.1),
+        }
+    }
+
+    /// Mask the content of all cells in this column, for instance to hide secrets.
+    ///
+    /// Masking is applied when the table is rendered or exported, the content of the cells isn't
+    /// changed.
+    /// The header cell of the column is never masked.
+    ///
+    /// ```
+    /// use super_table::{MaskPolicy, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table.set_header(vec!["User", "Token"]);
+    /// table.add_row(vec!["admin", "hunter2"]);
+    /// table.column_mut(1).unwrap().set_mask(MaskPolicy::Full);
+    ///
+    /// assert!(table.to_string().contains("*******"));
+    /// assert!(!table.to_string().contains("hunter2"));
+    /// ```
+    pub fn set_mask(&mut self, policy: MaskPolicy) -> &mut Self {
+        self.mask = Some(policy);
+
+        self
+    }
+
+    /// Stop masking the content of this column.
+    pub fn remove_mask(&mut self) -> &mut Self {
+        self.mask = None;
+
+        self
+    }
+
+    /// Get the [MaskPolicy] of this column.
+    pub fn mask(&self) -> Option<MaskPolicy> {
+        self.mask
+    }
+
+    /// Change the casing of all cells in this column, except for the header cell.
+    ///
+    /// The transformation is applied when the table is rendered, before the width of the column
+    /// is calculated. The content of the cells isn't changed.\
+    /// Use [Column::set_header_text_transform] to transform the header cell.
+    ///
+    /// ```
+    /// use super_table::{Table, TextTransform};
+    ///
+    /// let mut table = Table::new();
+    /// table.set_header(vec!["Status"]);
+    /// table.add_row(vec!["active"]);
+    /// table
+    ///     .column_mut(0)
+    ///     .unwrap()
+    ///     .set_text_transform(TextTransform::Uppercase);
+    ///
+    /// assert!(table.to_string().contains("ACTIVE"));
+    /// assert_eq!(table.row(0).unwrap().cell_iter().next().unwrap().content(), "active");
+    /// ```
+    pub fn set_text_transform(&mut self, transform: TextTransform) -> &mut Self {
+        self.text_transform = Some(transform);
+
+        self
+    }
+
+    /// Stop changing the casing of the cells in this column.
+    pub fn remove_text_transform(&mut self) -> &mut Self {
+        self.text_transform = None;
+
+        self
+    }
+
+    /// Get the [TextTransform] that's applied to the cells of this column.
+    pub fn text_transform(&self) -> Option<TextTransform> {
+        self.text_transform
+    }
+
+    /// Change the casing of the header cell of this column.
+    ///
+    /// ```
+    /// use super_table::{Table, TextTransform};
+    ///
+    /// let mut table = Table::new();
+    /// table.set_header(vec!["Status"]);
+    /// table
+    ///     .column_mut(0)
+    ///     .unwrap()
+    ///     .set_header_text_transform(TextTransform::Uppercase);
+    ///
+    /// assert!(table.to_string().contains("STATUS"));
+    /// ```
+    pub fn set_header_text_transform(&mut self, transform: TextTransform) -> &mut Self {
+        self.header_text_transform = Some(transform);
+
+        self
+    }
+
+    /// Stop changing the casing of the header cell of this column.
+    pub fn remove_header_text_transform(&mut self) -> &mut Self {
+        self.header_text_transform = None;
+
+        self
+    }
+
+    /// Get the [TextTransform] that's applied to the header cell of this column.
+    pub fn header_text_transform(&self) -> Option<TextTransform> {
+        self.header_text_transform
+    }
+
+    /// Describe the content of this column, e.g. its unit or how its values are calculated.
+    ///
+    /// Descriptions aren't displayed by default.
+    /// Use [Table::set_description_footnotes](crate::Table::set_description_footnotes) to
+    /// display them below the table.
+    /// [Table::to_html](crate::Table::to_html) adds them as `title` of the header cell.
+    ///
+    /// ```
+    /// use super_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.set_header(vec!["Name", "RSS"]);
+    /// table
+    ///     .column_mut(1)
+    ///     .unwrap()
+    ///     .set_description("Resident set size in MiB");
+    /// assert_eq!(table.column(1).unwrap().description(), Some("Resident set size in MiB"));
+    /// ```
+    pub fn set_description(&mut self, description: impl Into<String>) -> &mut Self {
+        self.description = Some(description.into());
+
+        self
+    }
+
+    /// Remove the description of this column.
+    pub fn remove_description(&mut self) -> &mut Self {
+        self.description = None;
+
+        self
+    }
+
+    /// Get the description of this column.
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Set the unit of this column's values, e.g. `[ms]`.
+    ///
+    /// The unit is displayed dimmed on a separate line below the header cell of this column,
+    /// but it isn't part of the header's content and isn't exported.
+    /// See [Table::set_units_row](crate::Table::set_units_row) to set the units of all columns.
+    pub fn set_unit(&mut self, unit: impl Into<String>) -> &mut Self {
+        self.unit = Some(unit.into());
+
+        self
+    }
+
+    /// Remove the unit of this column.
+    pub fn remove_unit(&mut self) -> &mut Self {
+        self.unit = None;
+
+        self
+    }
+
+    /// Get the unit of this column.
+    pub fn unit(&self) -> Option<&str> {
+        self.unit.as_deref()
+    }
+
+    /// Display all numbers of this column with the highest amount of decimal places any of them
+    /// needs, so their decimal points line up.
+    ///
+    /// This is a shortcut for [Column::set_decimal_precision] with [DecimalPrecision::Auto].
+    /// Numbers are cells with a numeric [typed value](crate::Cell::typed_value).
+    /// Numeric cells are right aligned, unless the cell, the column or the row has its own
+    /// alignment.
+    ///
+    /// ```
+    /// use super_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .add_row(vec!["3"])
+    ///     .add_row(vec!["1.25"])
+    ///     .add_row(vec!["10.5"]);
+    /// table.column_mut(0).unwrap().set_auto_precision();
+    ///
+    /// let expected = "
+    /// +-------+
+    /// |  3.00 |
+    /// |-------|
+    /// |  1.25 |
+    /// |-------|
+    /// | 10.50 |
+    /// +-------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_auto_precision(&mut self) -> &mut Self {
+        self.set_decimal_precision(DecimalPrecision::Auto)
+    }
+
+    /// Set the amount of decimal places, that numbers of this column are displayed with.
+    ///
+    /// See [Column::set_auto_precision] for details.
+    pub fn set_decimal_precision(&mut self, precision: DecimalPrecision) -> &mut Self {
+        self.decimal_precision = Some(precision);
+
+        self
+    }
+
+    /// Display numbers of this column as they are.
+    pub fn remove_decimal_precision(&mut self) -> &mut Self {
+        self.decimal_precision = None;
+
+        self
+    }
+
+    /// Get the decimal precision of this column.
+    pub fn decimal_precision(&self) -> Option<DecimalPrecision> {
+        self.decimal_precision
+    }
+
+    /// Set how negative numbers of this column are displayed.
+    ///
+    /// Numbers are cells with a numeric [typed value](crate::Cell::typed_value).
+    /// The content that's stored in the cells isn't changed, only the displayed content.
+    ///
+    /// ```
+    /// use super_table::{Cell, CellAlignment, NegativeFormat, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .add_row(vec![Cell::new("1,234").set_value(1234)])
+    ///     .add_row(vec![Cell::new("-56").set_value(-56)]);
+    /// let column = table.column_mut(0).unwrap();
+    /// column.set_negative_format(NegativeFormat::Parentheses);
+    /// column.set_cell_alignment(CellAlignment::Right);
+    ///
+    /// let expected = "
+    /// +--------+
+    /// | 1,234  |
+    /// |--------|
+    /// |   (56) |
+    /// +--------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_negative_format(&mut self, format: NegativeFormat) -> &mut Self {
+        self.negative_format = format;
+
+        self
+    }
+
+    /// Get the format of negative numbers of this column.
+    pub fn negative_format(&self) -> NegativeFormat {
+        self.negative_format
+    }
+
+    /// Style negative numbers of this column, e.g. in red.
+    ///
+    /// The colors of the style win over the colors of the cell.
+    /// Numbers are cells with a numeric [typed value](crate::Cell::typed_value).
+    ///
+    /// ```
+    /// use super_table::{Color, Style, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec!["12"]).add_row(vec!["-3.5"]);
+    /// table
+    ///     .column_mut(0)
+    ///     .unwrap()
+    ///     .set_negative_style(Style::new().fg(Color::Red));
+    /// ```
+    #[cfg(feature = "tty")]
+    pub fn set_negative_style(&mut self, style: Style) -> &mut Self {
+        self.negative_style = Some(style);
+
+        self
+    }
+
+    /// Display negative numbers of this column like all other cells.
+    #[cfg(feature = "tty")]
+    pub fn remove_negative_style(&mut self) -> &mut Self {
+        self.negative_style = None;
+
+        self
+    }
+
+    /// Get the style of negative numbers of this column.
+    #[cfg(feature = "tty")]
+    pub fn negative_style(&self) -> Option<&Style> {
+        self.negative_style.as_ref()
+    }
+
+    /// Set how booleans of this column are displayed.
+    ///
+    /// This wins over the [format of the table](crate::Table::set_bool_format).
+    /// See [BoolFormat] for details.
+    ///
+    /// ```
+    /// use super_table::{BoolFormat, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec!["true", "false"]);
+    /// table
+    ///     .column_mut(1)
+    ///     .unwrap()
+    ///     .set_bool_format(BoolFormat::yes_no());
+    ///
+    /// assert_eq!(table.lines().nth(1).unwrap(), "| true | no |");
+    /// ```
+    pub fn set_bool_format(&mut self, format: BoolFormat) -> &mut Self {
+        self.bool_format = Some(format);
+
+        self
+    }
+
+    /// Remove the boolean format of this column.
+    pub fn remove_bool_format(&mut self) -> &mut Self {
+        self.bool_format = None;
+
+        self
+    }
+
+    /// Get the boolean format of this column.
+    pub fn bool_format(&self) -> Option<&BoolFormat> {
+        self.bool_format.as_ref()
+    }
+
+    /// Color the cells of this column by their exact content.
+    ///
+    /// This is useful for columns with a few distinct values, e.g. the status of a job.
+    /// The colors are only applied when the table is rendered, so exports keep the plain text.
+    /// Cells with their own [foreground color](crate::Cell::fg) keep it.
+    /// The header isn't colored.
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use super_table::{Color, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Job", "Status"])
+    ///     .add_row(vec!["backup", "running"])
+    ///     .add_row(vec!["cleanup", "failed"]);
+    /// table.column_mut(1).unwrap().set_category_colors(HashMap::from([
+    ///     ("running".to_string(), Color::Green),
+    ///     ("failed".to_string(), Color::Red),
+    ///     ("pending".to_string(), Color::Yellow),
+    /// ]));
+    /// ```
+    #[cfg(feature = "tty")]
+    pub fn set_category_colors(&mut self, colors: HashMap<String, Color>) -> &mut Self {
+        self.category_colors = colors;
+
+        self
+    }
+
+    /// Get the colors of the categories of this column.
+    #[cfg(feature = "tty")]
+    pub fn category_colors(&self) -> &HashMap<String, Color> {
+        &self.category_colors
+    }
+
+    /// Compare the cells of this column to the cell of a baseline row.
+    ///
+    /// When the table is rendered, cells that equal the cell of the baseline row are dimmed and
+    /// cells that deviate from it are colored yellow.
+    /// This is useful to compare benchmark results against a reference run.
+    /// The baseline cell itself, the header and cells with their own
+    /// [foreground color](crate::Cell::fg) keep their colors.
+    ///
+    /// `row_index` is the index of the row without the header.
+    /// Nothing is highlighted, if there's no cell in this column of the baseline row.
+    ///
+    /// ```
+    /// use super_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Run", "Time"])
+    ///     .add_row(vec!["main", "1.20s"])
+    ///     .add_row(vec!["feature", "1.20s"])
+    ///     .add_row(vec!["fix", "0.98s"]);
+    /// table.column_mut(1).unwrap().set_baseline_row(0);
+    /// ```
+    #[cfg(feature = "tty")]
+    pub fn set_baseline_row(&mut self, row_index: usize) -> &mut Self {
+        self.baseline_row = Some(row_index);
+
+        self
+    }
+
+    /// Stop comparing the cells of this column to a baseline row.
+    #[cfg(feature = "tty")]
+    pub fn remove_baseline_row(&mut self) -> &mut Self {
+        self.baseline_row = None;
+
+        self
+    }
+
+    /// Get the index of the baseline row of this column.
+    #[cfg(feature = "tty")]
+    pub fn baseline_row(&self) -> Option<usize> {
+        self.baseline_row
+    }
+
+    /// Get the type of data in this column.
+    ///
+    /// This is `None`, unless it has been determined via
+    /// [Table::infer_column_types](crate::Table::infer_column_types).
+    pub fn column_type(&self) -> Option<ColumnType> {
+        self.column_type
+    }
 }
 
 #[cfg(test)]
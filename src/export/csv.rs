@@ -0,0 +1,268 @@
+use super::{LoadError, SpanPolicy, TableData};
+use crate::table::Table;
+use crate::utils::describe_column;
+
+/// Options for exporting a table as CSV via [Table::to_csv].
+///
+/// ```
+/// use super_table::{CsvWriteOptions, SpanPolicy};
+///
+/// let options = CsvWriteOptions::new()
+///     .delimiter(';')
+///     .include_header(false)
+///     .span_policy(SpanPolicy::RepeatValue);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CsvWriteOptions {
+    delimiter: char,
+    include_header: bool,
+    span_policy: SpanPolicy,
+    description_comments: bool,
+}
+
+impl Default for CsvWriteOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            include_header: true,
+            span_policy: SpanPolicy::BlankFill,
+            description_comments: false,
+        }
+    }
+}
+
+impl CsvWriteOptions {
+    /// Create options that write comma separated values, including the header.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the character that separates the fields of a record. Default is `,`.
+    #[must_use]
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+
+        self
+    }
+
+    /// Specify whether the header of the table is written as the first record. Default is `true`.
+    #[must_use]
+    pub fn include_header(mut self, include_header: bool) -> Self {
+        self.include_header = include_header;
+
+        self
+    }
+
+    /// Specify how positions that are covered by spanning cells are written.
+    /// Default is [SpanPolicy::BlankFill].
+    ///
+    /// CSV has no concept of spans, so [SpanPolicy::Preserve] is written like the default.
+    #[must_use]
+    pub fn span_policy(mut self, span_policy: SpanPolicy) -> Self {
+        self.span_policy = span_policy;
+
+        self
+    }
+
+    /// Write the [descriptions of columns](crate::Column::set_description) as comment lines
+    /// starting with `#`, before any record. Default is `false`.
+    ///
+    /// Not all CSV readers support comments, so make sure yours does before enabling this.
+    #[must_use]
+    pub fn description_comments(mut self, description_comments: bool) -> Self {
+        self.description_comments = description_comments;
+
+        self
+    }
+}
+
+pub(crate) fn to_csv(table: &Table, options: &CsvWriteOptions) -> String {
+    let mut output = String::new();
+    if options.description_comments {
+        for description in table
+            .columns
+            .iter()
+            .filter_map(|column| describe_column(table, column))
+        {
+            for line in description.lines() {
+                output += "# ";
+                output += line;
+                output += "\n";
+            }
+        }
+    }
+
+    let mut data = TableData::from_table(table, options.span_policy);
+    if !options.include_header {
+        data.header = None;
+    }
+
+    let delimiter = options.delimiter.to_string();
+    for record in data.records() {
+        let fields: Vec<String> = record
+            .iter()
+            .map(|field| quote(field, options.delimiter))
+            .collect();
+        // A record with a single empty field would be an empty line, which most readers skip.
+        if fields.len() == 1 && fields[0].is_empty() {
+            output += "\"\"";
+        } else {
+            output += &fields.join(&delimiter);
+        }
+        output += "\n";
+    }
+
+    output
+}
+
+/// Options for loading comma separated values via [Table::from_csv].
+///
+/// The defaults match the defaults of [CsvWriteOptions], so tables written with default options
+/// are loaded as they were written.
+///
+/// ```
+/// use super_table::CsvReadOptions;
+///
+/// let options = CsvReadOptions::new()
+///     .delimiter(';')
+///     .has_header(false)
+///     .skip_comments(true);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CsvReadOptions {
+    delimiter: char,
+    has_header: bool,
+    skip_comments: bool,
+}
+
+impl Default for CsvReadOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            has_header: true,
+            skip_comments: false,
+        }
+    }
+}
+
+impl CsvReadOptions {
+    /// Create options that read comma separated values, whose first record is the header.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the character that separates the fields of a record. Default is `,`.
+    #[must_use]
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+
+        self
+    }
+
+    /// Specify whether the first record is the header of the table. Default is `true`.
+    #[must_use]
+    pub fn has_header(mut self, has_header: bool) -> Self {
+        self.has_header = has_header;
+
+        self
+    }
+
+    /// Skip lines starting with `#`, like the ones written by
+    /// [CsvWriteOptions::description_comments]. Default is `false`.
+    #[must_use]
+    pub fn skip_comments(mut self, skip_comments: bool) -> Self {
+        self.skip_comments = skip_comments;
+
+        self
+    }
+}
+
+pub(crate) fn from_csv(input: &str, options: &CsvReadOptions) -> Result<Table, LoadError> {
+    let records = parse_records(input, options)?;
+    Ok(TableData::from_records(records, options.has_header).into_table())
+}
+
+/// Split the input into records of fields as described in RFC 4180.
+///
+/// Records are terminated by `\n` or `\r\n`. An empty line is a record without any fields,
+/// which is how records of rows without any cells are written.
+fn parse_records(input: &str, options: &CsvReadOptions) -> Result<Vec<Vec<String>>, LoadError> {
+    let mut records = Vec::new();
+    let mut chars = input.chars().peekable();
+    let mut line = 1;
+
+    while chars.peek().is_some() {
+        if options.skip_comments && chars.peek() == Some(&'#') {
+            for char in chars.by_ref() {
+                if char == '\n' {
+                    break;
+                }
+            }
+            line += 1;
+            continue;
+        }
+
+        let mut record = Vec::new();
+        let mut field = String::new();
+        let mut quoted = false;
+        loop {
+            match chars.next() {
+                // A quote at the start of a field starts a quoted field.
+                Some('"') if field.is_empty() && !quoted => {
+                    let start_line = line;
+                    quoted = true;
+                    loop {
+                        match chars.next() {
+                            Some('"') if chars.peek() == Some(&'"') => {
+                                chars.next();
+                                field.push('"');
+                            }
+                            Some('"') => break,
+                            Some(char) => {
+                                if char == '\n' {
+                                    line += 1;
+                                }
+                                field.push(char);
+                            }
+                            None => return Err(LoadError::UnclosedQuote { line: start_line }),
+                        }
+                    }
+                    if !matches!(chars.peek(), None | Some('\n' | '\r'))
+                        && chars.peek() != Some(&options.delimiter)
+                    {
+                        return Err(LoadError::UnexpectedCharacter { line });
+                    }
+                }
+                Some(char) if char == options.delimiter => {
+                    record.push(std::mem::take(&mut field));
+                    quoted = false;
+                }
+                Some('\r') if chars.peek() == Some(&'\n') => {}
+                Some('\n') | None => {
+                    // Only an empty line has no fields at all.
+                    if !record.is_empty() || !field.is_empty() || quoted {
+                        record.push(field);
+                    }
+                    line += 1;
+                    break;
+                }
+                Some(char) => field.push(char),
+            }
+        }
+        records.push(record);
+    }
+
+    Ok(records)
+}
+
+/// Quote a field as described in RFC 4180, if it contains any special characters.
+fn quote(field: &str, delimiter: char) -> String {
+    let needs_quotes = field
+        .chars()
+        .any(|char| char == delimiter || matches!(char, '"' | '\n' | '\r'));
+    if needs_quotes {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
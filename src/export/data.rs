@@ -0,0 +1,154 @@
+use std::borrow::Cow;
+
+use super::{SpanPolicy, content_grid};
+use crate::row::Row;
+use crate::style::ExportContent;
+use crate::table::Table;
+use crate::utils::booleans::format_booleans;
+use crate::utils::numbers::format_numbers;
+
+/// The plain content of a table, which is shared by the exporters and loaders of data formats.
+///
+/// Both directions go through this representation, so formats that can be loaded again
+/// always agree on what the data of a table is.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct TableData {
+    pub(crate) header: Option<Vec<String>>,
+    /// The rows of the table with one field for every column position.
+    pub(crate) rows: Vec<Vec<String>>,
+}
+
+impl TableData {
+    /// Collect the data of a table, filling positions covered by spans as specified.
+    pub(crate) fn from_table(table: &Table, policy: SpanPolicy) -> Self {
+        let mut rows = content_grid(table, policy).into_iter();
+        let header = if table.header.is_some() {
+            rows.next()
+        } else {
+            None
+        };
+
+        Self {
+            header,
+            rows: rows.collect(),
+        }
+    }
+
+    /// Split records into the header and the rows.
+    pub(crate) fn from_records(mut records: Vec<Vec<String>>, has_header: bool) -> Self {
+        let header = (has_header && !records.is_empty()).then(|| records.remove(0));
+
+        Self {
+            header,
+            rows: records,
+        }
+    }
+
+    /// The header, if there's any, followed by all rows.
+    pub(crate) fn records(&self) -> impl Iterator<Item = &Vec<String>> {
+        self.header.iter().chain(self.rows.iter())
+    }
+
+    /// Build a plain table with one cell per field.
+    pub(crate) fn into_table(self) -> Table {
+        let mut table = Table::new();
+        if let Some(header) = self.header {
+            table.set_header(header);
+        }
+        for row in self.rows {
+            table.add_row(row);
+        }
+
+        table
+    }
+}
+
+/// Specify how much of a table is restored, when it's exported to a data format and loaded
+/// again, as returned by [Table::round_trip].
+///
+/// Data formats are CSV and JSON, which can be loaded by [Table::from_csv] and
+/// [Table::from_json].
+/// Only the content of cells is exported, so styling and the configuration of columns are never
+/// restored.
+///
+/// ```
+/// use super_table::{Cell, MaskPolicy, RoundTrip, Table};
+///
+/// let mut table = Table::new();
+/// table.set_header(vec!["Name", "Age"]).add_row(vec!["Alice", "30"]);
+/// assert_eq!(table.round_trip(), RoundTrip::Lossless);
+///
+/// table.add_row(vec![Cell::new("Unknown").set_colspan(2)]);
+/// assert_eq!(table.round_trip(), RoundTrip::SpansExpanded);
+///
+/// table.column_mut(1).unwrap().set_mask(MaskPolicy::Full);
+/// assert_eq!(table.round_trip(), RoundTrip::ContentChanged);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RoundTrip {
+    /// The header and the content of every column position are restored exactly.
+    ///
+    /// Cells that are missing at the end of a row are restored as empty cells.
+    Lossless,
+    /// The table has spanning cells, which are written as separate fields.
+    /// The content is restored, but positions covered by a span become cells of their own,
+    /// which are filled as specified by the [SpanPolicy] of the exporter.
+    SpansExpanded,
+    /// The exported values differ from the content of cells.
+    /// Masked columns are restored with their masked content, formatted numbers and booleans
+    /// with their [formatted](ExportContent::Formatted) text and cells filled from a template
+    /// with their filled text, but without the template.
+    ContentChanged,
+    /// The content is exported as it's displayed, see [ExportContent::Rendered].
+    /// Content that has been truncated is restored in its truncated form.
+    Rendered,
+}
+
+pub(crate) fn round_trip(table: &Table) -> RoundTrip {
+    if table.export_content == ExportContent::Rendered {
+        return RoundTrip::Rendered;
+    }
+    if changes_content(table) {
+        return RoundTrip::ContentChanged;
+    }
+
+    let has_spans = table
+        .header
+        .iter()
+        .chain(table.rows.iter())
+        .flat_map(Row::cell_iter)
+        .any(|cell| cell.colspan() > 1 || cell.rowspan() > 1 || cell.spans_rest());
+    if has_spans {
+        RoundTrip::SpansExpanded
+    } else {
+        RoundTrip::Lossless
+    }
+}
+
+/// Check whether masks, templates or formats change the exported values of a table.
+fn changes_content(table: &Table) -> bool {
+    let has_masks = table.columns.iter().any(|column| column.mask().is_some());
+    let has_templates = table
+        .header
+        .iter()
+        .chain(table.rows.iter())
+        .flat_map(Row::cell_iter)
+        .any(|cell| cell.template.is_some());
+    if has_masks || has_templates {
+        return true;
+    }
+    if table.export_content != ExportContent::Formatted {
+        return false;
+    }
+
+    let mut formatted = Cow::Borrowed(table);
+    format_numbers(&mut formatted, false);
+    format_booleans(&mut formatted);
+    formatted
+        .rows
+        .iter()
+        .flat_map(Row::cell_iter)
+        .zip(table.rows.iter().flat_map(Row::cell_iter))
+        .any(|(formatted, cell)| formatted.content != cell.content)
+}
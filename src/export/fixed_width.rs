@@ -0,0 +1,144 @@
+use super::{SpanPolicy, content_grid, pad};
+use crate::style::CellAlignment;
+use crate::table::Table;
+use crate::utils::formatting::content_split::measure_text_width;
+
+/// Options for exporting a table as fixed-width text via [Table::to_fixed_width].
+///
+/// ```
+/// use super_table::FixedWidthOptions;
+///
+/// let options = FixedWidthOptions::new().column_gap(2).ruler(true);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixedWidthOptions {
+    column_gap: usize,
+    include_header: bool,
+    ruler: bool,
+    span_policy: SpanPolicy,
+}
+
+impl Default for FixedWidthOptions {
+    fn default() -> Self {
+        Self {
+            column_gap: 1,
+            include_header: true,
+            ruler: false,
+            span_policy: SpanPolicy::BlankFill,
+        }
+    }
+}
+
+impl FixedWidthOptions {
+    /// Create options that separate columns by a single space and include the header.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the amount of spaces between two columns. Default is `1`.
+    #[must_use]
+    pub fn column_gap(mut self, column_gap: usize) -> Self {
+        self.column_gap = column_gap;
+
+        self
+    }
+
+    /// Specify whether the header of the table is written as the first record. Default is `true`.
+    #[must_use]
+    pub fn include_header(mut self, include_header: bool) -> Self {
+        self.include_header = include_header;
+
+        self
+    }
+
+    /// Write a ruler like `----+----1----+----2` above all records, which marks every fifth
+    /// and tenth position of a line. Default is `false`.
+    #[must_use]
+    pub fn ruler(mut self, ruler: bool) -> Self {
+        self.ruler = ruler;
+
+        self
+    }
+
+    /// Specify how positions that are covered by spanning cells are written.
+    /// Default is [SpanPolicy::BlankFill].
+    ///
+    /// Fixed-width records have no concept of spans, so [SpanPolicy::Preserve] is written like
+    /// the default.
+    #[must_use]
+    pub fn span_policy(mut self, span_policy: SpanPolicy) -> Self {
+        self.span_policy = span_policy;
+
+        self
+    }
+}
+
+pub(crate) fn to_fixed_width(table: &Table, options: &FixedWidthOptions) -> String {
+    let mut records: Vec<Vec<String>> = content_grid(table, options.span_policy)
+        .into_iter()
+        .map(|record| record.iter().map(|field| single_line(field)).collect())
+        .collect();
+    if table.header.is_some() && !options.include_header && !records.is_empty() {
+        records.remove(0);
+    }
+    let column_count = records.iter().map(Vec::len).max().unwrap_or(0);
+    if column_count == 0 {
+        return String::new();
+    }
+
+    let mut widths = vec![0; column_count];
+    for record in records.iter() {
+        for (width, field) in widths.iter_mut().zip(record) {
+            *width = (*width).max(measure_text_width(field, table.ambiguous_width));
+        }
+    }
+
+    let gap = " ".repeat(options.column_gap);
+    let mut output = String::new();
+    if options.ruler {
+        let record_width = widths.iter().sum::<usize>() + options.column_gap * (column_count - 1);
+        output += &ruler(record_width);
+        output += "\n";
+    }
+    for record in records.iter() {
+        // All fields are padded, so each record has the same length.
+        let fields: Vec<String> = widths
+            .iter()
+            .enumerate()
+            .map(|(index, width)| {
+                let field = record.get(index).map(String::as_str).unwrap_or_default();
+                let alignment = table
+                    .columns
+                    .get(index)
+                    .and_then(|column| column.cell_alignment)
+                    .unwrap_or(CellAlignment::Left);
+                pad(field, *width, alignment, table.ambiguous_width)
+            })
+            .collect();
+        output += &fields.join(&gap);
+        output += "\n";
+    }
+
+    output
+}
+
+/// Records of fixed-width text can't contain line breaks, so lines are joined by spaces.
+fn single_line(field: &str) -> String {
+    field.lines().collect::<Vec<_>>().join(" ")
+}
+
+/// A ruler with the given width, which marks every fifth position with a `+` and every tenth
+/// position with the last digit of its tens.
+fn ruler(width: usize) -> String {
+    (1..=width)
+        .map(|position| {
+            if position % 10 == 0 {
+                char::from_digit(((position / 10) % 10) as u32, 10).unwrap_or('0')
+            } else if position % 5 == 0 {
+                '+'
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
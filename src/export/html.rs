@@ -0,0 +1,177 @@
+use std::borrow::Cow;
+
+use super::{SpanPolicy, cell_content, emphasize, flatten_spans, rows};
+use crate::style::{CellAlignment, CellRole};
+use crate::table::Table;
+
+/// Options for exporting a table as HTML via [Table::to_html].
+///
+/// ```
+/// use super_table::{HtmlOptions, SpanPolicy};
+///
+/// let options = HtmlOptions::new().span_policy(SpanPolicy::RepeatValue);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HtmlOptions {
+    span_policy: SpanPolicy,
+}
+
+impl Default for HtmlOptions {
+    fn default() -> Self {
+        Self {
+            span_policy: SpanPolicy::Preserve,
+        }
+    }
+}
+
+impl HtmlOptions {
+    /// Create options that write spanning cells with `colspan` and `rowspan` attributes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Specify how spanning cells are written. Default is [SpanPolicy::Preserve].
+    ///
+    /// Otherwise, each position covered by a span is written as a cell of its own, which has
+    /// the same attributes as the spanning cell.
+    #[must_use]
+    pub fn span_policy(mut self, span_policy: SpanPolicy) -> Self {
+        self.span_policy = span_policy;
+
+        self
+    }
+}
+
+pub(crate) fn to_html(table: &Table, options: &HtmlOptions) -> String {
+    let (table, content) = match (cell_content(table), options.span_policy) {
+        (cells, SpanPolicy::Preserve) => cells,
+        ((table, content), policy) => {
+            let (table, content) = flatten_spans(&table, content, policy);
+            (Cow::Owned(table), content)
+        }
+    };
+    let mut output = String::from("<table>\n");
+
+    // The remaining rows of rowspans from rows above, for each column position.
+    let mut rowspans: Vec<u16> = vec![0; table.columns.len()];
+    let mut in_body = false;
+    let mut in_footer = false;
+    let footer_start = table.footer_start();
+    for (index, (row, contents)) in rows(&table).zip(content).enumerate() {
+        let is_header = index == 0 && table.header.is_some();
+        if is_header {
+            output += "  <thead>\n";
+        } else if index >= footer_start {
+            if !in_footer {
+                if in_body {
+                    output += "  </tbody>\n";
+                    in_body = false;
+                }
+                output += "  <tfoot>\n";
+                in_footer = true;
+            }
+        } else if !in_body {
+            output += "  <tbody>\n";
+            in_body = true;
+        }
+
+        // Positions that are covered by rowspans from rows above are skipped.
+        let occupied: Vec<bool> = rowspans.iter().map(|remaining| *remaining > 0).collect();
+        for remaining in rowspans.iter_mut() {
+            *remaining = remaining.saturating_sub(1);
+        }
+
+        output += "    <tr>";
+        let mut position = 0;
+        for (cell, content) in row.cells.iter().zip(contents) {
+            while occupied.get(position).copied().unwrap_or(false) {
+                position += 1;
+            }
+
+            let default_role = if is_header {
+                CellRole::Header
+            } else {
+                CellRole::Data
+            };
+            let tag = match cell.role.unwrap_or(default_role) {
+                CellRole::Header => "th",
+                _ => "td",
+            };
+            output += &format!("<{tag}");
+            if tag == "th" && !is_header {
+                output += " scope=\"row\"";
+            }
+            if cell.colspan() > 1 {
+                output += &format!(" colspan=\"{}\"", cell.colspan());
+            }
+            if cell.rowspan() > 1 {
+                output += &format!(" rowspan=\"{}\"", cell.rowspan());
+            }
+            // Descriptions of columns are shown as tooltips of their header.
+            if let Some(description) = table
+                .columns
+                .get(position)
+                .and_then(|column| column.description())
+                .filter(|_| is_header)
+            {
+                output += &format!(" title=\"{}\"", escape(description));
+            }
+            let alignment = cell
+                .alignment
+                .or_else(|| {
+                    table
+                        .columns
+                        .get(position)
+                        .and_then(|column| column.cell_alignment)
+                })
+                .or(row.cell_alignment);
+            if let Some(class) = cell
+                .style_name()
+                .and_then(|name| table.style_map.as_ref()?.html_class(name))
+            {
+                output += &format!(" class=\"{}\"", escape(class));
+            }
+            match alignment {
+                Some(CellAlignment::Center) => output += " style=\"text-align: center\"",
+                Some(CellAlignment::Right) => output += " style=\"text-align: right\"",
+                Some(CellAlignment::Left) | None => (),
+            }
+            let content = escape(&content).replace('\n', "<br>");
+            let content = emphasize(cell, content, ["<strong>", "</strong>"], ["<em>", "</em>"]);
+            output += &format!(">{content}</{tag}>");
+
+            let colspan = usize::from(cell.colspan()).max(1);
+            if cell.rowspan() > 1 {
+                if rowspans.len() < position + colspan {
+                    rowspans.resize(position + colspan, 0);
+                }
+                for remaining in &mut rowspans[position..position + colspan] {
+                    *remaining = cell.rowspan() - 1;
+                }
+            }
+            position += colspan;
+        }
+        output += "</tr>\n";
+
+        if is_header {
+            output += "  </thead>\n";
+        }
+    }
+    if in_body {
+        output += "  </tbody>\n";
+    }
+    if in_footer {
+        output += "  </tfoot>\n";
+    }
+    output += "</table>\n";
+
+    output
+}
+
+/// Replace all characters that have a special meaning in HTML.
+pub(crate) fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
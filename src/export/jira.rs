@@ -0,0 +1,44 @@
+use super::{SpanPolicy, content_grid};
+use crate::table::Table;
+
+pub(crate) fn to_jira(table: &Table) -> String {
+    let mut output = String::new();
+    for (index, record) in content_grid(table, SpanPolicy::BlankFill)
+        .into_iter()
+        .enumerate()
+    {
+        if record.is_empty() {
+            continue;
+        }
+
+        // Header cells are enclosed by double pipes.
+        let separator = if index == 0 && table.header.is_some() {
+            "||"
+        } else {
+            "|"
+        };
+        output += separator;
+        for field in record {
+            output += &escape(&field);
+            output += separator;
+        }
+        output += "\n";
+    }
+
+    output
+}
+
+/// Pipes are escaped and line breaks are replaced by the `\\` line break markup.
+///
+/// Empty cells would be merged with their neighbour, so they contain a single space instead.
+fn escape(field: &str) -> String {
+    if field.is_empty() {
+        return " ".to_string();
+    }
+
+    field
+        .lines()
+        .map(|line| line.replace('|', "\\|"))
+        .collect::<Vec<_>>()
+        .join("\\\\")
+}
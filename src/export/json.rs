@@ -0,0 +1,252 @@
+use std::iter::Peekable;
+use std::str::CharIndices;
+
+use super::{LoadError, SpanPolicy, TableData};
+use crate::table::Table;
+
+pub(crate) fn to_json(table: &Table) -> String {
+    let data = TableData::from_table(table, SpanPolicy::BlankFill);
+
+    let mut output = String::from("{\n");
+    if let Some(header) = &data.header {
+        output += "  \"header\": ";
+        output += &array(header);
+        output += ",\n";
+    }
+    if data.rows.is_empty() {
+        output += "  \"rows\": []\n";
+    } else {
+        output += "  \"rows\": [\n";
+        let rows: Vec<String> = data
+            .rows
+            .iter()
+            .map(|row| format!("    {}", array(row)))
+            .collect();
+        output += &rows.join(",\n");
+        output += "\n  ]\n";
+    }
+    output += "}\n";
+
+    output
+}
+
+/// Write the fields of a record as an array of strings.
+fn array(fields: &[String]) -> String {
+    let fields: Vec<String> = fields.iter().map(|field| string(field)).collect();
+    format!("[{}]", fields.join(", "))
+}
+
+/// Write a JSON string, escaping quotes, backslashes and control characters.
+fn string(field: &str) -> String {
+    let mut output = String::with_capacity(field.len() + 2);
+    output.push('"');
+    for char in field.chars() {
+        match char {
+            '"' => output += "\\\"",
+            '\\' => output += "\\\\",
+            '\n' => output += "\\n",
+            '\r' => output += "\\r",
+            '\t' => output += "\\t",
+            char if char.is_control() => output += &format!("\\u{:04x}", u32::from(char)),
+            char => output.push(char),
+        }
+    }
+    output.push('"');
+
+    output
+}
+
+pub(crate) fn from_json(input: &str) -> Result<Table, LoadError> {
+    let mut parser = Parser {
+        input,
+        chars: input.char_indices().peekable(),
+    };
+    let data = parser.table()?;
+    parser.skip_whitespace();
+    if parser.chars.peek().is_some() {
+        return Err(parser.error("unexpected content after the table"));
+    }
+
+    Ok(data.into_table())
+}
+
+/// A parser for the subset of JSON, that's needed to describe a table.
+///
+/// The table is an object with an optional `header` and `rows`, whose fields are strings.
+struct Parser<'a> {
+    input: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+}
+
+impl Parser<'_> {
+    fn table(&mut self) -> Result<TableData, LoadError> {
+        let mut data = TableData::default();
+        let mut has_rows = false;
+
+        self.expect('{')?;
+        if !self.next_is('}') {
+            loop {
+                match self.string()?.as_str() {
+                    "header" if data.header.is_none() => {
+                        self.expect(':')?;
+                        data.header = Some(self.array_of_strings()?);
+                    }
+                    "rows" if !has_rows => {
+                        self.expect(':')?;
+                        data.rows = self.rows()?;
+                        has_rows = true;
+                    }
+                    _ => return Err(self.error("expected a single `header` or `rows` key")),
+                }
+                if self.next_is('}') {
+                    break;
+                }
+                self.expect(',')?;
+            }
+        }
+
+        if has_rows {
+            Ok(data)
+        } else {
+            Err(self.error("the table has no `rows`"))
+        }
+    }
+
+    fn rows(&mut self) -> Result<Vec<Vec<String>>, LoadError> {
+        let mut rows = Vec::new();
+        self.expect('[')?;
+        if self.next_is(']') {
+            return Ok(rows);
+        }
+        loop {
+            rows.push(self.array_of_strings()?);
+            if self.next_is(']') {
+                return Ok(rows);
+            }
+            self.expect(',')?;
+        }
+    }
+
+    fn array_of_strings(&mut self) -> Result<Vec<String>, LoadError> {
+        let mut fields = Vec::new();
+        self.expect('[')?;
+        if self.next_is(']') {
+            return Ok(fields);
+        }
+        loop {
+            fields.push(self.string()?);
+            if self.next_is(']') {
+                return Ok(fields);
+            }
+            self.expect(',')?;
+        }
+    }
+
+    fn string(&mut self) -> Result<String, LoadError> {
+        self.expect('"')?;
+        let mut string = String::new();
+        loop {
+            match self.chars.next() {
+                Some((_, '"')) => return Ok(string),
+                Some((_, '\\')) => {
+                    let char = match self.chars.next() {
+                        Some((_, '"')) => '"',
+                        Some((_, '\\')) => '\\',
+                        Some((_, '/')) => '/',
+                        Some((_, 'b')) => '\u{8}',
+                        Some((_, 'f')) => '\u{c}',
+                        Some((_, 'n')) => '\n',
+                        Some((_, 'r')) => '\r',
+                        Some((_, 't')) => '\t',
+                        Some((_, 'u')) => self.unicode_escape()?,
+                        _ => return Err(self.error("invalid escape sequence")),
+                    };
+                    string.push(char);
+                }
+                Some((_, char)) if char < ' ' => {
+                    return Err(self.error("unescaped control character in a string"));
+                }
+                Some((_, char)) => string.push(char),
+                None => return Err(self.error("unclosed string")),
+            }
+        }
+    }
+
+    /// Parse the digits of a `\u` escape, including the second half of a surrogate pair.
+    fn unicode_escape(&mut self) -> Result<char, LoadError> {
+        let high = self.hex_digits()?;
+        if !(0xD800..0xDC00).contains(&high) {
+            return char::from_u32(high).ok_or_else(|| self.error("invalid unicode escape"));
+        }
+
+        if self.chars.next().map(|(_, char)| char) != Some('\\')
+            || self.chars.next().map(|(_, char)| char) != Some('u')
+        {
+            return Err(self.error("unpaired surrogate"));
+        }
+        let low = self.hex_digits()?;
+        if !(0xDC00..0xE000).contains(&low) {
+            return Err(self.error("unpaired surrogate"));
+        }
+        char::from_u32(0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00))
+            .ok_or_else(|| self.error("invalid unicode escape"))
+    }
+
+    fn hex_digits(&mut self) -> Result<u32, LoadError> {
+        let mut value = 0;
+        for _ in 0..4 {
+            let digit = self
+                .chars
+                .next()
+                .and_then(|(_, char)| char.to_digit(16))
+                .ok_or_else(|| self.error("invalid unicode escape"))?;
+            value = value * 16 + digit;
+        }
+
+        Ok(value)
+    }
+
+    /// Consume the next character, if it's the given one, after skipping whitespace.
+    fn next_is(&mut self, expected: char) -> bool {
+        self.skip_whitespace();
+        let matches = self.chars.peek().is_some_and(|(_, char)| *char == expected);
+        if matches {
+            self.chars.next();
+        }
+
+        matches
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), LoadError> {
+        if self.next_is(expected) {
+            Ok(())
+        } else {
+            Err(self.error(match expected {
+                '{' => "expected an object",
+                '[' => "expected an array",
+                '"' => "expected a string",
+                ':' => "expected `:`",
+                _ => "expected `,` or the end of the array or object",
+            }))
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self
+            .chars
+            .peek()
+            .is_some_and(|(_, char)| matches!(char, ' ' | '\t' | '\n' | '\r'))
+        {
+            self.chars.next();
+        }
+    }
+
+    /// An error at the current position of the parser.
+    fn error(&mut self, reason: &'static str) -> LoadError {
+        let offset = self
+            .chars
+            .peek()
+            .map_or(self.input.len(), |(offset, _)| *offset);
+        LoadError::InvalidJson { offset, reason }
+    }
+}
@@ -0,0 +1,78 @@
+use crate::table::Table;
+
+/// Specify how lines of raw text are split into cells, see [Table::from_lines].
+///
+/// The content of each cell is trimmed.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum SplitPolicy {
+    /// Split at every run of whitespace, like `column -t` does.
+    Whitespace,
+    /// Split at every occurrence of a character.
+    Delimiter(char),
+    /// Split at every match of a regular expression.
+    #[cfg(feature = "regex")]
+    Regex(regex::Regex),
+    /// Split into columns with the given widths in characters, e.g. for output that's aligned
+    /// with spaces. The last column contains the rest of the line.
+    FixedWidths(Vec<usize>),
+}
+
+impl SplitPolicy {
+    /// Split a single line into the content of its cells.
+    fn split(&self, line: &str) -> Vec<String> {
+        let fields: Vec<&str> = match self {
+            SplitPolicy::Whitespace => line.split_whitespace().collect(),
+            SplitPolicy::Delimiter(delimiter) => line.split(*delimiter).collect(),
+            #[cfg(feature = "regex")]
+            SplitPolicy::Regex(regex) => regex.split(line).collect(),
+            SplitPolicy::FixedWidths(widths) => split_fixed_widths(line, widths),
+        };
+
+        fields
+            .into_iter()
+            .map(|field| field.trim().to_string())
+            .collect()
+    }
+}
+
+/// Split a line after the given amount of characters for each column.
+///
+/// Columns that start after the end of the line are left out.
+fn split_fixed_widths<'a>(line: &'a str, widths: &[usize]) -> Vec<&'a str> {
+    let mut fields = Vec::with_capacity(widths.len() + 1);
+    let mut rest = line;
+    for width in widths {
+        if rest.is_empty() {
+            return fields;
+        }
+        let end = rest
+            .char_indices()
+            .nth(*width)
+            .map_or(rest.len(), |(index, _)| index);
+        fields.push(&rest[..end]);
+        rest = &rest[end..];
+    }
+    if !rest.is_empty() {
+        fields.push(rest);
+    }
+
+    fields
+}
+
+pub(crate) fn from_lines<I>(lines: I, policy: &SplitPolicy) -> Table
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut table = Table::new();
+    for line in lines {
+        let line = line.as_ref();
+        if line.trim().is_empty() {
+            continue;
+        }
+        table.add_row(policy.split(line));
+    }
+
+    table
+}
@@ -0,0 +1,92 @@
+use super::{SpanPolicy, cell_content, emphasize, expand_spans, pad};
+use crate::style::CellAlignment;
+use crate::table::Table;
+use crate::utils::formatting::content_split::measure_text_width;
+
+pub(crate) fn to_markdown(table: &Table) -> String {
+    let (table, content) = cell_content(table);
+    let mut records: Vec<Vec<String>> = expand_spans(&table, content, SpanPolicy::BlankFill)
+        .into_iter()
+        .map(|fields| {
+            fields
+                .into_iter()
+                .map(|field| match field {
+                    Some((cell, content)) => {
+                        emphasize(&cell, escape(&content), ["**", "**"], ["*", "*"])
+                    }
+                    None => String::new(),
+                })
+                .collect()
+        })
+        .collect();
+    let column_count = records.iter().map(Vec::len).max().unwrap_or(0);
+    if column_count == 0 {
+        return String::new();
+    }
+    // Markdown tables can't be written without a header, so an empty one is used instead.
+    if table.header.is_none() {
+        records.insert(0, Vec::new());
+    }
+
+    let alignments: Vec<Option<CellAlignment>> = (0..column_count)
+        .map(|index| {
+            table
+                .columns
+                .get(index)
+                .and_then(|column| column.cell_alignment)
+        })
+        .collect();
+
+    // The delimiter row needs at least three dashes per column.
+    let mut widths = vec![3; column_count];
+    for record in records.iter() {
+        for (width, field) in widths.iter_mut().zip(record) {
+            *width = (*width).max(measure_text_width(field, table.ambiguous_width));
+        }
+    }
+
+    let draw_record = |record: &[String]| {
+        let fields: Vec<String> = widths
+            .iter()
+            .enumerate()
+            .map(|(index, width)| {
+                let field = record.get(index).map(String::as_str).unwrap_or_default();
+                let alignment = alignments[index].unwrap_or(CellAlignment::Left);
+                pad(field, *width, alignment, table.ambiguous_width)
+            })
+            .collect();
+        format!("| {} |", fields.join(" | "))
+    };
+
+    let delimiters: Vec<String> = widths
+        .iter()
+        .zip(alignments.iter())
+        .map(|(width, alignment)| match alignment {
+            None => "-".repeat(*width),
+            Some(CellAlignment::Left) => format!(":{}", "-".repeat(width - 1)),
+            Some(CellAlignment::Center) => format!(":{}:", "-".repeat(width - 2)),
+            Some(CellAlignment::Right) => format!("{}:", "-".repeat(width - 1)),
+        })
+        .collect();
+
+    let mut records = records.iter();
+    let mut lines = Vec::new();
+    if let Some(header) = records.next() {
+        lines.push(draw_record(header));
+    }
+    lines.push(format!("| {} |", delimiters.join(" | ")));
+    for record in records {
+        lines.push(draw_record(record));
+    }
+
+    lines.join("\n") + "\n"
+}
+
+/// Pipes would end a cell and line breaks would end the row, so both are replaced.
+fn escape(field: &str) -> String {
+    field
+        .lines()
+        .map(|line| line.replace('|', "\\|"))
+        .collect::<Vec<_>>()
+        .join("<br>")
+}
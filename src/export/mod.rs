@@ -0,0 +1,333 @@
+//! Conversion of tables into other formats.
+//!
+//! All exporters work on a grid of plain strings with one entry per column position,
+//! which is built by [content_grid].
+//! Data formats, that can be loaded again, share the [TableData] built from this grid.
+mod csv;
+mod data;
+mod fixed_width;
+mod html;
+mod jira;
+mod json;
+mod lines;
+mod markdown;
+mod org;
+
+use std::borrow::Cow;
+use std::fmt;
+
+pub use csv::{CsvReadOptions, CsvWriteOptions};
+pub(crate) use csv::{from_csv, to_csv};
+pub use data::RoundTrip;
+pub(crate) use data::{TableData, round_trip};
+pub use fixed_width::FixedWidthOptions;
+pub(crate) use fixed_width::to_fixed_width;
+pub use html::HtmlOptions;
+pub(crate) use html::{escape as escape_html, to_html};
+pub(crate) use jira::to_jira;
+pub(crate) use json::{from_json, to_json};
+pub use lines::SplitPolicy;
+pub(crate) use lines::from_lines;
+pub(crate) use markdown::to_markdown;
+pub(crate) use org::to_org;
+
+#[cfg(feature = "tty")]
+use crate::Attribute;
+use crate::cell::Cell;
+use crate::row::Row;
+use crate::style::{AmbiguousWidth, CellAlignment, ExportContent};
+use crate::table::Table;
+use crate::utils::arrangement::arrange_content;
+use crate::utils::booleans::format_booleans;
+use crate::utils::formatting::content_format::rendered_content;
+use crate::utils::formatting::content_split::measure_text_width;
+use crate::utils::numbers::{cell_positions, format_numbers};
+use crate::utils::prepare_table;
+use crate::utils::spanning::resolve_spans;
+use crate::utils::templates::fill_templates;
+
+/// Specify how spanning cells are exported.
+///
+/// Each exporter with options has its own policy, whose default suits its format:
+/// [HtmlOptions] preserve spans, while [CsvWriteOptions] and [FixedWidthOptions] fill the
+/// covered positions with blanks. Formats like CSV have no concept of spans, so each covered
+/// position needs a value. They export [SpanPolicy::Preserve] like [SpanPolicy::BlankFill].
+/// Exporters without options, like [Table::to_markdown], always fill with blanks.
+///
+/// ```
+/// use super_table::{Cell, CsvWriteOptions, SpanPolicy, Table};
+///
+/// let mut table = Table::new();
+/// table.add_row(vec![Cell::new("Total").set_colspan(2)]);
+///
+/// assert_eq!(table.to_csv(CsvWriteOptions::new()), "Total,\n");
+///
+/// let options = CsvWriteOptions::new().span_policy(SpanPolicy::RepeatValue);
+/// assert_eq!(table.to_csv(options), "Total,Total\n");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SpanPolicy {
+    /// Spanning cells are exported as a single cell, that covers multiple positions.
+    Preserve,
+    /// The content of a spanning cell is repeated in every position it covers.
+    RepeatValue,
+    /// Only the first position of a span contains the content, all others are left empty.
+    BlankFill,
+}
+
+/// An error that prevents exported data from being loaded into a table.
+///
+/// Returned by [Table::from_csv] and [Table::from_json].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LoadError {
+    /// A quoted CSV field isn't closed until the end of the input.
+    UnclosedQuote { line: usize },
+    /// A quote of a CSV field is followed by something other than a delimiter or a line break.
+    UnexpectedCharacter { line: usize },
+    /// The input isn't a JSON table in the format written by [Table::to_json].
+    /// The offset is the position in bytes at which the problem was found.
+    InvalidJson { offset: usize, reason: &'static str },
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::UnclosedQuote { line } => {
+                write!(f, "the quoted field starting in line {line} isn't closed")
+            }
+            LoadError::UnexpectedCharacter { line } => {
+                write!(
+                    f,
+                    "unexpected character after a quoted field in line {line}"
+                )
+            }
+            LoadError::InvalidJson { offset, reason } => {
+                write!(f, "invalid JSON table at byte {offset}: {reason}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+impl SpanPolicy {
+    /// The content of a position, that's covered by a cell with the given content.
+    fn fill(self, content: &str) -> String {
+        match self {
+            SpanPolicy::Preserve | SpanPolicy::BlankFill => String::new(),
+            SpanPolicy::RepeatValue => content.to_string(),
+        }
+    }
+}
+
+/// Collect the content of a table row by row, starting with the header.
+///
+/// Each row contains one entry for every column position.
+/// Positions that are covered by colspans or rowspans are filled according to `policy`.
+pub(crate) fn content_grid(table: &Table, policy: SpanPolicy) -> Vec<Vec<String>> {
+    let (table, content) = cell_content(table);
+    expand_spans(&table, content, policy)
+        .into_iter()
+        .map(|fields| {
+            fields
+                .into_iter()
+                .map(|field| field.map(|(_, content)| content).unwrap_or_default())
+                .collect()
+        })
+        .collect()
+}
+
+/// Replace each spanning cell by cells without any span at all positions it covers.
+///
+/// The content of the new cells is filled according to `policy`, their styling is the one of
+/// the spanning cell. This is used by formats that support spans, if they shouldn't be preserved.
+pub(crate) fn flatten_spans(
+    table: &Table,
+    content: Vec<Vec<String>>,
+    policy: SpanPolicy,
+) -> (Table, Vec<Vec<String>>) {
+    let mut flattened_rows: Vec<Row> = Vec::new();
+    let mut contents = Vec::new();
+    for (row, fields) in rows(table).zip(expand_spans(table, content, policy)) {
+        let (cells, row_contents): (Vec<Cell>, Vec<String>) = fields
+            .into_iter()
+            .map(|field| field.unwrap_or_else(|| (Cell::new(""), String::new())))
+            .unzip();
+        let mut row = row.clone();
+        row.cells = cells;
+        flattened_rows.push(row);
+        contents.push(row_contents);
+    }
+
+    let header = table.header.is_some().then(|| flattened_rows.remove(0));
+    let mut flattened = table.with_rows(flattened_rows);
+    flattened.header = header;
+    (flattened, contents)
+}
+
+/// Collect the content of each cell row by row, starting with the header.
+///
+/// Other than [content_grid], each row contains one entry per cell.
+/// The returned table is the one the cells belong to, after spans have been resolved.
+pub(crate) fn cell_content(table: &Table) -> (Cow<'_, Table>, Vec<Vec<String>>) {
+    match table.export_content {
+        ExportContent::Original | ExportContent::Formatted => {
+            let formatted = table.export_content == ExportContent::Formatted;
+            let mut table = Cow::Borrowed(table);
+            fill_templates(&mut table);
+            resolve_spans(&mut table);
+            if formatted {
+                format_numbers(&mut table, false);
+                format_booleans(&mut table);
+            }
+            let mut content: Vec<Vec<String>> = rows(&table)
+                .map(|row| row.cells.iter().map(original_content).collect())
+                .collect();
+            mask_content(&table, &mut content);
+            (table, content)
+        }
+        ExportContent::Rendered => {
+            let table = prepare_table(table);
+            let display_infos = arrange_content(&table);
+            let content = rendered_content(&table, &display_infos);
+            (table, content)
+        }
+    }
+}
+
+fn rows(table: &Table) -> impl Iterator<Item = &Row> {
+    table.header.iter().chain(table.rows.iter())
+}
+
+/// The full content of a cell without any styling.
+fn original_content(cell: &Cell) -> String {
+    let content = cell.content();
+    #[cfg(feature = "custom_styling")]
+    let content = console::strip_ansi_codes(&content).to_string();
+
+    content
+}
+
+/// Apply the [masks](crate::Column::set_mask) of all columns to the content of their cells.
+///
+/// The content starts with the header, whose cells are never masked.
+fn mask_content(table: &Table, content: &mut [Vec<String>]) {
+    let header_rows = usize::from(table.header.is_some());
+    for column in table.columns.iter() {
+        let Some(mask) = column.mask() else {
+            continue;
+        };
+        for (row_index, cell_index) in cell_positions(table, column.index) {
+            let cell_content = &mut content[row_index + header_rows][cell_index];
+            *cell_content = mask.mask(cell_content);
+        }
+    }
+}
+
+/// A cell together with its content, placed at a column position.
+type Field = Option<(Cell, String)>;
+
+/// Place each cell and its content at the column position it's displayed at.
+///
+/// Positions that are covered by colspans or rowspans get a copy of the spanning cell without
+/// any span, whose content is filled according to `policy`.
+/// Positions without any cell are `None`.
+fn expand_spans(table: &Table, content: Vec<Vec<String>>, policy: SpanPolicy) -> Vec<Vec<Field>> {
+    // The remaining rows, the cell and the content of rowspans, that cover a column position.
+    let mut rowspans: Vec<Option<(u16, Cell, String)>> = vec![None; table.columns.len()];
+
+    rows(table)
+        .zip(content)
+        .map(|(row, contents)| {
+            let mut fields: Vec<Field> = vec![None; table.columns.len()];
+
+            // Positions that are occupied by rowspans from rows above.
+            for (position, rowspan) in rowspans.iter_mut().enumerate() {
+                if let Some((remaining, cell, content)) = rowspan {
+                    let field = (cell.clone(), policy.fill(content));
+                    set_field(&mut fields, position, field);
+                    *remaining -= 1;
+                    if *remaining == 0 {
+                        *rowspan = None;
+                    }
+                }
+            }
+
+            let mut position = 0;
+            for (cell, content) in row.cells.iter().zip(contents) {
+                while fields.get(position).is_some_and(Option::is_some) {
+                    position += 1;
+                }
+
+                let colspan = usize::from(cell.colspan()).max(1);
+                let covering = cell.clone().set_colspan(1).set_rowspan(1);
+                for covered in position + 1..position + colspan {
+                    let field = (covering.clone(), policy.fill(&content));
+                    set_field(&mut fields, covered, field);
+                }
+                if cell.rowspan() > 1 {
+                    if rowspans.len() < position + colspan {
+                        rowspans.resize(position + colspan, None);
+                    }
+                    for rowspan in &mut rowspans[position..position + colspan] {
+                        *rowspan = Some((cell.rowspan() - 1, covering.clone(), content.clone()));
+                    }
+                }
+                set_field(&mut fields, position, (covering, content));
+                position += colspan;
+            }
+
+            fields
+        })
+        .collect()
+}
+
+fn set_field(fields: &mut Vec<Field>, position: usize, field: (Cell, String)) {
+    if fields.len() <= position {
+        fields.resize(position + 1, None);
+    }
+    fields[position] = Some(field);
+}
+
+/// Wrap the content of a cell in the markup of a format for bold and italic text, if the cell
+/// has these attributes.
+///
+/// Each markup is a pair of an opening and a closing tag. Other attributes have no equivalent
+/// in export formats and are dropped.
+#[cfg(feature = "tty")]
+fn emphasize(cell: &Cell, content: String, bold: [&str; 2], italic: [&str; 2]) -> String {
+    if content.is_empty() {
+        return content;
+    }
+
+    let mut content = content;
+    if cell.attributes.contains(&Attribute::Italic) {
+        content = format!("{}{content}{}", italic[0], italic[1]);
+    }
+    if cell.attributes.contains(&Attribute::Bold) {
+        content = format!("{}{content}{}", bold[0], bold[1]);
+    }
+    content
+}
+
+#[cfg(not(feature = "tty"))]
+fn emphasize(_cell: &Cell, content: String, _bold: [&str; 2], _italic: [&str; 2]) -> String {
+    content
+}
+
+/// Pad a field with spaces to the given width.
+fn pad(
+    field: &str,
+    width: usize,
+    alignment: CellAlignment,
+    ambiguous_width: AmbiguousWidth,
+) -> String {
+    let missing = width.saturating_sub(measure_text_width(field, ambiguous_width));
+    let (left, right) = match alignment {
+        CellAlignment::Left => (0, missing),
+        CellAlignment::Right => (missing, 0),
+        CellAlignment::Center => (missing / 2, missing - missing / 2),
+    };
+    format!("{}{field}{}", " ".repeat(left), " ".repeat(right))
+}
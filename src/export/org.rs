@@ -0,0 +1,82 @@
+use super::{SpanPolicy, content_grid, pad};
+use crate::style::CellAlignment;
+use crate::table::Table;
+use crate::utils::formatting::content_split::measure_text_width;
+
+pub(crate) fn to_org(table: &Table) -> String {
+    let records: Vec<Vec<String>> = content_grid(table, SpanPolicy::BlankFill)
+        .into_iter()
+        .map(|record| record.iter().map(|field| escape(field)).collect())
+        .collect();
+    let column_count = records.iter().map(Vec::len).max().unwrap_or(0);
+    if column_count == 0 {
+        return String::new();
+    }
+
+    let alignments: Vec<Option<CellAlignment>> = (0..column_count)
+        .map(|index| {
+            table
+                .columns
+                .get(index)
+                .and_then(|column| column.cell_alignment)
+        })
+        .collect();
+    // Alignment cookies are only needed, if any column isn't left aligned.
+    let cookies: Option<Vec<String>> = alignments.iter().any(Option::is_some).then(|| {
+        alignments
+            .iter()
+            .map(|alignment| match alignment.unwrap_or(CellAlignment::Left) {
+                CellAlignment::Left => "<l>".to_string(),
+                CellAlignment::Center => "<c>".to_string(),
+                CellAlignment::Right => "<r>".to_string(),
+            })
+            .collect()
+    });
+
+    let mut widths = vec![0; column_count];
+    for record in records.iter().chain(cookies.iter()) {
+        for (width, field) in widths.iter_mut().zip(record) {
+            *width = (*width).max(measure_text_width(field, table.ambiguous_width));
+        }
+    }
+
+    let mut lines = Vec::new();
+    let draw_record = |record: &[String]| {
+        let fields: Vec<String> = widths
+            .iter()
+            .enumerate()
+            .map(|(index, width)| {
+                let field = record.get(index).map(String::as_str).unwrap_or_default();
+                let alignment = alignments[index].unwrap_or(CellAlignment::Left);
+                pad(field, *width, alignment, table.ambiguous_width)
+            })
+            .collect();
+        format!("| {} |", fields.join(" | "))
+    };
+
+    let mut records = records.iter();
+    if table.header.is_some() {
+        if let Some(header) = records.next() {
+            lines.push(draw_record(header));
+        }
+        let separator: Vec<String> = widths.iter().map(|width| "-".repeat(width + 2)).collect();
+        lines.push(format!("|{}|", separator.join("+")));
+    }
+    if let Some(cookies) = &cookies {
+        lines.push(draw_record(cookies));
+    }
+    for record in records {
+        lines.push(draw_record(record));
+    }
+
+    lines.join("\n") + "\n"
+}
+
+/// Org tables can neither contain pipes nor line breaks inside of cells.
+fn escape(field: &str) -> String {
+    field
+        .lines()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace('|', "\\vert{}")
+}
@@ -0,0 +1,167 @@
+//! Lay out cells of the same size in a fixed amount of columns, for instance for calendars or
+//! heatmaps.
+//!
+//! Tables usually size their columns by their content. A [Grid] gives all cells the same
+//! width and height instead, so the layout doesn't depend on the values that are displayed.
+//! The grid is built into a regular [Table], which is rendered like any other table.
+//!
+//! ```
+//! use super_table::Table;
+//! use super_table::grid::Grid;
+//!
+//! let mut template = Table::new();
+//! template.set_header(vec!["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"]);
+//!
+//! // The month starts on a Wednesday.
+//! let mut grid = Grid::new(template, 7);
+//! grid.skip(2);
+//! for day in 1..=12 {
+//!     grid.add_cell(day);
+//! }
+//!
+//! let expected = "
+//! +----+----+----+----+----+----+----+
+//! | Mo | Tu | We | Th | Fr | Sa | Su |
+//! +==================================+
+//! |    |    | 1  | 2  | 3  | 4  | 5  |
+//! |----+----+----+----+----+----+----|
+//! | 6  | 7  | 8  | 9  | 10 | 11 | 12 |
+//! +----+----+----+----+----+----+----+";
+//! assert_eq!(expected, "\n".to_string() + &grid.build().to_string());
+//! ```
+use crate::cell::Cell;
+use crate::column::Column;
+use crate::row::Row;
+use crate::style::{ColumnConstraint, Width};
+use crate::table::Table;
+
+/// Cells of the same size, which are placed from left to right in a fixed amount of columns.
+///
+/// The table passed to [Grid::new] is used as a template.
+/// Its header, styling and the padding of its columns are kept.
+///
+/// Each cell has the same [size](Grid::set_cell_size), which is two characters wide and one
+/// line high by default. As characters in terminals are about twice as high as they're wide,
+/// this results in roughly square cells.
+/// Content never makes a cell larger: Content that doesn't fit into a cell is truncated with the
+/// [truncation indicator](Table::set_truncation_indicator) of the template.
+///
+/// Cells keep their own styling, so a heatmap can be built by giving each cell a background
+/// color.
+#[derive(Debug)]
+pub struct Grid {
+    table: Table,
+    columns: usize,
+    cell_width: u16,
+    cell_height: usize,
+    cells: Vec<Cell>,
+}
+
+impl Grid {
+    /// Create a new grid with the given amount of columns, which uses `table` as template.
+    ///
+    /// A grid has at least one column.
+    pub fn new(table: Table, columns: usize) -> Self {
+        Self {
+            table,
+            columns: columns.max(1),
+            cell_width: 2,
+            cell_height: 1,
+            cells: Vec::new(),
+        }
+    }
+
+    /// Set the size of the content of each cell.
+    ///
+    /// The `width` is measured in characters and doesn't include the padding of the columns.
+    /// The `height` is measured in lines. Both are at least 1.
+    pub fn set_cell_size(&mut self, width: u16, height: usize) -> &mut Self {
+        self.cell_width = width.max(1);
+        self.cell_height = height.max(1);
+
+        self
+    }
+
+    /// Get the size of the content of each cell as `(width, height)`.
+    ///
+    /// See [Grid::set_cell_size].
+    pub fn cell_size(&self) -> (u16, usize) {
+        (self.cell_width, self.cell_height)
+    }
+
+    /// Add a cell at the next free position of the grid.
+    pub fn add_cell<T: Into<Cell>>(&mut self, cell: T) -> &mut Self {
+        self.cells.push(cell.into());
+
+        self
+    }
+
+    /// Add multiple cells at the next free positions of the grid.
+    pub fn add_cells<I>(&mut self, cells: I) -> &mut Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Cell>,
+    {
+        self.cells.extend(cells.into_iter().map(Into::into));
+
+        self
+    }
+
+    /// Leave the next positions of the grid empty, e.g. the days of the previous month in a
+    /// calendar.
+    pub fn skip(&mut self, count: usize) -> &mut Self {
+        self.cells
+            .extend(std::iter::repeat_with(|| Cell::new("")).take(count));
+
+        self
+    }
+
+    /// Get the amount of cells, including skipped ones, that have been added to this grid.
+    pub fn cell_count(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns whether no cells have been added to this grid so far.
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Finalize the grid into a [Table].
+    ///
+    /// The cells are appended to the rows of the template table, with one row for each
+    /// line of the grid. The columns of the grid get an [absolute](ColumnConstraint::Absolute)
+    /// width and the rows a [maximum height](Row::max_height).
+    pub fn build(self) -> Table {
+        let mut table = self.table;
+        let height = self.cell_height;
+
+        let mut cells = self.cells.into_iter().peekable();
+        while cells.peek().is_some() {
+            let mut row = Row::new();
+            row.max_height(height);
+            for mut cell in cells.by_ref().take(self.columns) {
+                // Cells with fewer lines are filled up, so all rows have the same height.
+                if cell.content.len() < height {
+                    cell.content.resize(height, String::new());
+                }
+                row.add_cell(cell);
+            }
+            table.add_row(row);
+        }
+        if let Some(header) = table.header.as_mut() {
+            header.max_height(height);
+        }
+
+        table.discover_columns();
+        for index in table.columns.len()..self.columns {
+            table.columns.push(Column::new(index));
+        }
+        for column in table.column_iter_mut() {
+            let padding = column.padding.0 + column.padding.1;
+            let width = self.cell_width.saturating_add(padding);
+            column.set_constraint(ColumnConstraint::Absolute(Width::Fixed(width)));
+        }
+
+        table
+    }
+}
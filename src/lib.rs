@@ -5,11 +5,20 @@
 // Had a few false-positives on v1.81. Check lateron if they're still there.
 #![allow(clippy::manual_unwrap_or)]
 
+mod builder;
 mod cell;
 mod column;
+mod export;
+pub mod grid;
+pub mod live;
+pub mod render;
+mod report;
 mod row;
+#[cfg(feature = "serde")]
+mod serialize;
 mod style;
 mod table;
+mod user_data;
 #[cfg(feature = "_integration_test")]
 /// We publicly expose the internal [utils] module for our integration tests.
 /// There's some logic we need from inside here.
@@ -17,9 +26,19 @@ mod table;
 pub mod utils;
 #[cfg(not(feature = "_integration_test"))]
 mod utils;
+mod value;
 
+pub use crate::builder::TableBuilder;
 pub use crate::cell::{Cell, Cells};
 pub use crate::column::Column;
+pub use crate::export::{
+    CsvReadOptions, CsvWriteOptions, FixedWidthOptions, HtmlOptions, LoadError, RoundTrip,
+    SpanPolicy, SplitPolicy,
+};
+pub use crate::report::Report;
 pub use crate::row::Row;
+#[cfg(feature = "serde")]
+pub use crate::serialize::SerializeError;
 pub use crate::table::{ColumnCellIter, Table};
+pub use crate::value::{CellValue, ColumnType};
 pub use style::*;
@@ -0,0 +1,249 @@
+//! Redraw a table in place, for instance to display continuously updated data like `top` does.
+//!
+//! ```no_run
+//! use std::time::Duration;
+//!
+//! use super_table::Table;
+//! use super_table::live::Live;
+//!
+//! let mut table = Table::new();
+//! table.set_header(vec!["Tick"]);
+//!
+//! let mut live = Live::new(table);
+//! let mut tick = 0;
+//! live.run(Duration::from_millis(500), |table| {
+//!     tick += 1;
+//!     table.add_row(vec![tick]);
+//!     tick < 10
+//! })
+//! .unwrap();
+//! live.finish().unwrap();
+//! ```
+use std::io::{self, Stdout, Write};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::table::Table;
+
+const CURSOR_HOME: &str = "\x1b[H";
+const CLEAR_SCREEN: &str = "\x1b[2J";
+const CLEAR_LINE: &str = "\x1b[K";
+const CLEAR_BELOW: &str = "\x1b[J";
+const HIDE_CURSOR: &str = "\x1b[?25l";
+const SHOW_CURSOR: &str = "\x1b[?25h";
+const ENTER_ALTERNATE_SCREEN: &str = "\x1b[?1049h";
+const LEAVE_ALTERNATE_SCREEN: &str = "\x1b[?1049l";
+
+/// Specify how [Live] redraws its table.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RefreshMode {
+    /// Draw the table at the top of the current screen.
+    ///
+    /// The screen is cleared once, afterwards each redraw overwrites the previous one line by
+    /// line. The table stays visible, once drawing is finished.
+    #[default]
+    CursorHome,
+    /// Draw the table on the alternate screen of the terminal.
+    ///
+    /// The previous content of the terminal is restored, once drawing is finished.
+    AlternateScreen,
+}
+
+/// A table that's redrawn in place, either on each call to [Live::update] or at an interval.
+///
+/// Each frame is written at once and overwrites the previous frame line by line, instead of
+/// clearing the screen first. This prevents flickering.
+///
+/// The terminal is restored via [Live::finish], or once the `Live` is dropped.
+pub struct Live<W: Write = Stdout> {
+    table: Table,
+    output: W,
+    mode: RefreshMode,
+    started: bool,
+}
+
+impl Live<Stdout> {
+    /// Draw a table to stdout.
+    pub fn new(table: Table) -> Self {
+        Self::with_output(table, io::stdout())
+    }
+}
+
+impl<W: Write> Live<W> {
+    /// Draw a table to a custom output.
+    pub fn with_output(table: Table, output: W) -> Self {
+        Self {
+            table,
+            output,
+            mode: RefreshMode::default(),
+            started: false,
+        }
+    }
+
+    /// Specify how the table is redrawn.
+    ///
+    /// This has to be set before the table is drawn for the first time.
+    pub fn set_refresh_mode(&mut self, mode: RefreshMode) -> &mut Self {
+        self.mode = mode;
+
+        self
+    }
+
+    /// Get the current refresh mode.
+    pub fn refresh_mode(&self) -> RefreshMode {
+        self.mode
+    }
+
+    /// Reference to the table that's drawn.
+    pub fn table(&self) -> &Table {
+        &self.table
+    }
+
+    /// Mutable reference to the table that's drawn.
+    ///
+    /// Changes are displayed with the next call to [Live::update].
+    pub fn table_mut(&mut self) -> &mut Table {
+        &mut self.table
+    }
+
+    /// Get the output the table is drawn to.
+    pub fn output(&self) -> &W {
+        &self.output
+    }
+
+    /// Redraw the table.
+    pub fn update(&mut self) -> io::Result<()> {
+        let mut frame = String::new();
+        if !self.started {
+            self.started = true;
+            if self.mode == RefreshMode::AlternateScreen {
+                frame += ENTER_ALTERNATE_SCREEN;
+            }
+            frame += HIDE_CURSOR;
+            frame += CLEAR_SCREEN;
+        }
+
+        frame += CURSOR_HOME;
+        for line in self.table.lines() {
+            frame += &line;
+            frame += CLEAR_LINE;
+            frame += "\r\n";
+        }
+        // Remove the remains of a previous, longer frame.
+        frame += CLEAR_BELOW;
+
+        self.output.write_all(frame.as_bytes())?;
+        self.output.flush()
+    }
+
+    /// Change the table and redraw it right away.
+    pub fn update_with(&mut self, change: impl FnOnce(&mut Table)) -> io::Result<()> {
+        change(&mut self.table);
+        self.update()
+    }
+
+    /// Redraw the table at an interval.
+    ///
+    /// `tick` is called before each redraw and may change the table.
+    /// Drawing stops, once `tick` returns `false`.
+    pub fn run(
+        &mut self,
+        interval: Duration,
+        mut tick: impl FnMut(&mut Table) -> bool,
+    ) -> io::Result<()> {
+        self.update()?;
+        loop {
+            let next_frame = Instant::now() + interval;
+            if !tick(&mut self.table) {
+                return Ok(());
+            }
+            thread::sleep(next_frame.saturating_duration_since(Instant::now()));
+            self.update()?;
+        }
+    }
+
+    /// Stop drawing and restore the terminal.
+    ///
+    /// The cursor is shown again and the alternate screen is left.
+    /// Calling this again or dropping the `Live` afterwards doesn't do anything.
+    pub fn finish(&mut self) -> io::Result<()> {
+        if !self.started {
+            return Ok(());
+        }
+        self.started = false;
+
+        let mut restore = SHOW_CURSOR.to_string();
+        if self.mode == RefreshMode::AlternateScreen {
+            restore += LEAVE_ALTERNATE_SCREEN;
+        }
+        self.output.write_all(restore.as_bytes())?;
+        self.output.flush()
+    }
+}
+
+impl<W: Write> Drop for Live<W> {
+    fn drop(&mut self) {
+        let _ = self.finish();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table() -> Table {
+        let mut table = Table::new();
+        table.add_row(vec!["one"]);
+        table
+    }
+
+    #[test]
+    fn redraws_in_place() {
+        let mut live = Live::with_output(table(), Vec::new());
+        live.update().unwrap();
+        let first_frame = String::from_utf8(live.output().clone()).unwrap();
+        assert_eq!(
+            first_frame,
+            "\x1b[?25l\x1b[2J\x1b[H\
+             +-----+\x1b[K\r\n\
+             | one |\x1b[K\r\n\
+             +-----+\x1b[K\r\n\
+             \x1b[J"
+        );
+
+        live.update_with(|table| {
+            table.add_row(vec!["two"]);
+        })
+        .unwrap();
+        let output = String::from_utf8(live.output().clone()).unwrap();
+        let second_frame = &output[first_frame.len()..];
+        // The screen isn't cleared again.
+        assert!(second_frame.starts_with("\x1b[H+-----+"));
+        assert!(second_frame.contains("| two |"));
+
+        live.finish().unwrap();
+        let output = String::from_utf8(live.output().clone()).unwrap();
+        assert!(output.ends_with("\x1b[J\x1b[?25h"));
+    }
+
+    #[test]
+    fn alternate_screen() {
+        let mut live = Live::with_output(table(), Vec::new());
+        live.set_refresh_mode(RefreshMode::AlternateScreen);
+        let mut ticks = 0;
+        live.run(Duration::ZERO, |_| {
+            ticks += 1;
+            ticks < 3
+        })
+        .unwrap();
+        live.finish().unwrap();
+        // Finishing twice doesn't restore the terminal twice.
+        live.finish().unwrap();
+
+        let output = String::from_utf8(live.output().clone()).unwrap();
+        assert!(output.starts_with("\x1b[?1049h\x1b[?25l"));
+        assert!(output.ends_with("\x1b[?25h\x1b[?1049l"));
+        assert_eq!(output.matches("\x1b[H").count(), 3);
+        assert_eq!(output.matches("\x1b[?1049h").count(), 1);
+    }
+}
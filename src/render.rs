@@ -0,0 +1,288 @@
+//! Low-level access to the individual stages of rendering a [Table].
+//!
+//! [Table::lines] runs the following stages one after another:
+//!
+//! 1. [prepare] applies the memory limit and resolves spans that depend on the final columns.
+//! 2. [arrange_columns] determines the width of each column.
+//! 3. [format_rows] wraps, aligns and pads the content of each cell.
+//! 4. [draw_borders] surrounds the formatted content with borders.
+//!
+//! Calling these functions yourself allows you to intercept the rendering between two stages,
+//! for instance to post-process the formatted content of cells or to add custom lines.
+//!
+//! ```
+//! use super_table::Table;
+//! use super_table::render;
+//!
+//! let mut table = Table::new();
+//! table.set_header(vec!["Name", "Status"]).add_row(vec!["backup", "ok"]);
+//!
+//! let table = render::prepare(&table);
+//! let columns = render::arrange_columns(&table);
+//! let rows = render::format_rows(&table, &columns).map(|mut row| {
+//!     // Each row consists of lines, which consist of the formatted part of each column.
+//!     for line in row.iter_mut() {
+//!         for part in line.iter_mut() {
+//!             *part = part.replace("ok", "OK").into();
+//!         }
+//!     }
+//!     row
+//! });
+//! let lines: Vec<String> = render::draw_borders(&table, rows, &columns).collect();
+//!
+//! assert_eq!(lines[3], "| backup | OK     |");
+//! ```
+//!
+//! **Attention:**
+//! The formatted rows that are passed to [draw_borders] must belong to the same table and
+//! columns. Spans and colspans are detected via the table's rows and the empty parts of a line.
+use std::borrow::Cow;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::cell::Cell;
+use crate::row::Row;
+use crate::style::SeparatorKind;
+use crate::table::Table;
+pub use crate::utils::ColumnDisplayInfo;
+pub use crate::utils::formatting::content_format::FormattedContent;
+use crate::utils::{
+    arrangement::arrange_content, formatting::borders, formatting::content_format::format_content,
+    prepare_table,
+};
+
+/// Prepare a table for rendering.
+///
+/// Rows that exceed the [memory limit](Table::set_memory_limit) are dropped and
+/// [cells that span the remaining columns](crate::Cell::set_colspan_rest) are resolved.
+/// A copy of the table is only created, if anything has to be changed.
+pub fn prepare(table: &Table) -> Cow<'_, Table> {
+    prepare_table(table)
+}
+
+/// Determine the width and layout of each column of a table.
+///
+/// The returned list contains one entry per column, including hidden columns.
+pub fn arrange_columns(table: &Table) -> Vec<ColumnDisplayInfo> {
+    arrange_content(table)
+}
+
+/// Format the content of all cells.
+///
+/// The returned iterator yields the lines of each row, starting with the header.
+/// Each line consists of the formatted part of each visible column.
+/// Parts of columns that are covered by a colspan are empty.
+///
+/// Rows are formatted lazily, while the iterator is consumed. Only rows that are spanned by a
+/// cell are formatted together, once the first of them is requested.
+/// Blank parts of lines are borrowed, all other parts are owned.
+pub fn format_rows<'a>(table: &'a Table, columns: &[ColumnDisplayInfo]) -> FormattedContent<'a> {
+    format_content(table, columns)
+}
+
+/// Surround the formatted rows of a table with borders.
+///
+/// The lines are drawn lazily, while the returned iterator is consumed.
+pub fn draw_borders<'a, 's, I>(
+    table: &'a Table,
+    rows: I,
+    columns: &[ColumnDisplayInfo],
+) -> impl Iterator<Item = String> + 'a
+where
+    I: IntoIterator<Item = Vec<Vec<Cow<'s, str>>>>,
+    I::IntoIter: 'a,
+    's: 'a,
+{
+    let rows = borders::FormattedRows(rows.into_iter());
+    borders::draw_borders(Cow::Borrowed(table), rows, columns.to_vec())
+}
+
+/// An event that's emitted while a table is rendered.
+///
+/// Events are passed to the hook that's set via [Table::set_render_hook].
+/// Rows are indexed including the header, which is the row with index `0` if the table has one.
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum RenderEvent<'a> {
+    /// The content of a cell has been formatted.
+    ///
+    /// Cells are formatted row by row, while the lines of the table are drawn.
+    /// The cells of a row are formatted before the lines of the row above it are drawn, and all
+    /// rows spanned by a cell are formatted before the first of them is drawn.
+    CellFormatted {
+        row_index: usize,
+        column_index: usize,
+        /// The row of the cell.
+        row: &'a Row,
+        /// The cell, including any [user data](Cell::user_data).
+        cell: &'a Cell,
+        /// The formatted lines of the cell, including padding.
+        lines: &'a [String],
+    },
+    /// The lines of a row are about to be drawn.
+    RowStart { row_index: usize },
+    /// A horizontal line has been drawn.
+    SeparatorDrawn { kind: SeparatorKind, line: &'a str },
+}
+
+/// A callback, that's called for each [RenderEvent].
+#[derive(Clone)]
+pub(crate) struct RenderHook(pub(crate) Arc<dyn Fn(RenderEvent<'_>) + Send + Sync>);
+
+impl fmt::Debug for RenderHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("RenderHook")
+    }
+}
+
+/// The signature of a [line decorator](crate::Table::set_line_decorator).
+type DecoratorFn = dyn Fn(usize, &str) -> String + Send + Sync;
+
+/// A callback, that changes each rendered line of a table.
+#[derive(Clone)]
+pub(crate) struct LineDecorator(pub(crate) Arc<DecoratorFn>);
+
+impl fmt::Debug for LineDecorator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("LineDecorator")
+    }
+}
+
+/// An error that prevents a table from being rendered properly.
+///
+/// Returned by [Table::try_lines].
+/// Rows are indexed including the header, which is the row with index `0` if the table has one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RenderError {
+    /// The columns require more space than the width of the table allows.
+    ///
+    /// This usually happens, if the widths of column constraints add up to more than the
+    /// table width.
+    ConstraintConflict {
+        /// The width the table would be rendered with.
+        required_width: usize,
+        /// The width the table should have.
+        table_width: u16,
+    },
+    /// A cell spans its columns or rows in a way that can't be drawn.
+    InvalidSpan {
+        row_index: usize,
+        column_index: usize,
+        reason: InvalidSpanReason,
+    },
+    /// The constraint of a visible column doesn't leave any space for content, once the padding
+    /// of the column is subtracted.
+    ZeroWidthColumn { column_index: usize },
+    /// The content of some cells of a [strict](Table::set_strict) table isn't displayed
+    /// completely.
+    ContentLost { cells: Vec<LostContent> },
+}
+
+/// A cell, whose content isn't displayed completely.
+///
+/// Returned by [Table::try_lines] for [strict](Table::set_strict) tables.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LostContent {
+    /// The row of the cell, including the header.
+    pub row_index: usize,
+    /// The column the cell starts in.
+    pub column_index: usize,
+    pub reason: LossReason,
+}
+
+/// The reason why the content of a cell isn't displayed completely.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LossReason {
+    /// The content has more lines than the [maximum height](Row::max_height) of its row allows,
+    /// or its lines are cut off by the [overflow](crate::Column::set_overflow) of its column.
+    Truncated,
+    /// All columns of the cell have been hidden, as the table isn't wide enough for them.
+    HiddenColumn,
+}
+
+/// The reason why a span is invalid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidSpanReason {
+    /// The cell has a colspan of `0`.
+    ZeroColspan,
+    /// The cell has a rowspan of `0`.
+    ZeroRowspan,
+    /// The cell spans more rows than the table has below it.
+    RowspanExceedsTable,
+    /// The cell spans a column, which is already occupied by a cell with a rowspan.
+    Overlap,
+}
+
+impl fmt::Display for RenderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RenderError::ConstraintConflict {
+                required_width,
+                table_width,
+            } => write!(
+                f,
+                "the columns require a width of {required_width}, but the table width is {table_width}"
+            ),
+            RenderError::InvalidSpan {
+                row_index,
+                column_index,
+                reason,
+            } => write!(
+                f,
+                "invalid span of the cell in row {row_index}, column {column_index}: {reason}"
+            ),
+            RenderError::ZeroWidthColumn { column_index } => {
+                write!(f, "column {column_index} has a width of zero")
+            }
+            RenderError::ContentLost { cells } => {
+                write!(f, "{} cells aren't displayed completely", cells.len())
+            }
+        }
+    }
+}
+
+impl fmt::Display for InvalidSpanReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = match self {
+            InvalidSpanReason::ZeroColspan => "the cell spans zero columns",
+            InvalidSpanReason::ZeroRowspan => "the cell spans zero rows",
+            InvalidSpanReason::RowspanExceedsTable => "the cell spans more rows than the table has",
+            InvalidSpanReason::Overlap => "the cell overlaps a cell that spans multiple rows",
+        };
+        f.write_str(reason)
+    }
+}
+
+impl fmt::Display for LossReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = match self {
+            LossReason::Truncated => "the content is cut off to fit into its row or column",
+            LossReason::HiddenColumn => "the column has been hidden due to the table width",
+        };
+        f.write_str(reason)
+    }
+}
+
+impl std::error::Error for RenderError {}
+
+/// A part of a rendered line, that might be displayed wider than super-table expects.
+///
+/// Returned by [Table::audit_alignment].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlignmentIssue {
+    /// The row of the cell, including the header.
+    pub row_index: usize,
+    /// The column of the cell.
+    pub column_index: usize,
+    /// The line within the row.
+    pub line_index: usize,
+    /// The width super-table assumed for this part of the line, including padding.
+    pub expected_width: usize,
+    /// The width this part of the line might actually take up in a terminal.
+    pub actual_width: usize,
+    /// The formatted content of the cell in this line.
+    pub content: String,
+}
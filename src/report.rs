@@ -0,0 +1,236 @@
+use std::fmt;
+
+use crate::export::{HtmlOptions, escape_html, to_html, to_markdown};
+use crate::style::ContentArrangement;
+use crate::table::Table;
+use crate::utils::arrangement::arrange_content;
+use crate::utils::arrangement::helper::count_border_columns;
+use crate::utils::prepare_table;
+
+/// A document that consists of multiple tables and blocks of text.
+///
+/// When several tables are printed one after another, each of them is usually as wide as its
+/// content. A report renders all of its tables with the same width instead, which is the width
+/// of the widest table, but at most the width of the terminal.
+/// Narrower tables are stretched and wider tables wrap their content, as if they used a
+/// [DynamicFullWidth](ContentArrangement::DynamicFullWidth) arrangement.
+///
+/// A [preset](Report::load_preset) and [modifiers](Report::apply_modifier) can be set as a shared
+/// theme for all tables. Blocks are separated by an empty line.
+///
+/// ```
+/// use super_table::{Report, Table};
+///
+/// let mut hosts = Table::new();
+/// hosts.set_header(vec!["Host", "Status"]).add_row(vec!["db-1", "running"]);
+/// let mut jobs = Table::new();
+/// jobs.set_header(vec!["Job"]).add_row(vec!["backup"]);
+///
+/// let mut report = Report::new();
+/// report
+///     .add_text("Hosts:")
+///     .add_table(hosts)
+///     .add_text("Jobs:")
+///     .add_table(jobs);
+///
+/// let expected = "
+/// Hosts:
+///
+/// +------+---------+
+/// | Host | Status  |
+/// +================+
+/// | db-1 | running |
+/// +------+---------+
+///
+/// Jobs:
+///
+/// +----------------+
+/// | Job            |
+/// +================+
+/// | backup         |
+/// +----------------+";
+/// assert_eq!(expected, "\n".to_string() + &report.to_string());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Report {
+    blocks: Vec<Block>,
+    width: Option<u16>,
+    preset: Option<String>,
+    modifiers: Vec<String>,
+}
+
+#[derive(Clone, Debug)]
+enum Block {
+    Table(Box<Table>),
+    Text(String),
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.lines().collect::<Vec<_>>().join("\n"))
+    }
+}
+
+impl Report {
+    /// Create a new report without any blocks.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a table to the end of the report.
+    pub fn add_table(&mut self, table: Table) -> &mut Self {
+        self.blocks.push(Block::Table(Box::new(table)));
+
+        self
+    }
+
+    /// Add a block of text to the end of the report.
+    ///
+    /// The text is displayed as it is and isn't wrapped to the width of the report.
+    /// It's exported as Markdown without any changes, so it may contain Markdown syntax.
+    pub fn add_text<T: Into<String>>(&mut self, text: T) -> &mut Self {
+        self.blocks.push(Block::Text(text.into()));
+
+        self
+    }
+
+    /// Enforce the width of all tables in this report.
+    pub fn set_width(&mut self, width: u16) -> &mut Self {
+        self.width = Some(width);
+
+        self
+    }
+
+    /// Get the width all tables of this report are rendered with.
+    ///
+    /// This is the width that has been [set](Report::set_width). Otherwise, it's the width of
+    /// the widest table, but at most the width of the terminal, if it can be detected.
+    /// Returns `None`, if the report doesn't contain any tables.
+    pub fn width(&self) -> Option<u16> {
+        if self.width.is_some() {
+            return self.width;
+        }
+
+        let widest = self
+            .tables()
+            .map(|table| natural_width(&self.themed(table)))
+            .max()?;
+        let widest = u16::try_from(widest).unwrap_or(u16::MAX);
+        match Table::new().width() {
+            Some(terminal_width) => Some(widest.min(terminal_width)),
+            None => Some(widest),
+        }
+    }
+
+    /// Use the given [preset](crate::presets) for all tables of this report.
+    ///
+    /// This overwrites the styles of the tables and removes all previously applied modifiers.
+    pub fn load_preset(&mut self, preset: &str) -> &mut Self {
+        self.preset = Some(preset.to_string());
+        self.modifiers.clear();
+
+        self
+    }
+
+    /// Apply a [modifier](crate::modifiers) to all tables of this report.
+    ///
+    /// Modifiers are applied after the [preset](Report::load_preset), in the order they've been
+    /// added.
+    pub fn apply_modifier(&mut self, modifier: &str) -> &mut Self {
+        self.modifiers.push(modifier.to_string());
+
+        self
+    }
+
+    /// Get the amount of tables in this report.
+    pub fn table_count(&self) -> usize {
+        self.tables().count()
+    }
+
+    /// Returns an iterator over the lines of the rendered report.
+    pub fn lines(&self) -> impl Iterator<Item = String> {
+        let width = self.width();
+        let blocks: Vec<Vec<String>> = self
+            .blocks
+            .iter()
+            .map(|block| match block {
+                Block::Table(table) => {
+                    let mut table = self.themed(table);
+                    if let Some(width) = width {
+                        table
+                            .set_width(width)
+                            .set_content_arrangement(ContentArrangement::DynamicFullWidth);
+                    }
+                    table.lines().collect()
+                }
+                Block::Text(text) => text.lines().map(ToString::to_string).collect(),
+            })
+            .collect();
+
+        blocks.join(&String::new()).into_iter()
+    }
+
+    /// Export the report as Markdown.
+    ///
+    /// Tables are exported like [Table::to_markdown] and text is written as it is.
+    pub fn to_markdown(&self) -> String {
+        let blocks: Vec<String> = self
+            .blocks
+            .iter()
+            .map(|block| match block {
+                Block::Table(table) => to_markdown(table).trim_end_matches('\n').to_string(),
+                Block::Text(text) => text.trim_end_matches('\n').to_string(),
+            })
+            .collect();
+
+        blocks.join("\n\n") + "\n"
+    }
+
+    /// Export the report as HTML.
+    ///
+    /// Tables are exported like [Table::to_html] and text is written as an escaped paragraph.
+    pub fn to_html(&self) -> String {
+        self.blocks
+            .iter()
+            .map(|block| match block {
+                Block::Table(table) => to_html(table, &HtmlOptions::new()),
+                Block::Text(text) => {
+                    let lines: Vec<String> = text.lines().map(escape_html).collect();
+                    format!("<p>{}</p>\n", lines.join("<br>\n"))
+                }
+            })
+            .collect()
+    }
+
+    fn tables(&self) -> impl Iterator<Item = &Table> {
+        self.blocks.iter().filter_map(|block| match block {
+            Block::Table(table) => Some(table.as_ref()),
+            Block::Text(_) => None,
+        })
+    }
+
+    /// Copy a table and apply the theme of this report to it.
+    fn themed(&self, table: &Table) -> Table {
+        let mut table = table.clone();
+        if let Some(preset) = &self.preset {
+            table.load_preset(preset);
+        }
+        for modifier in &self.modifiers {
+            table.apply_modifier(modifier);
+        }
+
+        table
+    }
+}
+
+/// The width of a table, if its content isn't arranged.
+fn natural_width(table: &Table) -> usize {
+    let mut table = table.clone();
+    table.set_content_arrangement(ContentArrangement::Disabled);
+    let table = prepare_table(&table);
+    let infos = arrange_content(&table);
+
+    let visible: Vec<_> = infos.iter().filter(|info| !info.is_hidden).collect();
+    let content: usize = visible.iter().map(|info| info.width()).sum();
+    content + count_border_columns(&table, visible.len())
+}
@@ -1,8 +1,15 @@
-use std::slice::Iter;
+use std::any::Any;
+#[cfg(feature = "tty")]
+use std::borrow::Cow;
+use std::slice::{Iter, IterMut};
 
+#[cfg(feature = "tty")]
+use crate::{Attribute, Color};
 use crate::{
     cell::{Cell, Cells},
-    utils::formatting::content_split::measure_text_width,
+    style::{CellAlignment, VerticalAlignment},
+    user_data::UserData,
+    utils::templates::fill_row,
 };
 
 /// Each row contains [Cells](crate::Cell) and can be added to a [Table](crate::Table).
@@ -13,6 +20,22 @@ pub struct Row {
     pub(crate) index: Option<usize>,
     pub(crate) cells: Vec<Cell>,
     pub(crate) max_height: Option<usize>,
+    pub(crate) cell_alignment: Option<CellAlignment>,
+    pub(crate) vertical_alignment: Option<VerticalAlignment>,
+    #[cfg(feature = "tty")]
+    pub(crate) fg: Option<Color>,
+    #[cfg(feature = "tty")]
+    pub(crate) bg: Option<Color>,
+    #[cfg(feature = "tty")]
+    pub(crate) attributes: Vec<Attribute>,
+    /// Arbitrary data that's attached to this row.
+    pub(crate) user_data: Option<UserData>,
+    /// A stable identifier of this row, independent of its position.
+    pub(crate) id: Option<String>,
+    /// Whether this row is a note, that spans the whole table.
+    pub(crate) annotation: bool,
+    /// Whether a section separator is drawn below this row.
+    pub(crate) section_break: bool,
 }
 
 impl Row {
@@ -36,6 +59,9 @@ impl Row {
     /// ```
     pub fn add_cell(&mut self, cell: Cell) -> &mut Self {
         self.cells.push(cell);
+        if self.user_data.is_some() {
+            fill_row(self);
+        }
 
         self
     }
@@ -54,8 +80,199 @@ impl Row {
         self
     }
 
+    /// Set the horizontal alignment for content inside of all cells of this row.
+    ///
+    /// **Note:** Alignment on a [cell](Cell::set_alignment) or a
+    /// [column](crate::Column::set_cell_alignment) will always overwrite the row's setting.
+    ///
+    /// ```
+    /// use super_table::{CellAlignment, Row};
+    ///
+    /// let mut row = Row::from(vec!["One", "Two"]);
+    /// row.set_cell_alignment(CellAlignment::Right);
+    /// ```
+    pub fn set_cell_alignment(&mut self, alignment: CellAlignment) -> &mut Self {
+        self.cell_alignment = Some(alignment);
+
+        self
+    }
+
+    /// Set the vertical alignment for content inside of all cells of this row.
+    ///
+    /// Cells that are shorter than the tallest cell of the row, e.g. because its content
+    /// has been wrapped, are aligned within the height of the row.
+    ///
+    /// **Note:** Alignment on a [cell](Cell::set_vertical_alignment) or a
+    /// [column](crate::Column::set_vertical_alignment) will always overwrite the row's setting.
+    ///
+    /// ```
+    /// use super_table::{Row, VerticalAlignment};
+    ///
+    /// let mut row = Row::from(vec!["One", "Two\nLines"]);
+    /// row.set_vertical_alignment(VerticalAlignment::Middle);
+    /// ```
+    pub fn set_vertical_alignment(&mut self, alignment: VerticalAlignment) -> &mut Self {
+        self.vertical_alignment = Some(alignment);
+
+        self
+    }
+
+    /// Set the foreground text color for all cells of this row.
+    ///
+    /// Cells with their own [foreground color](Cell::fg) keep it.
+    /// This also applies to cells that are added to the row later on.
+    ///
+    /// ```
+    /// use super_table::{Color, Row};
+    ///
+    /// let mut row = Row::from(vec!["error", "Connection refused"]);
+    /// row.fg(Color::Red);
+    /// ```
+    #[cfg(feature = "tty")]
+    pub fn fg(&mut self, color: Color) -> &mut Self {
+        self.fg = Some(color);
+
+        self
+    }
+
+    /// Set the background color for all cells of this row.
+    ///
+    /// Cells with their own [background color](Cell::bg) keep it.
+    /// This also applies to cells that are added to the row later on.
+    ///
+    /// ```
+    /// use super_table::{Color, Row};
+    ///
+    /// let mut row = Row::from(vec!["error", "Connection refused"]);
+    /// row.bg(Color::DarkRed);
+    /// ```
+    #[cfg(feature = "tty")]
+    pub fn bg(&mut self, color: Color) -> &mut Self {
+        self.bg = Some(color);
+
+        self
+    }
+
+    /// Add a styling attribute to all cells of this row.
+    ///
+    /// The [attributes of a cell](Cell::add_attribute) are applied in addition to the ones of
+    /// its row.
+    /// This also applies to cells that are added to the row later on.
+    ///
+    /// ```
+    /// use super_table::{Attribute, Row};
+    ///
+    /// let mut row = Row::from(vec!["Total", "42"]);
+    /// row.add_attribute(Attribute::Bold);
+    /// ```
+    #[cfg(feature = "tty")]
+    pub fn add_attribute(&mut self, attribute: Attribute) -> &mut Self {
+        self.attributes.push(attribute);
+
+        self
+    }
+
+    /// Apply the styling of this row to one of its cells.
+    ///
+    /// The cell is only copied, if the row has any styling.
+    #[cfg(feature = "tty")]
+    pub(crate) fn style_cell<'a>(&self, cell: &'a Cell) -> Cow<'a, Cell> {
+        if self.fg.is_none() && self.bg.is_none() && self.attributes.is_empty() {
+            return Cow::Borrowed(cell);
+        }
+
+        let mut styled = cell.clone();
+        styled.fg = cell.fg.or(self.fg);
+        styled.bg = cell.bg.or(self.bg);
+        styled.attributes = self
+            .attributes
+            .iter()
+            .chain(cell.attributes.iter())
+            .copied()
+            .collect();
+
+        Cow::Owned(styled)
+    }
+
+    /// Set an identifier for this row.
+    ///
+    /// Other than the index of a row, the identifier doesn't change when rows are reordered or
+    /// removed. Use [Table::row_by_id](crate::Table::row_by_id) to find the row again.
+    ///
+    /// ```
+    /// use super_table::Row;
+    ///
+    /// let mut row = Row::from(vec!["nginx", "running"]);
+    /// row.set_id("pid-4242");
+    /// assert_eq!(row.id(), Some("pid-4242"));
+    /// ```
+    pub fn set_id(&mut self, id: impl Into<String>) -> &mut Self {
+        self.id = Some(id.into());
+
+        self
+    }
+
+    /// Get the identifier of this row.
+    ///
+    /// See [Row::set_id].
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// Returns whether this row has been added with [Table::add_annotation_row](crate::Table::add_annotation_row).
+    pub fn is_annotation(&self) -> bool {
+        self.annotation
+    }
+
+    /// Attach arbitrary data to this row, for instance the domain object it displays.
+    ///
+    /// The data isn't displayed, but it's available wherever the row is, e.g. in
+    /// [render hooks](crate::render::RenderEvent::CellFormatted).
+    /// That way, styling decisions can be based on the data instead of the displayed strings.
+    /// Maps of names to values also fill the placeholders of [templated](crate::Cell::templated)
+    /// cells. Setting new data replaces any previous data.
+    ///
+    /// ```
+    /// use super_table::Row;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum Status {
+    ///     Failed,
+    /// }
+    ///
+    /// let mut row = Row::from(vec!["backup", "failed"]);
+    /// row.set_user_data(Status::Failed);
+    /// assert_eq!(row.user_data::<Status>(), Some(&Status::Failed));
+    /// ```
+    pub fn set_user_data<T: Any + Send + Sync>(&mut self, data: T) -> &mut Self {
+        self.user_data = Some(UserData::new(data));
+        fill_row(self);
+
+        self
+    }
+
+    /// Get the data that's attached to this row, if it's of type `T`.
+    ///
+    /// See [Row::set_user_data].
+    pub fn user_data<T: Any>(&self) -> Option<&T> {
+        self.user_data.as_ref().and_then(UserData::get)
+    }
+
+    /// Get the amount of bytes the content of all cells of this row occupies.
+    pub(crate) fn content_size(&self) -> usize {
+        self.cells
+            .iter()
+            .flat_map(|cell| cell.content.iter())
+            .map(String::len)
+            .sum()
+    }
+
     /// Get the longest content width for all cells of this row
+    #[cfg(test)]
     pub(crate) fn max_content_widths(&self) -> Vec<usize> {
+        use crate::style::AmbiguousWidth;
+        use crate::utils::formatting::content_split::measure_text_width;
+
         // Iterate over all cells
         self.cells
             .iter()
@@ -64,7 +281,7 @@ impl Row {
                 // Each entry represents the longest string width for a cell.
                 cell.content
                     .iter()
-                    .map(|string| measure_text_width(string))
+                    .map(|string| measure_text_width(string, AmbiguousWidth::Narrow))
                     .max()
                     .unwrap_or(0)
             })
@@ -88,6 +305,59 @@ impl Row {
     pub fn cell_iter(&self) -> Iter<'_, Cell> {
         self.cells.iter()
     }
+
+    /// Returns an iterator over all cells of this row, which allows modifying each cell.
+    ///
+    /// ```
+    /// use super_table::{CellAlignment, Row};
+    ///
+    /// let mut row = Row::from(vec!["One", "Two"]);
+    /// for cell in row.cell_iter_mut() {
+    ///     *cell = cell.clone().set_alignment(CellAlignment::Right);
+    /// }
+    /// ```
+    pub fn cell_iter_mut(&mut self) -> IterMut<'_, Cell> {
+        self.cells.iter_mut()
+    }
+
+    /// Get a cell of this row by its index.
+    ///
+    /// The index is the position of the cell in this row, which might differ from the index of
+    /// the column it's displayed in, if cells span multiple columns.
+    pub fn cell(&self, index: usize) -> Option<&Cell> {
+        self.cells.get(index)
+    }
+
+    /// Get a mutable reference to a cell of this row by its index.
+    ///
+    /// See [Row::cell] for details on the index.
+    pub fn cell_mut(&mut self, index: usize) -> Option<&mut Cell> {
+        self.cells.get_mut(index)
+    }
+
+    /// Replace a cell of this row and return the previous cell.
+    ///
+    /// Returns `None` and leaves the row untouched, if there's no cell at the index.
+    /// See [Row::cell] for details on the index.
+    ///
+    /// **Attention:**
+    /// If the new cell spans more columns than the previous one and the row has already been
+    /// added to a [Table](crate::Table), call [crate::Table::discover_columns] afterwards.
+    ///
+    /// ```
+    /// use super_table::{Cell, Row};
+    ///
+    /// let mut row = Row::from(vec!["nginx", "running"]);
+    /// let previous = row.replace_cell(1, Cell::new("stopped"));
+    ///
+    /// assert_eq!(previous.unwrap().content(), "running");
+    /// assert_eq!(row.cell(1).unwrap().content(), "stopped");
+    /// ```
+    pub fn replace_cell(&mut self, index: usize, cell: Cell) -> Option<Cell> {
+        let previous = self.cells.get_mut(index)?;
+
+        Some(std::mem::replace(previous, cell))
+    }
 }
 
 /// Create a Row from any `Into<Cells>`. \
@@ -112,6 +382,18 @@ impl<T: Into<Cells>> From<T> for Row {
             index: None,
             cells: cells.into().0,
             max_height: None,
+            cell_alignment: None,
+            vertical_alignment: None,
+            #[cfg(feature = "tty")]
+            fg: None,
+            #[cfg(feature = "tty")]
+            bg: None,
+            #[cfg(feature = "tty")]
+            attributes: Vec::new(),
+            user_data: None,
+            id: None,
+            annotation: false,
+            section_break: false,
         }
     }
 }
@@ -152,4 +434,30 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_id() {
+        let mut row = Row::new();
+        assert_eq!(row.id(), None);
+
+        row.set_id("first").set_id(String::from("second"));
+        assert_eq!(row.id(), Some("second"));
+    }
+
+    #[test]
+    fn test_cell_access() {
+        let mut row = Row::from(vec!["one", "two"]);
+        assert_eq!(row.cell(1).map(Cell::content), Some("two".to_string()));
+        assert!(row.cell(2).is_none());
+
+        assert!(row.replace_cell(2, Cell::new("three")).is_none());
+        assert_eq!(row.cell_count(), 2);
+
+        *row.cell_mut(0).unwrap() = Cell::new("first");
+        for cell in row.cell_iter_mut() {
+            *cell = Cell::new(cell.content().to_uppercase());
+        }
+        let contents: Vec<String> = row.cell_iter().map(Cell::content).collect();
+        assert_eq!(contents, vec!["FIRST", "TWO"]);
+    }
 }
@@ -0,0 +1,479 @@
+use std::fmt;
+
+use serde::ser::{self, Serialize};
+
+use crate::cell::Cell;
+use crate::style::KeyOrder;
+use crate::table::{Table, record_cells, record_keys};
+use crate::value::CellValue;
+
+/// An error that prevents a value from being converted into a table.
+///
+/// Returned by [Table::from_serialize].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SerializeError {
+    /// The value isn't a sequence, so it can't be split into rows.
+    NotASequence,
+    /// An element of the sequence is neither a struct nor a map, so it can't be split into
+    /// columns.
+    NotARecord { row_index: usize },
+    /// A map has a key, that isn't a plain value like a string or a number.
+    InvalidKey,
+    /// The value failed to serialize itself.
+    Custom(String),
+}
+
+impl fmt::Display for SerializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SerializeError::NotASequence => f.write_str("the value isn't a sequence of rows"),
+            SerializeError::NotARecord { row_index } => {
+                write!(f, "row {row_index} is neither a struct nor a map")
+            }
+            SerializeError::InvalidKey => f.write_str("map keys must be plain values"),
+            SerializeError::Custom(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for SerializeError {}
+
+impl ser::Error for SerializeError {
+    fn custom<T: fmt::Display>(message: T) -> Self {
+        SerializeError::Custom(message.to_string())
+    }
+}
+
+/// Convert a sequence of structs or maps into a table.
+///
+/// See [Table::from_serialize] for details.
+pub(crate) fn from_serialize<T: Serialize + ?Sized>(value: &T) -> Result<Table, SerializeError> {
+    let Value::List(rows) = value.serialize(ValueSerializer)? else {
+        return Err(SerializeError::NotASequence);
+    };
+
+    let mut records = Vec::new();
+    for (row_index, row) in rows.into_iter().enumerate() {
+        let Value::Record(fields) = row else {
+            return Err(SerializeError::NotARecord { row_index });
+        };
+        let mut record = Vec::new();
+        flatten(None, fields, &mut record);
+        records.push(record);
+    }
+
+    let keys = {
+        let records: Vec<&[(String, Cell)]> = records.iter().map(Vec::as_slice).collect();
+        record_keys(&records, KeyOrder::FirstSeen)
+    };
+    let mut table = Table::new();
+    table.set_header(keys.iter().map(Cell::new).collect::<Vec<_>>());
+    for record in records {
+        table.add_row(record_cells(record, &keys));
+    }
+
+    Ok(table)
+}
+
+/// The serialized form of a value, before it's placed into cells.
+enum Value {
+    Scalar(CellValue),
+    List(Vec<Value>),
+    Record(Vec<(String, Value)>),
+}
+
+/// Nested records become columns of their own, whose key is prefixed by the key of their
+/// parent, e.g. `address.city`. Lists are displayed as text.
+fn flatten(prefix: Option<&str>, fields: Vec<(String, Value)>, record: &mut Vec<(String, Cell)>) {
+    for (key, value) in fields {
+        let key = match prefix {
+            Some(prefix) => format!("{prefix}.{key}"),
+            None => key,
+        };
+        match value {
+            Value::Scalar(value) => record.push((key, Cell::from(value))),
+            Value::Record(fields) => flatten(Some(&key), fields, record),
+            list @ Value::List(_) => record.push((key, Cell::new(stringify(&list)))),
+        }
+    }
+}
+
+/// Display a nested value as text, e.g. `[1, 2]` or `{x: 1, y: 2}`.
+fn stringify(value: &Value) -> String {
+    match value {
+        Value::Scalar(value) => value.to_display_string(),
+        Value::List(items) => {
+            let items: Vec<String> = items.iter().map(stringify).collect();
+            format!("[{}]", items.join(", "))
+        }
+        Value::Record(fields) => {
+            let fields: Vec<String> = fields
+                .iter()
+                .map(|(key, value)| format!("{key}: {}", stringify(value)))
+                .collect();
+            format!("{{{}}}", fields.join(", "))
+        }
+    }
+}
+
+struct ValueSerializer;
+
+impl ValueSerializer {
+    fn scalar<T: Into<CellValue>>(value: T) -> Result<Value, SerializeError> {
+        Ok(Value::Scalar(value.into()))
+    }
+
+    /// Integers that don't fit into a [CellValue::Int] are kept as text.
+    fn wide_int<T: TryInto<i64> + ToString + Copy>(value: T) -> Result<Value, SerializeError> {
+        match value.try_into() {
+            Ok(value) => Self::scalar(value),
+            Err(_) => Self::scalar(value.to_string()),
+        }
+    }
+}
+
+impl ser::Serializer for ValueSerializer {
+    type Ok = Value;
+    type Error = SerializeError;
+    type SerializeSeq = SeqSerializer;
+    type SerializeTuple = SeqSerializer;
+    type SerializeTupleStruct = SeqSerializer;
+    type SerializeTupleVariant = SeqSerializer;
+    type SerializeMap = MapSerializer;
+    type SerializeStruct = MapSerializer;
+    type SerializeStructVariant = MapSerializer;
+
+    fn serialize_bool(self, value: bool) -> Result<Value, SerializeError> {
+        Self::scalar(value)
+    }
+
+    fn serialize_i8(self, value: i8) -> Result<Value, SerializeError> {
+        Self::scalar(value)
+    }
+
+    fn serialize_i16(self, value: i16) -> Result<Value, SerializeError> {
+        Self::scalar(value)
+    }
+
+    fn serialize_i32(self, value: i32) -> Result<Value, SerializeError> {
+        Self::scalar(value)
+    }
+
+    fn serialize_i64(self, value: i64) -> Result<Value, SerializeError> {
+        Self::scalar(value)
+    }
+
+    fn serialize_i128(self, value: i128) -> Result<Value, SerializeError> {
+        Self::wide_int(value)
+    }
+
+    fn serialize_u8(self, value: u8) -> Result<Value, SerializeError> {
+        Self::scalar(value)
+    }
+
+    fn serialize_u16(self, value: u16) -> Result<Value, SerializeError> {
+        Self::scalar(value)
+    }
+
+    fn serialize_u32(self, value: u32) -> Result<Value, SerializeError> {
+        Self::scalar(value)
+    }
+
+    fn serialize_u64(self, value: u64) -> Result<Value, SerializeError> {
+        Self::wide_int(value)
+    }
+
+    fn serialize_u128(self, value: u128) -> Result<Value, SerializeError> {
+        Self::wide_int(value)
+    }
+
+    fn serialize_f32(self, value: f32) -> Result<Value, SerializeError> {
+        Self::scalar(value)
+    }
+
+    fn serialize_f64(self, value: f64) -> Result<Value, SerializeError> {
+        Self::scalar(value)
+    }
+
+    fn serialize_char(self, value: char) -> Result<Value, SerializeError> {
+        Self::scalar(value.to_string())
+    }
+
+    fn serialize_str(self, value: &str) -> Result<Value, SerializeError> {
+        Self::scalar(value)
+    }
+
+    fn serialize_bytes(self, value: &[u8]) -> Result<Value, SerializeError> {
+        let bytes = value
+            .iter()
+            .map(|byte| Value::Scalar(CellValue::from(*byte)))
+            .collect();
+        Ok(Value::List(bytes))
+    }
+
+    fn serialize_none(self) -> Result<Value, SerializeError> {
+        Ok(Value::Scalar(CellValue::Null))
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Value, SerializeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Value, SerializeError> {
+        Ok(Value::Scalar(CellValue::Null))
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Value, SerializeError> {
+        Ok(Value::Scalar(CellValue::Null))
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Value, SerializeError> {
+        Self::scalar(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Value, SerializeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Value, SerializeError> {
+        Ok(Value::Record(vec![(
+            variant.to_string(),
+            value.serialize(self)?,
+        )]))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SeqSerializer, SerializeError> {
+        Ok(SeqSerializer::new(None, len))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SeqSerializer, SerializeError> {
+        Ok(SeqSerializer::new(None, Some(len)))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SeqSerializer, SerializeError> {
+        Ok(SeqSerializer::new(None, Some(len)))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SeqSerializer, SerializeError> {
+        Ok(SeqSerializer::new(Some(variant), Some(len)))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<MapSerializer, SerializeError> {
+        Ok(MapSerializer::new(None, len))
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<MapSerializer, SerializeError> {
+        Ok(MapSerializer::new(None, Some(len)))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<MapSerializer, SerializeError> {
+        Ok(MapSerializer::new(Some(variant), Some(len)))
+    }
+}
+
+/// Wrap a value into a record with the name of its enum variant as key.
+fn wrap_variant(variant: Option<&'static str>, value: Value) -> Value {
+    match variant {
+        Some(variant) => Value::Record(vec![(variant.to_string(), value)]),
+        None => value,
+    }
+}
+
+struct SeqSerializer {
+    variant: Option<&'static str>,
+    items: Vec<Value>,
+}
+
+impl SeqSerializer {
+    fn new(variant: Option<&'static str>, len: Option<usize>) -> Self {
+        Self {
+            variant,
+            items: Vec::with_capacity(len.unwrap_or(0)),
+        }
+    }
+
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerializeError> {
+        self.items.push(value.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Value, SerializeError> {
+        Ok(wrap_variant(self.variant, Value::List(self.items)))
+    }
+}
+
+impl ser::SerializeSeq for SeqSerializer {
+    type Ok = Value;
+    type Error = SerializeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, SerializeError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for SeqSerializer {
+    type Ok = Value;
+    type Error = SerializeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, SerializeError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for SeqSerializer {
+    type Ok = Value;
+    type Error = SerializeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, SerializeError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleVariant for SeqSerializer {
+    type Ok = Value;
+    type Error = SerializeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, SerializeError> {
+        self.finish()
+    }
+}
+
+struct MapSerializer {
+    variant: Option<&'static str>,
+    fields: Vec<(String, Value)>,
+    /// The key of the entry, whose value is serialized next.
+    key: Option<String>,
+}
+
+impl MapSerializer {
+    fn new(variant: Option<&'static str>, len: Option<usize>) -> Self {
+        Self {
+            variant,
+            fields: Vec::with_capacity(len.unwrap_or(0)),
+            key: None,
+        }
+    }
+
+    fn push<T: Serialize + ?Sized>(
+        &mut self,
+        key: String,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        self.fields.push((key, value.serialize(ValueSerializer)?));
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Value, SerializeError> {
+        Ok(wrap_variant(self.variant, Value::Record(self.fields)))
+    }
+}
+
+impl ser::SerializeMap for MapSerializer {
+    type Ok = Value;
+    type Error = SerializeError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Self::Error> {
+        match key.serialize(ValueSerializer)? {
+            Value::Scalar(key) => {
+                self.key = Some(key.to_display_string());
+                Ok(())
+            }
+            Value::List(_) | Value::Record(_) => Err(SerializeError::InvalidKey),
+        }
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let key = self.key.take().unwrap_or_default();
+        self.push(key, value)
+    }
+
+    fn end(self) -> Result<Value, SerializeError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStruct for MapSerializer {
+    type Ok = Value;
+    type Error = SerializeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.push(key.to_string(), value)
+    }
+
+    fn end(self) -> Result<Value, SerializeError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStructVariant for MapSerializer {
+    type Ok = Value;
+    type Error = SerializeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.push(key.to_string(), value)
+    }
+
+    fn end(self) -> Result<Value, SerializeError> {
+        self.finish()
+    }
+}
@@ -0,0 +1,106 @@
+#[cfg(feature = "tty")]
+use crate::style::Style;
+
+/// Specify how boolean values are displayed.
+///
+/// The format applies to all cells, whose [typed value](crate::Cell::typed_value) is a
+/// [CellValue::Bool](crate::CellValue::Bool).
+/// It can be set for a whole [table](crate::Table::set_bool_format) or for a single
+/// [column](crate::Column::set_bool_format), which wins over the table's format.
+/// The content that's stored in the cells isn't changed, only the displayed content.
+///
+/// ```
+/// use super_table::{BoolFormat, Cell, Table};
+///
+/// let mut table = Table::new();
+/// table
+///     .set_bool_format(BoolFormat::check_marks())
+///     .add_row(vec![Cell::new("backup"), Cell::from_value(true)]);
+///
+/// assert_eq!(table.lines().nth(1).unwrap(), "| backup | ✔ |");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BoolFormat {
+    pub(crate) true_str: String,
+    pub(crate) false_str: String,
+    #[cfg(feature = "tty")]
+    pub(crate) style_true: Option<Style>,
+    #[cfg(feature = "tty")]
+    pub(crate) style_false: Option<Style>,
+}
+
+impl Default for BoolFormat {
+    fn default() -> Self {
+        Self::new("true", "false")
+    }
+}
+
+impl BoolFormat {
+    /// Create a format, which displays `true` and `false` as the given texts.
+    pub fn new(true_str: impl Into<String>, false_str: impl Into<String>) -> Self {
+        Self {
+            true_str: true_str.into(),
+            false_str: false_str.into(),
+            #[cfg(feature = "tty")]
+            style_true: None,
+            #[cfg(feature = "tty")]
+            style_false: None,
+        }
+    }
+
+    /// Display booleans as `✔` and `✘`.
+    pub fn check_marks() -> Self {
+        Self::new("✔", "✘")
+    }
+
+    /// Display booleans as `yes` and `no`.
+    pub fn yes_no() -> Self {
+        Self::new("yes", "no")
+    }
+
+    /// Display booleans as `1` and `0`.
+    pub fn numeric() -> Self {
+        Self::new("1", "0")
+    }
+
+    /// Set the style of `true` values.
+    ///
+    /// The colors of the style win over the colors of the cell.
+    #[cfg(feature = "tty")]
+    #[must_use]
+    pub fn style_true(mut self, style: Style) -> Self {
+        self.style_true = Some(style);
+
+        self
+    }
+
+    /// Set the style of `false` values.
+    ///
+    /// The colors of the style win over the colors of the cell.
+    #[cfg(feature = "tty")]
+    #[must_use]
+    pub fn style_false(mut self, style: Style) -> Self {
+        self.style_false = Some(style);
+
+        self
+    }
+
+    /// Get the text that's displayed for a value.
+    pub fn text(&self, value: bool) -> &str {
+        if value {
+            &self.true_str
+        } else {
+            &self.false_str
+        }
+    }
+
+    /// Get the style of a value.
+    #[cfg(feature = "tty")]
+    pub(crate) fn style(&self, value: bool) -> Option<&Style> {
+        if value {
+            self.style_true.as_ref()
+        } else {
+            self.style_false.as_ref()
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use std::fmt::Debug;
+
+use crate::style::TableComponent;
+use crate::table::Table;
+
+/// Draws the horizontal lines of a table, for instance the top border or the separators between rows.
+///
+/// By default, super-table draws these lines itself, based on the [TableComponents](crate::TableComponent)
+/// of the table. A custom renderer can be set via [Table::set_border_renderer] to draw these lines
+/// differently, without touching how the content of cells is formatted.
+///
+/// Renderers receive the layout of each line as a [Separator].
+/// The line that super-table would draw is available as well, so renderers can decide to only
+/// change some of the lines.
+///
+/// ```
+/// use super_table::{BorderRenderer, Separator, SeparatorKind, Table};
+///
+/// /// Draw the border below the header with a wavy line.
+/// #[derive(Debug)]
+/// struct WavyHeader;
+///
+/// impl BorderRenderer for WavyHeader {
+///     fn draw_separator(&self, separator: &Separator) -> String {
+///         if separator.kind != SeparatorKind::Header {
+///             return separator.default_line.to_string();
+///         }
+///         let width = separator.columns.iter().map(|column| column.width + 1).sum::<usize>() + 1;
+///         "~".repeat(width)
+///     }
+/// }
+///
+/// let mut table = Table::new();
+/// table
+///     .set_border_renderer(WavyHeader)
+///     .set_header(vec!["Header 1", "Header 2"])
+///     .add_row(vec!["One", "Two"]);
+///
+/// assert_eq!(table.lines().nth(2).unwrap(), "~~~~~~~~~~~~~~~~~~~~~~~");
+/// ```
+pub trait BorderRenderer: Debug + Send + Sync {
+    /// Draw a single horizontal line of the table.
+    fn draw_separator(&self, separator: &Separator<'_>) -> String;
+}
+
+/// The renderer, which is used if no other renderer is set.
+///
+/// It draws every line just like super-table does by default.
+/// This is convenient to delegate to, if a renderer only wants to change some of the lines.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DefaultBorderRenderer;
+
+impl BorderRenderer for DefaultBorderRenderer {
+    fn draw_separator(&self, separator: &Separator<'_>) -> String {
+        separator.default_line.to_string()
+    }
+}
+
+/// The position of a horizontal line within a table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SeparatorKind {
+    /// The border above the first row.
+    Top,
+    /// The line between the header and the first row.
+    Header,
+    /// The line between two rows.
+    Row,
+    /// The border below the last row.
+    Bottom,
+}
+
+/// The layout of a horizontal line, which is passed to a [BorderRenderer].
+#[derive(Debug)]
+pub struct Separator<'a> {
+    /// The table that's being rendered.
+    pub table: &'a Table,
+    /// The position of this line.
+    pub kind: SeparatorKind,
+    /// The index of the row above this line, including the header.
+    /// This is `None` for the top border.
+    pub row_above: Option<usize>,
+    /// All visible columns that are crossed by this line.
+    pub columns: Vec<SeparatorColumn>,
+    /// Whether a left border is drawn.
+    pub left_border: bool,
+    /// Whether a right border is drawn.
+    pub right_border: bool,
+    /// The line, as it would be drawn by default.
+    pub default_line: &'a str,
+}
+
+impl Separator<'_> {
+    /// Get the char that's used for a component of the table, if it's set.
+    pub fn component(&self, component: TableComponent) -> Option<char> {
+        self.table.component_char(component)
+    }
+}
+
+/// A single visible column that's crossed by a [Separator].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SeparatorColumn {
+    /// The width of the column, including padding.
+    pub width: usize,
+    /// The cell above the line spans this column and the column to its left.
+    pub joined_above: bool,
+    /// The cell below the line spans this column and the column to its left.
+    pub joined_below: bool,
+    /// A cell that spans multiple rows continues across the line in this column.
+    /// By default, no line is drawn in that case.
+    pub rowspan: bool,
+}
@@ -41,4 +41,106 @@ pub enum VerticalAlignment {
     Middle,
     /// Content is aligned to the bottom of the cell
     Bottom,
+    /// The given line of the content is the baseline of the cell, which is aligned with the
+    /// baselines of all other cells in the row, that use this alignment. The first line is `0`.
+    ///
+    /// This is useful for cells that stack a label above a value, e.g. `Baseline(2)` and
+    /// `Baseline(1)` for the value of these cells:
+    ///
+    /// ```text
+    /// +--------+-------+
+    /// | Total  |       |
+    /// | amount | Count |
+    /// | 1024   | 3     |
+    /// +--------+-------+
+    /// ```
+    ///
+    /// The line is the line of the content, as it has been added, and not a wrapped line.
+    /// Cells without that line, and cells that span multiple rows, are aligned to the top.
+    Baseline(usize),
+}
+
+/// Determines how the lines of a rowspan cell are filled, which aren't covered by its content.
+///
+/// Cells that span multiple rows often have less content than the spanned rows have lines.
+///
+/// ```text
+/// +---------+-----+        +---------+-----+
+/// | Rowspan | One |        | Rowspan | One |
+/// |         |-----|        | ------- |-----|
+/// |         | Two |        | ------- | Two |
+/// +---------+-----+        +---------+-----+
+///   Blank                    Repeat('-')
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub enum FillPolicy {
+    /// Leave the remaining lines empty (default)
+    #[default]
+    Blank,
+    /// Fill the remaining lines with the given character.
+    /// The padding of the cell is kept.
+    Repeat(char),
+}
+
+/// Determines in which direction the content of a cell is written.
+///
+/// Vertical text is useful for narrow header columns of wide matrices.
+///
+/// ```text
+/// +-------+---+---+
+/// | Name  | R | W |
+/// |       | e | r |
+/// |       | a | i |
+/// |       | d | t |
+/// |       |   | e |
+/// |-------+---+---|
+/// | admin | x | x |
+/// +-------+---+---+
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub enum Orientation {
+    /// The content is written from left to right (default)
+    #[default]
+    Horizontal,
+    /// Each grapheme of the content is written on its own line, from top to bottom.
+    /// The lines of the content are separated by an empty line.
+    Vertical,
+}
+
+/// The structural role of a cell within a table, see [Cell::set_role](crate::Cell::set_role).
+///
+/// Cells without a role are [header](CellRole::Header) cells in the header row and
+/// [data](CellRole::Data) cells in all other rows.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CellRole {
+    /// A regular value.
+    Data,
+    /// A cell that describes other cells, like the title of a column or row.
+    Header,
+    /// A cell of the rows at the end of a table, like notes or page numbers.
+    Footer,
+    /// A cell, whose value is calculated from other cells, like a total.
+    Summary,
+    /// A comment on other cells, that isn't part of the data.
+    Annotation,
+}
+
+impl CellRole {
+    /// The name of this role, which is used as [semantic style](crate::Cell::set_semantic_style)
+    /// of cells without one.
+    ///
+    /// ```
+    /// use super_table::CellRole;
+    ///
+    /// assert_eq!(CellRole::Summary.name(), "summary");
+    /// ```
+    pub fn name(self) -> &'static str {
+        match self {
+            CellRole::Data => "data",
+            CellRole::Header => "header",
+            CellRole::Footer => "footer",
+            CellRole::Summary => "summary",
+            CellRole::Annotation => "annotation",
+        }
+    }
 }
@@ -1,3 +1,5 @@
+use unicode_segmentation::UnicodeSegmentation;
+
 /// A Constraint can be added to a [columns](crate::Column).
 ///
 /// They allow some control over Column widths as well as the dynamic arrangement process.
@@ -40,3 +42,227 @@ pub enum Width {
     /// - the width of the table/terminal cannot be determined.
     Percentage(u16),
 }
+
+/// The order in which a column is sorted.
+///
+/// This is used to display a sort indicator in the header via
+/// [Column::set_sort_indicator](crate::Column::set_sort_indicator).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SortOrder {
+    /// Smallest values first. Displayed as `▲` by default.
+    Ascending,
+    /// Largest values first. Displayed as `▼` by default.
+    Descending,
+}
+
+/// Hide the content of a column's cells when rendering a table.
+///
+/// The content that's stored in the cells isn't changed, only the displayed content.
+/// Header cells are never masked.
+///
+/// Set the policy via [Column::set_mask](crate::Column::set_mask).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MaskPolicy {
+    /// Replace every character with `*`.
+    Full,
+    /// Only show the given amount of characters at the start and the end of the content.
+    /// All other characters are replaced with `*`.
+    ///
+    /// If the content is too short to hide anything, it's masked completely.
+    Partial {
+        visible_prefix: usize,
+        visible_suffix: usize,
+    },
+    /// Replace the content with a 16 digit hexadecimal hash of it.
+    ///
+    /// Identical values result in identical hashes, which allows to compare values without
+    /// revealing them.\
+    /// **Attention:** The hash is stable, but it's not cryptographically secure.
+    /// Values that are easy to guess can be recovered from their hash.
+    Hash,
+}
+
+impl MaskPolicy {
+    /// Mask some text according to this policy.
+    ///
+    /// [MaskPolicy::Full] and [MaskPolicy::Partial] are applied to each line separately.
+    ///
+    /// ```
+    /// use super_table::MaskPolicy;
+    ///
+    /// let policy = MaskPolicy::Partial {
+    ///     visible_prefix: 0,
+    ///     visible_suffix: 4,
+    /// };
+    /// assert_eq!(policy.mask("4111111111111111"), "************1111");
+    /// assert_eq!(MaskPolicy::Full.mask("secret"), "******");
+    /// ```
+    pub fn mask(&self, text: &str) -> String {
+        match *self {
+            MaskPolicy::Full => text
+                .split('\n')
+                .map(|line| "*".repeat(line.graphemes(true).count()))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            MaskPolicy::Partial {
+                visible_prefix,
+                visible_suffix,
+            } => text
+                .split('\n')
+                .map(|line| mask_partially(line, visible_prefix, visible_suffix))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            // There's nothing to hide in empty cells.
+            MaskPolicy::Hash if text.is_empty() => String::new(),
+            MaskPolicy::Hash => format!("{:016x}", fnv1a(text.as_bytes())),
+        }
+    }
+}
+
+/// Mask everything except the first `prefix` and the last `suffix` graphemes of a line.
+fn mask_partially(line: &str, prefix: usize, suffix: usize) -> String {
+    let graphemes: Vec<&str> = line.graphemes(true).collect();
+    let count = graphemes.len();
+    // Never show the full content.
+    if prefix.saturating_add(suffix) >= count {
+        return "*".repeat(count);
+    }
+
+    let mut masked = graphemes[..prefix].concat();
+    masked += &"*".repeat(count - prefix - suffix);
+    masked += &graphemes[count - suffix..].concat();
+
+    masked
+}
+
+/// The 64-bit FNV-1a hash of some bytes.
+///
+/// Used instead of the std hashers, as their output isn't guaranteed to be stable.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in bytes {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+
+    hash
+}
+
+/// Change the casing of a column's content when rendering a table.
+///
+/// The content that's stored in the cells isn't changed, only the displayed content.
+/// The transformation uses Unicode's default case mappings, which means that the result doesn't
+/// depend on the locale of the system.
+///
+/// Set the transformation via [Column::set_text_transform](crate::Column::set_text_transform).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TextTransform {
+    /// `some text` becomes `SOME TEXT`.
+    Uppercase,
+    /// `Some Text` becomes `some text`.
+    Lowercase,
+    /// `some TEXT` becomes `Some Text`.
+    ///
+    /// Every alphanumeric character that directly follows a non-alphanumeric character
+    /// starts a new word.
+    TitleCase,
+}
+
+impl TextTransform {
+    /// Transform some text.
+    ///
+    /// ```
+    /// use super_table::TextTransform;
+    ///
+    /// assert_eq!(TextTransform::Uppercase.apply("straße"), "STRASSE");
+    /// assert_eq!(TextTransform::TitleCase.apply("first-NAME"), "First-Name");
+    /// ```
+    pub fn apply(&self, text: &str) -> String {
+        self.apply_continued(text, &mut true)
+    }
+
+    /// Transform a piece of some larger text.
+    ///
+    /// `word_start` tracks whether the next character starts a new word, which allows to
+    /// transform text that's interrupted by other content (e.g. ansi codes).
+    pub(crate) fn apply_continued(&self, text: &str, word_start: &mut bool) -> String {
+        match self {
+            TextTransform::Uppercase => text.to_uppercase(),
+            TextTransform::Lowercase => text.to_lowercase(),
+            TextTransform::TitleCase => {
+                let mut transformed = String::with_capacity(text.len());
+                for character in text.chars() {
+                    if !character.is_alphanumeric() {
+                        *word_start = true;
+                        transformed.push(character);
+                    } else if *word_start {
+                        *word_start = false;
+                        transformed.extend(character.to_uppercase());
+                    } else {
+                        transformed.extend(character.to_lowercase());
+                    }
+                }
+
+                transformed
+            }
+        }
+    }
+}
+
+/// What happens to content, that's wider than its column.
+///
+/// Set the overflow via [Column::set_overflow](crate::Column::set_overflow).
+/// [Table::infer_column_types](crate::Table::infer_column_types) picks an overflow for each
+/// column, that doesn't have one yet.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Overflow {
+    /// Split the content into multiple lines.
+    #[default]
+    Wrap,
+    /// Split the content into multiple lines, but never within a word.
+    ///
+    /// Like [Cell::set_no_wrap](crate::Cell::set_no_wrap) for all cells of the column, except
+    /// for the header.
+    NoWrap,
+    /// Cut off the end of each line, that doesn't fit, and add the
+    /// [truncation indicator](crate::Table::set_truncation_indicator).
+    Truncate,
+    /// Cut off the middle of each line, that doesn't fit, and add the
+    /// [truncation indicator](crate::Table::set_truncation_indicator) in its place.
+    /// The start and end of identifiers like hashes or paths stay visible.
+    TruncateMiddle,
+}
+
+/// The amount of decimal places, that numbers of a column are displayed with.
+///
+/// All numeric cells of the column are displayed with the same amount of decimal places, so
+/// their decimal points line up, once the column is right aligned.
+/// The content that's stored in the cells isn't changed, only the displayed content.
+///
+/// Set the precision via [Column::set_decimal_precision](crate::Column::set_decimal_precision).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DecimalPrecision {
+    /// Use the highest amount of decimal places any number of the column needs.
+    Auto,
+    /// Like [DecimalPrecision::Auto], but use at most the given amount of decimal places.
+    /// Numbers with more decimal places are rounded.
+    Max(usize),
+}
+
+/// How negative numbers of a column are displayed.
+///
+/// Only the sign is changed, digits aren't grouped.
+/// Cells whose content already groups the digits of their value, like `-1,234`, keep their
+/// grouping, unless the column has a [DecimalPrecision], which displays the value itself.
+///
+/// Set the format via [Column::set_negative_format](crate::Column::set_negative_format).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum NegativeFormat {
+    /// Display negative numbers with a leading minus, e.g. `-1234`.
+    #[default]
+    Minus,
+    /// Display negative numbers in parentheses like in accounting, e.g. `(1234)`.
+    ///
+    /// Other numbers get a trailing space, so the digits of all numbers line up.
+    Parentheses,
+}
@@ -0,0 +1,99 @@
+use crate::cell::Cell;
+use crate::column::Column;
+use crate::row::Row;
+#[cfg(feature = "tty")]
+use crate::style::{Attribute, Color};
+use crate::style::{CellAlignment, HeaderDefaults, VerticalAlignment};
+
+/// The styling of a cell, after the settings of its column, its row and the table have been
+/// taken into account.
+///
+/// Each setting is resolved in the order `Cell > Column > Row > Table`.
+/// The first of them that has the setting wins.
+/// The [HeaderDefaults] of a table are resolved right after the cell for header cells.
+/// For instance, the alignment of a [column](Column::set_cell_alignment) overwrites the
+/// alignment of a [row](Row::set_cell_alignment), but not the one of a
+/// [cell](Cell::set_alignment).
+///
+/// Padding can only be set on columns.
+/// Cells that span multiple columns use the left padding of their first and the right padding
+/// of their last visible column.
+///
+/// Use [Cell::effective_style] to get the style of a cell in a table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct EffectiveStyle {
+    /// The horizontal alignment of the content. Default is [CellAlignment::Left].
+    pub alignment: CellAlignment,
+    /// The vertical alignment of the content. Default is [VerticalAlignment::Top].
+    pub vertical_alignment: VerticalAlignment,
+    /// The padding on the left and right side of the content.
+    pub padding: (u16, u16),
+    /// The foreground color of the content.
+    #[cfg(feature = "tty")]
+    pub fg: Option<Color>,
+    /// The background color of the content.
+    #[cfg(feature = "tty")]
+    pub bg: Option<Color>,
+    /// The attributes of the content.
+    #[cfg(feature = "tty")]
+    pub attributes: Vec<Attribute>,
+}
+
+impl EffectiveStyle {
+    /// Resolve the style of a cell in a row.
+    ///
+    /// `columns` are the columns the cell spans.
+    /// `header_defaults` are only passed for cells of the header.
+    pub(crate) fn resolve(
+        cell: &Cell,
+        row: &Row,
+        columns: &[Column],
+        header_defaults: Option<&HeaderDefaults>,
+    ) -> Self {
+        let visible_columns: Vec<&Column> = columns
+            .iter()
+            .filter(|column| !column.is_hidden())
+            .collect();
+        let first_column = visible_columns.first();
+        let padding = (
+            visible_columns.first().map_or(0, |column| column.padding.0),
+            visible_columns.last().map_or(0, |column| column.padding.1),
+        );
+
+        #[cfg(feature = "tty")]
+        let styled = row.style_cell(cell);
+
+        Self {
+            alignment: cell
+                .alignment
+                .or(header_defaults.and_then(|defaults| defaults.alignment))
+                .or(first_column.and_then(|column| column.cell_alignment))
+                .or(row.cell_alignment)
+                .unwrap_or(CellAlignment::Left),
+            vertical_alignment: cell
+                .vertical_alignment
+                .or(first_column.and_then(|column| column.vertical_alignment))
+                .or(row.vertical_alignment)
+                .unwrap_or(VerticalAlignment::Top),
+            padding,
+            #[cfg(feature = "tty")]
+            fg: styled.fg,
+            #[cfg(feature = "tty")]
+            bg: styled.bg,
+            #[cfg(feature = "tty")]
+            attributes: match header_defaults {
+                Some(defaults) => {
+                    let mut attributes = styled.attributes.clone();
+                    for attribute in &defaults.attributes {
+                        if !attributes.contains(attribute) {
+                            attributes.push(*attribute);
+                        }
+                    }
+                    attributes
+                }
+                None => styled.attributes.clone(),
+            },
+        }
+    }
+}
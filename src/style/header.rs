@@ -0,0 +1,63 @@
+#[cfg(feature = "tty")]
+use crate::Attribute;
+use crate::style::{CellAlignment, TextTransform};
+
+/// Defaults for all cells of the header, see [Table::set_header_defaults](crate::Table::set_header_defaults).
+///
+/// Each setting only applies to header cells, that don't have their own:
+/// - The [alignment](HeaderDefaults::alignment) is used for header cells without an
+///   [alignment](crate::Cell::set_alignment).
+///   It takes precedence over the [alignment of columns](crate::Column::set_cell_alignment),
+///   so headers of right-aligned number columns can still be centered.
+/// - The attributes are added to the attributes of each header cell.
+/// - The [transform](HeaderDefaults::transform) is used for columns without a
+///   [header text transform](crate::Column::set_header_text_transform).
+///
+/// ```
+/// use super_table::{CellAlignment, HeaderDefaults, TextTransform};
+///
+/// let defaults = HeaderDefaults::new()
+///     .alignment(CellAlignment::Center)
+///     .transform(TextTransform::Uppercase);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct HeaderDefaults {
+    pub(crate) alignment: Option<CellAlignment>,
+    #[cfg(feature = "tty")]
+    pub(crate) attributes: Vec<Attribute>,
+    pub(crate) transform: Option<TextTransform>,
+}
+
+impl HeaderDefaults {
+    /// Create defaults, that don't change any header cell.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the alignment of header cells.
+    #[must_use]
+    pub fn alignment(mut self, alignment: CellAlignment) -> Self {
+        self.alignment = Some(alignment);
+
+        self
+    }
+
+    /// Add an attribute to all header cells.
+    #[cfg(feature = "tty")]
+    #[must_use]
+    pub fn add_attribute(mut self, attribute: Attribute) -> Self {
+        if !self.attributes.contains(&attribute) {
+            self.attributes.push(attribute);
+        }
+
+        self
+    }
+
+    /// Set the transformation of the text of header cells.
+    #[must_use]
+    pub fn transform(mut self, transform: TextTransform) -> Self {
+        self.transform = Some(transform);
+
+        self
+    }
+}
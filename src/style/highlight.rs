@@ -0,0 +1,64 @@
+use std::ops::Range;
+
+/// A pattern that can be highlighted inside of cells via
+/// [Table::highlight_matches](crate::Table::highlight_matches).
+///
+/// Plain strings are matched as case-sensitive substrings.
+/// With the `regex` feature enabled, `regex::Regex` can be used as a pattern as well.
+///
+/// ```
+/// use super_table::HighlightPattern;
+///
+/// let pattern: HighlightPattern = "error".into();
+/// ```
+#[derive(Clone, Debug)]
+pub enum HighlightPattern {
+    /// Highlight every occurrence of this string.
+    Substring(String),
+    /// Highlight every match of this regular expression.
+    #[cfg(feature = "regex")]
+    Regex(regex::Regex),
+}
+
+impl HighlightPattern {
+    /// Returns the byte ranges of all non-overlapping matches in `text`.
+    ///
+    /// Empty matches are ignored, as there's nothing to highlight.
+    pub(crate) fn find_ranges(&self, text: &str) -> Vec<Range<usize>> {
+        match self {
+            HighlightPattern::Substring(pattern) => {
+                if pattern.is_empty() {
+                    return Vec::new();
+                }
+                text.match_indices(pattern.as_str())
+                    .map(|(start, matched)| start..start + matched.len())
+                    .collect()
+            }
+            #[cfg(feature = "regex")]
+            HighlightPattern::Regex(regex) => regex
+                .find_iter(text)
+                .filter(|found| !found.is_empty())
+                .map(|found| found.range())
+                .collect(),
+        }
+    }
+}
+
+impl From<&str> for HighlightPattern {
+    fn from(pattern: &str) -> Self {
+        HighlightPattern::Substring(pattern.to_string())
+    }
+}
+
+impl From<String> for HighlightPattern {
+    fn from(pattern: String) -> Self {
+        HighlightPattern::Substring(pattern)
+    }
+}
+
+#[cfg(feature = "regex")]
+impl From<regex::Regex> for HighlightPattern {
+    fn from(regex: regex::Regex) -> Self {
+        HighlightPattern::Regex(regex)
+    }
+}
@@ -1,24 +1,56 @@
 #[cfg(all(feature = "tty", not(feature = "reexport_crossterm")))]
 mod attribute;
+mod boolean;
+mod border_renderer;
 mod cell;
 #[cfg(all(feature = "tty", not(feature = "reexport_crossterm")))]
 mod color;
 mod column;
+mod effective;
+mod header;
+#[cfg(feature = "tty")]
+mod highlight;
 /// Contains modifiers, that can be used to alter certain parts of a preset.\
 /// For instance, the [UTF8_ROUND_CORNERS](modifiers::UTF8_ROUND_CORNERS) replaces all corners with round UTF8 box corners.
 pub mod modifiers;
+mod null;
+/// Color palettes, that stay distinguishable for people with color blindness.\
+/// Palettes can be used for categories and scales, or to [replace all colors](crate::Table::set_palette) of a table.
+#[cfg(feature = "tty")]
+pub mod palettes;
 /// This module provides styling presets for tables.\
 /// Every preset has an example preview.
 pub mod presets;
+mod style_map;
 mod table;
+#[cfg(feature = "tty")]
+mod text_style;
 
-pub use cell::{CellAlignment, VerticalAlignment};
-pub use column::{ColumnConstraint, Width};
+pub use boolean::BoolFormat;
+pub use border_renderer::{
+    BorderRenderer, DefaultBorderRenderer, Separator, SeparatorColumn, SeparatorKind,
+};
+pub use cell::{CellAlignment, CellRole, FillPolicy, Orientation, VerticalAlignment};
+pub use column::{
+    ColumnConstraint, DecimalPrecision, MaskPolicy, NegativeFormat, Overflow, SortOrder,
+    TextTransform, Width,
+};
+pub use effective::EffectiveStyle;
+pub use header::HeaderDefaults;
+#[cfg(feature = "tty")]
+pub use highlight::HighlightPattern;
+pub use null::NullStyle;
+pub use style_map::{StyleMap, StyleTarget};
 #[cfg(feature = "tty")]
 pub use styling_enums::{Attribute, Color};
 #[cfg(feature = "tty")]
 pub(crate) use styling_enums::{map_attribute, map_color};
-pub use table::{ContentArrangement, TableComponent};
+pub use table::{
+    AmbiguousWidth, ColspanBorderPolicy, ConstraintConflictPolicy, ContentArrangement,
+    ExportContent, HiddenColumnsSummary, KeyOrder, Margins, Section, TableComponent,
+};
+#[cfg(feature = "tty")]
+pub use text_style::{AttributePolicy, Style};
 
 /// Convenience module to have cleaner and "identical" conditional re-exports for style enums.
 #[cfg(all(feature = "tty", not(feature = "reexport_crossterm")))]
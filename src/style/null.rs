@@ -0,0 +1,80 @@
+#[cfg(feature = "tty")]
+use crate::{Attribute, Color};
+
+/// Specify how cells are displayed, whose typed value is [CellValue::Null](crate::CellValue::Null).
+///
+/// Database clients usually display missing values as a dimmed or italic `NULL`, so they can
+/// be told apart from empty strings.
+/// The style only applies to cells that have been created via
+/// [Cell::from_value](crate::Cell::from_value) or [Cell::set_value](crate::Cell::set_value),
+/// as empty cells without a typed value are just empty strings.
+///
+/// The default style displays `NULL`, which is dimmed and italic.
+///
+/// ```
+/// use super_table::{Cell, CellValue, NullStyle, Table};
+///
+/// let mut table = Table::new();
+/// table
+///     .set_null_style(NullStyle::new("(null)"))
+///     .add_row(vec![Cell::new("name"), Cell::from_value(CellValue::Null)]);
+/// assert!(table.to_string().contains("| name | (null) |"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NullStyle {
+    pub(crate) text: String,
+    #[cfg(feature = "tty")]
+    pub(crate) fg: Option<Color>,
+    #[cfg(feature = "tty")]
+    pub(crate) attributes: Vec<Attribute>,
+}
+
+impl Default for NullStyle {
+    fn default() -> Self {
+        let style = Self::new("NULL");
+        #[cfg(feature = "tty")]
+        let style = style
+            .add_attribute(Attribute::Dim)
+            .add_attribute(Attribute::Italic);
+
+        style
+    }
+}
+
+impl NullStyle {
+    /// Create a style, which displays `NULL` values as the given text without any styling.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            #[cfg(feature = "tty")]
+            fg: None,
+            #[cfg(feature = "tty")]
+            attributes: Vec::new(),
+        }
+    }
+
+    /// Get the text that's displayed instead of `NULL` values.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Set the foreground color of `NULL` values.
+    ///
+    /// Cells with their own [foreground color](crate::Cell::fg) keep it.
+    #[cfg(feature = "tty")]
+    #[must_use]
+    pub fn fg(mut self, color: Color) -> Self {
+        self.fg = Some(color);
+
+        self
+    }
+
+    /// Add a styling attribute to `NULL` values.
+    #[cfg(feature = "tty")]
+    #[must_use]
+    pub fn add_attribute(mut self, attribute: Attribute) -> Self {
+        self.attributes.push(attribute);
+
+        self
+    }
+}
@@ -0,0 +1,284 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use crate::style::Color;
+
+/// The colors of the [Okabe-Ito](https://jfly.uni-koeln.de/color/) palette, which stay
+/// distinguishable for all common forms of color blindness.
+///
+/// The black of the original palette is left out, as it isn't visible on dark terminals.
+/// This palette is meant for [categories](Palette::category_colors).
+pub const OKABE_ITO: Palette = Palette::from_static(&[
+    rgb(230, 159, 0),
+    rgb(86, 180, 233),
+    rgb(0, 158, 115),
+    rgb(240, 228, 66),
+    rgb(0, 114, 178),
+    rgb(213, 94, 0),
+    rgb(204, 121, 167),
+]);
+
+/// Eight steps of the [Viridis](https://cran.r-project.org/web/packages/viridis/vignettes/intro-to-viridis.html)
+/// color map, from dark purple to yellow.
+///
+/// Its brightness increases steadily, so values can be told apart with any form of color
+/// blindness and even in grayscale.
+/// This palette is meant for [scales](Palette::scale), e.g. heatmaps.
+pub const VIRIDIS: Palette = Palette::from_static(&[
+    rgb(68, 1, 84),
+    rgb(70, 50, 126),
+    rgb(54, 92, 141),
+    rgb(39, 127, 142),
+    rgb(31, 161, 135),
+    rgb(74, 193, 109),
+    rgb(160, 218, 57),
+    rgb(253, 231, 37),
+]);
+
+/// Colors whose difference between the strongest and the weakest channel doesn't exceed this
+/// value are treated as shades of grey, which are kept by [Palette::remap].
+const MAX_GREY_CHROMA: u8 = 24;
+
+/// An ordered list of colors, which is used for categories, scales or to
+/// [replace the colors of a table](crate::Table::set_palette).
+///
+/// ```
+/// use super_table::Color;
+/// use super_table::palettes::{OKABE_ITO, Palette};
+///
+/// assert_eq!(OKABE_ITO.remap(Color::Red), Color::Rgb { r: 213, g: 94, b: 0 });
+///
+/// let palette = Palette::new(vec![
+///     Color::Rgb { r: 0, g: 0, b: 0 },
+///     Color::Rgb { r: 200, g: 100, b: 0 },
+/// ]);
+/// assert_eq!(palette.scale(0.5), Color::Rgb { r: 100, g: 50, b: 0 });
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Palette {
+    colors: Cow<'static, [Color]>,
+}
+
+impl Palette {
+    /// Create a palette with custom colors.
+    pub fn new(colors: Vec<Color>) -> Self {
+        Self {
+            colors: Cow::Owned(colors),
+        }
+    }
+
+    const fn from_static(colors: &'static [Color]) -> Self {
+        Self {
+            colors: Cow::Borrowed(colors),
+        }
+    }
+
+    /// Get the colors of this palette.
+    pub fn colors(&self) -> &[Color] {
+        &self.colors
+    }
+
+    /// Get the color of the category with the given index.
+    ///
+    /// Once all colors have been used, they're used again from the start.
+    /// An empty palette returns [Color::Reset].
+    pub fn color(&self, index: usize) -> Color {
+        if self.colors.is_empty() {
+            return Color::Reset;
+        }
+
+        self.colors[index % self.colors.len()]
+    }
+
+    /// Assign the colors of this palette to the categories in the given order, e.g. for
+    /// [Column::set_category_colors](crate::Column::set_category_colors).
+    ///
+    /// ```
+    /// use super_table::Table;
+    /// use super_table::palettes::OKABE_ITO;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Job", "Status"])
+    ///     .add_row(vec!["backup", "running"]);
+    /// let colors = OKABE_ITO.category_colors(["running", "failed", "pending"]);
+    /// table.column_mut(1).unwrap().set_category_colors(colors);
+    /// ```
+    pub fn category_colors<I, T>(&self, categories: I) -> HashMap<String, Color>
+    where
+        I: IntoIterator<Item = T>,
+        T: ToString,
+    {
+        categories
+            .into_iter()
+            .enumerate()
+            .map(|(index, category)| (category.to_string(), self.color(index)))
+            .collect()
+    }
+
+    /// Get the color at a position between `0.0` and `1.0` of a scale from the first to the
+    /// last color of this palette.
+    ///
+    /// Positions between two colors are interpolated. Positions outside of the scale are
+    /// clamped and `NaN` returns the first color.
+    pub fn scale(&self, position: f64) -> Color {
+        let steps = self.colors.len().saturating_sub(1);
+        if steps == 0 || position.is_nan() || position <= 0.0 {
+            return self.color(0);
+        }
+        if position >= 1.0 {
+            return self.color(steps);
+        }
+
+        let exact = position * steps as f64;
+        let index = (exact.floor() as usize).min(steps - 1);
+        let fraction = exact - index as f64;
+        let (Some(from), Some(to)) = (to_rgb(self.colors[index]), to_rgb(self.colors[index + 1]))
+        else {
+            return self.colors[if fraction < 0.5 { index } else { index + 1 }];
+        };
+
+        let channel = |from: u8, to: u8| {
+            (f64::from(from) + (f64::from(to) - f64::from(from)) * fraction).round() as u8
+        };
+        Color::Rgb {
+            r: channel(from.0, to.0),
+            g: channel(from.1, to.1),
+            b: channel(from.2, to.2),
+        }
+    }
+
+    /// Replace a color by the most similar color of this palette.
+    ///
+    /// Shades of grey, black, white and [Color::Reset] are kept, as are all colors, if no color
+    /// of the palette can be compared.
+    pub fn remap(&self, color: Color) -> Color {
+        let Some(rgb) = to_rgb(color) else {
+            return color;
+        };
+        let chroma = rgb.0.max(rgb.1).max(rgb.2) - rgb.0.min(rgb.1).min(rgb.2);
+        if chroma <= MAX_GREY_CHROMA {
+            return color;
+        }
+
+        let distance = |other: (u8, u8, u8)| {
+            let channel = |a: u8, b: u8| (i32::from(a) - i32::from(b)).pow(2);
+            channel(rgb.0, other.0) + channel(rgb.1, other.1) + channel(rgb.2, other.2)
+        };
+        self.colors
+            .iter()
+            .filter_map(|candidate| Some((*candidate, distance(to_rgb(*candidate)?))))
+            .min_by_key(|(_, distance)| *distance)
+            .map_or(color, |(candidate, _)| candidate)
+    }
+}
+
+const fn rgb(r: u8, g: u8, b: u8) -> Color {
+    Color::Rgb { r, g, b }
+}
+
+/// The RGB values of a color, as most terminals display it.
+///
+/// Returns `None` for [Color::Reset], which depends on the terminal.
+pub(crate) fn to_rgb(color: Color) -> Option<(u8, u8, u8)> {
+    let value = match color {
+        Color::Reset => return None,
+        Color::Rgb { r, g, b } => return Some((r, g, b)),
+        Color::AnsiValue(value) => value,
+        Color::Black => 0,
+        Color::DarkRed => 1,
+        Color::DarkGreen => 2,
+        Color::DarkYellow => 3,
+        Color::DarkBlue => 4,
+        Color::DarkMagenta => 5,
+        Color::DarkCyan => 6,
+        Color::Grey => 7,
+        Color::DarkGrey => 8,
+        Color::Red => 9,
+        Color::Green => 10,
+        Color::Yellow => 11,
+        Color::Blue => 12,
+        Color::Magenta => 13,
+        Color::Cyan => 14,
+        Color::White => 15,
+    };
+
+    Some(ansi_to_rgb(value))
+}
+
+/// The RGB values of the 256 ANSI colors in the xterm palette.
+fn ansi_to_rgb(value: u8) -> (u8, u8, u8) {
+    const BASIC: [(u8, u8, u8); 16] = [
+        (0, 0, 0),
+        (128, 0, 0),
+        (0, 128, 0),
+        (128, 128, 0),
+        (0, 0, 128),
+        (128, 0, 128),
+        (0, 128, 128),
+        (192, 192, 192),
+        (128, 128, 128),
+        (255, 0, 0),
+        (0, 255, 0),
+        (255, 255, 0),
+        (0, 0, 255),
+        (255, 0, 255),
+        (0, 255, 255),
+        (255, 255, 255),
+    ];
+    const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+    match value {
+        0..=15 => BASIC[usize::from(value)],
+        16..=231 => {
+            let index = value - 16;
+            (
+                CUBE_LEVELS[usize::from(index / 36)],
+                CUBE_LEVELS[usize::from(index / 6 % 6)],
+                CUBE_LEVELS[usize::from(index % 6)],
+            )
+        }
+        _ => {
+            let grey = 8 + (value - 232) * 10;
+            (grey, grey, grey)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remap_named_colors() {
+        let remapped: Vec<Color> = [Color::Red, Color::Green, Color::Blue, Color::Yellow]
+            .into_iter()
+            .map(|color| OKABE_ITO.remap(color))
+            .collect();
+        assert_eq!(
+            remapped,
+            vec![
+                OKABE_ITO.color(5),
+                OKABE_ITO.color(2),
+                OKABE_ITO.color(4),
+                OKABE_ITO.color(3),
+            ]
+        );
+
+        assert_eq!(OKABE_ITO.remap(Color::Grey), Color::Grey);
+        assert_eq!(
+            OKABE_ITO.remap(Color::AnsiValue(240)),
+            Color::AnsiValue(240)
+        );
+        assert_eq!(OKABE_ITO.remap(Color::Reset), Color::Reset);
+    }
+
+    #[test]
+    fn test_scale() {
+        assert_eq!(VIRIDIS.scale(-1.0), VIRIDIS.color(0));
+        assert_eq!(VIRIDIS.scale(f64::NAN), VIRIDIS.color(0));
+        assert_eq!(VIRIDIS.scale(1.0), VIRIDIS.color(7));
+        assert_eq!(VIRIDIS.scale(3.0 / 7.0), VIRIDIS.color(3));
+        assert_eq!(Palette::new(Vec::new()).scale(0.5), Color::Reset);
+    }
+}
@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+#[cfg(feature = "tty")]
+use crate::Style;
+
+/// How a semantic style is represented in one output format.
+///
+/// See [StyleMap] for details.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StyleTarget {
+    /// Colors and attributes, which are written as ANSI escape sequences when the table is
+    /// displayed in a terminal.
+    #[cfg(feature = "tty")]
+    Terminal(Style),
+    /// CSS classes, which are added to the element of a cell by [Table::to_html](crate::Table::to_html).
+    /// Multiple classes are separated by spaces, like in the `class` attribute.
+    HtmlClass(String),
+}
+
+/// Maps the [semantic styles](crate::Cell::set_semantic_style) of cells, like `error` or
+/// `muted`, to their representation in each output format.
+///
+/// Cells only name the meaning of their content, while the table decides how that meaning
+/// looks like via [Table::set_style_map](crate::Table::set_style_map).
+/// Each name can have one [StyleTarget] per output format.
+/// Formats without a target for a name display the cell without any styling, which is always
+/// the case for plain text formats like CSV.
+///
+/// ```
+/// # #[cfg(feature = "tty")] {
+/// use super_table::{Attribute, Color, Style, StyleMap, StyleTarget};
+///
+/// let styles = StyleMap::new()
+///     .add("error", StyleTarget::Terminal(Style::new().fg(Color::Red)))
+///     .add("error", StyleTarget::HtmlClass("text-error".to_string()))
+///     .add("muted", StyleTarget::Terminal(Style::new().add_attribute(Attribute::Dim)));
+/// assert_eq!(styles.targets("error").len(), 2);
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StyleMap {
+    styles: HashMap<String, Vec<StyleTarget>>,
+}
+
+impl StyleMap {
+    /// Create a map without any styles.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the representation of a semantic style in an output format.
+    ///
+    /// A previous target of the same output format is replaced.
+    #[must_use]
+    pub fn add(mut self, name: impl Into<String>, target: StyleTarget) -> Self {
+        let targets = self.styles.entry(name.into()).or_default();
+        targets
+            .retain(|existing| std::mem::discriminant(existing) != std::mem::discriminant(&target));
+        targets.push(target);
+
+        self
+    }
+
+    /// Get the representations of a semantic style in all output formats.
+    pub fn targets(&self, name: &str) -> &[StyleTarget] {
+        self.styles.get(name).map_or(&[], Vec::as_slice)
+    }
+
+    /// Get the terminal style of a semantic style.
+    #[cfg(feature = "tty")]
+    pub(crate) fn terminal(&self, name: &str) -> Option<&Style> {
+        self.targets(name).iter().find_map(|target| match target {
+            StyleTarget::Terminal(style) => Some(style),
+            _ => None,
+        })
+    }
+
+    /// Get the CSS classes of a semantic style.
+    #[cfg_attr(not(feature = "tty"), allow(clippy::unnecessary_find_map))]
+    pub(crate) fn html_class(&self, name: &str) -> Option<&str> {
+        self.targets(name).iter().find_map(|target| match target {
+            StyleTarget::HtmlClass(class) => Some(class.as_str()),
+            #[cfg(feature = "tty")]
+            _ => None,
+        })
+    }
+}
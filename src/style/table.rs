@@ -26,6 +26,169 @@ pub enum ContentArrangement {
     DynamicFullWidth,
 }
 
+/// Specify what happens, if the widths required by column constraints exceed the width of a
+/// table with a [dynamic content arrangement](ContentArrangement::Dynamic).
+///
+/// The required width consists of the widths of constrained columns, including their padding,
+/// the smallest possible width of all other columns and the borders.
+///
+/// ```
+/// use super_table::{ConstraintConflictPolicy, Table};
+///
+/// let mut table = Table::new();
+/// table.set_constraint_conflict_policy(ConstraintConflictPolicy::Shrink);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ConstraintConflictPolicy {
+    /// Shrink the constrained columns proportionally to their width, until the table fits.
+    Shrink,
+    /// Ignore the constraints of columns, starting with the lowest-priority column, until the
+    /// table fits.\
+    /// Columns with a lower [truncation priority](crate::Column::set_truncation_priority) lose
+    /// their constraints first. Of columns with the same priority, the rightmost column goes first.
+    DropLowestPriority,
+    /// Keep all constraints.\
+    /// The table is drawn wider than its width and
+    /// [Table::try_lines](crate::Table::try_lines) returns an error.
+    #[default]
+    Error,
+}
+
+/// Specify whether the bottom border is drawn without intersections below cells of the last row,
+/// that span multiple columns.
+///
+/// ```
+/// use super_table::{ColspanBorderPolicy, Table};
+///
+/// let mut table = Table::new();
+/// table.set_colspan_border_policy(ColspanBorderPolicy::AlwaysMerge);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ColspanBorderPolicy {
+    /// Merge the border below a colspan, if the header spans the same columns or the table has
+    /// at most two rows.
+    #[default]
+    Auto,
+    /// Always merge the border below a colspan.
+    AlwaysMerge,
+    /// Always draw the intersections of all columns.
+    NeverMerge,
+}
+
+/// Specify where a trailing column displays how many columns are hidden.
+///
+/// The column is generated when the table is rendered and only displayed, if columns are
+/// hidden, either via [ColumnConstraint::Hidden](crate::ColumnConstraint::Hidden) or because
+/// they don't fit into the width of the table.
+///
+/// ```text
+/// +------+----+        +------+----+
+/// | Host | +2 |        | Host | +2 |
+/// +===========+        +===========+
+/// | db-1 |    |        | db-1 | +2 |
+/// +------+----+        +------+----+
+///    Header               Rows
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HiddenColumnsSummary {
+    /// Display the amount of hidden columns in the header only.
+    /// Without a header, it's displayed in the first row.
+    Header,
+    /// Display the amount of hidden columns in the header and the amount of hidden cells with
+    /// content in each row.
+    Rows,
+}
+
+/// The parts of a table, that can be styled with their own
+/// [preset](crate::Table::load_preset_for).
+///
+/// The top border belongs to the section of the first row and the bottom border to the section
+/// of the last row. Lines between two rows belong to the section of the row above, except for
+/// the line above the footer, which belongs to the footer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Section {
+    /// The header row and the line below it.
+    Header,
+    /// All rows, that aren't part of the header or footer.
+    Body,
+    /// The trailing rows, whose cells all have the [footer role](crate::CellRole::Footer).
+    Footer,
+}
+
+/// Blank space around a table, see [Table::set_margin](crate::Table::set_margin).
+///
+/// The left and right margins are measured in characters, the top and bottom margins in lines.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Margins {
+    /// The spaces before every line.
+    pub left: u16,
+    /// The spaces after every line.
+    pub right: u16,
+    /// The blank lines above the table.
+    pub top: u16,
+    /// The blank lines below the table.
+    pub bottom: u16,
+}
+
+/// Specify how wide characters with an ambiguous width are displayed.
+///
+/// Some characters, like `±`, `→` or `★`, have an
+/// [ambiguous width](https://www.unicode.org/reports/tr11/).
+/// Most terminals display them a single column wide, but terminals in East Asian locales
+/// usually display them two columns wide.
+///
+/// ```
+/// use super_table::{AmbiguousWidth, Table};
+///
+/// let mut table = Table::new();
+/// table.set_ambiguous_width(AmbiguousWidth::Wide);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum AmbiguousWidth {
+    /// Characters with an ambiguous width are a single column wide.
+    #[default]
+    Narrow,
+    /// Characters with an ambiguous width are two columns wide, like in East Asian locales.
+    Wide,
+}
+
+/// Specify which content of a cell is used, when a table is exported to another format.
+///
+/// Content may be wrapped and truncated, once a table is rendered.
+/// See [Table::rendered_content](crate::table::Table::rendered_content) for details.
+///
+/// ```
+/// use super_table::{ExportContent, Table};
+///
+/// let mut table = Table::new();
+/// table.set_export_content(ExportContent::Rendered);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ExportContent {
+    /// Export the full content of cells, like [Cell::content](crate::Cell::content).
+    ///
+    /// Cells of [masked](crate::Column::set_mask) columns are still masked.
+    #[default]
+    Original,
+    /// Export the full content of cells like [ExportContent::Original], but format numbers and
+    /// booleans as configured by their column, e.g. by a [BoolFormat](crate::BoolFormat) or
+    /// [DecimalPrecision](crate::DecimalPrecision).
+    Formatted,
+    /// Export the content of cells as it's displayed, including wrapped and truncated lines.
+    Rendered,
+}
+
+/// Specify in which order the keys of maps become columns, see
+/// [Table::from_map_of_maps](crate::Table::from_map_of_maps).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum KeyOrder {
+    /// Keys are sorted alphabetically.
+    #[default]
+    Sorted,
+    /// Keys are ordered by their first occurrence.
+    FirstSeen,
+}
+
 /// All configurable table components.
 /// A character can be assigned to each component via [Table::set_style](crate::table::Table::set_style).
 /// This is then used to draw character of the respective component to the commandline.
@@ -44,7 +207,7 @@ pub enum ContentArrangement {
 /// |   |   |   |    The inner "+" chars are MiddleIntersections
 /// +---+---+---+
 /// ```
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
 pub enum TableComponent {
     LeftBorder,
     RightBorder,
@@ -74,10 +237,46 @@ pub enum TableComponent {
     TopRightCorner,
     BottomLeftCorner,
     BottomRightCorner,
+    /// Used where a horizontal line starts to the right of a cell, whose rowspan continues
+    /// below the line.
+    /// If it isn't set, [LeftBorderIntersections](TableComponent::LeftBorderIntersections) is
+    /// used, e.g. '├'.
+    RowspanLeftIntersection,
+    /// Used where a horizontal line ends to the left of a cell, whose rowspan continues below
+    /// the line.
+    /// If it isn't set, [RightBorderIntersections](TableComponent::RightBorderIntersections) is
+    /// used, e.g. '┤'.
+    RowspanRightIntersection,
+    /// Used at horizontal lines between rows, where a colspan starts in the row below.
+    /// If it isn't set, [BottomBorderIntersections](TableComponent::BottomBorderIntersections)
+    /// is used, e.g. '┴'.
+    MiddleMergeIntersection,
+    /// Used at horizontal lines between rows, where a colspan ends in the row above and the
+    /// columns are separated in the row below.
+    /// If it isn't set, [HorizontalLines](TableComponent::HorizontalLines) are used, which draws
+    /// a continuous line. A character like '┬' separates the columns instead.
+    MiddleSplitIntersection,
+    /// Like [MiddleSplitIntersection](TableComponent::MiddleSplitIntersection), but used at the
+    /// header separator below a colspan in the header.
+    /// If it isn't set, [HeaderLines](TableComponent::HeaderLines) are used, e.g. '═' instead of
+    /// '╤'.
+    MiddleHeaderSplitIntersection,
+    /// Used at the top border above a colspan in the header.
+    /// If it isn't set, [TopBorder](TableComponent::TopBorder) is used, which draws a continuous
+    /// line.
+    TopBorderColspanIntersections,
+    /// The line between the body and the footer of a table.
+    /// Its intersections are the ones of lines between rows.
+    /// If it isn't set, [HorizontalLines](TableComponent::HorizontalLines) are used.
+    FooterLines,
+    /// The line of a [section break](crate::Table::add_section_break).
+    /// Its intersections are the ones of the header separator.
+    /// If it isn't set, [HeaderLines](TableComponent::HeaderLines) are used, e.g. '═'.
+    SectionSeparator,
 }
 
 impl TableComponent {
-    const fn components() -> [TableComponent; 21] {
+    const fn components() -> [TableComponent; 29] {
         [
             TableComponent::LeftBorder,
             TableComponent::RightBorder,
@@ -100,10 +299,58 @@ impl TableComponent {
             TableComponent::TopRightCorner,
             TableComponent::BottomLeftCorner,
             TableComponent::BottomRightCorner,
+            TableComponent::RowspanLeftIntersection,
+            TableComponent::RowspanRightIntersection,
+            TableComponent::MiddleMergeIntersection,
+            TableComponent::MiddleSplitIntersection,
+            TableComponent::MiddleHeaderSplitIntersection,
+            TableComponent::TopBorderColspanIntersections,
+            TableComponent::FooterLines,
+            TableComponent::SectionSeparator,
         ]
     }
 
+    /// The component, that's drawn in place of this component, if it isn't set.
+    ///
+    /// Components, that have been added after presets could already be written, are derived
+    /// from an older component. That way, existing presets and styles keep working.
+    pub(crate) const fn fallback(self) -> Option<TableComponent> {
+        match self {
+            TableComponent::RowspanLeftIntersection => {
+                Some(TableComponent::LeftBorderIntersections)
+            }
+            TableComponent::RowspanRightIntersection => {
+                Some(TableComponent::RightBorderIntersections)
+            }
+            TableComponent::MiddleMergeIntersection => {
+                Some(TableComponent::BottomBorderIntersections)
+            }
+            TableComponent::MiddleSplitIntersection => Some(TableComponent::HorizontalLines),
+            TableComponent::MiddleHeaderSplitIntersection => Some(TableComponent::HeaderLines),
+            TableComponent::TopBorderColspanIntersections => Some(TableComponent::TopBorder),
+            TableComponent::FooterLines => Some(TableComponent::HorizontalLines),
+            TableComponent::SectionSeparator => Some(TableComponent::HeaderLines),
+            _ => None,
+        }
+    }
+
     pub fn iter() -> impl Iterator<Item = TableComponent> {
         TableComponent::components().into_iter()
     }
+
+    /// The component of each character of a preset string.
+    ///
+    /// Presets used to describe only the components up to
+    /// [BottomRightCorner](TableComponent::BottomRightCorner) and some were padded with two more
+    /// characters. Those two positions are `None`, so older presets are still loaded the same
+    /// way. All components that have been added later on follow after them.
+    pub(crate) fn preset_positions() -> impl Iterator<Item = Option<TableComponent>> {
+        const LEGACY_COMPONENTS: usize = 21;
+        let components = TableComponent::components().into_iter().map(Some);
+        components
+            .clone()
+            .take(LEGACY_COMPONENTS)
+            .chain([None, None])
+            .chain(components.skip(LEGACY_COMPONENTS))
+    }
 }
@@ -0,0 +1,185 @@
+use crossterm::style::{Stylize, style};
+
+use crate::style::{Attribute, Color, map_attribute, map_color};
+
+/// A reusable set of styling options for text.
+///
+/// A style bundles a foreground color, a background color and a list of [Attributes](Attribute).
+/// It's used wherever super-table applies styling that doesn't belong to a specific cell,
+/// for instance when [highlighting matches](crate::Table::highlight_matches).
+///
+/// ```
+/// use super_table::{Attribute, Color, Style};
+///
+/// let style = Style::new()
+///     .fg(Color::Black)
+///     .bg(Color::Yellow)
+///     .add_attribute(Attribute::Bold);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Style {
+    pub(crate) fg: Option<Color>,
+    pub(crate) bg: Option<Color>,
+    pub(crate) attributes: Vec<Attribute>,
+}
+
+impl Style {
+    /// Create a new style without any colors or attributes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the foreground text color of this style.
+    #[must_use]
+    pub fn fg(mut self, color: Color) -> Self {
+        self.fg = Some(color);
+
+        self
+    }
+
+    /// Set the background color of this style.
+    #[must_use]
+    pub fn bg(mut self, color: Color) -> Self {
+        self.bg = Some(color);
+
+        self
+    }
+
+    /// Add a styling attribute to this style.
+    #[must_use]
+    pub fn add_attribute(mut self, attribute: Attribute) -> Self {
+        self.attributes.push(attribute);
+
+        self
+    }
+
+    /// Same as add_attribute, but you can pass a vector of [Attributes](Attribute)
+    #[must_use]
+    pub fn add_attributes(mut self, mut attributes: Vec<Attribute>) -> Self {
+        self.attributes.append(&mut attributes);
+
+        self
+    }
+
+    /// Returns whether this style doesn't change the appearance of text at all.
+    pub fn is_empty(&self) -> bool {
+        self.fg.is_none() && self.bg.is_none() && self.attributes.is_empty()
+    }
+
+    /// Layer `other` on top of this style.
+    ///
+    /// Colors of `other` win, attributes of both styles are combined.
+    pub(crate) fn merge(&self, other: &Style) -> Style {
+        let mut attributes = self.attributes.clone();
+        attributes.extend(other.attributes.iter().copied());
+
+        Style {
+            fg: other.fg.or(self.fg),
+            bg: other.bg.or(self.bg),
+            attributes,
+        }
+    }
+
+    /// Wrap some text in the ansi escape codes of this style.
+    pub(crate) fn apply(&self, text: String) -> String {
+        // Just return the text, if there's no need to style.
+        if self.is_empty() {
+            return text;
+        }
+
+        let mut content = style(text);
+
+        if let Some(color) = self.fg {
+            content = content.with(map_color(color));
+        }
+
+        if let Some(color) = self.bg {
+            content = content.on(map_color(color));
+        }
+
+        for attribute in self.attributes.iter() {
+            content = content.attribute(map_attribute(*attribute));
+        }
+
+        content.to_string()
+    }
+}
+
+/// Specify how styling attributes are treated, which aren't supported by all terminals.
+///
+/// Attributes like [blinking](Attribute::SlowBlink) or [italic](Attribute::Italic) text are
+/// ignored or displayed in surprising ways by many terminals.
+/// Colors are never changed by this policy.
+///
+/// ```
+/// use super_table::{AttributePolicy, Table};
+///
+/// let mut table = Table::new();
+/// table.set_attribute_policy(AttributePolicy::Auto);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum AttributePolicy {
+    /// Keep all attributes as they are.
+    #[default]
+    Keep,
+    /// Replace attributes, that aren't widely supported, with similar ones.
+    ///
+    /// Italic text is underlined instead, blinking text is bold and all special kinds of
+    /// underlining become a plain underline.
+    /// Attributes without a similar replacement, like [Attribute::Fraktur], are removed.
+    Fallback,
+    /// Remove all attributes.
+    Strip,
+    /// Decide depending on the output:
+    /// - Output, that isn't a terminal, e.g. due to [Table::enforce_styling](crate::Table::enforce_styling), is stripped.
+    /// - Terminals with a `TERM` of `dumb` are stripped as well.
+    /// - Terminals with limited capabilities, like the Linux console or `vt100`, use the
+    ///   [fallbacks](AttributePolicy::Fallback).
+    /// - All attributes are kept for all other terminals.
+    Auto,
+}
+
+impl AttributePolicy {
+    /// Apply this policy to a list of attributes.
+    ///
+    /// [AttributePolicy::Auto] has to be resolved beforehand, it keeps all attributes.
+    pub(crate) fn apply(self, attributes: &[Attribute]) -> Vec<Attribute> {
+        match self {
+            AttributePolicy::Keep | AttributePolicy::Auto => attributes.to_vec(),
+            AttributePolicy::Strip => Vec::new(),
+            AttributePolicy::Fallback => {
+                let mut sanitized = Vec::with_capacity(attributes.len());
+                for attribute in attributes
+                    .iter()
+                    .filter_map(|attribute| fallback(*attribute))
+                {
+                    if !sanitized.contains(&attribute) {
+                        sanitized.push(attribute);
+                    }
+                }
+                sanitized
+            }
+        }
+    }
+}
+
+/// Map an attribute to a widely supported one.
+fn fallback(attribute: Attribute) -> Option<Attribute> {
+    match attribute {
+        Attribute::Italic
+        | Attribute::DoubleUnderlined
+        | Attribute::Undercurled
+        | Attribute::Underdotted
+        | Attribute::Underdashed => Some(Attribute::Underlined),
+        Attribute::SlowBlink | Attribute::RapidBlink => Some(Attribute::Bold),
+        Attribute::NoItalic => Some(Attribute::NoUnderline),
+        Attribute::NoBlink => Some(Attribute::NormalIntensity),
+        Attribute::Fraktur
+        | Attribute::Framed
+        | Attribute::Encircled
+        | Attribute::OverLined
+        | Attribute::NotFramedOrEncircled
+        | Attribute::NotOverLined => None,
+        attribute => Some(attribute),
+    }
+}
@@ -1,16 +1,41 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt;
 use std::iter::IntoIterator;
 use std::slice::{Iter, IterMut};
+use std::sync::Arc;
 #[cfg(feature = "tty")]
 use std::sync::OnceLock;
 
 use crate::cell::Cell;
 use crate::column::Column;
+use crate::export::{
+    self, CsvReadOptions, CsvWriteOptions, FixedWidthOptions, HtmlOptions, LoadError, RoundTrip,
+    SplitPolicy,
+};
+use crate::render::{AlignmentIssue, LineDecorator, RenderError, RenderEvent, RenderHook};
 use crate::row::Row;
-use crate::style::presets::ASCII_FULL;
-use crate::style::{ColumnConstraint, ContentArrangement, TableComponent};
-use crate::utils::build_table;
+#[cfg(feature = "tty")]
+use crate::style::palettes::Palette;
+use crate::style::presets::{ASCII_FULL, NOTHING};
+use crate::style::{
+    AmbiguousWidth, BoolFormat, BorderRenderer, CellAlignment, CellRole, ColspanBorderPolicy,
+    ColumnConstraint, ConstraintConflictPolicy, ContentArrangement, ExportContent, HeaderDefaults,
+    HiddenColumnsSummary, KeyOrder, Margins, NullStyle, Overflow, Section, StyleMap,
+    TableComponent,
+};
+#[cfg(feature = "tty")]
+use crate::style::{AttributePolicy, HighlightPattern, Style};
+use crate::utils::audit::audit_alignment;
+use crate::utils::chunks::column_chunks;
+use crate::utils::debug_layout::debug_layout;
+use crate::utils::formatting::display::display_width;
+use crate::utils::memory::fitting_rows;
+use crate::utils::numbers::cell_positions;
+use crate::utils::rendered_content;
+use crate::utils::row_order::{remove_leading_rows, tail};
+use crate::utils::spanning::{SpanTracker, span_aware_column_count};
+use crate::utils::{assert_rectangular, build_header, build_table, try_build_table};
+use crate::value::{CellValue, ColumnType, format_date, is_identifier_column, is_midnight};
 
 /// This is the main interface for building a table.
 /// Each table consists of [Rows](Row), which in turn contain [Cells](crate::cell::Cell).
@@ -20,10 +45,16 @@ use crate::utils::build_table;
 #[derive(Debug, Clone)]
 pub struct Table {
     pub(crate) columns: Vec<Column>,
-    style: HashMap<TableComponent, char>,
+    pub(crate) style: HashMap<TableComponent, char>,
+    /// The styles of sections, that don't use the style of the table.
+    pub(crate) section_styles: BTreeMap<Section, HashMap<TableComponent, char>>,
     pub(crate) header: Option<Row>,
     pub(crate) rows: Vec<Row>,
     pub(crate) arrangement: ContentArrangement,
+    pub(crate) constraint_conflict_policy: ConstraintConflictPolicy,
+    pub(crate) colspan_border_policy: ColspanBorderPolicy,
+    pub(crate) ambiguous_width: AmbiguousWidth,
+    pub(crate) export_content: ExportContent,
     pub(crate) delimiter: Option<char>,
     pub(crate) truncation_indicator: String,
     #[cfg(feature = "tty")]
@@ -39,6 +70,62 @@ pub struct Table {
     /// or whether only the text should be styled.
     #[cfg(feature = "tty")]
     pub(crate) style_text_only: bool,
+    /// How attributes are treated, that aren't supported by all terminals.
+    #[cfg(feature = "tty")]
+    pub(crate) attribute_policy: AttributePolicy,
+    /// Patterns, whose matches are styled when rendering the table.
+    #[cfg(feature = "tty")]
+    pub(crate) highlights: Vec<(HighlightPattern, Style)>,
+    /// The palette, through which all colors are replaced when rendering the table.
+    #[cfg(feature = "tty")]
+    pub(crate) palette: Option<Palette>,
+    /// Whether cells with a background color get a readable foreground color.
+    #[cfg(feature = "tty")]
+    pub(crate) auto_contrast: bool,
+    /// Whether 24-bit colors are used, even if the terminal doesn't announce support for them.
+    #[cfg(feature = "tty")]
+    pub(crate) enforce_truecolor: bool,
+    /// The maximum amount of bytes the content of all cells may occupy.
+    pub(crate) memory_limit: Option<usize>,
+    /// The amount of bytes the content of all added rows occupies.
+    content_size: usize,
+    /// The amount of rows that weren't added due to the memory limit.
+    pub(crate) omitted_rows: usize,
+    /// The maximum amount of rows that's kept, older rows are removed.
+    pub(crate) max_retained_rows: Option<usize>,
+    /// A custom renderer for the horizontal lines of the table.
+    pub(crate) border_renderer: Option<Arc<dyn BorderRenderer>>,
+    /// A callback for events that happen while the table is rendered.
+    pub(crate) render_hook: Option<RenderHook>,
+    /// Whether the descriptions of columns are displayed below the table.
+    pub(crate) description_footnotes: bool,
+    /// How cells with a `NULL` value are displayed.
+    pub(crate) null_style: Option<NullStyle>,
+    /// How booleans are displayed, unless their column has its own format.
+    pub(crate) bool_format: Option<BoolFormat>,
+    /// Settings for all header cells, that don't have their own.
+    pub(crate) header_defaults: Option<HeaderDefaults>,
+    /// How the semantic styles of cells are displayed in each output format.
+    pub(crate) style_map: Option<StyleMap>,
+    /// Whether the first column contains the headers of the rows.
+    pub(crate) row_headers: bool,
+    /// Whether padding and inner lines are removed when rendering.
+    pub(crate) compact: bool,
+    /// Whether content always keeps one space to the border opposite to its alignment.
+    pub(crate) alignment_gap: bool,
+    /// The maximum width of all columns without a constraint.
+    pub(crate) default_max_cell_width: Option<u16>,
+    /// Where a trailing column displays how many columns are hidden.
+    pub(crate) hidden_columns_summary: Option<HiddenColumnsSummary>,
+    /// Blank space around the table.
+    pub(crate) margin: Option<Margins>,
+    /// The text in front of every line.
+    pub(crate) line_prefix: Option<String>,
+    /// A callback, that changes every rendered line.
+    pub(crate) line_decorator: Option<LineDecorator>,
+    /// Whether [Table::try_lines] fails, if content isn't displayed completely.
+    pub(crate) strict: bool,
+    pub(crate) reversed: bool,
 }
 
 impl fmt::Display for Table {
@@ -61,6 +148,10 @@ impl Table {
             header: None,
             rows: Vec::new(),
             arrangement: ContentArrangement::Disabled,
+            constraint_conflict_policy: ConstraintConflictPolicy::default(),
+            colspan_border_policy: ColspanBorderPolicy::default(),
+            ambiguous_width: AmbiguousWidth::default(),
+            export_content: ExportContent::default(),
             delimiter: None,
             truncation_indicator: "...".to_string(),
             #[cfg(feature = "tty")]
@@ -71,10 +162,42 @@ impl Table {
             use_stderr: false,
             width: None,
             style: HashMap::new(),
+            section_styles: BTreeMap::new(),
             #[cfg(feature = "tty")]
             enforce_styling: false,
             #[cfg(feature = "tty")]
             style_text_only: false,
+            #[cfg(feature = "tty")]
+            attribute_policy: AttributePolicy::default(),
+            #[cfg(feature = "tty")]
+            highlights: Vec::new(),
+            #[cfg(feature = "tty")]
+            palette: None,
+            #[cfg(feature = "tty")]
+            auto_contrast: false,
+            #[cfg(feature = "tty")]
+            enforce_truecolor: false,
+            memory_limit: None,
+            content_size: 0,
+            omitted_rows: 0,
+            max_retained_rows: None,
+            border_renderer: None,
+            render_hook: None,
+            description_footnotes: false,
+            null_style: None,
+            bool_format: None,
+            header_defaults: None,
+            style_map: None,
+            row_headers: false,
+            compact: false,
+            alignment_gap: false,
+            default_max_cell_width: None,
+            hidden_columns_summary: None,
+            margin: None,
+            line_prefix: None,
+            line_decorator: None,
+            strict: false,
+            reversed: false,
         };
 
         table.load_preset(ASCII_FULL);
@@ -82,6 +205,216 @@ impl Table {
         table
     }
 
+    /// Create a table with two columns from pairs of keys and values.
+    ///
+    /// This is handy to display a configuration or the fields of a struct.
+    /// The table doesn't have any borders and the keys are aligned to the right, so they're
+    /// displayed next to their value. Both can be changed like on any other table.
+    ///
+    /// ```
+    /// use super_table::Table;
+    ///
+    /// let table = Table::from_pairs([("Name", "nginx"), ("Port", "8080"), ("Workers", "4")]);
+    ///
+    /// let expected = "
+    ///     Name  nginx
+    ///     Port  8080
+    ///  Workers  4";
+    /// assert_eq!(expected, "\n".to_string() + &table.trim_fmt());
+    /// ```
+    pub fn from_pairs<I, K, V>(pairs: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<Cell>,
+        V: Into<Cell>,
+    {
+        let mut table = Table::new();
+        table.load_preset(NOTHING);
+        for (key, value) in pairs {
+            table.add_row(vec![key.into(), value.into()]);
+        }
+        if let Some(column) = table.column_mut(0) {
+            column.set_cell_alignment(CellAlignment::Right);
+        }
+
+        table
+    }
+
+    /// Create a table from a map, whose values are maps as well.
+    ///
+    /// Each entry of the outer map becomes a row, with its key in the first column.
+    /// The keys of all inner maps become the header, ordered as specified by `order`.
+    /// Keys that are missing in an inner map are filled with a [null value](CellValue::Null),
+    /// which is displayed as configured by [Table::set_null_style].
+    ///
+    /// Rows keep the order of the outer map, so use a [BTreeMap](std::collections::BTreeMap) or
+    /// [KeyOrder::FirstSeen] with an ordered collection to get a stable output.
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use super_table::{KeyOrder, Table};
+    ///
+    /// let hosts = BTreeMap::from([
+    ///     ("db-1", BTreeMap::from([("cpu", "12%"), ("memory", "4.1G")])),
+    ///     ("web-1", BTreeMap::from([("cpu", "63%"), ("disk", "81%")])),
+    /// ]);
+    /// let table = Table::from_map_of_maps(hosts, KeyOrder::Sorted);
+    ///
+    /// let expected = "
+    /// +-------+-----+------+--------+
+    /// |       | cpu | disk | memory |
+    /// +=============================+
+    /// | db-1  | 12% |      | 4.1G   |
+    /// |-------+-----+------+--------|
+    /// | web-1 | 63% | 81%  |        |
+    /// +-------+-----+------+--------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn from_map_of_maps<O, K, I, C, V>(map: O, order: KeyOrder) -> Self
+    where
+        O: IntoIterator<Item = (K, I)>,
+        K: Into<Cell>,
+        I: IntoIterator<Item = (C, V)>,
+        C: ToString,
+        V: Into<Cell>,
+    {
+        let entries: Vec<(Cell, Vec<(String, Cell)>)> = map
+            .into_iter()
+            .map(|(key, inner)| {
+                let values = inner
+                    .into_iter()
+                    .map(|(key, value)| (key.to_string(), value.into()))
+                    .collect();
+                (key.into(), values)
+            })
+            .collect();
+
+        let records: Vec<&[(String, Cell)]> = entries
+            .iter()
+            .map(|(_, values)| values.as_slice())
+            .collect();
+        let keys = record_keys(&records, order);
+
+        let mut table = Table::new();
+        let header: Vec<Cell> = std::iter::once(Cell::new(""))
+            .chain(keys.iter().map(Cell::new))
+            .collect();
+        table.set_header(header);
+        for (key, values) in entries {
+            let mut row = vec![key];
+            row.extend(record_cells(values, &keys));
+            table.add_row(row);
+        }
+
+        table
+    }
+
+    /// Create a table from a sequence of values, that implement [serde::Serialize].
+    ///
+    /// Each element of the sequence becomes a row and must be a struct or a map.
+    /// The keys of all elements become the header, in the order they first appear in.
+    /// - Nested structs and maps are flattened into columns of their own, which are named by
+    ///   the keys leading up to them, e.g. `address.city`.
+    /// - Sequences are displayed as text, e.g. `[1, 2, 3]`.
+    /// - `None`, the unit type and keys that are missing in an element become
+    ///   [null values](CellValue::Null).
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use super_table::Table;
+    ///
+    /// let hosts = vec![
+    ///     BTreeMap::from([("host", "db-1"), ("status", "running")]),
+    ///     BTreeMap::from([("host", "web-1"), ("status", "failed")]),
+    /// ];
+    /// let table = Table::from_serialize(&hosts).unwrap();
+    ///
+    /// let expected = "
+    /// +-------+---------+
+    /// | host  | status  |
+    /// +=================+
+    /// | db-1  | running |
+    /// |-------+---------|
+    /// | web-1 | failed  |
+    /// +-------+---------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn from_serialize<T: serde::Serialize + ?Sized>(
+        value: &T,
+    ) -> Result<Self, crate::SerializeError> {
+        crate::serialize::from_serialize(value)
+    }
+
+    /// Load a table from comma separated values, like the ones written by [Table::to_csv].
+    ///
+    /// Each record becomes a row with one cell per field and the first record becomes the
+    /// header, unless configured otherwise in the [CsvReadOptions].
+    /// Fields may be quoted as described in RFC 4180 and records may be terminated by `\n` or
+    /// `\r\n`.
+    ///
+    /// ```
+    /// use super_table::{CsvReadOptions, Table};
+    ///
+    /// let table = Table::from_csv("Name,Comment\nAlice,\"Likes, commas\"\n", CsvReadOptions::new())
+    ///     .unwrap();
+    ///
+    /// let expected = "
+    /// +-------+---------------+
+    /// | Name  | Comment       |
+    /// +=======================+
+    /// | Alice | Likes, commas |
+    /// +-------+---------------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn from_csv(input: &str, options: CsvReadOptions) -> Result<Self, LoadError> {
+        export::from_csv(input, &options)
+    }
+
+    /// Turn lines of raw text, like the output of a command, into a table.
+    ///
+    /// Each line becomes a row, whose cells are split as specified by the [SplitPolicy].
+    /// Lines that only consist of whitespace are skipped.
+    /// Like `column -t`, the table doesn't have a header, but all styling features can be
+    /// applied to it afterwards.
+    ///
+    /// ```
+    /// use super_table::{SplitPolicy, Table};
+    /// use super_table::presets::NOTHING;
+    ///
+    /// let output = "nginx 4242 running\npostgres 17 stopped\n";
+    /// let mut table = Table::from_lines(output.lines(), SplitPolicy::Whitespace);
+    /// table.load_preset(NOTHING);
+    ///
+    /// let expected = "
+    ///  nginx     4242  running
+    ///  postgres  17    stopped";
+    /// assert_eq!(expected, "\n".to_string() + &table.trim_fmt());
+    /// ```
+    pub fn from_lines<I>(lines: I, policy: SplitPolicy) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        export::from_lines(lines, &policy)
+    }
+
+    /// Load a table from JSON in the format written by [Table::to_json].
+    ///
+    /// The JSON is an object with an optional `header` and `rows`, which are arrays of strings.
+    ///
+    /// ```
+    /// use super_table::Table;
+    ///
+    /// let table = Table::from_json(r#"{"header": ["Name"], "rows": [["Alice"], ["Bob"]]}"#)
+    ///     .unwrap();
+    /// assert_eq!(table.row_count(), 2);
+    /// assert_eq!(table.header().unwrap().cell_count(), 1);
+    /// ```
+    pub fn from_json(input: &str) -> Result<Self, LoadError> {
+        export::from_json(input)
+    }
+
     /// This is an alternative `fmt` function, which simply removes any trailing whitespaces.
     /// Trailing whitespaces often occur, when using tables without a right border.
     pub fn trim_fmt(&self) -> String {
@@ -97,213 +430,1675 @@ impl Table {
         build_table(self)
     }
 
-    /// Set the header row of the table. This is usually the title of each column.\
-    /// There'll be no header unless you explicitly set it with this function.
+    /// Like [Table::lines], but return an error instead of drawing a broken table.
+    ///
+    /// The table is checked for problems after its columns have been arranged, for instance
+    /// column constraints that don't fit into the [width](Table::set_width) of the table,
+    /// invalid spans or constraints that leave no space for the content of a column.
+    /// See [RenderError] for all possible errors.
     ///
     /// ```
-    /// use super_table::{Table, Row};
+    /// use super_table::render::RenderError;
+    /// use super_table::{ColumnConstraint, ContentArrangement, Table, Width};
     ///
     /// let mut table = Table::new();
-    /// let header = Row::from(vec!["Header One", "Header Two"]);
-    /// table.set_header(header);
+    /// table
+    ///     .set_width(20)
+    ///     .set_content_arrangement(ContentArrangement::Dynamic)
+    ///     .add_row(vec!["Left", "Right"]);
+    /// assert!(table.try_lines().is_ok());
+    ///
+    /// table
+    ///     .column_mut(0)
+    ///     .unwrap()
+    ///     .set_constraint(ColumnConstraint::Absolute(Width::Fixed(30)));
+    /// assert_eq!(
+    ///     table.try_lines(),
+    ///     Err(RenderError::ConstraintConflict {
+    ///         required_width: 36,
+    ///         table_width: 20,
+    ///     })
+    /// );
     /// ```
-    pub fn set_header<T: Into<Row>>(&mut self, row: T) -> &mut Self {
-        let row = row.into();
-        self.autogenerate_columns(&row);
-        self.header = Some(row);
-
-        self
-    }
-
-    pub fn header(&self) -> Option<&Row> {
-        self.header.as_ref()
+    pub fn try_lines(&self) -> Result<Vec<String>, RenderError> {
+        try_build_table(self)
     }
 
-    /// Returns the number of currently present columns.
+    /// Render only the top border, the header and the line below the header.
+    ///
+    /// This shows the columns of a table, that doesn't have any rows yet, for instance before
+    /// rows are streamed or to display an empty result.
+    /// If the table already has rows, the columns are as wide as they would be in the full table.
+    /// Tables without a header render to an empty string.
     ///
     /// ```
     /// use super_table::Table;
     ///
     /// let mut table = Table::new();
-    /// table.set_header(vec!["Col 1", "Col 2", "Col 3"]);
+    /// table.set_header(vec!["Name", "Size"]);
     ///
-    /// assert_eq!(table.column_count(), 3);
+    /// let expected = "
+    /// +------+------+
+    /// | Name | Size |
+    /// +=============+";
+    /// assert_eq!(expected, "\n".to_string() + &table.render_header_only());
     /// ```
-    pub fn column_count(&mut self) -> usize {
-        self.discover_columns();
-        self.columns.len()
+    pub fn render_header_only(&self) -> String {
+        if self.header.is_none() {
+            return String::new();
+        }
+        build_header(self).collect::<Vec<_>>().join("\n")
     }
 
-    /// Add a new row to the table.
+    /// Let [Table::try_lines] fail with [RenderError::ContentLost], if the content of any cell
+    /// isn't displayed completely.
+    ///
+    /// Content is lost, if it exceeds the [maximum height](Row::max_height) of its row, or if
+    /// its columns are hidden, because the table isn't wide enough for them.
+    /// All affected cells are listed in the error.
+    /// Wrapped content and columns with a [hidden](ColumnConstraint::Hidden) constraint don't
+    /// count as lost.
+    ///
+    /// This is useful for reports, which must not silently drop any data.
+    /// [Table::lines] and [Table::to_string](ToString::to_string) aren't affected.
     ///
     /// ```
-    /// use super_table::{Table, Row};
+    /// use super_table::render::{LossReason, LostContent, RenderError};
+    /// use super_table::{Row, Table};
     ///
     /// let mut table = Table::new();
-    /// table.add_row(vec!["One", "Two"]);
+    /// let mut row = Row::from(vec!["Line 1\nLine 2", "Short"]);
+    /// row.max_height(1);
+    /// table.set_strict(true).set_header(vec!["Log", "Note"]).add_row(row);
+    ///
+    /// assert_eq!(
+    ///     table.try_lines(),
+    ///     Err(RenderError::ContentLost {
+    ///         cells: vec![LostContent {
+    ///             row_index: 1,
+    ///             column_index: 0,
+    ///             reason: LossReason::Truncated,
+    ///         }],
+    ///     })
+    /// );
     /// ```
-    pub fn add_row<T: Into<Row>>(&mut self, row: T) -> &mut Self {
-        let mut row = row.into();
-        self.autogenerate_columns(&row);
-        row.index = Some(self.rows.len());
-        self.rows.push(row);
+    pub fn set_strict(&mut self, strict: bool) -> &mut Self {
+        self.strict = strict;
 
         self
     }
 
-    /// Add a new row to the table if the predicate evaluates to `true`.
+    /// Returns whether [Table::try_lines] fails, if content isn't displayed completely.
+    ///
+    /// See [Table::set_strict].
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Display the rows in reverse order, so the newest rows are at the top.
+    ///
+    /// The rows of the table aren't changed, they're only reversed while the table is rendered.
+    /// [Footer](CellRole::Footer) rows stay at the bottom of the table.
+    /// Combined with [Table::tail], this shows the latest entries of a log.
     ///
     /// ```
-    /// use super_table::{Table, Row};
+    /// use super_table::Table;
     ///
     /// let mut table = Table::new();
-    /// table.add_row_if(|index, row| true, vec!["One", "Two"]);
+    /// table
+    ///     .set_reversed(true)
+    ///     .set_header(vec!["Time", "Event"])
+    ///     .add_row(vec!["12:00", "Started"])
+    ///     .add_row(vec!["12:05", "Connected"])
+    ///     .add_row(vec!["12:09", "Stopped"]);
+    ///
+    /// let expected = "
+    /// +-------+-----------+
+    /// | Time  | Event     |
+    /// +===================+
+    /// | 12:09 | Stopped   |
+    /// |-------+-----------|
+    /// | 12:05 | Connected |
+    /// |-------+-----------|
+    /// | 12:00 | Started   |
+    /// +-------+-----------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// assert_eq!(table.row(0).unwrap().cell_iter().next().unwrap().content(), "12:00");
     /// ```
-    pub fn add_row_if<P, T>(&mut self, predicate: P, row: T) -> &mut Self
-    where
-        P: Fn(usize, &T) -> bool,
-        T: Into<Row>,
-    {
-        if predicate(self.rows.len(), &row) {
-            return self.add_row(row);
-        }
+    pub fn set_reversed(&mut self, reversed: bool) -> &mut Self {
+        self.reversed = reversed;
 
         self
     }
 
-    /// Add multiple rows to the table.
+    /// Returns whether the rows are displayed in reverse order.
+    ///
+    /// See [Table::set_reversed].
+    pub fn is_reversed(&self) -> bool {
+        self.reversed
+    }
+
+    /// Find content, that might break the alignment of the table in some terminals.
+    ///
+    /// Some characters don't have a well-defined width.
+    /// Characters with an [ambiguous width](https://www.unicode.org/reports/tr11/) are displayed
+    /// two columns wide by terminals in East Asian locales and emoji sequences are often
+    /// displayed differently, depending on the terminal and its font.
+    ///
+    /// This is an opt-in validation pass, which formats the table and re-measures each part of
+    /// every line with the widest interpretation of those characters.
+    /// All parts, whose width deviates from the width that was used for the layout, are returned
+    /// with their row and column.
     ///
     /// ```
-    /// use super_table::{Table, Row};
+    /// use super_table::Table;
     ///
     /// let mut table = Table::new();
-    /// let rows = vec![
-    ///     vec!["One", "Two"],
-    ///     vec!["Three", "Four"]
-    /// ];
-    /// table.add_rows(rows);
+    /// table
+    ///     .set_header(vec!["Value", "Tolerance"])
+    ///     .add_row(vec!["10", "± 2"]);
+    ///
+    /// let issues = table.audit_alignment();
+    /// assert_eq!(issues.len(), 1);
+    /// assert_eq!((issues[0].row_index, issues[0].column_index), (1, 1));
     /// ```
-    pub fn add_rows<I>(&mut self, rows: I) -> &mut Self
-    where
-        I: IntoIterator,
-        I::Item: Into<Row>,
-    {
-        for row in rows.into_iter() {
-            let mut row = row.into();
-            self.autogenerate_columns(&row);
-            row.index = Some(self.rows.len());
-            self.rows.push(row);
-        }
+    pub fn audit_alignment(&self) -> Vec<AlignmentIssue> {
+        audit_alignment(self)
+    }
 
-        self
+    /// Check that all lines of the rendered table have the same display width.
+    ///
+    /// Lines are always padded to the width of the table, including lines of custom
+    /// [border renderers](Table::set_border_renderer) and tables without borders.
+    /// That way, tables can be placed in boxes of other layouts without padding them manually.
+    /// This helper checks that guarantee, e.g. in the tests of such layouts.
+    /// The [description footnotes](Table::set_description_footnotes) below the table aren't part
+    /// of the table and aren't checked.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if any line has a different display width than the first line.
+    ///
+    /// ```
+    /// use super_table::Table;
+    /// use super_table::presets::NOTHING;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .load_preset(NOTHING)
+    ///     .set_header(vec!["Name", "Description"])
+    ///     .add_row(vec!["super-table", ""]);
+    ///
+    /// table.assert_rectangular();
+    /// ```
+    pub fn assert_rectangular(&self) {
+        assert_rectangular(self);
     }
 
-    /// Add multiple rows to the table if the predicate evaluates to `true`.
+    /// Get the content of a cell, as it's displayed once the table is rendered.
+    ///
+    /// [Cell::content] always returns the full content of a cell.
+    /// Once rendered, the content might look different, as it's wrapped to fit into its column
+    /// and truncated, if the row has a [maximum height](Row::max_height).
+    /// Presentational settings like [prefixes](Cell::set_prefix) are applied as well.
+    ///
+    /// `row_index` is the index of the row without the header and `cell_index` the index of the
+    /// cell in its row.
+    /// Returns `None`, if there's no such cell or the row isn't displayed due to the
+    /// [memory limit](Table::set_memory_limit).
     ///
     /// ```
-    /// use super_table::{Table, Row};
+    /// use super_table::{Row, Table};
     ///
     /// let mut table = Table::new();
-    /// let rows = vec![
-    ///     vec!["One", "Two"],
-    ///     vec!["Three", "Four"]
-    /// ];
-    /// table.add_rows_if(|index, rows| true, rows);
+    /// let mut row = Row::from(vec!["First\nSecond\nThird"]);
+    /// row.max_height(2);
+    /// table.add_row(row);
+    ///
+    /// let cell = table.row(0).unwrap().cell_iter().next().unwrap();
+    /// assert_eq!(cell.content(), "First\nSecond\nThird");
+    /// assert_eq!(table.rendered_content(0, 0).unwrap(), "First\nSec...");
     /// ```
-    pub fn add_rows_if<P, I>(&mut self, predicate: P, rows: I) -> &mut Self
-    where
-        P: Fn(usize, &I) -> bool,
-        I: IntoIterator,
-        I::Item: Into<Row>,
-    {
-        if predicate(self.rows.len(), &rows) {
-            return self.add_rows(rows);
+    pub fn rendered_content(&self, row_index: usize, cell_index: usize) -> Option<String> {
+        if row_index >= fitting_rows(self) {
+            return None;
         }
 
-        self
+        let row_index = row_index + usize::from(self.header.is_some());
+        rendered_content(self)
+            .into_iter()
+            .nth(row_index)?
+            .into_iter()
+            .nth(cell_index)
     }
 
-    /// Returns the number of currently present rows.
+    /// Export the table as comma separated values.
+    ///
+    /// The header and all rows are written as records, which are terminated by `\n`.
+    /// Fields are quoted as described in RFC 4180, if they contain the delimiter, quotes or
+    /// line breaks.
+    /// The [content of cells](ExportContent) is written without any styling, and positions
+    /// that are covered by spanning cells are filled as configured in the [CsvWriteOptions].
     ///
     /// ```
-    /// use super_table::Table;
+    /// use super_table::{CsvWriteOptions, Table};
     ///
     /// let mut table = Table::new();
-    /// table.add_row(vec!["One", "Two"]);
-    ///
-    /// assert_eq!(table.row_count(), 1);
+    /// table
+    ///     .set_header(vec!["Name", "Comment"])
+    ///     .add_row(vec!["Alice", "Likes \"quotes\", commas"]);
+    ///
+    /// assert_eq!(
+    ///     table.to_csv(CsvWriteOptions::new()),
+    ///     "Name,Comment\nAlice,\"Likes \"\"quotes\"\", commas\"\n"
+    /// );
     /// ```
-    pub fn row_count(&self) -> usize {
-        self.rows.len()
+    pub fn to_csv(&self, options: CsvWriteOptions) -> String {
+        export::to_csv(self, &options)
     }
 
-    /// Returns if the table is empty (contains no data rows).
+    /// Export the table as JSON.
+    ///
+    /// The JSON is an object with the header, if there's one, and the rows, which are arrays of
+    /// strings with one entry per column position.
+    /// Like [Table::to_csv], the [content of cells](ExportContent) is written without any
+    /// styling. Positions that are covered by spanning cells are left empty.
     ///
     /// ```
     /// use super_table::Table;
     ///
     /// let mut table = Table::new();
-    /// assert!(table.is_empty());
-    ///
-    /// table.add_row(vec!["One", "Two"]);
-    /// assert!(!table.is_empty());
+    /// table
+    ///     .set_header(vec!["Name", "Age"])
+    ///     .add_row(vec!["Alice \"Al\"", "30"]);
+    ///
+    /// let expected = r#"{
+    ///   "header": ["Name", "Age"],
+    ///   "rows": [
+    ///     ["Alice \"Al\"", "30"]
+    ///   ]
+    /// }
+    /// "#;
+    /// assert_eq!(table.to_json(), expected);
     /// ```
-    pub fn is_empty(&self) -> bool {
-        self.rows.is_empty()
-    }
-
-    /// Enforce a max width that should be used in combination with [dynamic content arrangement](ContentArrangement::Dynamic).\
-    /// This is usually not necessary, if you plan to output your table to a tty,
-    /// since the terminal width can be automatically determined.
-    pub fn set_width(&mut self, width: u16) -> &mut Self {
-        self.width = Some(width);
-
-        self
+    pub fn to_json(&self) -> String {
+        export::to_json(self)
     }
 
-    /// Get the expected width of the table.
+    /// Returns how much of the table is restored, when it's exported as CSV or JSON and loaded
+    /// again with [Table::from_csv] or [Table::from_json].
     ///
-    /// This will be `Some(width)`, if the terminal width can be detected or if the table width is set via [set_width](Table::set_width).
+    /// Plain tables without spans are [lossless](RoundTrip::Lossless), as long as the options
+    /// for reading CSV match the ones it was written with.
+    /// Masks, templates and the formats of [ExportContent::Formatted] change the exported
+    /// values, which is reported as [RoundTrip::ContentChanged].
     ///
-    /// If neither is not possible, `None` will be returned.\
-    /// This implies that both the [Dynamic](ContentArrangement::Dynamic) mode and the [Percentage](crate::style::Width::Percentage) constraint won't work.
-    #[cfg(feature = "tty")]
-    pub fn width(&self) -> Option<u16> {
-        if let Some(width) = self.width {
-            Some(width)
-        } else if self.is_tty() {
-            if let Ok((width, _)) = crossterm::terminal::size() {
-                Some(width)
-            } else {
-                None
-            }
-        } else {
-            None
-        }
+    /// ```
+    /// use super_table::{CsvReadOptions, CsvWriteOptions, RoundTrip, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Name", "Comment"])
+    ///     .add_row(vec!["Alice", "Line one\nLine \"two\""]);
+    /// assert_eq!(table.round_trip(), RoundTrip::Lossless);
+    ///
+    /// let csv = table.to_csv(CsvWriteOptions::new());
+    /// let loaded = Table::from_csv(&csv, CsvReadOptions::new()).unwrap();
+    /// assert_eq!(loaded.to_string(), table.to_string());
+    /// ```
+    pub fn round_trip(&self) -> RoundTrip {
+        export::round_trip(self)
     }
 
-    #[cfg(not(feature = "tty"))]
-    pub fn width(&self) -> Option<u16> {
-        self.width
+    /// Export the table as an [Org mode](https://orgmode.org/manual/Tables.html) table.
+    ///
+    /// The header is separated from the rows by a horizontal line.
+    /// If any column has a [cell alignment](Column::set_cell_alignment), a row with alignment
+    /// cookies like `<r>` is added, and cells are padded accordingly.
+    /// Org tables don't support spans or line breaks, so cells only appear in their first
+    /// column and lines are joined by spaces.
+    ///
+    /// ```
+    /// use super_table::{CellAlignment, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Name", "Age"])
+    ///     .add_row(vec!["Alice", "30"]);
+    /// table
+    ///     .column_mut(1)
+    ///     .unwrap()
+    ///     .set_cell_alignment(CellAlignment::Right);
+    ///
+    /// let expected = "\
+    /// | Name  | Age |
+    /// |-------+-----|
+    /// | <l>   | <r> |
+    /// | Alice |  30 |
+    /// ";
+    /// assert_eq!(table.to_org(), expected);
+    /// ```
+    pub fn to_org(&self) -> String {
+        export::to_org(self)
     }
 
-    /// Specify how Comfy Table should arrange the content in your table.
+    /// Export the table in the wiki markup of Jira and Confluence.
+    ///
+    /// Header cells are enclosed by `||`, all other cells by `|`.
+    /// Line breaks inside of cells are written as `\\`.
+    /// The markup has no syntax for alignment or spans, so cells only appear in their first
+    /// column.
     ///
     /// ```
-    /// use super_table::{Table, ContentArrangement};
+    /// use super_table::Table;
     ///
     /// let mut table = Table::new();
-    /// table.set_content_arrangement(ContentArrangement::Dynamic);
+    /// table
+    ///     .set_header(vec!["Name", "Age"])
+    ///     .add_row(vec!["Alice", "30"]);
+    ///
+    /// assert_eq!(table.to_jira(), "||Name||Age||\n|Alice|30|\n");
     /// ```
-    pub fn set_content_arrangement(&mut self, arrangement: ContentArrangement) -> &mut Self {
-        self.arrangement = arrangement;
-
-        self
+    pub fn to_jira(&self) -> String {
+        export::to_jira(self)
     }
 
-    /// Get the current content arrangement of the table.
-    pub fn content_arrangement(&self) -> ContentArrangement {
-        self.arrangement.clone()
+    /// Export the table as a Markdown table, as supported by GitHub Flavored Markdown.
+    ///
+    /// Markdown tables always have a header, so an empty one is written, if the table has none.
+    /// The [cell alignment](Column::set_cell_alignment) of columns is written to the delimiter
+    /// row below the header.
+    /// Spans aren't supported, so cells only appear in their first column and line breaks
+    /// inside of cells are written as `<br>`.
+    /// Cells with the bold or italic attribute are written as `**bold**`
+    /// or `*italic*`, all other attributes are dropped.
+    ///
+    /// ```
+    /// use super_table::{CellAlignment, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Name", "Age"])
+    ///     .add_row(vec!["Alice", "30"]);
+    /// table
+    ///     .column_mut(1)
+    ///     .unwrap()
+    ///     .set_cell_alignment(CellAlignment::Right);
+    ///
+    /// let expected = "\
+    /// | Name  | Age |
+    /// | ----- | --: |
+    /// | Alice |  30 |
+    /// ";
+    /// assert_eq!(table.to_markdown(), expected);
+    /// ```
+    pub fn to_markdown(&self) -> String {
+        export::to_markdown(self)
+    }
+
+    /// Export the table as fixed-width text, e.g. for legacy systems or for text files, whose
+    /// changes are easy to diff.
+    ///
+    /// Each row is written as a record, which is terminated by `\n`.
+    /// The fields are padded with spaces to the width of their column and separated by the
+    /// [column gap](FixedWidthOptions::column_gap), so all records have the same length.
+    /// Fields are aligned like the [cells of their column](Column::set_cell_alignment).
+    /// No border characters are written, spans are left empty and lines are joined by spaces.
+    ///
+    /// ```
+    /// use super_table::{CellAlignment, FixedWidthOptions, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Name", "Age"])
+    ///     .add_row(vec!["Alice", "30"]);
+    /// table
+    ///     .column_mut(1)
+    ///     .unwrap()
+    ///     .set_cell_alignment(CellAlignment::Right);
+    ///
+    /// let expected = "\
+    /// Name  Age
+    /// Alice  30
+    /// ";
+    /// assert_eq!(table.to_fixed_width(FixedWidthOptions::new()), expected);
+    /// ```
+    pub fn to_fixed_width(&self, options: FixedWidthOptions) -> String {
+        export::to_fixed_width(self, &options)
+    }
+
+    /// Export the table as an HTML `<table>` element.
+    ///
+    /// The header is written to a `<thead>` and all other rows to a `<tbody>`.
+    /// Spanning cells keep their `colspan` and `rowspan`, unless the [HtmlOptions] specify
+    /// another [SpanPolicy](crate::SpanPolicy), and alignment is written as inline style.
+    /// The content is escaped and line breaks inside of cells are written as `<br>`.
+    /// Cells with the bold or italic attribute are wrapped in `<strong>`
+    /// or `<em>`, all other attributes are dropped.
+    ///
+    /// ```
+    /// use super_table::{HtmlOptions, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Name", "Age"])
+    ///     .add_row(vec!["Alice & Bob", "30"]);
+    ///
+    /// let expected = "\
+    /// <table>
+    ///   <thead>
+    ///     <tr><th>Name</th><th>Age</th></tr>
+    ///   </thead>
+    ///   <tbody>
+    ///     <tr><td>Alice &amp; Bob</td><td>30</td></tr>
+    ///   </tbody>
+    /// </table>
+    /// ";
+    /// assert_eq!(table.to_html(HtmlOptions::new()), expected);
+    /// ```
+    pub fn to_html(&self, options: HtmlOptions) -> String {
+        export::to_html(self, &options)
+    }
+
+    /// Describe how the table is laid out, to find out why a table renders the way it does.
+    ///
+    /// The description contains the resolved width of each column, its constraint and whether
+    /// its content had to be wrapped or the column is hidden.
+    /// Cells that span multiple columns or rows and rows with a limited height are listed as well.
+    ///
+    /// The format of this description is meant for humans and may change at any time.
+    ///
+    /// ```
+    /// use super_table::{ColumnConstraint, Table, Width};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Name", "Description"])
+    ///     .add_row(vec!["super-table", "A library for building tables"]);
+    /// table
+    ///     .column_mut(1)
+    ///     .unwrap()
+    ///     .set_constraint(ColumnConstraint::UpperBoundary(Width::Fixed(12)));
+    ///
+    /// let layout = table.debug_layout();
+    /// assert!(layout.contains("1: width 12"));
+    /// assert!(layout.contains("wrapped due to constraint"));
+    /// ```
+    pub fn debug_layout(&self) -> String {
+        debug_layout(self)
+    }
+
+    /// Split a wide table into chunks of columns, which each fit into the given width.
+    ///
+    /// Each chunk is a copy of this table, in which the columns of all other chunks are
+    /// [hidden](ColumnConstraint::Hidden). The columns are distributed by the width they need
+    /// without wrapping any content, so the chunks can be printed one below the other.
+    /// [Frozen columns](Column::set_frozen) are repeated in every chunk.
+    /// Every chunk has at least one column, that isn't frozen, even if it doesn't fit.
+    ///
+    /// ```
+    /// use super_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Host", "CPU", "Memory", "Disk"])
+    ///     .add_row(vec!["db-1", "12%", "4.1G", "81%"]);
+    /// table.column_mut(0).unwrap().set_frozen(true);
+    ///
+    /// let chunks: Vec<String> = table
+    ///     .column_chunks(23)
+    ///     .iter()
+    ///     .map(Table::to_string)
+    ///     .collect();
+    ///
+    /// let expected = "
+    /// +------+-----+--------+
+    /// | Host | CPU | Memory |
+    /// +=====================+
+    /// | db-1 | 12% | 4.1G   |
+    /// +------+-----+--------+
+    /// +------+------+
+    /// | Host | Disk |
+    /// +=============+
+    /// | db-1 | 81%  |
+    /// +------+------+";
+    /// assert_eq!(expected, "\n".to_string() + &chunks.join("\n"));
+    /// ```
+    pub fn column_chunks(&self, width: u16) -> Vec<Table> {
+        column_chunks(self, width)
+    }
+
+    /// Create a copy of this table with only its last `count` rows.
+    ///
+    /// The header, the columns, the styling and [footer](CellRole::Footer) rows are kept.
+    /// Cells, that span into the kept rows from rows that are left out, are cut off at the first
+    /// kept row, so the borders stay intact.
+    ///
+    /// ```
+    /// use super_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.set_header(vec!["Line", "Message"]);
+    /// for line in 1..=100 {
+    ///     table.add_row(vec![line.to_string(), format!("Message {line}")]);
+    /// }
+    ///
+    /// let expected = "
+    /// +------+-------------+
+    /// | Line | Message     |
+    /// +====================+
+    /// | 99   | Message 99  |
+    /// |------+-------------|
+    /// | 100  | Message 100 |
+    /// +------+-------------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.tail(2).to_string());
+    /// ```
+    pub fn tail(&self, count: usize) -> Table {
+        let mut table = tail(self, count);
+        table.content_size = table
+            .header
+            .iter()
+            .chain(table.rows.iter())
+            .map(Row::content_size)
+            .sum();
+
+        table
+    }
+
+    /// Set the header row of the table. This is usually the title of each column.\
+    /// There'll be no header unless you explicitly set it with this function.
+    ///
+    /// ```
+    /// use super_table::{Table, Row};
+    ///
+    /// let mut table = Table::new();
+    /// let header = Row::from(vec!["Header One", "Header Two"]);
+    /// table.set_header(header);
+    /// ```
+    pub fn set_header<T: Into<Row>>(&mut self, row: T) -> &mut Self {
+        let row = row.into();
+        self.autogenerate_columns(&row);
+        if let Some(previous) = self.header.as_ref() {
+            self.content_size = self.content_size.saturating_sub(previous.content_size());
+        }
+        self.content_size += row.content_size();
+        self.header = Some(row);
+
+        self
+    }
+
+    pub fn header(&self) -> Option<&Row> {
+        self.header.as_ref()
+    }
+
+    /// Set the header cell of a single column.
+    ///
+    /// This allows to tweak the header after the fact, e.g. to append a unit or a sort arrow,
+    /// without rebuilding the whole header row.
+    /// If there's no header yet, or if it's too short, it's filled with empty cells up to the
+    /// given column.
+    /// Columns that are covered by a header cell, which starts in a previous column, are left
+    /// untouched.
+    ///
+    /// ```
+    /// use super_table::{Cell, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Name", "Size"])
+    ///     .set_column_header(1, "Size ▼")
+    ///     .set_column_header(3, Cell::new("Owner"));
+    ///
+    /// let header: Vec<String> = table.header().unwrap().cell_iter().map(Cell::content).collect();
+    /// assert_eq!(header, vec!["Name", "Size ▼", "", "Owner"]);
+    /// ```
+    pub fn set_column_header<T: Into<Cell>>(&mut self, column_index: usize, cell: T) -> &mut Self {
+        let header = self.header.get_or_insert_with(Row::new);
+        let previous_size = header.content_size();
+
+        match header_cell_index(header, column_index) {
+            Ok(index) => header.cells[index] = cell.into(),
+            // The column is covered by a cell that spans multiple columns.
+            Err(column) if column > column_index => return self,
+            Err(column) => {
+                for _ in column..column_index {
+                    header.cells.push(Cell::new(""));
+                }
+                header.cells.push(cell.into());
+            }
+        }
+
+        let size = header.content_size();
+        self.content_size = self.content_size.saturating_sub(previous_size) + size;
+        self.discover_columns();
+
+        self
+    }
+
+    /// Get the header cell, that starts in a specific column.
+    ///
+    /// Returns `None`, if there's no header or no cell starts in this column.
+    ///
+    /// ```
+    /// use super_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.set_header(vec!["Name", "Size"]);
+    /// assert_eq!(table.header_cell(1).unwrap().content(), "Size");
+    /// assert!(table.header_cell(2).is_none());
+    /// ```
+    pub fn header_cell(&self, column_index: usize) -> Option<&Cell> {
+        let header = self.header.as_ref()?;
+        let index = header_cell_index(header, column_index).ok()?;
+        header.cells.get(index)
+    }
+
+    /// Get a mutable reference to the header cell, that starts in a specific column.
+    ///
+    /// See [Table::header_cell].
+    ///
+    /// ```
+    /// use super_table::{Cell, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table.set_header(vec!["Name", "Latency"]);
+    ///
+    /// let cell = table.header_cell_mut(1).unwrap();
+    /// *cell = Cell::new(format!("{} [ms]", cell.content()));
+    /// assert_eq!(table.header_cell(1).unwrap().content(), "Latency [ms]");
+    /// ```
+    pub fn header_cell_mut(&mut self, column_index: usize) -> Option<&mut Cell> {
+        let header = self.header.as_mut()?;
+        let index = header_cell_index(header, column_index).ok()?;
+        header.cells.get_mut(index)
+    }
+
+    /// Set the units of all columns, which are displayed as a secondary line below the header.
+    ///
+    /// Units are displayed dimmed, are considered when the width of columns is calculated and
+    /// aren't part of any export.
+    /// A unit is displayed below the header cell that starts in its column, so units of columns
+    /// that are covered by a spanning header cell aren't displayed.
+    /// Without a header, no units are displayed at all.
+    ///
+    /// Empty units are ignored.
+    /// See [Column::set_unit] to set the unit of a single column.
+    ///
+    /// ```
+    /// use super_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Latency", "Memory", "CPU"])
+    ///     .set_units_row(vec!["[ms]", "[MB]", "[%]"])
+    ///     .add_row(vec!["12", "512", "3.5"]);
+    ///
+    /// let expected = "
+    /// +---------+--------+-----+
+    /// | Latency | Memory | CPU |
+    /// | [ms]    | [MB]   | [%] |
+    /// +========================+
+    /// | 12      | 512    | 3.5 |
+    /// +---------+--------+-----+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_units_row<T: IntoIterator<Item = U>, U: Into<String>>(
+        &mut self,
+        units: T,
+    ) -> &mut Self {
+        for (index, unit) in units.into_iter().enumerate() {
+            let unit = unit.into();
+            if index >= self.columns.len() {
+                self.columns.push(Column::new(index));
+            }
+            if unit.is_empty() {
+                self.columns[index].remove_unit();
+            } else {
+                self.columns[index].set_unit(unit);
+            }
+        }
+
+        self
+    }
+
+    /// Returns the number of currently present columns.
+    ///
+    /// This considers the colspan of cells, as well as cells that are pushed to the right by
+    /// rowspans from previous rows.
+    ///
+    /// ```
+    /// use super_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.set_header(vec!["Col 1", "Col 2", "Col 3"]);
+    ///
+    /// assert_eq!(table.column_count(), 3);
+    /// ```
+    pub fn column_count(&mut self) -> usize {
+        self.discover_columns();
+        self.columns.len()
+    }
+
+    /// Add a new column with a header to the right of all existing columns.
+    ///
+    /// The first cell is added to the first row, the second cell to the second row and so on.
+    /// This is convenient if the data of a table is available column by column.
+    /// If the column has fewer cells than the table has rows, or the other way around, the
+    /// missing cells are filled with [null values](CellValue::Null), which are displayed as
+    /// configured by [Table::set_null_style].
+    ///
+    /// **Note:** Rowspans of existing cells aren't considered, when the new cells are placed.
+    ///
+    /// ```
+    /// use super_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .add_column("Name", vec!["Alice", "Bob", "Carol"])
+    ///     .add_column("Age", vec![30, 25]);
+    ///
+    /// assert_eq!(table.row_count(), 3);
+    /// assert_eq!(table.row(2).unwrap().cell_count(), 2);
+    /// ```
+    pub fn add_column<H, I>(&mut self, header: H, cells: I) -> &mut Self
+    where
+        H: Into<Cell>,
+        I: IntoIterator,
+        I::Item: Into<Cell>,
+    {
+        self.discover_columns();
+        let index = self.columns.len();
+
+        let header_row = self.header.get_or_insert_with(Row::new);
+        while header_row.effective_column_count() < index {
+            header_row.cells.push(Cell::new(""));
+        }
+        header_row.cells.push(header.into());
+
+        let mut cells = cells.into_iter().map(Into::into);
+        let mut row_index = 0;
+        loop {
+            let cell = match cells.next() {
+                Some(cell) => cell,
+                None if row_index < self.rows.len() => Cell::from(CellValue::Null),
+                None => break,
+            };
+            if row_index == self.rows.len() {
+                let mut row = Row::new();
+                row.index = Some(row_index);
+                self.rows.push(row);
+            }
+
+            if self.memory_limit.is_some() {
+                self.content_size += cell.content.iter().map(String::len).sum::<usize>();
+            }
+            let row = &mut self.rows[row_index];
+            while row.effective_column_count() < index {
+                row.cells.push(Cell::from(CellValue::Null));
+            }
+            row.cells.push(cell);
+            row_index += 1;
+        }
+        self.discover_columns();
+
+        self
+    }
+
+    /// Set the headers of all rows, which turns the table into a cross table.
+    ///
+    /// The headers are displayed in a new column to the left of all existing columns.
+    /// The first cell is the header of the first row, the second cell the header of the second
+    /// row and so on. Rows without a header get an empty one, surplus headers are added as new
+    /// rows. If the table already has row headers, they are replaced.
+    ///
+    /// Row headers have the [header role](CellRole::Header), unless they have their own role.
+    /// [Table::to_html] writes them as `<th scope="row">` and they are styled as `header` by
+    /// the [StyleMap] of the table.
+    ///
+    /// **Note:** Rows and headers that are added afterwards need their own first cell.
+    ///
+    /// ```
+    /// use super_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Mon", "Tue"])
+    ///     .add_row(vec!["3", "5"])
+    ///     .add_row(vec!["1", "0"])
+    ///     .set_row_headers(vec!["Alice", "Bob"])
+    ///     .set_corner_cell("Name");
+    ///
+    /// let expected = "
+    /// +-------+-----+-----+
+    /// | Name  | Mon | Tue |
+    /// +===================+
+    /// | Alice | 3   | 5   |
+    /// |-------+-----+-----|
+    /// | Bob   | 1   | 0   |
+    /// +-------+-----+-----+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_row_headers<I>(&mut self, headers: I) -> &mut Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Cell>,
+    {
+        self.insert_header_column();
+
+        let mut headers = headers.into_iter().map(Into::into);
+        let mut row_index = 0;
+        loop {
+            let mut cell = match headers.next() {
+                Some(cell) => cell,
+                None if row_index < self.rows.len() => Cell::new(""),
+                None => break,
+            };
+            cell.role = cell.role.or(Some(CellRole::Header));
+            if row_index == self.rows.len() {
+                let mut row = Row::new();
+                row.index = Some(row_index);
+                row.cells.push(Cell::new(""));
+                self.rows.push(row);
+            }
+
+            let row = &mut self.rows[row_index];
+            if self.memory_limit.is_some() {
+                let previous = row.cells[0].content.iter().map(String::len).sum::<usize>();
+                let size = cell.content.iter().map(String::len).sum::<usize>();
+                self.content_size = self.content_size.saturating_sub(previous) + size;
+            }
+            row.cells[0] = cell;
+            row_index += 1;
+        }
+
+        self
+    }
+
+    /// Returns whether the first column contains the headers of the rows.
+    ///
+    /// See [Table::set_row_headers].
+    pub fn has_row_headers(&self) -> bool {
+        self.row_headers
+    }
+
+    /// Set the top left cell of a cross table, which is part of both the header row and the
+    /// column of [row headers](Table::set_row_headers).
+    ///
+    /// If the table has no row headers yet, an empty column of row headers is added.
+    /// If it has no header, a header is added, which only consists of the corner cell.
+    ///
+    /// ```
+    /// use super_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Read", "Write"])
+    ///     .add_row(vec!["x", ""])
+    ///     .set_row_headers(vec!["guest"])
+    ///     .set_corner_cell("User");
+    ///
+    /// assert_eq!(table.header_cell(0).unwrap().content(), "User");
+    /// assert_eq!(table.header_cell(1).unwrap().content(), "Read");
+    /// ```
+    pub fn set_corner_cell<T: Into<Cell>>(&mut self, cell: T) -> &mut Self {
+        self.insert_header_column();
+        self.set_column_header(0, cell)
+    }
+
+    /// Add an empty column of row headers to the left of all existing columns, unless the table
+    /// already has one.
+    fn insert_header_column(&mut self) {
+        if self.row_headers {
+            return;
+        }
+        self.row_headers = true;
+        self.discover_columns();
+
+        self.columns.insert(0, Column::new(0));
+        for (index, column) in self.columns.iter_mut().enumerate() {
+            column.index = index;
+        }
+        if let Some(header) = self.header.as_mut() {
+            header.cells.insert(0, Cell::new(""));
+        }
+        for row in self.rows.iter_mut() {
+            row.cells
+                .insert(0, Cell::new("").set_role(CellRole::Header));
+        }
+    }
+
+    /// Add a new row to the table.
+    ///
+    /// ```
+    /// use super_table::{Table, Row};
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec!["One", "Two"]);
+    /// ```
+    pub fn add_row<T: Into<Row>>(&mut self, row: T) -> &mut Self {
+        let mut row = row.into();
+        if let Some(limit) = self.memory_limit {
+            let size = row.content_size();
+            // Once a row has been discarded, all following rows are discarded as well.
+            // Otherwise, the table would silently have gaps in it.
+            if self.omitted_rows > 0 || self.content_size + size > limit {
+                self.omitted_rows += 1;
+                return self;
+            }
+            self.content_size += size;
+        }
+        self.autogenerate_columns(&row);
+        row.index = Some(self.rows.len());
+        self.rows.push(row);
+        if let Some(max_rows) = self.max_retained_rows {
+            self.remove_oldest_rows(max_rows);
+        }
+
+        self
+    }
+
+    /// Remove the oldest rows, until at most `max_rows` rows are left.
+    fn remove_oldest_rows(&mut self, max_rows: usize) {
+        let count = self.rows.len().saturating_sub(max_rows);
+        if count == 0 {
+            return;
+        }
+
+        // Rowspans of removed rows are moved into the first remaining row.
+        let removed_size: usize = self.rows[..=count.min(self.rows.len() - 1)]
+            .iter()
+            .map(Row::content_size)
+            .sum();
+        remove_leading_rows(self.header.as_ref(), &mut self.rows, count);
+        let first_size = self.rows.first().map_or(0, Row::content_size);
+        self.content_size = self.content_size.saturating_sub(removed_size) + first_size;
+    }
+
+    /// Add a note, which spans the whole width of the table, below the current rows.
+    ///
+    /// In contrast to a cell that [spans all columns](Cell::set_colspan_rest), an annotation
+    /// doesn't belong to any column:
+    /// - Its content never widens the table and is wrapped within the table's width instead.
+    /// - The padding, alignment, styles and formatting of the columns don't apply to it.
+    ///   It only keeps a single space next to the left and right border of the table.
+    ///
+    /// Style the annotation by passing a styled [Cell].
+    ///
+    /// ```
+    /// use super_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Host", "State"])
+    ///     .add_row(vec!["db-1", "up"])
+    ///     .add_annotation_row("Restarted after the kernel update")
+    ///     .add_row(vec!["db-2", "up"]);
+    ///
+    /// let expected = "
+    /// +------+-------+
+    /// | Host | State |
+    /// +==============+
+    /// | db-1 | up    |
+    /// |------+-------|
+    /// | Restarted    |
+    /// | after the    |
+    /// | kernel       |
+    /// | update       |
+    /// |--------------|
+    /// | db-2 | up    |
+    /// +------+-------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn add_annotation_row<T: Into<Cell>>(&mut self, annotation: T) -> &mut Self {
+        let mut row = Row::from(vec![annotation.into().set_colspan_rest()]);
+        row.annotation = true;
+
+        self.add_row(row)
+    }
+
+    /// Draw a [section separator](TableComponent::SectionSeparator) below the current last row,
+    /// to group the rows of the body.
+    ///
+    /// Section separators are drawn like the line below the header by default, even if a preset
+    /// doesn't draw lines between rows.
+    /// Nothing happens, if the table doesn't have any rows yet.
+    ///
+    /// ```
+    /// use super_table::Table;
+    /// use super_table::presets::ASCII_BORDERS_ONLY_CONDENSED;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .load_preset(ASCII_BORDERS_ONLY_CONDENSED)
+    ///     .set_header(vec!["Host", "State"])
+    ///     .add_row(vec!["db-1", "up"])
+    ///     .add_row(vec!["db-2", "up"])
+    ///     .add_section_break()
+    ///     .add_row(vec!["web-1", "down"]);
+    ///
+    /// let expected = "
+    /// +---------------+
+    /// | Host    State |
+    /// +===============+
+    /// | db-1    up    |
+    /// | db-2    up    |
+    /// +===============+
+    /// | web-1   down  |
+    /// +---------------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn add_section_break(&mut self) -> &mut Self {
+        if let Some(row) = self.rows.last_mut() {
+            row.section_break = true;
+        }
+
+        self
+    }
+
+    /// Set the row at a specific index, replacing any row that's already there.
+    ///
+    /// If the table has fewer rows, it's filled up with empty rows until the index exists.
+    /// This is convenient if rows are computed out of order, but must be displayed in a fixed
+    /// order.
+    ///
+    /// Like with [Table::add_row], rows that don't fit into the
+    /// [memory limit](Table::set_memory_limit) are discarded, the previous row is kept in that
+    /// case.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the empty rows in front of the index can't be allocated, e.g. for an index
+    /// of `usize::MAX`.
+    ///
+    /// ```
+    /// use super_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.set_row(2, vec!["Third"]);
+    /// assert_eq!(table.row_count(), 3);
+    ///
+    /// table.set_row(0, vec!["First"]);
+    /// assert_eq!(table.row(0).unwrap().cell_count(), 1);
+    /// assert_eq!(table.row(1).unwrap().cell_count(), 0);
+    /// ```
+    pub fn set_row<T: Into<Row>>(&mut self, index: usize, row: T) -> &mut Self {
+        let appended = index >= self.rows.len();
+        let mut row = row.into();
+        let mut content_size = self.content_size;
+        if let Some(limit) = self.memory_limit {
+            let replaced = self.rows.get(index).map_or(0, Row::content_size);
+            content_size = self.content_size.saturating_sub(replaced) + row.content_size();
+            // Once a row has been discarded, no rows are appended anymore, like in add_row.
+            if (appended && self.omitted_rows > 0) || content_size > limit {
+                self.omitted_rows += 1;
+                return self;
+            }
+        }
+        if appended {
+            // Reserve all empty rows at once, so an index that can't fit into memory fails
+            // before the table is changed.
+            self.rows.reserve(index - self.rows.len());
+        }
+        self.content_size = content_size;
+        self.autogenerate_columns(&row);
+        row.index = Some(index);
+
+        while self.rows.len() <= index {
+            let mut placeholder = Row::new();
+            placeholder.index = Some(self.rows.len());
+            self.rows.push(placeholder);
+        }
+        self.rows[index] = row;
+        if let Some(max_rows) = self.max_retained_rows.filter(|_| appended) {
+            self.remove_oldest_rows(max_rows);
+        }
+
+        self
+    }
+
+    /// Add a new row to the table if the predicate evaluates to `true`.
+    ///
+    /// ```
+    /// use super_table::{Table, Row};
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row_if(|index, row| true, vec!["One", "Two"]);
+    /// ```
+    pub fn add_row_if<P, T>(&mut self, predicate: P, row: T) -> &mut Self
+    where
+        P: Fn(usize, &T) -> bool,
+        T: Into<Row>,
+    {
+        if predicate(self.rows.len(), &row) {
+            return self.add_row(row);
+        }
+
+        self
+    }
+
+    /// Add multiple rows to the table.
+    ///
+    /// ```
+    /// use super_table::{Table, Row};
+    ///
+    /// let mut table = Table::new();
+    /// let rows = vec![
+    ///     vec!["One", "Two"],
+    ///     vec!["Three", "Four"]
+    /// ];
+    /// table.add_rows(rows);
+    /// ```
+    pub fn add_rows<I>(&mut self, rows: I) -> &mut Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Row>,
+    {
+        for row in rows.into_iter() {
+            self.add_row(row);
+        }
+
+        self
+    }
+
+    /// Add multiple rows to the table if the predicate evaluates to `true`.
+    ///
+    /// ```
+    /// use super_table::{Table, Row};
+    ///
+    /// let mut table = Table::new();
+    /// let rows = vec![
+    ///     vec!["One", "Two"],
+    ///     vec!["Three", "Four"]
+    /// ];
+    /// table.add_rows_if(|index, rows| true, rows);
+    /// ```
+    pub fn add_rows_if<P, I>(&mut self, predicate: P, rows: I) -> &mut Self
+    where
+        P: Fn(usize, &I) -> bool,
+        I: IntoIterator,
+        I::Item: Into<Row>,
+    {
+        if predicate(self.rows.len(), &rows) {
+            return self.add_rows(rows);
+        }
+
+        self
+    }
+
+    /// Returns the number of currently present rows.
+    ///
+    /// The header isn't included. Use [Table::shape] to also count the header.
+    ///
+    /// ```
+    /// use super_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec!["One", "Two"]);
+    ///
+    /// assert_eq!(table.row_count(), 1);
+    /// ```
+    pub fn row_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns the shape of the table as `(rows, columns)`.
+    ///
+    /// Unlike [Table::row_count], the header is counted as a row.
+    /// The amount of columns considers the colspan of cells and positions that are occupied by
+    /// rowspans, just like [Table::column_count]. However, no columns are added to the table.
+    ///
+    /// ```
+    /// use super_table::{Cell, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Col 1", "Col 2"])
+    ///     .add_row(vec![Cell::new("Tall").set_rowspan(2), Cell::new("Wide").set_colspan(2)])
+    ///     .add_row(vec!["One", "Two"]);
+    ///
+    /// // The last row is pushed to the right by the rowspan above.
+    /// assert_eq!(table.shape(), (3, 3));
+    /// ```
+    pub fn shape(&self) -> (usize, usize) {
+        let rows = self.rows.len() + usize::from(self.header.is_some());
+        let columns = self.columns.len().max(span_aware_column_count(self));
+
+        (rows, columns)
+    }
+
+    /// Returns if the table is empty (contains no data rows).
+    ///
+    /// ```
+    /// use super_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// assert!(table.is_empty());
+    ///
+    /// table.add_row(vec!["One", "Two"]);
+    /// assert!(!table.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Enforce a max width that should be used in combination with [dynamic content arrangement](ContentArrangement::Dynamic).\
+    /// This is usually not necessary, if you plan to output your table to a tty,
+    /// since the terminal width can be automatically determined.
+    pub fn set_width(&mut self, width: u16) -> &mut Self {
+        self.width = Some(width);
+
+        self
+    }
+
+    /// Get the expected width of the table.
+    ///
+    /// This will be `Some(width)`, if the terminal width can be detected or if the table width is set via [set_width](Table::set_width).
+    ///
+    /// If neither is not possible, `None` will be returned.\
+    /// This implies that both the [Dynamic](ContentArrangement::Dynamic) mode and the [Percentage](crate::style::Width::Percentage) constraint won't work.
+    #[cfg(feature = "tty")]
+    pub fn width(&self) -> Option<u16> {
+        if let Some(width) = self.width {
+            Some(width)
+        } else if self.is_tty() {
+            if let Ok((width, _)) = crossterm::terminal::size() {
+                Some(width)
+            } else {
+                None
+            }
+        } else {
+            None
+        }
+    }
+
+    #[cfg(not(feature = "tty"))]
+    pub fn width(&self) -> Option<u16> {
+        self.width
+    }
+
+    /// Specify how Comfy Table should arrange the content in your table.
+    ///
+    /// ```
+    /// use super_table::{Table, ContentArrangement};
+    ///
+    /// let mut table = Table::new();
+    /// table.set_content_arrangement(ContentArrangement::Dynamic);
+    /// ```
+    pub fn set_content_arrangement(&mut self, arrangement: ContentArrangement) -> &mut Self {
+        self.arrangement = arrangement;
+
+        self
+    }
+
+    /// Get the current content arrangement of the table.
+    pub fn content_arrangement(&self) -> ContentArrangement {
+        self.arrangement.clone()
+    }
+
+    /// Specify what happens, if column constraints require more space than the
+    /// [width](Table::set_width) of the table allows.
+    ///
+    /// This only applies to tables with a [dynamic content arrangement](ContentArrangement::Dynamic).
+    /// By default, all constraints are kept and [Table::try_lines] returns an error.
+    /// [Table::debug_layout] reports conflicts and how they've been resolved.
+    ///
+    /// ```
+    /// use super_table::*;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_width(30)
+    ///     .set_content_arrangement(ContentArrangement::Dynamic)
+    ///     .set_constraint_conflict_policy(ConstraintConflictPolicy::Shrink)
+    ///     .add_row(vec!["Left", "Right"])
+    ///     .set_constraints(vec![
+    ///         ColumnConstraint::Absolute(Width::Fixed(20)),
+    ///         ColumnConstraint::Absolute(Width::Fixed(20)),
+    ///     ]);
+    ///
+    /// assert!(table.lines().all(|line| line.len() <= 30));
+    /// ```
+    pub fn set_constraint_conflict_policy(
+        &mut self,
+        policy: ConstraintConflictPolicy,
+    ) -> &mut Self {
+        self.constraint_conflict_policy = policy;
+
+        self
+    }
+
+    /// Get the current constraint conflict policy of the table.
+    pub fn constraint_conflict_policy(&self) -> ConstraintConflictPolicy {
+        self.constraint_conflict_policy
+    }
+
+    /// Specify whether the bottom border is merged below cells of the last row, that span
+    /// multiple columns.
+    ///
+    /// By default, the border is only merged if the header spans the same columns or the table
+    /// has at most two rows. See [ColspanBorderPolicy] for all options.
+    ///
+    /// ```
+    /// use super_table::{Cell, ColspanBorderPolicy, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_colspan_border_policy(ColspanBorderPolicy::NeverMerge)
+    ///     .add_row(vec!["a", "b"])
+    ///     .add_row(vec![Cell::new("Total").set_colspan(2)]);
+    ///
+    /// let expected = "
+    /// +------+-----+
+    /// | a    | b   |
+    /// |------+-----|
+    /// | Total      |
+    /// +------+-----+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_colspan_border_policy(&mut self, policy: ColspanBorderPolicy) -> &mut Self {
+        self.colspan_border_policy = policy;
+
+        self
+    }
+
+    /// Get the current colspan border policy of the table.
+    pub fn colspan_border_policy(&self) -> ColspanBorderPolicy {
+        self.colspan_border_policy
+    }
+
+    /// Specify how wide characters with an ambiguous width are, for instance `±` or `→`.
+    ///
+    /// Terminals in East Asian locales usually display these characters two columns wide,
+    /// which breaks the borders of tables that assume a single column.
+    /// [Table::audit_alignment] finds content with such characters.
+    ///
+    /// This only applies to the content of cells.
+    /// Every border character still takes up a single column, as horizontal lines repeat their
+    /// character once per column. Use an ASCII [preset](crate::presets) for terminals, that also
+    /// display the box-drawing characters of the UTF-8 presets two columns wide.
+    ///
+    /// Default is [AmbiguousWidth::Narrow].
+    ///
+    /// ```
+    /// use super_table::{AmbiguousWidth, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec!["±1"]);
+    /// assert_eq!(table.lines().next().unwrap(), "+----+");
+    ///
+    /// table.set_ambiguous_width(AmbiguousWidth::Wide);
+    /// assert_eq!(table.lines().next().unwrap(), "+-----+");
+    /// ```
+    pub fn set_ambiguous_width(&mut self, ambiguous_width: AmbiguousWidth) -> &mut Self {
+        self.ambiguous_width = ambiguous_width;
+
+        self
+    }
+
+    /// Display a trailing column with the amount of hidden columns, e.g. `+2`.
+    ///
+    /// The column is only displayed, if columns are hidden, either manually or because they
+    /// don't fit into the width of the table. See [HiddenColumnsSummary] for all options.
+    ///
+    /// ```
+    /// use super_table::{HiddenColumnsSummary, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_hidden_columns_summary(HiddenColumnsSummary::Rows)
+    ///     .set_header(vec!["Host", "Token", "Note"])
+    ///     .add_row(vec!["db-1", "secret", "restarted"])
+    ///     .add_row(vec!["db-2", "secret", ""]);
+    /// table.column_mut(1).unwrap().set_hidden(true);
+    /// table.column_mut(2).unwrap().set_hidden(true);
+    ///
+    /// let expected = "
+    /// +------+----+
+    /// | Host | +2 |
+    /// +===========+
+    /// | db-1 | +2 |
+    /// |------+----|
+    /// | db-2 | +1 |
+    /// +------+----+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_hidden_columns_summary(&mut self, summary: HiddenColumnsSummary) -> &mut Self {
+        self.hidden_columns_summary = Some(summary);
+
+        self
+    }
+
+    /// Get where the amount of hidden columns is displayed, if at all.
+    ///
+    /// See [Table::set_hidden_columns_summary].
+    pub fn hidden_columns_summary(&self) -> Option<HiddenColumnsSummary> {
+        self.hidden_columns_summary
+    }
+
+    /// Get the width of characters with an ambiguous width.
+    pub fn ambiguous_width(&self) -> AmbiguousWidth {
+        self.ambiguous_width
+    }
+
+    /// Specify whether exporters use the full content of cells or the content as it's displayed.
+    ///
+    /// Default is [ExportContent::Original], which exports content that has been truncated
+    /// due to a [row's maximum height](Row::max_height) in full.
+    /// [ExportContent::Formatted] exports the full content as well, but formats numbers and
+    /// booleans like they're displayed.
+    ///
+    /// ```
+    /// use super_table::{ExportContent, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table.set_export_content(ExportContent::Rendered);
+    /// assert_eq!(table.export_content(), ExportContent::Rendered);
+    /// ```
+    pub fn set_export_content(&mut self, export_content: ExportContent) -> &mut Self {
+        self.export_content = export_content;
+
+        self
+    }
+
+    /// Get the content that's used by exporters.
+    pub fn export_content(&self) -> ExportContent {
+        self.export_content
+    }
+
+    /// Render the table as compact as possible, which survives being pasted into chats or
+    /// emails with a limited width.
+    ///
+    /// The padding of all columns is removed and columns are separated by a single space.
+    /// Lines between rows are removed, the border and the line below the header are kept.
+    /// This is applied when rendering, so the padding and the style of the table are kept.
+    ///
+    /// ```
+    /// use super_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_compact(true)
+    ///     .set_header(vec!["Host", "Status"])
+    ///     .add_row(vec!["db-1", "running"])
+    ///     .add_row(vec!["web-1", "stopped"]);
+    ///
+    /// let expected = "
+    /// +-------------+
+    /// |Host  Status |
+    /// +=============+
+    /// |db-1  running|
+    /// |web-1 stopped|
+    /// +-------------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_compact(&mut self, compact: bool) -> &mut Self {
+        self.compact = compact;
+
+        self
+    }
+
+    /// Returns whether the table is rendered [compact](Table::set_compact).
+    pub fn is_compact(&self) -> bool {
+        self.compact
+    }
+
+    /// Keep at least one space between content and the border on the side opposite to its
+    /// alignment, even if the content fills its column.
+    ///
+    /// Columns without padding on that side, e.g. in [compact](Table::set_compact) tables,
+    /// are made one character wider. Centered columns keep a space on both sides.
+    /// The alignment of the column is used, the alignment of single cells isn't considered.
+    ///
+    /// ```
+    /// use super_table::{CellAlignment, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_compact(true)
+    ///     .set_alignment_gap(true)
+    ///     .set_header(vec!["Host", "Uptime"])
+    ///     .add_row(vec!["db-1", "12d"])
+    ///     .add_row(vec!["web-1", "3h"]);
+    /// table
+    ///     .column_mut(1)
+    ///     .unwrap()
+    ///     .set_cell_alignment(CellAlignment::Right);
+    ///
+    /// let expected = "
+    /// +--------------+
+    /// |Host    Uptime|
+    /// +==============+
+    /// |db-1       12d|
+    /// |web-1       3h|
+    /// +--------------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_alignment_gap(&mut self, gap: bool) -> &mut Self {
+        self.alignment_gap = gap;
+
+        self
+    }
+
+    /// Returns whether content keeps a [gap](Table::set_alignment_gap) to the border opposite
+    /// to its alignment.
+    pub fn has_alignment_gap(&self) -> bool {
+        self.alignment_gap
+    }
+
+    /// Surround the table with blank space, e.g. to embed it into indented output.
+    ///
+    /// The left and right margin are added as spaces to every line, the top and bottom margin
+    /// as blank lines, which are as wide as the table.
+    /// The [width](Table::set_width) of the table includes its margins, so content is arranged
+    /// in the remaining space.
+    ///
+    /// ```
+    /// use super_table::{Margins, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_margin(Margins {
+    ///         left: 4,
+    ///         ..Margins::default()
+    ///     })
+    ///     .set_header(vec!["Host", "State"])
+    ///     .add_row(vec!["db-1", "up"]);
+    ///
+    /// let expected = "
+    ///     +------+-------+
+    ///     | Host | State |
+    ///     +==============+
+    ///     | db-1 | up    |
+    ///     +------+-------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_margin(&mut self, margin: Margins) -> &mut Self {
+        self.margin = Some(margin);
+
+        self
+    }
+
+    /// Get the blank space around the table, if any has been set.
+    ///
+    /// See [Table::set_margin].
+    pub fn margin(&self) -> Option<Margins> {
+        self.margin
+    }
+
+    /// Put a text in front of every rendered line, e.g. `// ` to render the table as a code
+    /// comment or `> ` to quote it.
+    ///
+    /// The prefix is placed in front of the [margin](Table::set_margin).
+    /// The [width](Table::set_width) of the table includes the prefix, so content is arranged
+    /// in the remaining space.
+    ///
+    /// ```
+    /// use super_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_line_prefix("// ")
+    ///     .set_header(vec!["Host", "State"])
+    ///     .add_row(vec!["db-1", "up"]);
+    ///
+    /// let expected = "
+    /// // +------+-------+
+    /// // | Host | State |
+    /// // +==============+
+    /// // | db-1 | up    |
+    /// // +------+-------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_line_prefix(&mut self, prefix: &str) -> &mut Self {
+        self.line_prefix = Some(prefix.to_string());
+
+        self
+    }
+
+    /// Get the text in front of every rendered line, if one has been set.
+    pub fn line_prefix(&self) -> Option<&str> {
+        self.line_prefix.as_deref()
+    }
+
+    /// Set a callback, which is called with the index and the content of every rendered line
+    /// and returns the line that's displayed instead.
+    ///
+    /// The callback gets the lines including their [prefix](Table::set_line_prefix) and
+    /// [margin](Table::set_margin).
+    /// In contrast to a prefix, text that's added by the callback isn't considered when the
+    /// content of the table is arranged, so it may exceed the [width](Table::set_width) of the
+    /// table.
+    ///
+    /// ```
+    /// use super_table::Table;
+    /// use super_table::presets::NOTHING;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .load_preset(NOTHING)
+    ///     .set_line_decorator(|index, line| format!("{:>2} {}", index + 1, line.trim_end()))
+    ///     .set_header(vec!["Host", "State"])
+    ///     .add_row(vec!["db-1", "up"]);
+    ///
+    /// let expected = "
+    ///  1  Host  State
+    ///  2  db-1  up";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_line_decorator<F>(&mut self, decorator: F) -> &mut Self
+    where
+        F: Fn(usize, &str) -> String + Send + Sync + 'static,
+    {
+        self.line_decorator = Some(LineDecorator(Arc::new(decorator)));
+
+        self
+    }
+
+    /// Remove the line decorator of this table.
+    pub fn remove_line_decorator(&mut self) -> &mut Self {
+        self.line_decorator = None;
+
+        self
     }
 
     /// Set the delimiter used to split text in all cells.
@@ -326,6 +2121,325 @@ impl Table {
         self
     }
 
+    /// Display the [descriptions of columns](Column::set_description) below the table.
+    ///
+    /// Each description is displayed on its own line, following the column's header.
+    /// Descriptions of hidden columns aren't displayed.
+    ///
+    /// ```
+    /// use super_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Name", "RSS"])
+    ///     .add_row(vec!["nginx", "12"])
+    ///     .set_description_footnotes(true);
+    /// table.column_mut(1).unwrap().set_description("Resident set size in MiB");
+    ///
+    /// assert_eq!(
+    ///     table.lines().last().unwrap(),
+    ///     "RSS: Resident set size in MiB"
+    /// );
+    /// ```
+    pub fn set_description_footnotes(&mut self, enabled: bool) -> &mut Self {
+        self.description_footnotes = enabled;
+
+        self
+    }
+
+    /// Specify how cells with a [NULL value](CellValue::Null) are displayed.
+    ///
+    /// By default, `NULL` values are displayed like any other cell, which is an empty string for
+    /// cells that have been created via [Cell::from_value].
+    /// See [NullStyle] for details.
+    ///
+    /// ```
+    /// use super_table::{Cell, CellValue, NullStyle, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_null_style(NullStyle::new("NULL"))
+    ///     .add_row(vec![Cell::from_value(1), Cell::from_value(CellValue::Null)]);
+    ///
+    /// assert!(table.to_string().contains("| 1 | NULL |"));
+    /// ```
+    pub fn set_null_style(&mut self, style: NullStyle) -> &mut Self {
+        self.null_style = Some(style);
+
+        self
+    }
+
+    /// Get the style of `NULL` values, if one has been set.
+    pub fn null_style(&self) -> Option<&NullStyle> {
+        self.null_style.as_ref()
+    }
+
+    /// Set how booleans are displayed in all columns without their own
+    /// [format](Column::set_bool_format).
+    ///
+    /// See [BoolFormat] for details.
+    ///
+    /// ```
+    /// use super_table::{BoolFormat, Cell, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_bool_format(BoolFormat::numeric())
+    ///     .add_row(vec![Cell::from_value(true), Cell::from_value(false)]);
+    ///
+    /// assert_eq!(table.lines().nth(1).unwrap(), "| 1 | 0 |");
+    /// ```
+    pub fn set_bool_format(&mut self, format: BoolFormat) -> &mut Self {
+        self.bool_format = Some(format);
+
+        self
+    }
+
+    /// Get the format of booleans, if one has been set.
+    pub fn bool_format(&self) -> Option<&BoolFormat> {
+        self.bool_format.as_ref()
+    }
+
+    /// Set defaults for all header cells, which are used unless a cell or its column has its
+    /// own setting.
+    ///
+    /// The defaults are applied when the table is rendered, so they also apply to header cells
+    /// that are set later on. See [HeaderDefaults] for details.
+    ///
+    /// ```
+    /// use super_table::{CellAlignment, HeaderDefaults, Table, TextTransform};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header_defaults(
+    ///         HeaderDefaults::new()
+    ///             .alignment(CellAlignment::Center)
+    ///             .transform(TextTransform::Uppercase),
+    ///     )
+    ///     .set_header(vec!["Name", "Requests"])
+    ///     .add_row(vec!["nginx-frontend", "1024"]);
+    /// table
+    ///     .column_mut(1)
+    ///     .unwrap()
+    ///     .set_cell_alignment(CellAlignment::Right);
+    ///
+    /// let expected = "
+    /// +----------------+----------+
+    /// |      NAME      | REQUESTS |
+    /// +===========================+
+    /// | nginx-frontend |     1024 |
+    /// +----------------+----------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_header_defaults(&mut self, defaults: HeaderDefaults) -> &mut Self {
+        self.header_defaults = Some(defaults);
+
+        self
+    }
+
+    /// Get the defaults for header cells, if they have been set.
+    pub fn header_defaults(&self) -> Option<&HeaderDefaults> {
+        self.header_defaults.as_ref()
+    }
+
+    /// Define how the [semantic styles](Cell::set_semantic_style) of cells are displayed in
+    /// each output format.
+    ///
+    /// The terminal style of a cell is applied when the table is displayed, CSS classes are
+    /// added by [Table::to_html]. All other exporters ignore semantic styles.
+    /// See [StyleMap] for details.
+    ///
+    /// ```
+    /// # #[cfg(feature = "tty")] {
+    /// use super_table::{Cell, Color, Style, StyleMap, StyleTarget, Table};
+    ///
+    /// let styles = StyleMap::new()
+    ///     .add("error", StyleTarget::Terminal(Style::new().fg(Color::Red)))
+    ///     .add("error", StyleTarget::HtmlClass("error".into()));
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_style_map(styles)
+    ///     .add_row(vec![Cell::new("db-1"), Cell::new("failed").set_semantic_style("error")]);
+    ///
+    /// table.force_no_tty().enforce_styling();
+    /// assert!(table.to_string().contains("\u{1b}[38;5;9m failed \u{1b}[39m"));
+    /// assert!(table.to_html(Default::default()).contains("<td class=\"error\">failed</td>"));
+    /// assert!(table.to_csv(Default::default()).contains("db-1,failed"));
+    /// # }
+    /// ```
+    pub fn set_style_map(&mut self, style_map: StyleMap) -> &mut Self {
+        self.style_map = Some(style_map);
+
+        self
+    }
+
+    /// Get the map of semantic styles, if one has been set.
+    pub fn style_map(&self) -> Option<&StyleMap> {
+        self.style_map.as_ref()
+    }
+
+    /// Limit the amount of memory the content of this table may occupy.
+    ///
+    /// The memory usage is approximated by the amount of bytes of the content of all cells.
+    /// Once the limit is reached, [Table::add_row] discards any further rows.
+    /// When rendering, only the rows that fit into the limit are displayed, which also covers
+    /// rows that have been modified after they've been added.
+    ///
+    /// If any rows have been omitted, a final row with a notice is displayed instead.
+    ///
+    /// ```
+    /// use super_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.set_memory_limit(10);
+    /// table.add_row(vec!["12345"]);
+    /// table.add_row(vec!["67890"]);
+    /// table.add_row(vec!["discarded"]);
+    ///
+    /// assert_eq!(table.row_count(), 2);
+    /// assert_eq!(table.omitted_rows(), 1);
+    /// ```
+    pub fn set_memory_limit(&mut self, bytes: usize) -> &mut Self {
+        self.memory_limit = Some(bytes);
+        self.content_size = self
+            .header
+            .iter()
+            .chain(self.rows.iter())
+            .map(Row::content_size)
+            .sum();
+
+        self
+    }
+
+    /// Get the memory limit of this table, if one is set.
+    pub fn memory_limit(&self) -> Option<usize> {
+        self.memory_limit
+    }
+
+    /// Remove the memory limit of this table.
+    ///
+    /// Rows that have already been discarded won't be restored.
+    pub fn remove_memory_limit(&mut self) -> &mut Self {
+        self.memory_limit = None;
+
+        self
+    }
+
+    /// Get the amount of rows that have been discarded due to the [memory limit](Table::set_memory_limit).
+    pub fn omitted_rows(&self) -> usize {
+        self.omitted_rows
+    }
+
+    /// Keep at most `max_rows` rows, e.g. for a live view of a log that runs for a long time.
+    ///
+    /// Once the limit is reached, [Table::add_row] removes the oldest rows of the table.
+    /// Cells of removed rows, that span into the remaining rows, are moved to the first remaining
+    /// row and only span the remaining rows.
+    /// Rows exceeding the limit are removed immediately.
+    ///
+    /// ```
+    /// use super_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.set_max_retained_rows(2);
+    /// for tick in 1..=5 {
+    ///     table.add_row(vec![tick]);
+    /// }
+    ///
+    /// let expected = "
+    /// +---+
+    /// | 4 |
+    /// |---|
+    /// | 5 |
+    /// +---+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_max_retained_rows(&mut self, max_rows: usize) -> &mut Self {
+        self.max_retained_rows = Some(max_rows);
+        self.remove_oldest_rows(max_rows);
+
+        self
+    }
+
+    /// Get the maximum amount of rows that's kept, if one is set.
+    ///
+    /// See [Table::set_max_retained_rows].
+    pub fn max_retained_rows(&self) -> Option<usize> {
+        self.max_retained_rows
+    }
+
+    /// Keep all rows that are added to the table.
+    ///
+    /// Rows that have already been removed won't be restored.
+    pub fn remove_max_retained_rows(&mut self) -> &mut Self {
+        self.max_retained_rows = None;
+
+        self
+    }
+
+    /// Create a copy of this table with a different set of rows.
+    ///
+    /// This avoids cloning all rows of the table, if only a part of them is needed.
+    pub(crate) fn with_rows(&self, rows: Vec<Row>) -> Table {
+        Table {
+            columns: self.columns.clone(),
+            style: self.style.clone(),
+            section_styles: self.section_styles.clone(),
+            header: self.header.clone(),
+            rows,
+            arrangement: self.arrangement.clone(),
+            constraint_conflict_policy: self.constraint_conflict_policy,
+            colspan_border_policy: self.colspan_border_policy,
+            ambiguous_width: self.ambiguous_width,
+            export_content: self.export_content,
+            delimiter: self.delimiter,
+            truncation_indicator: self.truncation_indicator.clone(),
+            #[cfg(feature = "tty")]
+            no_tty: self.no_tty,
+            #[cfg(feature = "tty")]
+            is_tty_cache: self.is_tty_cache.clone(),
+            #[cfg(feature = "tty")]
+            use_stderr: self.use_stderr,
+            width: self.width,
+            #[cfg(feature = "tty")]
+            enforce_styling: self.enforce_styling,
+            #[cfg(feature = "tty")]
+            style_text_only: self.style_text_only,
+            #[cfg(feature = "tty")]
+            attribute_policy: self.attribute_policy,
+            #[cfg(feature = "tty")]
+            highlights: self.highlights.clone(),
+            #[cfg(feature = "tty")]
+            palette: self.palette.clone(),
+            #[cfg(feature = "tty")]
+            auto_contrast: self.auto_contrast,
+            #[cfg(feature = "tty")]
+            enforce_truecolor: self.enforce_truecolor,
+            memory_limit: self.memory_limit,
+            content_size: self.content_size,
+            omitted_rows: self.omitted_rows,
+            max_retained_rows: self.max_retained_rows,
+            border_renderer: self.border_renderer.clone(),
+            render_hook: self.render_hook.clone(),
+            description_footnotes: self.description_footnotes,
+            null_style: self.null_style.clone(),
+            bool_format: self.bool_format.clone(),
+            header_defaults: self.header_defaults.clone(),
+            style_map: self.style_map.clone(),
+            row_headers: self.row_headers,
+            compact: self.compact,
+            alignment_gap: self.alignment_gap,
+            default_max_cell_width: self.default_max_cell_width,
+            hidden_columns_summary: self.hidden_columns_summary,
+            margin: self.margin,
+            line_prefix: self.line_prefix.clone(),
+            line_decorator: self.line_decorator.clone(),
+            strict: self.strict,
+            reversed: self.reversed,
+        }
+    }
+
     /// In case you are sure you don't want export tables to a tty or you experience
     /// problems with tty specific code, you can enforce a non_tty mode.
     ///
@@ -374,40 +2488,215 @@ impl Table {
         })
     }
 
-    /// Enforce terminal styling.
+    /// Enforce terminal styling.
+    ///
+    /// Only useful if you forcefully disabled tty, but still want those fancy terminal styles.
+    ///
+    /// ```
+    /// use super_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.force_no_tty()
+    ///     .enforce_styling();
+    /// ```
+    #[cfg(feature = "tty")]
+    pub fn enforce_styling(&mut self) -> &mut Self {
+        self.enforce_styling = true;
+
+        self
+    }
+
+    /// Returns whether the content of this table should be styled with the current settings and
+    /// environment.
+    #[cfg(feature = "tty")]
+    pub fn should_style(&self) -> bool {
+        if self.enforce_styling {
+            return true;
+        }
+        self.is_tty()
+    }
+
+    /// Enforce 24-bit colors for [gradients](Cell::set_gradient), even if the terminal doesn't
+    /// announce support for them.
+    ///
+    /// ```
+    /// use super_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.enforce_truecolor();
+    /// assert!(table.supports_truecolor());
+    /// ```
+    #[cfg(feature = "tty")]
+    pub fn enforce_truecolor(&mut self) -> &mut Self {
+        self.enforce_truecolor = true;
+
+        self
+    }
+
+    /// Returns whether 24-bit colors can be used for [gradients](Cell::set_gradient).
+    ///
+    /// That's the case, if it has been [enforced](Table::enforce_truecolor) or if the
+    /// `COLORTERM` environment variable is `truecolor` or `24bit`.
+    #[cfg(feature = "tty")]
+    pub fn supports_truecolor(&self) -> bool {
+        if self.enforce_truecolor {
+            return true;
+        }
+        std::env::var("COLORTERM").is_ok_and(|value| value == "truecolor" || value == "24bit")
+    }
+
+    /// By default, the whole content of a cells will be styled.
+    /// Calling this function disables this behavior for all cells, resulting in
+    /// only the text of cells being styled.
+    #[cfg(feature = "tty")]
+    pub fn style_text_only(&mut self) {
+        self.style_text_only = true;
+    }
+
+    /// Specify how attributes are treated, that aren't supported by all terminals.
+    ///
+    /// By default, all attributes are kept as they are, even if styling is
+    /// [enforced](Table::enforce_styling) for output that isn't a terminal.
+    /// Use [AttributePolicy::Auto] to adapt the attributes to the output instead.
+    ///
+    /// ```
+    /// use super_table::{Attribute, AttributePolicy, Cell, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .enforce_styling()
+    ///     .set_attribute_policy(AttributePolicy::Strip)
+    ///     .add_row(vec![Cell::new("Blinking").add_attribute(Attribute::SlowBlink)]);
+    ///
+    /// assert!(!table.to_string().contains('\x1b'));
+    /// ```
+    #[cfg(feature = "tty")]
+    pub fn set_attribute_policy(&mut self, policy: AttributePolicy) -> &mut Self {
+        self.attribute_policy = policy;
+
+        self
+    }
+
+    /// Get the current attribute policy of the table.
+    #[cfg(feature = "tty")]
+    pub fn attribute_policy(&self) -> AttributePolicy {
+        self.attribute_policy
+    }
+
+    /// Style all matches of a pattern inside of the table's cells.
+    ///
+    /// The highlighting happens while rendering, the content of the cells isn't changed.
+    /// Matches are searched in each line of a cell's content before it's wrapped or truncated.
+    /// If a match is split across multiple lines, every visible part is highlighted.
+    ///
+    /// The highlight style is layered on top of the cell's style.
+    /// If multiple patterns match the same text, the pattern that was added first wins.
+    ///
+    /// Like any other styling, highlighting only takes effect if the table
+    /// [should be styled](Table::should_style).
+    ///
+    /// ```
+    /// use super_table::{Color, Style, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .add_row(vec!["connection refused", "ok"])
+    ///     .highlight_matches("refused", Style::new().fg(Color::Red));
+    /// ```
+    ///
+    /// With the `regex` feature, a `regex::Regex` can be passed as the pattern.
+    #[cfg(feature = "tty")]
+    pub fn highlight_matches<P: Into<HighlightPattern>>(
+        &mut self,
+        pattern: P,
+        style: Style,
+    ) -> &mut Self {
+        self.highlights.push((pattern.into(), style));
+
+        self
+    }
+
+    /// Remove all patterns that have been added via [Table::highlight_matches].
+    #[cfg(feature = "tty")]
+    pub fn clear_highlights(&mut self) -> &mut Self {
+        self.highlights.clear();
+
+        self
+    }
+
+    /// Replace all colors of the table by the most similar colors of a [Palette] when rendering,
+    /// e.g. to make a table readable for color blind people.
+    ///
+    /// This covers the colors of cells and rows, highlights and the colors that are applied
+    /// depending on the content, like [category colors](Column::set_category_colors).
+    /// Shades of grey are kept, see [Palette::remap].
+    ///
+    /// ```
+    /// use super_table::{Cell, Color, Table};
+    /// use super_table::palettes::OKABE_ITO;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .add_row(vec![Cell::new("failed").fg(Color::Red), Cell::new("ok").fg(Color::Green)])
+    ///     .set_palette(OKABE_ITO);
+    ///
+    /// table.force_no_tty().enforce_styling();
+    /// assert!(table.to_string().contains("\u{1b}[38;2;213;94;0m failed \u{1b}[39m"));
+    /// ```
+    #[cfg(feature = "tty")]
+    pub fn set_palette(&mut self, palette: Palette) -> &mut Self {
+        self.palette = Some(palette);
+
+        self
+    }
+
+    /// Get the palette of this table, if one has been set.
+    #[cfg(feature = "tty")]
+    pub fn palette(&self) -> Option<&Palette> {
+        self.palette.as_ref()
+    }
+
+    /// Display the colors of the table as they are, see [Table::set_palette].
+    #[cfg(feature = "tty")]
+    pub fn remove_palette(&mut self) -> &mut Self {
+        self.palette = None;
+
+        self
+    }
+
+    /// Give cells with a background color, but without a foreground color, a black or white
+    /// foreground, whichever is more readable on the background. Default is `false`.
     ///
-    /// Only useful if you forcefully disabled tty, but still want those fancy terminal styles.
+    /// The background and foreground colors of a cell may come from the cell or from its row.
+    /// The foreground is picked by the relative luminance of the background, after the
+    /// [palette](Table::set_palette) has been applied. Cells with an explicit foreground color
+    /// keep it, as do cells whose background is [Color::Reset](crate::Color::Reset).
     ///
     /// ```
-    /// use super_table::Table;
+    /// use super_table::{Cell, Color, Table};
     ///
     /// let mut table = Table::new();
-    /// table.force_no_tty()
-    ///     .enforce_styling();
+    /// table
+    ///     .set_auto_contrast(true)
+    ///     .add_row(vec![Cell::new("hot").bg(Color::Yellow), Cell::new("cold").bg(Color::DarkBlue)]);
+    ///
+    /// table.force_no_tty().enforce_styling();
+    /// let rendered = table.to_string();
+    /// assert!(rendered.contains("\u{1b}[48;5;11m\u{1b}[38;5;0m hot "));
+    /// assert!(rendered.contains("\u{1b}[48;5;4m\u{1b}[38;5;15m cold "));
     /// ```
     #[cfg(feature = "tty")]
-    pub fn enforce_styling(&mut self) -> &mut Self {
-        self.enforce_styling = true;
+    pub fn set_auto_contrast(&mut self, auto_contrast: bool) -> &mut Self {
+        self.auto_contrast = auto_contrast;
 
         self
     }
 
-    /// Returns whether the content of this table should be styled with the current settings and
-    /// environment.
-    #[cfg(feature = "tty")]
-    pub fn should_style(&self) -> bool {
-        if self.enforce_styling {
-            return true;
-        }
-        self.is_tty()
-    }
-
-    /// By default, the whole content of a cells will be styled.
-    /// Calling this function disables this behavior for all cells, resulting in
-    /// only the text of cells being styled.
+    /// Returns whether cells with a background color get a
+    /// [readable foreground color](Table::set_auto_contrast).
     #[cfg(feature = "tty")]
-    pub fn style_text_only(&mut self) {
-        self.style_text_only = true;
+    pub fn has_auto_contrast(&self) -> bool {
+        self.auto_contrast
     }
 
     /// Convenience method to set a [ColumnConstraint] for all columns at once.
@@ -446,6 +2735,46 @@ impl Table {
         self
     }
 
+    /// Limit the width of all columns, that don't have their own [constraint](Column::set_constraint).
+    ///
+    /// This is the same as an [UpperBoundary](ColumnConstraint::UpperBoundary) of
+    /// [Width::Fixed](crate::Width::Fixed) on each of those columns, so the width includes the
+    /// padding.
+    /// It prevents a single cell with long content from dominating the layout, without having
+    /// to constrain each column on its own. Columns that are added later on are limited as well.
+    ///
+    /// ```
+    /// use super_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_default_max_cell_width(12)
+    ///     .set_header(vec!["Id", "Message"])
+    ///     .add_row(vec!["1", "Connection refused by peer"]);
+    ///
+    /// let expected = "
+    /// +----+------------+
+    /// | Id | Message    |
+    /// +=================+
+    /// | 1  | Connection |
+    /// |    | refused by |
+    /// |    | peer       |
+    /// +----+------------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_default_max_cell_width(&mut self, width: u16) -> &mut Self {
+        self.default_max_cell_width = Some(width);
+
+        self
+    }
+
+    /// Get the maximum width of all columns without a constraint.
+    ///
+    /// See [Table::set_default_max_cell_width].
+    pub fn default_max_cell_width(&self) -> Option<u16> {
+        self.default_max_cell_width
+    }
+
     /// This function creates a TableStyle from a given preset string.\
     /// Preset strings can be found in `styling::presets::*`.
     ///
@@ -455,26 +2784,98 @@ impl Table {
     /// The function expects the to-be-drawn characters to be in the same order as in the [TableComponent] enum.
     ///
     /// If the string isn't long enough, the default [ASCII_FULL] style will be used for all remaining components.
+    /// Components that have been added later on, like [TableComponent::RowspanLeftIntersection],
+    /// are derived from older components instead.
+    /// The 22nd and 23rd characters are always ignored, as older presets used them as padding.
+    /// Components after [TableComponent::BottomRightCorner] start at the 24th character.
     ///
     /// If the string is too long, remaining charaacters will be simply ignored.
     pub fn load_preset(&mut self, preset: &str) -> &mut Self {
-        let mut components = TableComponent::iter();
-
-        for character in preset.chars() {
-            if let Some(component) = components.next() {
-                // White spaces mean "don't draw this" in presets
-                // If we want to override the default preset, we need to remove
-                // this component from the HashMap in case we find a whitespace.
-                if character == ' ' {
-                    self.remove_style(component);
-                    continue;
-                }
+        load_preset_into(&mut self.style, preset);
 
-                self.set_style(component, character);
-            } else {
-                break;
-            }
-        }
+        self
+    }
+
+    /// Load a preset for a single [Section] of the table, e.g. a heavy box around the header and
+    /// light lines in the body.
+    ///
+    /// Components, that aren't part of the preset, are taken from the current style of the
+    /// table. Sections without a preset of their own use the style of the table.
+    /// Vertical borders and lines, that are drawn in any section, take up space in all
+    /// sections, so all lines of the table have the same width.
+    ///
+    /// ```
+    /// use super_table::presets::{UTF8_BORDERS_ONLY, UTF8_FULL};
+    /// use super_table::{Section, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .load_preset(UTF8_BORDERS_ONLY)
+    ///     .load_preset_for(Section::Header, UTF8_FULL)
+    ///     .set_header(vec!["Host", "State"])
+    ///     .add_row(vec!["db-1", "up"])
+    ///     .add_row(vec!["db-2", "down"]);
+    ///
+    /// let expected = "
+    /// ┌──────┬───────┐
+    /// │ Host ┆ State │
+    /// ╞══════╪═══════╡
+    /// │ db-1   up    │
+    /// │ db-2   down  │
+    /// └──────────────┘";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn load_preset_for(&mut self, section: Section, preset: &str) -> &mut Self {
+        let style = self
+            .section_styles
+            .entry(section)
+            .or_insert_with(|| self.style.clone());
+        load_preset_into(style, preset);
+
+        self
+    }
+
+    /// Define the char that will be used to draw a specific component in a single [Section].
+    ///
+    /// If the section doesn't have a style of its own yet, it starts with the current style
+    /// of the table. See [Table::set_style] for details.
+    pub fn set_style_for(
+        &mut self,
+        section: Section,
+        component: TableComponent,
+        character: char,
+    ) -> &mut Self {
+        self.section_styles
+            .entry(section)
+            .or_insert_with(|| self.style.clone())
+            .insert(component, character);
+
+        self
+    }
+
+    /// Remove the style for a specific component in a single [Section].
+    ///
+    /// Returns the char that has been used for this component in this section.
+    /// See [Table::remove_style] for details.
+    pub fn remove_style_for(
+        &mut self,
+        section: Section,
+        component: TableComponent,
+    ) -> Option<char> {
+        self.section_styles
+            .entry(section)
+            .or_insert_with(|| self.style.clone())
+            .remove(&component)
+    }
+
+    /// Get the char that's currently used for drawing a component in a single [Section].
+    pub fn style_for(&self, section: Section, component: TableComponent) -> Option<char> {
+        self.section_style(section).get(&component).copied()
+    }
+
+    /// Use the style of the table for a [Section] again.
+    pub fn clear_style_for(&mut self, section: Section) -> &mut Self {
+        self.section_styles.remove(&section);
 
         self
     }
@@ -493,17 +2894,29 @@ impl Table {
     /// assert_eq!(UTF8_FULL, table.current_style_as_preset())
     /// ```
     pub fn current_style_as_preset(&mut self) -> String {
-        let components = TableComponent::iter();
-        let mut preset_string = String::new();
-
-        for component in components {
-            match self.style(component) {
-                None => preset_string.push(' '),
-                Some(character) => preset_string.push(character),
-            }
-        }
-
-        preset_string
+        let positions: Vec<Option<TableComponent>> = TableComponent::preset_positions().collect();
+
+        // Trailing components, that are derived from older ones, don't have to be part of the
+        // preset, neither does the padding in front of them.
+        let derived = positions
+            .iter()
+            .rev()
+            .take_while(|position| match position {
+                Some(component) => {
+                    component.fallback().is_some() && self.style(*component).is_none()
+                }
+                None => true,
+            })
+            .count();
+
+        positions[..positions.len() - derived]
+            .iter()
+            .map(|position| {
+                position
+                    .and_then(|component| self.style(component))
+                    .unwrap_or(' ')
+            })
+            .collect()
     }
 
     /// Modify a preset with a modifier string from [modifiers](crate::style::modifiers).
@@ -520,18 +2933,10 @@ impl Table {
     /// table.apply_modifier(UTF8_ROUND_CORNERS);
     /// ```
     pub fn apply_modifier(&mut self, modifier: &str) -> &mut Self {
-        let mut components = TableComponent::iter();
-
-        for character in modifier.chars() {
+        for (character, position) in modifier.chars().zip(TableComponent::preset_positions()) {
             // Skip spaces while applying modifiers.
-            if character == ' ' {
-                components.next();
-                continue;
-            }
-            if let Some(component) = components.next() {
+            if let Some(component) = position.filter(|_| character != ' ') {
                 self.set_style(component, character);
-            } else {
-                break;
             }
         }
 
@@ -588,13 +2993,121 @@ impl Table {
         self.style.get(&component).copied()
     }
 
+    /// Use a custom [BorderRenderer] to draw the horizontal lines of this table.
+    ///
+    /// This includes the top and bottom border, as well as the lines below the header and
+    /// between rows. Check the [BorderRenderer] docs for an example.
+    pub fn set_border_renderer<R: BorderRenderer + 'static>(&mut self, renderer: R) -> &mut Self {
+        self.border_renderer = Some(Arc::new(renderer));
+
+        self
+    }
+
+    /// Remove the custom border renderer and draw all lines by default again.
+    pub fn remove_border_renderer(&mut self) -> &mut Self {
+        self.border_renderer = None;
+
+        self
+    }
+
+    /// Set a callback, which is called for [events](RenderEvent) that happen while this table is
+    /// rendered.
+    ///
+    /// This is useful to report the progress of rendering huge tables or to debug layout issues.
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    ///
+    /// use super_table::Table;
+    /// use super_table::render::RenderEvent;
+    ///
+    /// let drawn_rows = Arc::new(AtomicUsize::new(0));
+    /// let counter = drawn_rows.clone();
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_render_hook(move |event| {
+    ///         if let RenderEvent::RowStart { .. } = event {
+    ///             counter.fetch_add(1, Ordering::Relaxed);
+    ///         }
+    ///     })
+    ///     .add_row(vec!["One", "Two"])
+    ///     .add_row(vec!["Three", "Four"]);
+    ///
+    /// let _ = table.to_string();
+    /// assert_eq!(drawn_rows.load(Ordering::Relaxed), 2);
+    /// ```
+    pub fn set_render_hook<F>(&mut self, hook: F) -> &mut Self
+    where
+        F: Fn(RenderEvent<'_>) + Send + Sync + 'static,
+    {
+        self.render_hook = Some(RenderHook(Arc::new(hook)));
+
+        self
+    }
+
+    /// Remove the render hook of this table.
+    pub fn remove_render_hook(&mut self) -> &mut Self {
+        self.render_hook = None;
+
+        self
+    }
+
+    /// Pass an event to the render hook, if there's one.
+    pub(crate) fn emit(&self, event: RenderEvent<'_>) {
+        if let Some(hook) = &self.render_hook {
+            (hook.0)(event);
+        }
+    }
+
     /// Remove the style for a specific component of the table.\
     /// By default, a space will be used as a placeholder instead.\
     /// Though, if for instance all components of the left border are removed, the left border won't be displayed.
-    pub fn remove_style(&mut self, component: TableComponent) -> &mut Self {
-        self.style.remove(&component);
+    ///
+    /// Returns the char that has been used for this component, so it can be restored later on.
+    ///
+    /// ```
+    /// use super_table::Table;
+    /// use super_table::TableComponent::*;
+    ///
+    /// let mut table = Table::new();
+    /// // Temporarily drop the lines between rows.
+    /// let previous = table.remove_style(HorizontalLines);
+    /// assert_eq!(previous, Some('-'));
+    /// assert_eq!(table.style(HorizontalLines), None);
+    ///
+    /// if let Some(character) = previous {
+    ///     table.set_style(HorizontalLines, character);
+    /// }
+    /// assert_eq!(table.style(HorizontalLines), Some('-'));
+    /// ```
+    pub fn remove_style(&mut self, component: TableComponent) -> Option<char> {
+        self.style.remove(&component)
+    }
 
-        self
+    /// Get a snapshot of the chars that are currently used for drawing the components of this
+    /// table.
+    ///
+    /// Components without a char aren't part of the map. They either aren't displayed or are
+    /// [derived](TableComponent) from older components.
+    ///
+    /// ```
+    /// use super_table::Table;
+    /// use super_table::TableComponent::*;
+    ///
+    /// let mut table = Table::new();
+    /// let snapshot = table.current_style();
+    /// assert_eq!(snapshot.get(&TopLeftCorner), Some(&'+'));
+    ///
+    /// table.set_style(TopLeftCorner, '*');
+    /// assert_ne!(snapshot, table.current_style());
+    /// ```
+    pub fn current_style(&self) -> BTreeMap<TableComponent, char> {
+        self.style
+            .iter()
+            .map(|(component, character)| (*component, *character))
+            .collect()
     }
 
     /// Get a reference to a specific column.
@@ -644,6 +3157,11 @@ impl Table {
     /// The iterator returns a nested `Option<Option<Cell>>`, since there might be
     /// rows that are missing this specific Cell.
     ///
+    /// Columns are counted like they're displayed: A cell that spans multiple columns is only
+    /// returned for the column it starts in. Positions that are covered by a colspan or by a
+    /// rowspan from a previous row are `None`.
+    /// This makes per-column analysis like statistics or aggregates easy to write.
+    ///
     /// ```
     /// use super_table::Table;
     /// let mut table = Table::new();
@@ -658,11 +3176,25 @@ impl Table {
     /// assert_eq!(cell_iter.next().unwrap().unwrap().content(), "Fifth");
     /// assert!(cell_iter.next().is_none());
     /// ```
+    ///
+    /// ```
+    /// use super_table::{Cell, Table};
+    /// let mut table = Table::new();
+    /// table.add_row(vec![Cell::new("Wide").set_colspan(2), Cell::new("Last")]);
+    /// table.add_row(vec!["One", "Two", "Three"]);
+    ///
+    /// let contents: Vec<Option<String>> = table
+    ///     .column_cells_iter(1)
+    ///     .map(|cell| cell.map(|cell| cell.content()))
+    ///     .collect();
+    /// assert_eq!(contents, vec![None, Some("Two".to_string())]);
+    /// ```
     pub fn column_cells_iter(&self, column_index: usize) -> ColumnCellIter<'_> {
         ColumnCellIter {
             rows: &self.rows,
             column_index,
             row_index: 0,
+            span_tracker: SpanTracker::new(),
         }
     }
 
@@ -671,6 +3203,8 @@ impl Table {
     /// The iterator returns a nested `Option<Option<Cell>>`, since there might be
     /// rows that are missing this specific Cell.
     ///
+    /// Spans are handled like in [Table::column_cells_iter].
+    ///
     /// ```
     /// use super_table::Table;
     /// let mut table = Table::new();
@@ -697,6 +3231,7 @@ impl Table {
             rows: &self.rows,
             column_index,
             row_index: 0,
+            span_tracker: SpanTracker::new(),
         }
     }
 
@@ -705,11 +3240,69 @@ impl Table {
         self.rows.get(index)
     }
 
+    /// Find the row of a cell and the index of the column it starts in.
+    ///
+    /// Cells are identified by their address, so only references into this table are found.
+    pub(crate) fn locate_cell(&self, cell: &Cell) -> Option<(&Row, usize)> {
+        let mut span_tracker = SpanTracker::new();
+        let rows = self.header.iter().chain(self.rows.iter());
+        for (row_index, row) in rows.enumerate() {
+            let mut col_index = 0;
+            for candidate in row.cells.iter() {
+                while span_tracker.is_col_occupied_by_rowspan(row_index, col_index) {
+                    col_index += 1;
+                }
+                if std::ptr::eq(candidate, cell) {
+                    return Some((row, col_index));
+                }
+                if candidate.rowspan() > 1 {
+                    span_tracker.register_rowspan(
+                        row_index,
+                        col_index,
+                        candidate.rowspan(),
+                        candidate.colspan(),
+                        None,
+                    );
+                }
+                col_index += usize::from(candidate.colspan());
+            }
+            span_tracker.advance_row(row_index + 1);
+        }
+
+        None
+    }
+
     /// Mutable reference to a specific row
     pub fn row_mut(&mut self, index: usize) -> Option<&mut Row> {
         self.rows.get_mut(index)
     }
 
+    /// Mutable reference to the row with a specific [identifier](Row::set_id).
+    ///
+    /// If multiple rows have the same identifier, the first one is returned.
+    /// The header isn't considered.
+    ///
+    /// ```
+    /// use super_table::{Cell, Row, Table};
+    ///
+    /// let mut table = Table::new();
+    /// for (id, name) in [("a", "nginx"), ("b", "postgres")] {
+    ///     let mut row = Row::from(vec![name, "running"]);
+    ///     row.set_id(id);
+    ///     table.add_row(row);
+    /// }
+    ///
+    /// let row = table.row_by_id("b").unwrap();
+    /// row.add_cell(Cell::new("restarted"));
+    /// assert_eq!(table.row(1).unwrap().cell_count(), 3);
+    /// assert!(table.row_by_id("c").is_none());
+    /// ```
+    pub fn row_by_id(&mut self, id: &str) -> Option<&mut Row> {
+        self.rows
+            .iter_mut()
+            .find(|row| row.id.as_deref() == Some(id))
+    }
+
     /// Iterator over all rows
     pub fn row_iter(&self) -> Iter<'_, Row> {
         self.rows.iter()
@@ -765,15 +3358,20 @@ impl Table {
         // Helper function to update max widths for a row, accounting for colspan and rowspan
         fn set_max_content_widths(
             max_widths: &mut [u16],
+            columns: &[Column],
+            ambiguous_width: AmbiguousWidth,
             row: &Row,
             row_index: usize,
+            header: bool,
             active_rowspans: &mut HashMap<(usize, usize), (u16, u16)>,
         ) {
-            // Get the max width for each cell of the row
-            let row_max_widths = row.max_content_widths();
+            // Annotations wrap within the width of the table instead of widening its columns.
+            if row.annotation {
+                return;
+            }
             let mut col_index = 0;
 
-            for (cell_index, width) in row_max_widths.iter().enumerate() {
+            for cell in row.cells.iter() {
                 // Skip column positions that are occupied by rowspan from above
                 while col_index < max_widths.len()
                     && is_col_occupied_by_rowspan(active_rowspans, row_index, col_index)
@@ -785,10 +3383,11 @@ impl Table {
                     break;
                 }
 
-                let cell = &row.cells[cell_index];
                 let colspan = cell.colspan() as usize;
                 let rowspan = cell.rowspan();
-                let mut cell_width = (*width).try_into().unwrap_or(u16::MAX);
+                // Get the max width of the displayed content of this cell
+                let width = display_width(cell, columns.get(col_index), header, ambiguous_width);
+                let mut cell_width = width.try_into().unwrap_or(u16::MAX);
                 // A column's content is at least 1 char wide.
                 cell_width = std::cmp::max(1, cell_width);
 
@@ -871,7 +3470,15 @@ impl Table {
 
         // Process header if it exists
         if let Some(header) = &self.header {
-            set_max_content_widths(&mut max_widths, header, 0, &mut active_rowspans);
+            set_max_content_widths(
+                &mut max_widths,
+                &self.columns,
+                self.ambiguous_width,
+                header,
+                0,
+                true,
+                &mut active_rowspans,
+            );
         }
 
         // Iterate through all rows of the table
@@ -881,7 +3488,15 @@ impl Table {
             } else {
                 row_idx
             };
-            set_max_content_widths(&mut max_widths, row, actual_row_index, &mut active_rowspans);
+            set_max_content_widths(
+                &mut max_widths,
+                &self.columns,
+                self.ambiguous_width,
+                row,
+                actual_row_index,
+                false,
+                &mut active_rowspans,
+            );
             // Advance rowspans after processing this row
             // First decrement remaining_rows for all active spans that have been displayed
             for ((start_row, _), (remaining_rows, _)) in active_rowspans.iter_mut() {
@@ -898,12 +3513,16 @@ impl Table {
         // We compare total widths (content + padding), not just content widths
         active_rowspans.clear();
         if let Some(header) = &self.header {
-            let row_max_widths = header.max_content_widths();
             let mut col_index = 0;
-            for (cell_index, width) in row_max_widths.iter().enumerate() {
-                let cell = &header.cells[cell_index];
+            for cell in header.cells.iter() {
                 let colspan = cell.colspan() as usize;
-                let cell_width = (*width).try_into().unwrap_or(u16::MAX);
+                let width = display_width(
+                    cell,
+                    self.columns.get(col_index),
+                    true,
+                    self.ambiguous_width,
+                );
+                let cell_width = width.try_into().unwrap_or(u16::MAX);
                 let cell_width = std::cmp::max(1, cell_width);
 
                 if colspan > 1 {
@@ -958,13 +3577,17 @@ impl Table {
             }
         }
 
-        for row in &self.rows {
-            let row_max_widths = row.max_content_widths();
+        for row in self.rows.iter().filter(|row| !row.annotation) {
             let mut col_index = 0;
-            for (cell_index, width) in row_max_widths.iter().enumerate() {
-                let cell = &row.cells[cell_index];
+            for cell in row.cells.iter() {
                 let colspan = cell.colspan() as usize;
-                let cell_width = (*width).try_into().unwrap_or(u16::MAX);
+                let width = display_width(
+                    cell,
+                    self.columns.get(col_index),
+                    false,
+                    self.ambiguous_width,
+                );
+                let cell_width = width.try_into().unwrap_or(u16::MAX);
                 let cell_width = std::cmp::max(1, cell_width);
 
                 if colspan > 1 {
@@ -1007,6 +3630,108 @@ impl Table {
         max_widths
     }
 
+    /// Scan the data rows of the table and infer the [ColumnType] of each column.
+    ///
+    /// Afterwards, some sensible defaults are applied to each column:
+    /// - Numeric columns are right-aligned.
+    /// - Boolean columns are centered.
+    /// - Dates are displayed in a uniform format.
+    ///   If no value has a time of day, only the date (`YYYY-MM-DD`) is displayed.
+    ///   Otherwise, all values are displayed as RFC 3339 timestamps.
+    /// - The [overflow](Column::set_overflow) of numbers, booleans and dates is
+    ///   [Overflow::NoWrap], so values are never split.
+    ///   Text columns, whose values are single words with digits or separators like `-`, `_`,
+    ///   `/`, `.` or `:`, contain identifiers like hashes or paths and are
+    ///   [truncated in the middle](Overflow::TruncateMiddle).
+    ///   All other text is [wrapped](Overflow::Wrap).
+    ///
+    /// Alignments and overflows that have already been set on a column won't be touched.
+    /// The header isn't considered during inference.
+    ///
+    /// The returned vector contains the inferred type of each column and is also available via
+    /// [Column::column_type] afterwards.
+    ///
+    /// ```
+    /// use super_table::{ColumnType, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table.set_header(vec!["Name", "Amount"]);
+    /// table.add_row(vec!["Apples", "12"]);
+    /// table.add_row(vec!["Pears", "3.5"]);
+    ///
+    /// let report = table.infer_column_types();
+    /// assert_eq!(report, vec![ColumnType::Text, ColumnType::Float]);
+    /// ```
+    pub fn infer_column_types(&mut self) -> Vec<ColumnType> {
+        self.discover_columns();
+
+        let mut report = Vec::with_capacity(self.columns.len());
+        for column_index in 0..self.columns.len() {
+            let values: Vec<CellValue> = self
+                .column_cells_iter(column_index)
+                .flatten()
+                .map(Cell::typed_value)
+                .collect();
+            let column_type = ColumnType::from_values(&values);
+
+            if column_type == ColumnType::DateTime {
+                self.format_date_column(column_index);
+            }
+
+            let overflow = match column_type {
+                ColumnType::Integer
+                | ColumnType::Float
+                | ColumnType::Boolean
+                | ColumnType::DateTime => Overflow::NoWrap,
+                ColumnType::Text if is_identifier_column(&values) => Overflow::TruncateMiddle,
+                ColumnType::Text | ColumnType::Empty => Overflow::Wrap,
+            };
+
+            let column = &mut self.columns[column_index];
+            column.column_type = Some(column_type);
+            column.overflow.get_or_insert(overflow);
+            if column.cell_alignment.is_none() {
+                match column_type {
+                    ColumnType::Integer | ColumnType::Float => {
+                        column.set_cell_alignment(CellAlignment::Right)
+                    }
+                    ColumnType::Boolean => column.set_cell_alignment(CellAlignment::Center),
+                    _ => {}
+                }
+            }
+
+            report.push(column_type);
+        }
+
+        report
+    }
+
+    /// Display all dates of a column in a uniform format.
+    /// The parsed values are attached to the cells, as their content is replaced.
+    fn format_date_column(&mut self, column_index: usize) {
+        let dates: Vec<(usize, usize, _)> = cell_positions(self, column_index)
+            .into_iter()
+            .filter_map(|(row_index, cell_index)| {
+                match self.rows[row_index].cells[cell_index].typed_value() {
+                    CellValue::DateTime(time) => Some((row_index, cell_index, time)),
+                    _ => None,
+                }
+            })
+            .collect();
+
+        let date_only = dates.iter().all(|(_, _, time)| is_midnight(*time));
+        for (row_index, cell_index, time) in dates {
+            let cell = &mut self.rows[row_index].cells[cell_index];
+            let value = CellValue::DateTime(time);
+            cell.content = if date_only {
+                vec![format_date(time)]
+            } else {
+                vec![value.to_display_string()]
+            };
+            cell.value = Some(value);
+        }
+    }
+
     pub(crate) fn style_or_default(&self, component: TableComponent) -> String {
         match self.style.get(&component) {
             None => " ".to_string(),
@@ -1014,6 +3739,44 @@ impl Table {
         }
     }
 
+    /// Like [Table::style_or_default], but use the fallback of a component, if it isn't set.
+    pub(crate) fn style_or_fallback(&self, component: TableComponent) -> String {
+        match (self.style.get(&component), component.fallback()) {
+            (Some(character), _) => character.to_string(),
+            (None, Some(fallback)) => self.style_or_default(fallback),
+            (None, None) => " ".to_string(),
+        }
+    }
+
+    /// The style of a section, which is the style of the table, unless the section has its own.
+    pub(crate) fn section_style(&self, section: Section) -> &HashMap<TableComponent, char> {
+        self.section_styles.get(&section).unwrap_or(&self.style)
+    }
+
+    /// The index of the first row, including the header, of the trailing rows that only consist
+    /// of footer cells.
+    pub(crate) fn footer_start(&self) -> usize {
+        let is_footer = |row: &Row| {
+            !row.cells.is_empty()
+                && row
+                    .cells
+                    .iter()
+                    .all(|cell| cell.role == Some(CellRole::Footer))
+        };
+        let footer_rows = self
+            .rows
+            .iter()
+            .rev()
+            .take_while(|row| is_footer(row))
+            .count();
+
+        usize::from(self.header.is_some()) + self.rows.len() - footer_rows
+    }
+
+    pub(crate) fn component_char(&self, component: TableComponent) -> Option<char> {
+        self.style.get(&component).copied()
+    }
+
     pub(crate) fn style_exists(&self, component: TableComponent) -> bool {
         self.style.contains_key(&component)
     }
@@ -1039,26 +3802,11 @@ impl Table {
     /// To make sure everything works as expected, just call this function if you're adding cells
     /// to rows that're already added to the table.
     ///
-    /// Accounts for colspan when determining the required number of columns.
+    /// Accounts for colspan and rowspan when determining the required number of columns.
     pub fn discover_columns(&mut self) {
-        // Check header if it exists
-        if let Some(header) = &self.header {
-            let effective_cols = header.effective_column_count();
-            if effective_cols > self.columns.len() {
-                for index in self.columns.len()..effective_cols {
-                    self.columns.push(Column::new(index));
-                }
-            }
-        }
-
-        // Check all rows
-        for row in self.rows.iter() {
-            let effective_cols = row.effective_column_count();
-            if effective_cols > self.columns.len() {
-                for index in self.columns.len()..effective_cols {
-                    self.columns.push(Column::new(index));
-                }
-            }
+        let column_count = span_aware_column_count(self);
+        for index in self.columns.len()..column_count {
+            self.columns.push(Column::new(index));
         }
     }
 }
@@ -1070,59 +3818,187 @@ pub struct ColumnCellIter<'a> {
     rows: &'a [Row],
     column_index: usize,
     row_index: usize,
+    span_tracker: SpanTracker,
 }
 
 impl<'a> Iterator for ColumnCellIter<'a> {
     type Item = Option<&'a Cell>;
     fn next(&mut self) -> Option<Option<&'a Cell>> {
         // Check if there's a next row
-        if let Some(row) = self.rows.get(self.row_index) {
-            self.row_index += 1;
-
-            // Return the cell (if it exists).
-            return Some(row.cells.get(self.column_index));
-        }
-
-        None
+        let row = self.rows.get(self.row_index)?;
+        let cell = cell_at_column(
+            row,
+            self.row_index,
+            self.column_index,
+            &mut self.span_tracker,
+        );
+        self.row_index += 1;
+        self.span_tracker.advance_row(self.row_index);
+
+        Some(cell)
     }
 }
 
 /// An iterator over cells of a specific column.
 /// A dedicated struct is necessary, as data is usually handled by rows and thereby stored in
-/// `Table::rows`. This type is returned by [Table::column_cells_iter].
+/// `Table::rows`. This type is returned by [Table::column_cells_with_header_iter].
 pub struct ColumnCellsWithHeaderIter<'a> {
     header_checked: bool,
     header: &'a Option<Row>,
     rows: &'a [Row],
     column_index: usize,
     row_index: usize,
+    span_tracker: SpanTracker,
 }
 
 impl<'a> Iterator for ColumnCellsWithHeaderIter<'a> {
     type Item = Option<&'a Cell>;
     fn next(&mut self) -> Option<Option<&'a Cell>> {
-        // Get the header as the first cell
+        // Get the header as the first cell.
+        // Rowspans of the header don't reach into the rows, so the header is tracked separately.
         if !self.header_checked {
             self.header_checked = true;
 
             return match self.header {
-                Some(header) => {
-                    // Return the cell (if it exists).
-                    Some(header.cells.get(self.column_index))
-                }
+                Some(header) => Some(cell_at_column(
+                    header,
+                    0,
+                    self.column_index,
+                    &mut SpanTracker::new(),
+                )),
                 None => Some(None),
             };
         }
 
         // Check if there's a next row
-        if let Some(row) = self.rows.get(self.row_index) {
-            self.row_index += 1;
+        let row = self.rows.get(self.row_index)?;
+        let cell = cell_at_column(
+            row,
+            self.row_index,
+            self.column_index,
+            &mut self.span_tracker,
+        );
+        self.row_index += 1;
+        self.span_tracker.advance_row(self.row_index);
+
+        Some(cell)
+    }
+}
+
+/// Collect the keys of all records, ordered as specified.
+pub(crate) fn record_keys(records: &[&[(String, Cell)]], order: KeyOrder) -> Vec<String> {
+    let mut keys: Vec<String> = Vec::new();
+    for record in records {
+        for (key, _) in record.iter() {
+            if !keys.contains(key) {
+                keys.push(key.clone());
+            }
+        }
+    }
+    if order == KeyOrder::Sorted {
+        keys.sort();
+    }
+
+    keys
+}
+
+/// Take the cells of a record in the order of the given keys.
+/// Keys that are missing in the record become null values.
+pub(crate) fn record_cells(mut record: Vec<(String, Cell)>, keys: &[String]) -> Vec<Cell> {
+    keys.iter()
+        .map(
+            |key| match record.iter().position(|(record_key, _)| record_key == key) {
+                Some(index) => record.swap_remove(index).1,
+                None => Cell::from(CellValue::Null),
+            },
+        )
+        .collect()
+}
 
-            // Return the cell (if it exists).
-            return Some(row.cells.get(self.column_index));
+/// Find the index of the header cell, that starts in a specific column.
+///
+/// If no cell starts in this column, the column after the cell that covers it is returned as
+/// error. For columns behind the last cell, this is the amount of columns of the header.
+fn header_cell_index(header: &Row, column_index: usize) -> Result<usize, usize> {
+    let mut col_index = 0;
+    for (index, cell) in header.cells.iter().enumerate() {
+        if col_index == column_index {
+            return Ok(index);
         }
+        col_index += usize::from(cell.colspan());
+        if col_index > column_index {
+            return Err(col_index);
+        }
+    }
 
-        None
+    Err(col_index)
+}
+
+/// Find the cell of a row, that starts in a specific column.
+///
+/// Returns `None`, if the column is covered by a cell that starts in another column or by a
+/// rowspan from a previous row.
+/// All rowspans of this row are registered with the tracker.
+fn cell_at_column<'a>(
+    row: &'a Row,
+    row_index: usize,
+    column_index: usize,
+    span_tracker: &mut SpanTracker,
+) -> Option<&'a Cell> {
+    let mut found = None;
+    let mut col_index = 0;
+    for cell in row.cells.iter() {
+        // Skip column positions that are occupied by rowspans from previous rows.
+        while span_tracker.is_col_occupied_by_rowspan(row_index, col_index) {
+            col_index += 1;
+        }
+        if col_index == column_index {
+            found = Some(cell);
+        }
+        if cell.rowspan() > 1 {
+            span_tracker.register_rowspan(
+                row_index,
+                col_index,
+                cell.rowspan(),
+                cell.colspan(),
+                None,
+            );
+        }
+        col_index += usize::from(cell.colspan());
+    }
+
+    found
+}
+
+/// Load a preset into the given style. See [Table::load_preset] for details.
+fn load_preset_into(style: &mut HashMap<TableComponent, char>, preset: &str) {
+    let mut positions = TableComponent::preset_positions();
+
+    for character in preset.chars() {
+        let Some(position) = positions.next() else {
+            break;
+        };
+        // The padding of older presets is ignored.
+        let Some(component) = position else {
+            continue;
+        };
+
+        // White spaces mean "don't draw this" in presets
+        // If we want to override the default preset, we need to remove
+        // this component from the HashMap in case we find a whitespace.
+        if character == ' ' {
+            style.remove(&component);
+            continue;
+        }
+
+        style.insert(component, character);
+    }
+
+    // Newer components, that aren't part of the preset, are derived from older components.
+    for component in positions.flatten() {
+        if component.fallback().is_some() {
+            style.remove(&component);
+        }
     }
 }
 
@@ -0,0 +1,42 @@
+use std::any::Any;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+/// Arbitrary data, that's attached to a [Row](crate::Row) or a [Cell](crate::Cell).
+///
+/// The data is shared between all copies of its row or cell.
+/// Two instances are only equal, if they refer to the very same data.
+#[derive(Clone)]
+pub(crate) struct UserData(Arc<dyn Any + Send + Sync>);
+
+impl UserData {
+    pub(crate) fn new<T: Any + Send + Sync>(data: T) -> Self {
+        Self(Arc::new(data))
+    }
+
+    /// Get the data, if it's of type `T`.
+    pub(crate) fn get<T: Any>(&self) -> Option<&T> {
+        self.0.downcast_ref()
+    }
+}
+
+impl fmt::Debug for UserData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("UserData(..)")
+    }
+}
+
+impl PartialEq for UserData {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for UserData {}
+
+impl Hash for UserData {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.0).cast::<()>().hash(state);
+    }
+}
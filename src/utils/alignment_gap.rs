@@ -0,0 +1,37 @@
+use std::borrow::Cow;
+
+use crate::style::CellAlignment;
+use crate::table::Table;
+
+/// Make sure that content is [never flush](Table::set_alignment_gap) against the border on the
+/// side opposite to its alignment.
+///
+/// Columns without padding on that side get one space of padding, which makes them one
+/// character wider. Centered content gets one space on both sides.
+pub(crate) fn apply_alignment_gap(table: &mut Cow<'_, Table>) {
+    if !table.alignment_gap {
+        return;
+    }
+
+    let needs_gap = |padding: (u16, u16), alignment: Option<CellAlignment>| match alignment {
+        None | Some(CellAlignment::Left) => padding.1 == 0,
+        Some(CellAlignment::Right) => padding.0 == 0,
+        Some(CellAlignment::Center) => padding.0 == 0 || padding.1 == 0,
+    };
+    if !table
+        .columns
+        .iter()
+        .any(|column| needs_gap(column.padding, column.cell_alignment))
+    {
+        return;
+    }
+
+    for column in table.to_mut().columns.iter_mut() {
+        let (left, right) = column.padding;
+        column.padding = match column.cell_alignment {
+            None | Some(CellAlignment::Left) => (left, right.max(1)),
+            Some(CellAlignment::Right) => (left.max(1), right),
+            Some(CellAlignment::Center) => (left.max(1), right.max(1)),
+        };
+    }
+}
@@ -1,26 +1,61 @@
-use unicode_width::UnicodeWidthStr;
-
 use super::constraint;
 use super::helper::*;
 use super::{ColumnDisplayInfo, DisplayInfos};
 use crate::style::*;
-use crate::utils::formatting::content_split::split_line;
+use crate::utils::formatting::borders::should_draw_vertical_lines;
+use crate::utils::formatting::content_split::{split_line, str_width};
+use crate::utils::formatting::display::{display_lines, displayed_unit};
 use crate::{Column, Table};
 
+/// The widths a column may occupy, as determined by its content and constraint.
+///
+/// These are computed once for every column in the first pass of the solver.
+/// See [arrange] for more information.
+#[derive(Debug, Clone, Copy)]
+struct ColumnBounds {
+    /// The column must be at least this wide, if there isn't enough space for all columns.
+    ///
+    /// This is either its [LowerBoundary] constraint or the longest word of a cell that must
+    /// not be wrapped. Like constraints, this includes the padding of the column.
+    lower: Option<u16>,
+    /// The column never needs a wider content than this.
+    ///
+    /// This is the width of its content, capped by an [UpperBoundary] constraint.
+    max: u16,
+}
+
 /// Try to find the best fit for a given content and table_width
 ///
+/// The solver works in two passes.
+///
+/// **First pass:** Determine the bounds of every column, whose width hasn't been fixed by its
+/// constraint yet. See [column_bounds] and [ColumnBounds] for more information.
+///
+/// **Second pass:** Distribute the available space between those columns.
+///
 /// 1. Determine the amount of available space after applying fixed columns, padding, and borders.
-/// 2. Now that we know how much space we have to work with, we have to check again for
-///    LowerBoundary constraints. If there are any columns that have a higher LowerBoundary,
-///    we have to fix that column to this size.
-/// 3. Check if there are any columns that require less space than the average
-///    remaining space for the remaining columns. (This includes the MaxWidth constraint).
-/// 4. Take those columns, fix their size and add the surplus in space to the remaining space.
-/// 5. Repeat step 2-3 until no columns with smaller size than average remaining space are left.
-/// 6. At this point, the remaining spaces is equally distributed between all columns.
-///    It get's a little tricky now. Check the documentation of [optimize_space_after_split]
-///    for more information.
-/// 7. Divide the remaining space in relatively equal chunks.
+/// 2. Columns with a lower bound that's larger than the average space are fixed to their lower
+///    bound.\
+///    If the remaining columns have different truncation priorities, columns with a higher
+///    priority get their natural width afterwards. Check [prioritize_columns] for more information.
+/// 3. Columns whose maximum width fits into the average space are fixed to their maximum width.
+///    Their surplus space increases the average space for the remaining columns.
+///    This is repeated until all remaining columns need more than the average.
+///    The result doesn't depend on the order of columns. Check [fix_columns_that_fit] for more
+///    information.
+/// 4. The remaining columns have to be wrapped.
+///    Wrapping may lead to content that needs less than the average space, which is given to
+///    other columns. Check the documentation of [optimize_space_after_split] for more
+///    information.
+/// 5. Divide the remaining space in relatively equal chunks.
+///    When the full width is used, cells that span multiple columns get the space they need
+///    first. Check [use_full_width] for more information.
+/// 6. Widen columns, that are smaller than the longest word of a cell that must not be wrapped.
+///    Check [enforce_no_wrap_widths] for more information.
+///
+/// Visible columns always get a content width of at least one character.
+/// The table only gets wider than its width, if the constraints or words that must not be wrapped
+/// leave no other choice.
 ///
 /// This breaks when:
 ///
@@ -31,10 +66,21 @@ pub fn arrange(
     infos: &mut DisplayInfos,
     table_width: usize,
     max_content_widths: &[u16],
+    spanned_widths: &[SpannedWidth],
+    no_wrap_widths: &[SpannedWidth],
 ) {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::debug_span!("dynamic_arrangement", table_width).entered();
+
     let visible_columns = count_visible_columns(&table.columns);
 
-    // Step 1
+    // First pass
+    let bounds = column_bounds(table, visible_columns, max_content_widths, no_wrap_widths);
+
+    #[cfg(feature = "_debug")]
+    println!("dynamic::arrange: Column bounds: {bounds:#?}");
+
+    // Second pass, Step 1
     // Find out how much space there is left.
     let mut remaining_width: usize =
         available_content_width(table, infos, visible_columns, table_width);
@@ -44,34 +90,33 @@ pub fn arrange(
     println!(
         "dynamic::arrange: Table width: {table_width}, Start remaining width {remaining_width}"
     );
-    #[cfg(feature = "_debug")]
-    println!("dynamic::arrange: Max content widths: {max_content_widths:#?}");
 
     // Step 2.
     //
-    // Iterate through all undecided columns and enforce LowerBoundary constraints, if they're
+    // Iterate through all undecided columns and enforce their lower bounds, if they're
     // bigger than the current average space.
     if remaining_columns > 0 {
-        (remaining_width, remaining_columns) = enforce_lower_boundary_constraints(
+        (remaining_width, remaining_columns) = fix_columns_below_lower_bound(
             table,
             infos,
+            &bounds,
             remaining_width,
             remaining_columns,
-            visible_columns,
         );
     }
 
-    // Step 3-5.
+    // Step 2b
+    // Columns with a higher truncation priority get their natural width first.
+    if remaining_columns > 0 {
+        (remaining_width, remaining_columns) =
+            prioritize_columns(table, infos, &bounds, remaining_width, remaining_columns);
+    }
+
+    // Step 3.
     // Find all columns that require less space than the average.
     // Returns the remaining available width and the amount of remaining columns that need handling
-    let (mut remaining_width, mut remaining_columns) = find_columns_that_fit_into_average(
-        table,
-        infos,
-        remaining_width,
-        remaining_columns,
-        visible_columns,
-        max_content_widths,
-    );
+    let (mut remaining_width, mut remaining_columns) =
+        fix_columns_that_fit(table, infos, &bounds, remaining_width, remaining_columns);
 
     #[cfg(feature = "_debug")]
     {
@@ -79,17 +124,17 @@ pub fn arrange(
         println!("Remaining width {remaining_width}, column {remaining_columns}");
     }
 
-    // Step 6
+    // Step 4
     // All remaining columns should get an equal amount of remaining space.
     // However, we check if we can save some space after the content has been split.
     //
     // We only do this if there are remaining columns.
     if remaining_columns > 0 {
-        // This is where Step 5 happens.
         (remaining_width, remaining_columns) = optimize_space_after_split(
             table,
             &table.columns,
             infos,
+            &bounds,
             remaining_width,
             remaining_columns,
         );
@@ -101,7 +146,7 @@ pub fn arrange(
         println!("dynamic::arrange: Remaining width {remaining_width}, column {remaining_columns}",);
     }
 
-    // Early exit and one branch of Part 7.
+    // Early exit and one branch of Step 5.
     //
     // All columns have been successfully assigned a width.
     // However, in case the user specified that the full terminal width should always be fully
@@ -109,14 +154,15 @@ pub fn arrange(
     if remaining_columns == 0 {
         if remaining_width > 0 && matches!(table.arrangement, ContentArrangement::DynamicFullWidth)
         {
-            use_full_width(infos, remaining_width);
+            use_full_width(infos, remaining_width, spanned_widths);
             #[cfg(feature = "_debug")]
             println!("dynamic::arrange: After full width: {infos:#?}");
         }
+        enforce_no_wrap_widths(table, infos, no_wrap_widths);
         return;
     }
 
-    // Step 7. Equally distribute the remaining_width to all remaining columns
+    // Step 5. Equally distribute the remaining_width to all remaining columns
     // If we have less than one space per remaining column, give at least one space per column
     if remaining_width < remaining_columns {
         remaining_width = remaining_columns;
@@ -126,6 +172,50 @@ pub fn arrange(
 
     #[cfg(feature = "_debug")]
     println!("dynamic::arrange: After distribute: {infos:#?}");
+
+    // Step 6
+    enforce_no_wrap_widths(table, infos, no_wrap_widths);
+}
+
+/// First pass
+///
+/// Determine the bounds of each column from its content and constraint.
+/// The bounds of columns, that have already been fixed by their constraint, are never used.
+fn column_bounds(
+    table: &Table,
+    visible_columns: usize,
+    max_content_widths: &[u16],
+    no_wrap_widths: &[SpannedWidth],
+) -> Vec<ColumnBounds> {
+    let no_wrap_minimums = no_wrap_minimums(table, no_wrap_widths);
+
+    table
+        .columns
+        .iter()
+        .map(|column| {
+            let content_width = max_content_widths[column.index];
+
+            let lower =
+                if let Some(lower) = constraint::min(table, &column.constraint, visible_columns) {
+                    Some(lower)
+                } else if column.constraint.is_none() && no_wrap_minimums[column.index] > 0 {
+                    // Lower bounds include padding, just like constraints.
+                    Some(no_wrap_minimums[column.index].saturating_add(column.padding_width()))
+                } else {
+                    None
+                };
+
+            // Max/Min constraints always include padding!
+            let max = match constraint::max(table, &column.constraint, visible_columns) {
+                Some(upper) if content_width.saturating_add(column.padding_width()) >= upper => {
+                    absolute_width_with_padding(column, upper)
+                }
+                _ => content_width,
+            };
+
+            ColumnBounds { lower, max }
+        })
+        .collect()
 }
 
 /// Step 1
@@ -164,42 +254,30 @@ fn available_content_width(
         if info.is_hidden {
             continue;
         }
-        width = width.saturating_sub(info.width().into());
+        width = width.saturating_sub(info.width());
     }
 
     width
 }
 
-/// Step 2-4
-/// This function is part of the column width calculation process.
-/// It checks if there are columns that take less space than there's currently available in average
-/// for each column.
-///
-/// The algorithm is a while loop with a nested for loop.
-/// 1. We iterate over all columns and check if there are columns that take less space.
-/// 2. If we find one or more such columns, we fix their width and add the surplus space to the
-///    emaining space. Due to this step, the average space per column increased. Now some other
-///    column might be fixed in width as well.
-/// 3. Do step 1 and 2, as long as there are columns left and as long as we find columns
-///    that take up less space than the current remaining average.
-///
-/// Parameters:
-/// - `table_width`: The absolute amount of available space.
-/// - `remaining_width`: This is the amount of space that isn't yet reserved by any other column.
-///   We need this to determine the average space each column has left.
-///   Any columns that needs less than this average receives a fixed width.
-///   The leftover space can then be used for the other columns.
-/// - `visible_columns`: All visible columns that should be displayed.
+/// Step 3
+///
+/// Fix the width of all columns, whose maximum width fits into the average space that's left
+/// for each remaining column.
+///
+/// Fixing such a column never decreases the average space of the other columns, as the column
+/// needs at most the average. So once no column fits anymore, all remaining columns need more
+/// space than the final average. This is the same result, no matter in which order columns are
+/// checked, which makes the distribution of space fair.
 ///
 /// Returns:
 /// `(remaining_width: usize, remaining_columns: u16)`
-fn find_columns_that_fit_into_average(
+fn fix_columns_that_fit(
     table: &Table,
     infos: &mut DisplayInfos,
+    bounds: &[ColumnBounds],
     mut remaining_width: usize,
     mut remaining_columns: usize,
-    visible_columns: usize,
-    max_content_widths: &[u16],
 ) -> (usize, usize) {
     let mut found_smaller = true;
     while found_smaller {
@@ -211,6 +289,13 @@ fn find_columns_that_fit_into_average(
         }
 
         let mut average_space = remaining_width / remaining_columns;
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            remaining_width,
+            remaining_columns,
+            average_space,
+            "Solving widths of columns that fit into the average space"
+        );
         // We have no space left, the terminal is either tiny or the other columns are huge.
         if average_space == 0 {
             break;
@@ -223,89 +308,49 @@ fn find_columns_that_fit_into_average(
                 continue;
             }
 
-            let max_column_width = max_content_widths[column.index];
-
-            // The column has a MaxWidth Constraint.
-            // we can fix the column to this max_width and mark it as checked if these
-            // two conditions are met:
-            // - The average remaining space is bigger then the MaxWidth constraint.
-            // - The actual max content of the column is bigger than the MaxWidth constraint.
-            if let Some(max_width) = constraint::max(table, &column.constraint, visible_columns) {
-                // Max/Min constraints always include padding!
-                let average_space_with_padding =
-                    average_space + usize::from(column.padding_width());
-
-                let width_with_padding = max_column_width + column.padding_width();
-                // Check that both conditions mentioned above are met.
-                if usize::from(max_width) <= average_space_with_padding
-                    && width_with_padding >= max_width
-                {
-                    // Save the calculated info, this column has been handled.
-                    let width = absolute_width_with_padding(column, max_width);
-                    let info = ColumnDisplayInfo::new(column, width);
-                    infos.insert(column.index, info);
-
-                    #[cfg(feature = "_debug")]
-                    println!(
-                        "dynamic::find_columns_that_fit_into_average: Fixed column {} via MaxWidth constraint with size {}, as it's bigger than average {}",
-                        column.index, width, average_space
-                    );
-
-                    // Continue with new recalculated width
-                    remaining_width = remaining_width.saturating_sub(width.into());
-                    remaining_columns -= 1;
-
-                    if remaining_columns == 0 {
-                        break;
-                    }
-                    average_space = remaining_width / remaining_columns;
-                    found_smaller = true;
-                    continue;
-                }
+            let max_width = bounds[column.index].max;
+            if usize::from(max_width) > average_space {
+                continue;
             }
 
-            // The column has a smaller or equal max_content_width than the average space.
-            // Fix the width to max_content_width and mark it as checked
-            if usize::from(max_column_width) <= average_space {
-                let info = ColumnDisplayInfo::new(column, max_column_width);
-                infos.insert(column.index, info);
+            let info = ColumnDisplayInfo::new(column, max_width);
+            infos.insert(column.index, info);
 
-                #[cfg(feature = "_debug")]
-                println!(
-                    "dynamic::find_columns_that_fit_into_average: Fixed column {} with size {}, as it's smaller than average {}",
-                    column.index, max_column_width, average_space
-                );
+            #[cfg(feature = "_debug")]
+            println!(
+                "dynamic::fix_columns_that_fit: Fixed column {} with size {}, as it's smaller than average {}",
+                column.index, max_width, average_space
+            );
 
-                // Continue with new recalculated width
-                remaining_width = remaining_width.saturating_sub(max_column_width.into());
-                remaining_columns -= 1;
-                if remaining_columns == 0 {
-                    break;
-                }
-                average_space = remaining_width / remaining_columns;
-                found_smaller = true;
+            // Continue with new recalculated width
+            remaining_width = remaining_width.saturating_sub(max_width.into());
+            remaining_columns -= 1;
+            if remaining_columns == 0 {
+                break;
             }
+            average_space = remaining_width / remaining_columns;
+            found_smaller = true;
         }
     }
 
     (remaining_width, remaining_columns)
 }
 
-/// Step 5
+/// Step 2
 ///
-/// Determine, whether there are any columns that are allowed to occupy more width than the current
-/// `average_space` via a [LowerBoundary] constraint.
+/// Determine, whether there are any columns that have to occupy more width than the current
+/// `average_space` due to their lower bound.
 ///
-/// These columns will then get fixed to the width specified in the [LowerBoundary] constraint.
+/// These columns will then get fixed to their lower bound.
 ///
 /// I.e. if a column has to have at least 10 characters, but the average width left for a column is
 /// only 6, we fix the column to this 10 character minimum!
-fn enforce_lower_boundary_constraints(
+fn fix_columns_below_lower_bound(
     table: &Table,
     infos: &mut DisplayInfos,
+    bounds: &[ColumnBounds],
     mut remaining_width: usize,
     mut remaining_columns: usize,
-    visible_columns: usize,
 ) -> (usize, usize) {
     let mut average_space = remaining_width / remaining_columns;
     for column in table.columns.iter() {
@@ -315,28 +360,24 @@ fn enforce_lower_boundary_constraints(
             continue;
         }
 
-        // Check whether the column has a LowerBoundary constraint.
-        let min_width =
-            if let Some(min_width) = constraint::min(table, &column.constraint, visible_columns) {
-                min_width
-            } else {
-                continue;
-            };
+        let Some(lower) = bounds[column.index].lower else {
+            continue;
+        };
 
-        // Only proceed if the average spaces is smaller than the specified lower boundary.
-        if average_space >= min_width.into() {
+        // Only proceed if the average spaces is smaller than the lower bound.
+        if average_space >= lower.into() {
             continue;
         }
 
-        // This column would get smaller than the specified lower boundary.
+        // This column would get smaller than its lower bound.
         // Fix its width!!!
-        let width = absolute_width_with_padding(column, min_width);
+        let width = absolute_width_with_padding(column, lower);
         let info = ColumnDisplayInfo::new(column, width);
         infos.insert(column.index, info);
 
         #[cfg(feature = "_debug")]
         println!(
-            "dynamic::enforce_lower_boundary_constraints: Fixed column {} to min constraint width {}",
+            "dynamic::fix_columns_below_lower_bound: Fixed column {} to its lower bound {}",
             column.index, width
         );
 
@@ -347,13 +388,86 @@ fn enforce_lower_boundary_constraints(
             break;
         }
         average_space = remaining_width / remaining_columns;
-        continue;
     }
 
     (remaining_width, remaining_columns)
 }
 
-/// Step 5.
+/// Part of the first pass.
+///
+/// Return the minimum content width of each column, that's required by cells whose words must not
+/// be wrapped.
+///
+/// A cell that spans multiple columns requires an equal share of each of them, so the space is
+/// reserved before it's given to other columns.
+fn no_wrap_minimums(table: &Table, no_wrap_widths: &[SpannedWidth]) -> Vec<u16> {
+    let mut minimums = vec![0; table.columns.len()];
+    for span in no_wrap_widths {
+        let columns: Vec<usize> = (span.start..span.start + span.colspan)
+            .filter(|index| table.columns.get(*index).is_some_and(|c| !c.is_hidden()))
+            .collect();
+        if columns.is_empty() {
+            continue;
+        }
+
+        // A spanning cell also occupies the padding and border between its columns.
+        let width = span.width.saturating_sub((columns.len() - 1) * 3);
+        let share = width.div_ceil(columns.len()).try_into().unwrap_or(u16::MAX);
+        for index in columns {
+            minimums[index] = minimums[index].max(share);
+        }
+    }
+
+    minimums
+}
+
+/// Step 6
+///
+/// Columns may still be smaller than the longest word of a cell, that must not be wrapped.
+/// This happens, if the space was distributed equally or the table is simply too narrow.
+///
+/// Such columns are widened, even if the table gets wider than its width.
+/// If the cell spans multiple columns, the missing space is distributed between all of them.
+/// Columns with a constraint keep their width.
+fn enforce_no_wrap_widths(
+    table: &Table,
+    infos: &mut DisplayInfos,
+    no_wrap_widths: &[SpannedWidth],
+) {
+    for span in no_wrap_widths {
+        let visible: Vec<usize> = (span.start..span.start + span.colspan)
+            .filter(|index| infos.get(index).is_some_and(|info| !info.is_hidden))
+            .collect();
+        if visible.is_empty() {
+            continue;
+        }
+
+        // A spanning cell also occupies the padding and border between its columns.
+        let current_width = visible
+            .iter()
+            .map(|index| usize::from(infos[index].content_width))
+            .sum::<usize>()
+            + (visible.len() - 1) * 3;
+        let missing = span.width.saturating_sub(current_width);
+        if missing == 0 {
+            continue;
+        }
+
+        let unconstrained: Vec<usize> = visible
+            .into_iter()
+            .filter(|index| table.columns[*index].constraint.is_none())
+            .collect();
+        spread_space(infos, &unconstrained, missing);
+
+        #[cfg(feature = "_debug")]
+        println!(
+            "dynamic::enforce_no_wrap_widths: Gave {missing} to the columns of a cell at column {}",
+            span.start
+        );
+    }
+}
+
+/// Step 4
 ///
 /// Some Column's are too big and need to be split.
 /// We're now going to simulate how this might look like.
@@ -371,10 +485,15 @@ fn enforce_lower_boundary_constraints(
 /// Even though the column required 17 spaces beforehand, it can now be shrunk to 8 chars width.
 ///
 /// By doing this for each column, we can save a lot of space in some edge-cases.
+///
+/// The saved space increases the average space of the other columns. Just like in Step 3,
+/// columns whose maximum width now fits are fixed to it, so they never get more space than
+/// their content or upper bound needs.
 fn optimize_space_after_split(
     table: &Table,
     columns: &[Column],
     infos: &mut DisplayInfos,
+    bounds: &[ColumnBounds],
     mut remaining_width: usize,
     mut remaining_columns: usize,
 ) -> (usize, usize) {
@@ -391,13 +510,35 @@ fn optimize_space_after_split(
     // Do this as long as we find a smaller column
     while found_smaller {
         found_smaller = false;
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            remaining_width,
+            remaining_columns,
+            average_space,
+            "Solving widths of columns after splitting their content"
+        );
         for column in columns.iter() {
             // We already checked this column, skip it
             if infos.contains_key(&column.index) {
                 continue;
             }
 
-            let longest_line = longest_line_after_split(average_space, column, table);
+            // Shrinking other columns increases the average space, which may now be enough for
+            // the maximum width of this column.
+            let max_width = usize::from(bounds[column.index].max);
+            let fits = max_width <= average_space;
+            let longest_line = if fits {
+                max_width
+            } else {
+                let longest_line = longest_line_after_split(average_space, column, table);
+                // Wrapping never shrinks a column below its lower bound.
+                match bounds[column.index].lower {
+                    Some(lower) => {
+                        longest_line.max(absolute_width_with_padding(column, lower).into())
+                    }
+                    None => longest_line,
+                }
+            };
 
             #[cfg(feature = "_debug")]
             println!(
@@ -408,7 +549,7 @@ fn optimize_space_after_split(
             // If there's a considerable amount space left after splitting, we freeze the column and
             // set its content width to the calculated post-split width.
             let remaining_space = average_space.saturating_sub(longest_line);
-            if remaining_space >= 3 {
+            if fits || remaining_space >= 3 {
                 let info =
                     ColumnDisplayInfo::new(column, longest_line.try_into().unwrap_or(u16::MAX));
                 infos.insert(column.index, info);
@@ -433,7 +574,7 @@ fn optimize_space_after_split(
     (remaining_width, remaining_columns)
 }
 
-/// Part of Step 5.
+/// Part of Step 4.
 ///
 /// This function simulates the split of a Column's content and returns the longest
 /// existing line after the split.
@@ -444,8 +585,12 @@ fn longest_line_after_split(average_space: usize, column: &Column, table: &Table
     // That way we can easily determine the longest line afterwards.
     let mut column_lines = Vec::new();
 
-    // Iterate
-    for cell in table.column_cells_with_header_iter(column.index) {
+    // Iterate over the cell at this position of each row, starting with the header.
+    let header_cell = table
+        .header()
+        .and_then(|header| header.cells.get(column.index));
+    let cells = table.rows.iter().map(|row| row.cells.get(column.index));
+    for (index, cell) in std::iter::once(header_cell).chain(cells).enumerate() {
         // Only look at rows that actually contain this cell.
         let cell = match cell {
             Some(cell) => cell,
@@ -456,18 +601,24 @@ fn longest_line_after_split(average_space: usize, column: &Column, table: &Table
 
         // Create a temporary ColumnDisplayInfo with the average space as width.
         // That way we can simulate how the split text will look like.
-        let info = ColumnDisplayInfo::new(column, average_space.try_into().unwrap_or(u16::MAX));
+        let mut info = ColumnDisplayInfo::new(column, average_space.try_into().unwrap_or(u16::MAX));
+        info.ambiguous_width = table.ambiguous_width;
+
+        // The first cell of the iterator belongs to the header.
+        let is_header = index == 0;
 
         // Iterate over each line and split it into multiple lines, if necessary.
         // Newlines added by the user will be preserved.
-        for line in cell.content.iter() {
-            if line.width() > average_space {
+        let lines = display_lines(cell, Some(column), is_header);
+        let unit = displayed_unit(Some(column), is_header);
+        for line in lines.iter().map(String::as_str).chain(unit) {
+            if str_width(line, table.ambiguous_width) > average_space {
                 let mut parts = split_line(line, &info, delimiter);
 
                 #[cfg(feature = "_debug")]
                 println!(
                     "dynamic::longest_line_after_split: Splitting line with width {}. Original:\n    {}\nSplitted:\n    {:?}",
-                    line.width(),
+                    str_width(line, table.ambiguous_width),
                     line,
                     parts
                 );
@@ -482,35 +633,78 @@ fn longest_line_after_split(average_space: usize, column: &Column, table: &Table
     // Get the longest line, default to length 0 if no lines exist.
     column_lines
         .iter()
-        .map(|line| line.width())
+        .map(|line| str_width(line, table.ambiguous_width))
         .max()
         .unwrap_or(0)
 }
 
-/// Step 6 - First branch
+/// Step 5 - First branch
 ///
 /// At this point of time, all columns have been assigned some kind of width!
 /// The user wants to utilize the full width of the terminal and there's space left.
 ///
-/// Equally distribute the remaining space between all columns.
-fn use_full_width(infos: &mut DisplayInfos, remaining_width: usize) {
-    let visible_columns = infos.iter().filter(|(_, info)| !info.is_hidden).count();
+/// Columns below a cell that spans multiple columns are only sized by their own content.
+/// The content of such a cell would thereby be wrapped, while the space is given to its
+/// neighbors. So spanned columns get the space their cell needs first, narrower spans first.
+///
+/// Equally distribute the remaining space between all columns afterwards.
+fn use_full_width(
+    infos: &mut DisplayInfos,
+    mut remaining_width: usize,
+    spanned_widths: &[SpannedWidth],
+) {
+    for span in spanned_widths {
+        let columns: Vec<usize> = (span.start..span.start + span.colspan)
+            .filter(|index| infos.get(index).is_some_and(|info| !info.is_hidden))
+            .collect();
+        if columns.is_empty() || remaining_width == 0 {
+            continue;
+        }
+
+        // A spanning cell also occupies the padding and border between its columns.
+        let current_width = columns
+            .iter()
+            .map(|index| usize::from(infos[index].content_width))
+            .sum::<usize>()
+            + (columns.len() - 1) * 3;
+        let missing = span
+            .width
+            .saturating_sub(current_width)
+            .min(remaining_width);
+        spread_space(infos, &columns, missing);
+        remaining_width -= missing;
+
+        #[cfg(feature = "_debug")]
+        println!(
+            "dynamic::use_full_width: Gave {missing} to the columns spanned by a cell at column {}",
+            span.start
+        );
+    }
+
+    let visible_columns: Vec<usize> = infos
+        .iter()
+        .filter(|(_, info)| !info.is_hidden)
+        .map(|(index, _)| *index)
+        .collect();
+    spread_space(infos, &visible_columns, remaining_width);
+}
 
-    if visible_columns == 0 {
+/// Equally distribute some space between the given columns.
+fn spread_space(infos: &mut DisplayInfos, columns: &[usize], space: usize) {
+    if columns.is_empty() {
         return;
     }
 
     // Calculate the amount of average remaining space per column.
     // Since we do integer division, there is most likely a little bit of non equally-divisible space.
     // We then try to distribute it as fair as possible (from left to right).
-    let average_space = remaining_width / visible_columns;
-    let mut excess = remaining_width - (average_space * visible_columns);
+    let average_space = space / columns.len();
+    let mut excess = space - (average_space * columns.len());
 
-    for (_, info) in infos.iter_mut() {
-        // Ignore hidden columns
-        if info.is_hidden {
+    for index in columns {
+        let Some(info) = infos.get_mut(index) else {
             continue;
-        }
+        };
 
         // Distribute the non-divisible excess from left-to right until nothing is left.
         let width = if excess > 0 {
@@ -520,11 +714,100 @@ fn use_full_width(infos: &mut DisplayInfos, remaining_width: usize) {
             average_space.try_into().unwrap_or(u16::MAX)
         };
 
-        info.content_width += width;
+        info.content_width = info.content_width.saturating_add(width);
     }
 }
 
-/// Step 6 - Second branch
+/// Step 2b
+///
+/// This step only applies, if the remaining columns have different
+/// [truncation priorities](Column::set_truncation_priority).
+///
+/// 1. If there's less than a single character per remaining column, columns with the lowest
+///    priority are hidden, starting with the rightmost column.
+///    This frees the space of their padding and border.
+/// 2. Columns are handled by their priority, starting with the highest one.
+///    Each column receives its maximum width (see [ColumnBounds]),
+///    but only as much as possible, while every other remaining column can still get
+///    a single character.
+///
+/// Returns:
+/// `(remaining_width: usize, remaining_columns: u16)`
+fn prioritize_columns(
+    table: &Table,
+    infos: &mut DisplayInfos,
+    bounds: &[ColumnBounds],
+    mut remaining_width: usize,
+    mut remaining_columns: usize,
+) -> (usize, usize) {
+    let mut columns: Vec<&Column> = table
+        .columns
+        .iter()
+        .filter(|column| !infos.contains_key(&column.index))
+        .collect();
+    let lowest = columns
+        .iter()
+        .map(|column| column.truncation_priority)
+        .min()
+        .unwrap_or_default();
+    if columns
+        .iter()
+        .all(|column| column.truncation_priority == lowest)
+    {
+        return (remaining_width, remaining_columns);
+    }
+
+    // The sort is stable, so columns with the same priority keep their order.
+    columns.sort_by_key(|column| std::cmp::Reverse(column.truncation_priority));
+
+    // Hide the columns with the lowest priority, as long as there isn't a single character left
+    // for each of them.
+    for column in columns.iter().rev() {
+        if remaining_width >= remaining_columns || column.truncation_priority != lowest {
+            break;
+        }
+
+        let mut info = ColumnDisplayInfo::new(column, 0);
+        info.is_hidden = true;
+        infos.insert(column.index, info);
+
+        remaining_width += usize::from(column.padding_width());
+        if should_draw_vertical_lines(table) {
+            remaining_width += 1;
+        }
+        remaining_columns -= 1;
+    }
+
+    for column in columns.iter() {
+        if column.truncation_priority == lowest {
+            break;
+        }
+
+        let width = bounds[column.index].max;
+        // Leave a single character for every other remaining column.
+        let available = remaining_width.saturating_sub(remaining_columns - 1);
+        let width = width.min(available.try_into().unwrap_or(u16::MAX));
+        if width == 0 {
+            continue;
+        }
+
+        let info = ColumnDisplayInfo::new(column, width);
+        infos.insert(column.index, info);
+
+        #[cfg(feature = "_debug")]
+        println!(
+            "dynamic::prioritize_columns: Fixed column {} with size {} due to its priority {}",
+            column.index, width, column.truncation_priority
+        );
+
+        remaining_width -= usize::from(width);
+        remaining_columns -= 1;
+    }
+
+    (remaining_width, remaining_columns)
+}
+
+/// Step 5 - Second branch
 ///
 /// Not all columns have a determined width yet -> The content still doesn't fully fit into the
 /// given width.
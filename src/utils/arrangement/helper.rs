@@ -1,9 +1,23 @@
 use super::DisplayInfos;
+use crate::style::Overflow;
 use crate::utils::formatting::borders::{
-    should_draw_left_border, should_draw_right_border, should_draw_vertical_lines,
+    should_draw_left_border, should_draw_right_border, should_draw_vertical_lines, spanned_width,
 };
+use crate::utils::formatting::display::{display_width, longest_word_width, widest_grapheme_width};
+use crate::utils::spanning::SpanTracker;
 use crate::{Cell, Column, Table};
 
+/// The content width of a cell, which may span multiple columns.
+#[derive(Debug, Clone, Copy)]
+pub struct SpannedWidth {
+    /// The first column spanned by the cell.
+    pub start: usize,
+    /// The amount of columns spanned by the cell.
+    pub colspan: usize,
+    /// The width of the cell's content.
+    pub width: usize,
+}
+
 /// The ColumnDisplayInfo works with a fixed value for content width.
 /// However, if a column is supposed to get a absolute width, we have to make sure that
 /// the padding on top of the content width doesn't get larger than the specified absolute width.
@@ -66,3 +80,184 @@ pub fn delimiter(table: &Table, column: &Column, cell: &Cell) -> char {
         ' '
     }
 }
+
+/// Return the content width of all cells that span multiple columns.
+///
+/// The spans are ordered by the amount of spanned columns, narrower spans first.
+pub fn spanned_content_widths(table: &Table) -> Vec<SpannedWidth> {
+    let mut widths = Vec::new();
+    for_each_positioned_cell(table, |cell, col_index, header| {
+        let colspan = usize::from(cell.colspan());
+        if colspan > 1 {
+            widths.push(SpannedWidth {
+                start: col_index,
+                colspan,
+                width: display_width(
+                    cell,
+                    table.columns.get(col_index),
+                    header,
+                    table.ambiguous_width,
+                ),
+            });
+        }
+    });
+
+    // The sort is stable, so spans of the same size keep their order.
+    widths.sort_by_key(|span| span.colspan);
+    widths
+}
+
+/// Return the width of the longest word of all cells that must not be wrapped.
+///
+/// See [Cell::set_no_wrap](crate::Cell::set_no_wrap).
+/// The widths are ordered by the amount of spanned columns, narrower spans first.
+pub fn no_wrap_widths(table: &Table) -> Vec<SpannedWidth> {
+    let mut widths = Vec::new();
+    for_each_positioned_cell(table, |cell, col_index, header| {
+        let Some(column) = table.columns.get(col_index) else {
+            return;
+        };
+        // Columns only keep the words of their own cells together, but not of their header.
+        let no_wrap_column =
+            !header && cell.colspan() == 1 && column.overflow == Some(Overflow::NoWrap);
+        if !cell.no_wrap && !no_wrap_column {
+            return;
+        }
+        widths.push(SpannedWidth {
+            start: col_index,
+            colspan: usize::from(cell.colspan()),
+            width: longest_word_width(
+                cell,
+                Some(column),
+                header,
+                delimiter(table, column, cell),
+                table.ambiguous_width,
+            ),
+        });
+    });
+
+    widths.sort_by_key(|span| span.colspan);
+    widths
+}
+
+/// Widen columns, that are narrower than the widest grapheme of their content.
+///
+/// Content is never split within a grapheme, so a double-width character needs a column that's
+/// at least two characters wide, no matter what the constraints or the table width say.
+/// Only columns whose content is wrapped and cells with a single visible column are checked.
+pub fn fit_widest_graphemes(table: &Table, infos: &mut DisplayInfos, max_content_widths: &[u16]) {
+    let wrapped = |index: usize| {
+        infos
+            .get(&index)
+            .is_some_and(|info| !info.is_hidden && info.content_width < max_content_widths[index])
+    };
+    if !(0..table.columns.len()).any(wrapped) {
+        return;
+    }
+
+    let mut widths = vec![0; table.columns.len()];
+    for_each_positioned_cell(table, |cell, col_index, header| {
+        let end = (col_index + usize::from(cell.colspan())).min(table.columns.len());
+        let mut visible = (col_index..end).filter(|index| !table.columns[*index].is_hidden());
+        let (Some(index), None) = (visible.next(), visible.next()) else {
+            return;
+        };
+        if !wrapped(index) {
+            return;
+        }
+        let width = widest_grapheme_width(
+            cell,
+            table.columns.get(col_index),
+            header,
+            table.ambiguous_width,
+        );
+        widths[index] = widths[index].max(width);
+    });
+
+    for (index, width) in widths.into_iter().enumerate() {
+        if let Some(info) = infos.get_mut(&index) {
+            let width = u16::try_from(width).unwrap_or(u16::MAX);
+            info.content_width = info.content_width.max(width);
+        }
+    }
+}
+
+/// Widen the last column of an [annotation](Table::add_annotation_row), if its widest grapheme
+/// doesn't fit into the width of the spanned columns.
+///
+/// Annotations are wrapped to the width of the table and don't affect the width of columns
+/// otherwise.
+pub fn fit_annotations(table: &Table, infos: &mut DisplayInfos) {
+    let vertical_lines = should_draw_vertical_lines(table);
+    // Annotations only keep a space next to the borders.
+    let padding =
+        usize::from(should_draw_left_border(table)) + usize::from(should_draw_right_border(table));
+    walk_positioned_cells(table, true, |cell, col_index, _| {
+        let end = col_index + usize::from(cell.colspan());
+        let spanned = infos.range(col_index..end).map(|(_, info)| info);
+        let available = spanned_width(vertical_lines, spanned);
+        let required = widest_grapheme_width(cell, None, false, table.ambiguous_width) + padding;
+        if required <= available {
+            return;
+        }
+
+        let last = infos
+            .range_mut(col_index..end)
+            .map(|(_, info)| info)
+            .rfind(|info| !info.is_hidden);
+        if let Some(info) = last {
+            let missing = u16::try_from(required - available).unwrap_or(u16::MAX);
+            info.content_width = info.content_width.saturating_add(missing);
+        }
+    });
+}
+
+/// Call the given function with every cell of the table and the column it starts in.
+///
+/// The function also receives whether the cell is part of the header.
+/// [Annotations](Table::add_annotation_row) don't affect the width of columns and are skipped.
+fn for_each_positioned_cell(table: &Table, function: impl FnMut(&Cell, usize, bool)) {
+    walk_positioned_cells(table, false, function);
+}
+
+/// Call the given function with the cells of either all annotations or all other rows
+/// and the column they start in.
+///
+/// Rowspans of all rows are tracked, so cells below them start in the right column.
+fn walk_positioned_cells(
+    table: &Table,
+    annotations: bool,
+    mut function: impl FnMut(&Cell, usize, bool),
+) {
+    let mut span_tracker = SpanTracker::new();
+    let column_count = table.columns.len();
+
+    for (row_index, row) in table.header.iter().chain(table.rows.iter()).enumerate() {
+        let included = row.annotation == annotations;
+        let header = table.header.is_some() && row_index == 0;
+        let mut col_index = 0;
+        for cell in row.cells.iter() {
+            // Skip positions that are occupied by rowspans from rows above.
+            while col_index < column_count
+                && span_tracker.is_col_occupied_by_rowspan(row_index, col_index)
+            {
+                col_index += 1;
+            }
+
+            if included {
+                function(cell, col_index, header);
+            }
+            if cell.rowspan() > 1 {
+                span_tracker.register_rowspan(
+                    row_index,
+                    col_index,
+                    cell.rowspan(),
+                    cell.colspan(),
+                    None,
+                );
+            }
+            col_index += usize::from(cell.colspan());
+        }
+        span_tracker.advance_row(row_index + 1);
+    }
+}
@@ -14,10 +14,22 @@ type DisplayInfos = BTreeMap<usize, ColumnDisplayInfo>;
 /// Determine the width of each column depending on the content of the given table.
 /// The results uses Option<usize>, since users can choose to hide columns.
 pub fn arrange_content(table: &Table) -> Vec<ColumnDisplayInfo> {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::debug_span!(
+        "arrange_content",
+        columns = table.columns.len(),
+        rows = table.rows.len()
+    )
+    .entered();
+
     let table_width = table.width().map(usize::from);
     let mut infos = BTreeMap::new();
 
+    #[cfg(feature = "tracing")]
+    let scan_start = std::time::Instant::now();
     let max_content_widths = table.column_max_content_widths();
+    #[cfg(feature = "tracing")]
+    tracing::debug!(elapsed = ?scan_start.elapsed(), "Scanned content widths");
 
     // Check if we can already resolve some constraints.
     // This step also populates the ColumnDisplayInfo structs.
@@ -42,7 +54,7 @@ pub fn arrange_content(table: &Table) -> Vec<ColumnDisplayInfo> {
         table_width
     } else {
         disabled::arrange(table, &mut infos, visible_columns, &max_content_widths);
-        return infos.into_values().collect();
+        return finish(table, infos, &max_content_widths);
     };
 
     match &table.arrangement {
@@ -50,11 +62,48 @@ pub fn arrange_content(table: &Table) -> Vec<ColumnDisplayInfo> {
             disabled::arrange(table, &mut infos, visible_columns, &max_content_widths)
         }
         ContentArrangement::Dynamic | ContentArrangement::DynamicFullWidth => {
-            dynamic::arrange(table, &mut infos, table_width, &max_content_widths);
+            let spanned_widths = helper::spanned_content_widths(table);
+            let no_wrap_widths = helper::no_wrap_widths(table);
+            dynamic::arrange(
+                table,
+                &mut infos,
+                table_width,
+                &max_content_widths,
+                &spanned_widths,
+                &no_wrap_widths,
+            );
         }
     }
 
-    infos.into_values().collect()
+    finish(table, infos, &max_content_widths)
+}
+
+/// Make sure that every column and annotation fits its widest grapheme and pass table-wide settings that are
+/// needed for formatting on to all columns.
+fn finish(
+    table: &Table,
+    mut infos: DisplayInfos,
+    max_content_widths: &[u16],
+) -> Vec<ColumnDisplayInfo> {
+    helper::fit_widest_graphemes(table, &mut infos, max_content_widths);
+    helper::fit_annotations(table, &mut infos);
+
+    // Every column of the table is arranged and no visible column is collapsed to nothing.
+    debug_assert_eq!(infos.len(), table.columns.len());
+    debug_assert!(
+        infos
+            .values()
+            .all(|info| info.is_hidden || info.content_width > 0),
+        "Visible columns need at least one character of content width: {infos:?}"
+    );
+
+    infos
+        .into_values()
+        .map(|mut info| {
+            info.ambiguous_width = table.ambiguous_width;
+            info
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -70,7 +119,7 @@ mod tests {
         let display_infos = arrange_content(&table);
 
         // The width should be the width of the rows + padding
-        let widths: Vec<u16> = display_infos.iter().map(ColumnDisplayInfo::width).collect();
+        let widths: Vec<usize> = display_infos.iter().map(ColumnDisplayInfo::width).collect();
         assert_eq!(widths, vec![6, 7, 8]);
     }
 
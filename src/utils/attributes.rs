@@ -0,0 +1,41 @@
+use std::borrow::Cow;
+
+use crate::style::AttributePolicy;
+use crate::table::Table;
+
+/// Apply the [AttributePolicy] of a table to the attributes of all cells, rows and highlights.
+pub(crate) fn sanitize_attributes(table: &mut Cow<'_, Table>) {
+    let policy = resolve_policy(table);
+    if policy == AttributePolicy::Keep || !table.should_style() {
+        return;
+    }
+
+    let sanitized = table.to_mut();
+    let rows = sanitized.header.iter_mut().chain(sanitized.rows.iter_mut());
+    for row in rows {
+        row.attributes = policy.apply(&row.attributes);
+        for cell in row.cells.iter_mut() {
+            cell.attributes = policy.apply(&cell.attributes);
+        }
+    }
+    for (_, style) in sanitized.highlights.iter_mut() {
+        style.attributes = policy.apply(&style.attributes);
+    }
+}
+
+/// Determine the policy for [AttributePolicy::Auto], depending on the output of the table.
+fn resolve_policy(table: &Table) -> AttributePolicy {
+    if table.attribute_policy != AttributePolicy::Auto {
+        return table.attribute_policy;
+    }
+
+    if !table.is_tty() {
+        return AttributePolicy::Strip;
+    }
+
+    match std::env::var("TERM") {
+        Ok(term) if term == "dumb" => AttributePolicy::Strip,
+        Ok(term) if term == "linux" || term.starts_with("vt") => AttributePolicy::Fallback,
+        _ => AttributePolicy::Keep,
+    }
+}
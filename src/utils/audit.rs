@@ -0,0 +1,87 @@
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use super::arrangement::arrange_content;
+use super::formatting::content_format::format_content;
+use super::formatting::content_split::measure_text_width;
+use super::prepare_table;
+use crate::render::AlignmentIssue;
+use crate::table::Table;
+
+/// Re-measure the formatted content of all cells with the widths some terminals use and report
+/// every part of a line, whose width differs from the one that was used for the layout.
+///
+/// See [Table::audit_alignment] for details.
+pub(crate) fn audit_alignment(table: &Table) -> Vec<AlignmentIssue> {
+    let table = prepare_table(table);
+    let display_infos = arrange_content(&table);
+    let visible_columns: Vec<usize> = display_infos
+        .iter()
+        .enumerate()
+        .filter(|(_, info)| !info.is_hidden)
+        .map(|(index, _)| index)
+        .collect();
+
+    let mut issues = Vec::new();
+    for (row_index, row) in format_content(&table, &display_infos).enumerate() {
+        for (line_index, line) in row.iter().enumerate() {
+            for (part, column_index) in line.iter().zip(visible_columns.iter()) {
+                let part = strip_ansi_codes(part);
+                let expected_width = measure_text_width(&part, table.ambiguous_width);
+                let actual_width = terminal_width(&part);
+                if expected_width != actual_width {
+                    issues.push(AlignmentIssue {
+                        row_index,
+                        column_index: *column_index,
+                        line_index,
+                        expected_width,
+                        actual_width,
+                        content: part.trim().to_string(),
+                    });
+                }
+            }
+        }
+    }
+
+    issues
+}
+
+/// Measure the width of a line, like terminals in East Asian locales and with emoji support
+/// display it.
+///
+/// - Characters with an ambiguous width are two columns wide.
+/// - Graphemes that are joined via a zero width joiner or carry an emoji presentation selector
+///   are displayed as a single emoji, which is two columns wide.
+fn terminal_width(line: &str) -> usize {
+    line.graphemes(true)
+        .map(|grapheme| {
+            if grapheme.chars().count() > 1 && grapheme.contains(['\u{200d}', '\u{fe0f}']) {
+                2
+            } else {
+                grapheme.width_cjk()
+            }
+        })
+        .sum()
+}
+
+/// Remove all ANSI escape sequences, which are added by styling.
+fn strip_ansi_codes(line: &str) -> String {
+    let mut stripped = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(char) = chars.next() {
+        if char != '\u{1b}' {
+            stripped.push(char);
+            continue;
+        }
+        // Control sequences consist of `ESC [`, parameters and a final byte in the range `@`-`~`.
+        if chars.next() == Some('[') {
+            for char in chars.by_ref() {
+                if ('@'..='~').contains(&char) {
+                    break;
+                }
+            }
+        }
+    }
+
+    stripped
+}
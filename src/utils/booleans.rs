@@ -0,0 +1,49 @@
+use std::borrow::Cow;
+
+use crate::table::Table;
+use crate::utils::numbers::cell_positions;
+use crate::value::CellValue;
+
+/// Display all boolean values as configured by the [BoolFormat](crate::BoolFormat) of their
+/// column or the table.
+pub(crate) fn format_booleans(table: &mut Cow<'_, Table>) {
+    for column_index in 0..table.columns.len() {
+        if table.columns[column_index].bool_format.is_none() && table.bool_format.is_none() {
+            continue;
+        }
+
+        // The positions and values of all boolean cells of this column.
+        let booleans: Vec<(usize, usize, bool)> = cell_positions(table, column_index)
+            .into_iter()
+            .filter_map(|(row_index, cell_index)| {
+                match table.rows[row_index].cells[cell_index].typed_value() {
+                    CellValue::Bool(value) => Some((row_index, cell_index, value)),
+                    _ => None,
+                }
+            })
+            .collect();
+        if booleans.is_empty() {
+            continue;
+        }
+
+        let formatted = table.to_mut();
+        let Some(format) = formatted.columns[column_index]
+            .bool_format
+            .as_ref()
+            .or(formatted.bool_format.as_ref())
+        else {
+            continue;
+        };
+        for (row_index, cell_index, value) in booleans {
+            let cell = &mut formatted.rows[row_index].cells[cell_index];
+            cell.content = vec![format.text(value).to_string()];
+
+            #[cfg(feature = "tty")]
+            if let Some(style) = format.style(value) {
+                cell.fg = style.fg.or(cell.fg);
+                cell.bg = style.bg.or(cell.bg);
+                cell.attributes.extend(style.attributes.iter().copied());
+            }
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use crate::style::{ColumnConstraint, ContentArrangement};
+use crate::table::Table;
+use crate::utils::arrangement::arrange_content;
+use crate::utils::arrangement::helper::count_border_columns;
+use crate::utils::prepare_table;
+
+/// Split a table into copies, that each display some of its columns and fit into `width`.
+///
+/// Columns are distributed in their order by the width they need without any wrapping.
+/// Frozen columns are displayed in every chunk and all other columns are hidden in the chunks
+/// they don't belong to.
+/// Each chunk contains at least one column that isn't frozen, even if it doesn't fit.
+pub(crate) fn column_chunks(table: &Table, width: u16) -> Vec<Table> {
+    let mut unwrapped = table.clone();
+    unwrapped.set_content_arrangement(ContentArrangement::Disabled);
+    let prepared = prepare_table(&unwrapped);
+    let infos = arrange_content(&prepared);
+
+    let visible = || {
+        table
+            .columns
+            .iter()
+            .zip(infos.iter())
+            .filter(|(_, info)| !info.is_hidden())
+    };
+    let frozen_width: usize = visible()
+        .filter(|(column, _)| column.frozen)
+        .map(|(_, info)| info.width())
+        .sum();
+    let frozen_count = visible().filter(|(column, _)| column.frozen).count();
+
+    let mut chunks: Vec<Vec<usize>> = Vec::new();
+    let mut chunk: Vec<usize> = Vec::new();
+    let mut chunk_width = frozen_width;
+    for (column, info) in visible().filter(|(column, _)| !column.frozen) {
+        let columns = frozen_count + chunk.len() + 1;
+        let total_width = chunk_width + info.width() + count_border_columns(table, columns);
+        if !chunk.is_empty() && total_width > usize::from(width) {
+            chunks.push(std::mem::take(&mut chunk));
+            chunk_width = frozen_width;
+        }
+        chunk.push(column.index);
+        chunk_width += info.width();
+    }
+    if !chunk.is_empty() {
+        chunks.push(chunk);
+    }
+    if chunks.is_empty() {
+        return vec![table.clone()];
+    }
+
+    chunks
+        .into_iter()
+        .map(|chunk| {
+            let mut copy = table.clone();
+            for (column, info) in copy.columns.iter_mut().zip(infos.iter()) {
+                if !info.is_hidden() && !column.frozen && !chunk.contains(&column.index) {
+                    column.constraint = Some(ColumnConstraint::Hidden);
+                }
+            }
+            copy
+        })
+        .collect()
+}
@@ -0,0 +1,59 @@
+use std::borrow::Cow;
+
+use crate::column::Column;
+use crate::style::{Attribute, Color};
+use crate::table::Table;
+use crate::utils::numbers::cell_positions;
+
+/// Apply the styling of columns, that depends on the content of their cells.
+///
+/// - Cells that match one of the [category colors](Column::set_category_colors) are colored.
+/// - Cells that are compared to a [baseline row](Column::set_baseline_row) are dimmed, if they
+///   equal the baseline, and colored yellow otherwise.
+///
+/// Cells with their own foreground color keep it.
+pub(crate) fn style_columns(table: &mut Cow<'_, Table>) {
+    for column_index in 0..table.columns.len() {
+        if !styles_content(&table.columns[column_index]) {
+            continue;
+        }
+
+        let positions = cell_positions(table, column_index);
+        let baseline = table.columns[column_index]
+            .baseline_row
+            .and_then(|baseline| {
+                positions
+                    .iter()
+                    .find(|(row_index, _)| *row_index == baseline)
+                    .map(|(row_index, cell_index)| {
+                        table.rows[*row_index].cells[*cell_index].content()
+                    })
+            });
+
+        let styled = table.to_mut();
+        let column = &styled.columns[column_index];
+        for (row_index, cell_index) in positions {
+            let cell = &mut styled.rows[row_index].cells[cell_index];
+            let content = cell.content();
+            if let Some(color) = column.category_colors.get(&content) {
+                cell.fg = cell.fg.or(Some(*color));
+            }
+
+            if let Some(baseline) = baseline.as_ref() {
+                if column.baseline_row == Some(row_index) {
+                    continue;
+                }
+                if content == *baseline {
+                    cell.attributes.push(Attribute::Dim);
+                } else {
+                    cell.fg = cell.fg.or(Some(Color::Yellow));
+                }
+            }
+        }
+    }
+}
+
+/// Returns whether cells of a column are styled depending on their content.
+fn styles_content(column: &Column) -> bool {
+    !column.category_colors.is_empty() || column.baseline_row.is_some()
+}
@@ -0,0 +1,73 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use crate::style::TableComponent;
+use crate::table::Table;
+
+/// Lines between rows and the intersections they consist of.
+const ROW_LINE_COMPONENTS: [TableComponent; 9] = [
+    TableComponent::HorizontalLines,
+    TableComponent::FooterLines,
+    TableComponent::MiddleIntersections,
+    TableComponent::LeftBorderIntersections,
+    TableComponent::RightBorderIntersections,
+    TableComponent::RowspanLeftIntersection,
+    TableComponent::RowspanRightIntersection,
+    TableComponent::MiddleMergeIntersection,
+    TableComponent::MiddleSplitIntersection,
+];
+
+/// Remove the padding and the inner lines of a [compact](Table::set_compact) table.
+///
+/// Columns are separated by a single space, lines between rows are removed and the
+/// intersections of the remaining lines are replaced by the lines themselves.
+pub(crate) fn compact_table(table: &mut Cow<'_, Table>) {
+    if !table.compact {
+        return;
+    }
+
+    let compact = table.to_mut();
+    for column in compact.columns.iter_mut() {
+        column.padding = (0, 0);
+    }
+    compact_style(&mut compact.style);
+    for style in compact.section_styles.values_mut() {
+        compact_style(style);
+    }
+}
+
+/// Remove the lines between rows from a style and separate columns by a single space.
+fn compact_style(style: &mut HashMap<TableComponent, char>) {
+    for component in ROW_LINE_COMPONENTS {
+        style.remove(&component);
+    }
+    style.insert(TableComponent::VerticalLines, ' ');
+
+    for (intersection, line) in [
+        (
+            TableComponent::TopBorderIntersections,
+            TableComponent::TopBorder,
+        ),
+        (
+            TableComponent::MiddleHeaderIntersections,
+            TableComponent::HeaderLines,
+        ),
+        (
+            TableComponent::MiddleHeaderMergeIntersection,
+            TableComponent::HeaderLines,
+        ),
+        (
+            TableComponent::BottomBorderIntersections,
+            TableComponent::BottomBorder,
+        ),
+        (
+            TableComponent::BottomBorderColspanIntersections,
+            TableComponent::BottomBorder,
+        ),
+    ] {
+        match style.get(&line).copied() {
+            Some(character) => style.insert(intersection, character),
+            None => style.remove(&intersection),
+        };
+    }
+}
@@ -0,0 +1,169 @@
+use super::arrangement::constraint::{absolute_value_from_width, min};
+use super::arrangement::helper::{count_border_columns, count_visible_columns};
+use crate::column::Column;
+use crate::style::{ColumnConstraint, ConstraintConflictPolicy, ContentArrangement, Width};
+use crate::table::Table;
+
+/// Column constraints, which require more space than the width of a table allows.
+#[derive(Debug)]
+pub(crate) struct ConstraintConflict {
+    /// The width the table requires with all constraints.
+    pub(crate) required_width: usize,
+    pub(crate) table_width: u16,
+    /// The columns, whose constraints have been changed to resolve the conflict.
+    pub(crate) changed_columns: Vec<usize>,
+    /// The width the table requires after the conflict has been resolved.
+    pub(crate) resolved_width: usize,
+}
+
+/// Detect a conflict between the column constraints and the width of a table and resolve it
+/// according to the table's [ConstraintConflictPolicy].
+///
+/// Returns the conflict and the columns with adjusted constraints.
+/// The columns are `None`, if the constraints have to be kept.
+pub(crate) fn resolve_constraint_conflict(
+    table: &Table,
+) -> Option<(ConstraintConflict, Option<Vec<Column>>)> {
+    if matches!(table.arrangement, ContentArrangement::Disabled) {
+        return None;
+    }
+    let table_width = table.width()?;
+    let max_content_widths = table.column_max_content_widths();
+    let required_width = required_width(table, &table.columns, &max_content_widths);
+    if required_width <= usize::from(table_width) {
+        return None;
+    }
+
+    let mut conflict = ConstraintConflict {
+        required_width,
+        table_width,
+        changed_columns: Vec::new(),
+        resolved_width: required_width,
+    };
+    let mut columns = table.columns.clone();
+    match table.constraint_conflict_policy {
+        ConstraintConflictPolicy::Error => return Some((conflict, None)),
+        ConstraintConflictPolicy::Shrink => {
+            shrink(table, &mut columns, &max_content_widths, &mut conflict);
+        }
+        ConstraintConflictPolicy::DropLowestPriority => {
+            drop_constraints(table, &mut columns, &max_content_widths, &mut conflict);
+        }
+    }
+
+    Some((conflict, Some(columns)))
+}
+
+/// Shrink all constrained columns by the same ratio of their flexible width.
+///
+/// The flexible width of a column is the part of its width that exceeds a single character
+/// of content and the padding.
+fn shrink(
+    table: &Table,
+    columns: &mut [Column],
+    max_content_widths: &[u16],
+    conflict: &mut ConstraintConflict,
+) {
+    let visible_columns = count_visible_columns(columns);
+    let flexible_widths: Vec<usize> = columns
+        .iter()
+        .map(|column| {
+            if column.constraint.is_none() {
+                return 0;
+            }
+            let width = column_width(table, column, visible_columns, max_content_widths);
+            width.saturating_sub(smallest_width(column))
+        })
+        .collect();
+    let flexible_width: usize = flexible_widths.iter().sum();
+    let excess = conflict.required_width - usize::from(conflict.table_width);
+    let target = flexible_width.saturating_sub(excess);
+
+    for (column, flexible) in columns.iter_mut().zip(flexible_widths) {
+        if flexible == 0 {
+            continue;
+        }
+        let shrunk = flexible * target / flexible_width;
+        let width = u16::try_from(smallest_width(column) + shrunk).unwrap_or(u16::MAX);
+        let constraint = match column.constraint {
+            Some(ColumnConstraint::LowerBoundary(_)) => {
+                ColumnConstraint::LowerBoundary(Width::Fixed(width))
+            }
+            Some(ColumnConstraint::Boundaries { upper, .. }) => ColumnConstraint::Boundaries {
+                lower: Width::Fixed(width),
+                upper,
+            },
+            _ => ColumnConstraint::Absolute(Width::Fixed(width)),
+        };
+        column.constraint = Some(constraint);
+        conflict.changed_columns.push(column.index);
+    }
+
+    conflict.resolved_width = required_width(table, columns, max_content_widths);
+}
+
+/// Remove the constraints of the columns with the lowest truncation priority, until the table fits.
+///
+/// Of columns with the same priority, the rightmost column loses its constraint first.
+fn drop_constraints(
+    table: &Table,
+    columns: &mut [Column],
+    max_content_widths: &[u16],
+    conflict: &mut ConstraintConflict,
+) {
+    let mut order: Vec<usize> = (0..columns.len()).rev().collect();
+    // The sort is stable, so columns with the same priority stay in order from right to left.
+    order.sort_by_key(|index| columns[*index].truncation_priority);
+
+    for index in order {
+        if conflict.resolved_width <= usize::from(conflict.table_width) {
+            break;
+        }
+        let column = &mut columns[index];
+        if column.constraint.is_none() || column.is_hidden() {
+            continue;
+        }
+        column.constraint = None;
+        conflict.changed_columns.push(index);
+        conflict.resolved_width = required_width(table, columns, max_content_widths);
+    }
+}
+
+/// The width a table requires at least with the given columns.
+fn required_width(table: &Table, columns: &[Column], max_content_widths: &[u16]) -> usize {
+    let visible_columns = count_visible_columns(columns);
+    let column_widths: usize = columns
+        .iter()
+        .map(|column| column_width(table, column, visible_columns, max_content_widths))
+        .sum();
+
+    count_border_columns(table, visible_columns) + column_widths
+}
+
+/// The width a column requires at least, including its padding.
+fn column_width(
+    table: &Table,
+    column: &Column,
+    visible_columns: usize,
+    max_content_widths: &[u16],
+) -> usize {
+    let smallest_width = smallest_width(column);
+    let width = match &column.constraint {
+        Some(ColumnConstraint::Hidden) => return 0,
+        Some(ColumnConstraint::ContentWidth) => {
+            let content_width = max_content_widths.get(column.index).copied().unwrap_or(1);
+            usize::from(content_width + column.padding_width())
+        }
+        Some(ColumnConstraint::Absolute(width)) => {
+            absolute_value_from_width(table, width, visible_columns).map_or(0, usize::from)
+        }
+        constraint => min(table, constraint, visible_columns).map_or(0, usize::from),
+    };
+
+    width.max(smallest_width)
+}
+
+/// A column is at least a single character wide, plus its padding.
+fn smallest_width(column: &Column) -> usize {
+    usize::from(column.padding_width()) + 1
+}
@@ -0,0 +1,71 @@
+use std::borrow::Cow;
+
+use crate::cell::Cell;
+use crate::row::Row;
+use crate::style::Color;
+use crate::style::palettes::to_rgb;
+use crate::table::Table;
+
+/// Backgrounds with a relative luminance above this value have a better contrast to black text
+/// than to white text.
+const BLACK_TEXT_LUMINANCE: f64 = 0.179;
+
+/// Give cells with a background, but without a foreground color, a readable foreground color,
+/// see [Table::set_auto_contrast].
+pub(crate) fn apply_auto_contrast(table: &mut Cow<'_, Table>) {
+    if !table.auto_contrast {
+        return;
+    }
+    let needs_contrast = table.header.iter().chain(table.rows.iter()).any(|row| {
+        row.cells.iter().any(|cell| {
+            needs_foreground(row, cell) && cell.bg.or(row.bg).and_then(to_rgb).is_some()
+        })
+    });
+    if !needs_contrast {
+        return;
+    }
+
+    let contrasted = table.to_mut();
+    let rows = contrasted
+        .header
+        .iter_mut()
+        .chain(contrasted.rows.iter_mut());
+    for row in rows {
+        if row.fg.is_some() {
+            continue;
+        }
+        for cell in row.cells.iter_mut() {
+            if cell.fg.is_none() && cell.gradient.is_none() {
+                cell.fg = cell.bg.or(row.bg).and_then(contrasting_color);
+            }
+        }
+    }
+}
+
+/// Returns whether neither the cell nor its row have a foreground color or a gradient.
+fn needs_foreground(row: &Row, cell: &Cell) -> bool {
+    cell.fg.or(row.fg).is_none() && cell.gradient.is_none()
+}
+
+/// Black or white, whichever is more readable on the given background.
+///
+/// Returns `None`, if the background depends on the terminal.
+fn contrasting_color(background: Color) -> Option<Color> {
+    let (r, g, b) = to_rgb(background)?;
+    // The relative luminance as defined by WCAG 2.
+    let linear = |channel: u8| {
+        let value = f64::from(channel) / 255.0;
+        if value <= 0.04045 {
+            value / 12.92
+        } else {
+            ((value + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    let luminance = 0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b);
+
+    if luminance > BLACK_TEXT_LUMINANCE {
+        Some(Color::Black)
+    } else {
+        Some(Color::White)
+    }
+}
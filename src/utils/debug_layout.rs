@@ -0,0 +1,189 @@
+use std::fmt::Write;
+
+use super::arrangement::arrange_content;
+use super::constraint_conflict::resolve_constraint_conflict;
+use super::prepare_table;
+use super::spanning::SpanTracker;
+use crate::style::ColumnConstraint;
+use crate::table::Table;
+
+/// Describe how the columns of a table are laid out, once the table is rendered.
+///
+/// See [Table::debug_layout] for details.
+pub(crate) fn debug_layout(table: &Table) -> String {
+    let conflict = resolve_constraint_conflict(table).map(|(conflict, _)| conflict);
+    let table = prepare_table(table);
+    let display_infos = arrange_content(&table);
+    let max_content_widths = table.column_max_content_widths();
+
+    let mut output = String::new();
+    match table.width() {
+        Some(width) => {
+            let _ = writeln!(
+                output,
+                "Table width: {width} ({:?})",
+                table.content_arrangement()
+            );
+        }
+        None => {
+            let _ = writeln!(
+                output,
+                "Table width: unknown ({:?})",
+                table.content_arrangement()
+            );
+        }
+    }
+
+    if let Some(conflict) = conflict {
+        let _ = writeln!(
+            output,
+            "Constraint conflict: columns require a width of {}, but the table width is {}",
+            conflict.required_width, conflict.table_width
+        );
+        let policy = table.constraint_conflict_policy();
+        if conflict.changed_columns.is_empty() {
+            let _ = writeln!(output, "  {policy:?}: all constraints are kept");
+        } else {
+            let columns: Vec<String> = conflict
+                .changed_columns
+                .iter()
+                .map(ToString::to_string)
+                .collect();
+            let _ = writeln!(
+                output,
+                "  {policy:?}: changed constraints of columns {}, now requires a width of {}",
+                columns.join(", "),
+                conflict.resolved_width
+            );
+        }
+    }
+
+    let _ = writeln!(output, "Columns:");
+    for (column, info) in table.columns.iter().zip(display_infos.iter()) {
+        let index = column.index;
+        let constraint = match column.constraint {
+            Some(constraint) => format!("{constraint:?}"),
+            None => "none".to_string(),
+        };
+
+        if info.is_hidden {
+            if column.is_hidden() {
+                let _ = writeln!(output, "  {index}: hidden (constraint: {constraint})");
+            } else {
+                let _ = writeln!(
+                    output,
+                    "  {index}: hidden (no space left for truncation priority {})",
+                    column.truncation_priority
+                );
+            }
+            continue;
+        }
+
+        let content_width = info.content_width;
+        let max_content_width = max_content_widths.get(index).copied().unwrap_or(0);
+        let _ = write!(
+            output,
+            "  {index}: width {} (content {content_width}, padding {}+{}), \
+             max content {max_content_width}, constraint: {constraint}",
+            info.width(),
+            info.padding.0,
+            info.padding.1,
+        );
+        if column.truncation_priority != 0 {
+            let _ = write!(
+                output,
+                ", truncation priority {}",
+                column.truncation_priority
+            );
+        }
+
+        // Explain why content doesn't fit into its column.
+        if content_width < max_content_width {
+            let reason = if column.constraint.is_some() {
+                "constraint"
+            } else {
+                "table width"
+            };
+            let _ = write!(output, " -> content is wrapped due to {reason}");
+        }
+        let _ = writeln!(output);
+    }
+
+    let spans = span_map(&table);
+    if !spans.is_empty() {
+        let _ = writeln!(output, "Spans:");
+        output += &spans;
+    }
+
+    // The height of rows may be limited as well, which truncates content.
+    let limited_rows: Vec<String> = table
+        .rows
+        .iter()
+        .enumerate()
+        .filter_map(|(index, row)| {
+            row.max_height.map(|height| {
+                let noun = if height == 1 { "line" } else { "lines" };
+                format!("  row {index}: at most {height} {noun}")
+            })
+        })
+        .collect();
+    if !limited_rows.is_empty() {
+        let _ = writeln!(output, "Truncated rows:");
+        for row in limited_rows {
+            let _ = writeln!(output, "{row}");
+        }
+    }
+
+    output.trim_end().to_string()
+}
+
+/// List the position of every cell that spans multiple columns or rows.
+///
+/// Rows are indexed including the header.
+fn span_map(table: &Table) -> String {
+    let mut output = String::new();
+    let mut span_tracker = SpanTracker::new();
+    let column_count = table.columns.len();
+
+    for (row_index, row) in table.header.iter().chain(table.rows.iter()).enumerate() {
+        let mut col_index = 0;
+        for cell in row.cells.iter() {
+            // Skip positions that are occupied by rowspans from rows above.
+            while col_index < column_count
+                && span_tracker.is_col_occupied_by_rowspan(row_index, col_index)
+            {
+                col_index += 1;
+            }
+
+            let colspan = cell.colspan();
+            let rowspan = cell.rowspan();
+            if colspan > 1 || rowspan > 1 {
+                let hidden_columns = table
+                    .columns
+                    .iter()
+                    .skip(col_index)
+                    .take(colspan.into())
+                    .filter(|column| matches!(column.constraint, Some(ColumnConstraint::Hidden)))
+                    .count();
+                let _ = write!(
+                    output,
+                    "  row {row_index}, column {col_index}: colspan {colspan}, rowspan {rowspan}"
+                );
+                if hidden_columns > 0 {
+                    let _ = write!(
+                        output,
+                        " ({hidden_columns} of {colspan} spanned columns hidden)"
+                    );
+                }
+                let _ = writeln!(output);
+            }
+            if rowspan > 1 {
+                span_tracker.register_rowspan(row_index, col_index, rowspan, colspan, None);
+            }
+            col_index += usize::from(colspan);
+        }
+        span_tracker.advance_row(row_index + 1);
+    }
+
+    output
+}
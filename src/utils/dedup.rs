@@ -0,0 +1,48 @@
+use std::borrow::Cow;
+
+use crate::table::Table;
+use crate::utils::numbers::cell_positions;
+
+/// Display cells of [deduplicated](crate::Column::set_dedup_display) columns, that repeat the
+/// content of the cell above them, as the dedup marker of their column.
+pub(crate) fn dedup_values(table: &mut Cow<'_, Table>) {
+    if !table.columns.iter().any(|column| column.dedup_display) {
+        return;
+    }
+
+    let body_rows = table.footer_start() - usize::from(table.header.is_some());
+    // The position of each repeated cell and the marker it's displayed as.
+    let mut repeated_cells = Vec::new();
+    for column in table.columns.iter().filter(|column| column.dedup_display) {
+        // The row index and the original content of the previous cell in this column.
+        let mut previous: Option<(usize, &[String])> = None;
+        for (row_index, cell_index) in cell_positions(table, column.index) {
+            if row_index >= body_rows {
+                break;
+            }
+            let cell = &table.rows[row_index].cells[cell_index];
+            if cell.colspan() > 1 || cell.rowspan() > 1 {
+                previous = None;
+                continue;
+            }
+
+            let repeated = previous.is_some_and(|(previous_row, content)| {
+                previous_row + 1 == row_index
+                    && !table.rows[previous_row].section_break
+                    && content == cell.content.as_slice()
+            });
+            if repeated && !cell.content.iter().all(String::is_empty) {
+                repeated_cells.push((row_index, cell_index, column.dedup_marker.clone()));
+            }
+            previous = Some((row_index, cell.content.as_slice()));
+        }
+    }
+
+    if repeated_cells.is_empty() {
+        return;
+    }
+    let deduped = table.to_mut();
+    for (row_index, cell_index, marker) in repeated_cells {
+        deduped.rows[row_index].cells[cell_index].content = vec![marker];
+    }
+}
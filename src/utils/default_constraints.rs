@@ -0,0 +1,42 @@
+use std::borrow::Cow;
+
+use crate::style::{ColumnConstraint, Width};
+use crate::table::Table;
+
+/// Let [collapsed](crate::Column::set_collapsed) columns be as wide as their placeholder.
+pub(crate) fn collapse_columns(table: &mut Cow<'_, Table>) {
+    if !table
+        .columns
+        .iter()
+        .any(|column| column.collapsed && !column.is_hidden())
+    {
+        return;
+    }
+
+    for column in table.to_mut().columns.iter_mut() {
+        if column.collapsed && !column.is_hidden() {
+            column.set_constraint(ColumnConstraint::ContentWidth);
+        }
+    }
+}
+
+/// Apply the [default maximum cell width](Table::set_default_max_cell_width) of a table to all
+/// columns without a constraint.
+pub(crate) fn apply_default_max_cell_width(table: &mut Cow<'_, Table>) {
+    let Some(width) = table.default_max_cell_width else {
+        return;
+    };
+    if table
+        .columns
+        .iter()
+        .all(|column| column.constraint.is_some())
+    {
+        return;
+    }
+
+    for column in table.to_mut().columns.iter_mut() {
+        if column.constraint.is_none() {
+            column.set_constraint(ColumnConstraint::UpperBoundary(Width::Fixed(width)));
+        }
+    }
+}
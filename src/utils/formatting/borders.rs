@@ -1,6 +1,17 @@
-use crate::style::TableComponent;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+use super::content_format::RowFormatter;
+use super::content_split::{char_width, is_printable_ascii, str_width};
+use crate::render::RenderEvent;
+use crate::row::Row;
+use crate::style::{
+    AmbiguousWidth, ColspanBorderPolicy, Section, Separator, SeparatorColumn, SeparatorKind,
+    TableComponent,
+};
 use crate::table::Table;
 use crate::utils::ColumnDisplayInfo;
+use crate::utils::arrangement::helper::count_border_columns;
 use crate::utils::spanning::SpanTracker;
 
 /// Information about a column's state at a horizontal border position.
@@ -27,6 +38,8 @@ struct ColumnBorderInfo {
     is_colspan_continuation: bool,
     /// Next row has a colspan at this position (for merge intersection)
     next_row_has_colspan: bool,
+    /// Next row has a vertical line at the left edge of this column (for split intersection)
+    next_row_has_border: bool,
 }
 
 /// Pre-compute border info for all columns at a given row separator.
@@ -34,8 +47,10 @@ fn compute_column_border_info(
     display_info: &[ColumnDisplayInfo],
     row_index: usize,
     span_tracker: &SpanTracker,
-    row_line: &[String],
-    next_row_line: Option<&[String]>,
+    row_line: &[Cow<'_, str>],
+    row_cells: Option<&[Option<bool>]>,
+    next_row_line: Option<&[Cow<'_, str>]>,
+    next_row_cells: Option<&[Option<bool>]>,
 ) -> Vec<ColumnBorderInfo> {
     let mut infos: Vec<ColumnBorderInfo> = Vec::with_capacity(display_info.len());
     let mut visible_col_index = 0;
@@ -43,7 +58,7 @@ fn compute_column_border_info(
     for (col_index, info) in display_info.iter().enumerate() {
         let mut col_info = ColumnBorderInfo {
             is_hidden: info.is_hidden,
-            width: info.width() as usize,
+            width: info.width(),
             ..Default::default()
         };
 
@@ -70,14 +85,24 @@ fn compute_column_border_info(
         }
 
         // Check if this column is a colspan continuation in the current row
-        if visible_col_index < row_line.len() {
+        if let Some(row_cells) = row_cells {
+            col_info.is_colspan_continuation = row_cells[col_index] == Some(false);
+        } else if visible_col_index < row_line.len() {
             col_info.is_colspan_continuation = row_line[visible_col_index].is_empty();
         }
 
         // Check if next row has colspan at this position
-        col_info.next_row_has_colspan = next_row_line
-            .map(|next| visible_col_index < next.len() && next[visible_col_index].is_empty())
-            .unwrap_or(false);
+        if let Some(next_row_cells) = next_row_cells {
+            col_info.next_row_has_colspan = next_row_cells[col_index] == Some(false);
+            col_info.next_row_has_border = next_row_cells[col_index] == Some(true);
+        } else {
+            col_info.next_row_has_colspan = next_row_line
+                .map(|next| visible_col_index < next.len() && next[visible_col_index].is_empty())
+                .unwrap_or(false);
+            col_info.next_row_has_border = next_row_line
+                .map(|next| visible_col_index < next.len() && !next[visible_col_index].is_empty())
+                .unwrap_or(false);
+        }
 
         infos.push(col_info);
         visible_col_index += 1;
@@ -86,6 +111,107 @@ fn compute_column_border_info(
     infos
 }
 
+/// Find the cells of the row above a horizontal line.
+///
+/// See [next_row_cells] for the meaning of the returned values.
+/// Columns that are covered by rowspans from previous rows are `None`,
+/// as their borders are drawn by the rowspan.
+fn row_cells(
+    table: &Table,
+    display_info: &[ColumnDisplayInfo],
+    row_index: usize,
+    span_tracker: &SpanTracker,
+) -> Option<Vec<Option<bool>>> {
+    let header_rows = usize::from(table.header.is_some());
+    let row = match row_index.checked_sub(header_rows) {
+        Some(index) => table.rows.get(index)?,
+        None => table.header.as_ref()?,
+    };
+
+    let mut cells = cell_starts(display_info, row, |col_index| {
+        span_tracker
+            .get_rowspan_including_row(row_index, col_index)
+            .filter(|(start_row, _, _)| *start_row < row_index)
+            .map(|(_, start_col, colspan)| (start_col, colspan))
+    });
+    for (index, cell) in cells.iter_mut().enumerate() {
+        if span_tracker
+            .get_rowspan_including_row(row_index, index)
+            .is_some_and(|(start_row, _, _)| start_row < row_index)
+        {
+            *cell = None;
+        }
+    }
+    Some(cells)
+}
+
+/// Find the cells of the row below a horizontal line.
+///
+/// For every column, this is `Some(true)` if a cell starts at its left edge,
+/// `Some(false)` if the column is spanned by a cell starting further left and
+/// `None` if there's no cell in the column at all.
+///
+/// The first line of the row can't tell this reliably, as a continuing rowspan is formatted as
+/// a single part, no matter how many columns it spans.
+fn next_row_cells(
+    table: &Table,
+    display_info: &[ColumnDisplayInfo],
+    row_index: usize,
+    span_tracker: &SpanTracker,
+) -> Option<Vec<Option<bool>>> {
+    let header_rows = usize::from(table.header.is_some());
+    // The rowspans of the header aren't registered yet, when the header line is drawn.
+    if row_index < header_rows {
+        return None;
+    }
+    let next_row = table.rows.get(row_index + 1 - header_rows)?;
+
+    Some(cell_starts(display_info, next_row, |col_index| {
+        span_tracker
+            .get_rowspan_start_at_row(row_index, col_index)
+            .map(|(_, start_col, colspan)| (start_col, colspan))
+    }))
+}
+
+/// Walk the cells of a row and mark the first visible column of each cell.
+///
+/// `rowspan_at` returns the start column and colspan of a rowspan from a previous row,
+/// that occupies a column.
+fn cell_starts(
+    display_info: &[ColumnDisplayInfo],
+    row: &Row,
+    rowspan_at: impl Fn(usize) -> Option<(usize, u16)>,
+) -> Vec<Option<bool>> {
+    let mut cells = vec![None; display_info.len()];
+    let mut row_cells = row.cells.iter();
+    let mut col_index = 0;
+    while col_index < display_info.len() {
+        let colspan = match rowspan_at(col_index) {
+            Some((start_col, colspan)) => start_col + usize::from(colspan) - col_index,
+            None => match row_cells.next() {
+                Some(cell) => usize::from(cell.colspan()),
+                None => break,
+            },
+        };
+        let end_col = (col_index + colspan.max(1)).min(display_info.len());
+
+        // A cell starts at the left edge of its first visible column.
+        let mut started = false;
+        for (index, info) in display_info
+            .iter()
+            .enumerate()
+            .take(end_col)
+            .skip(col_index)
+        {
+            cells[index] = Some(!started && !info.is_hidden);
+            started |= !info.is_hidden;
+        }
+        col_index = end_col;
+    }
+
+    cells
+}
+
 /// Determine which intersection character to use based on context.
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum IntersectionType {
@@ -93,7 +219,7 @@ enum IntersectionType {
     Normal,
     /// Merge intersection for colspan below (╧ or ┴)
     Merge,
-    /// Left border intersection after rowspan (├)
+    /// Intersection where a line starts after a continuing rowspan (├)
     LeftBorderAfterRowspan,
 }
 
@@ -111,151 +237,382 @@ fn select_intersection_type(
     }
 }
 
-pub(crate) fn draw_borders(
-    table: &Table,
-    rows: &[Vec<Vec<String>>],
-    display_info: &[ColumnDisplayInfo],
-) -> Vec<String> {
-    // We know how many lines there should be. Initialize the vector with the rough correct amount.
-    // We might over allocate a bit, but that's better than under allocating.
-    let mut lines = if let Some(capacity) = rows.first().map(|lines| lines.len()) {
-        // Lines * 2 -> Lines + delimiters
-        // + 5 -> header delimiters + header + bottom/top borders
-        Vec::with_capacity(capacity * 2 + 5)
-    } else {
-        Vec::new()
-    };
+/// The formatted rows of a table, which are requested one after another while its lines are
+/// drawn.
+pub(crate) trait RowSource<'s> {
+    /// The lines of the next row, each consisting of the formatted part of each visible column.
+    fn next_row(&mut self, table: &Table) -> Option<Vec<Vec<Cow<'s, str>>>>;
+}
 
-    // Build span information for border drawing
-    let mut span_tracker = SpanTracker::new();
-    let header_rows = if table.header.is_some() { 1 } else { 0 };
+impl RowSource<'static> for RowFormatter {
+    fn next_row(&mut self, table: &Table) -> Option<Vec<Vec<Cow<'static, str>>>> {
+        RowFormatter::next_row(self, table)
+    }
+}
+
+/// Rows that are formatted independently of the drawing, e.g. via
+/// [format_rows](crate::render::format_rows).
+pub(crate) struct FormattedRows<I>(pub(crate) I);
 
-    if should_draw_top_border(table) {
-        lines.push(draw_top_border(table, display_info));
+impl<'s, I: Iterator<Item = Vec<Vec<Cow<'s, str>>>>> RowSource<'s> for FormattedRows<I> {
+    fn next_row(&mut self, _table: &Table) -> Option<Vec<Vec<Cow<'s, str>>>> {
+        self.0.next()
     }
+}
 
-    draw_rows(
-        &mut lines,
-        rows,
+/// Surround the formatted content of a table with borders.
+///
+/// The lines are drawn lazily, row by row, while the returned iterator is consumed.
+/// Each row is only requested from `rows`, once the row above it is drawn.
+pub(crate) fn draw_borders<'a, 's, S: RowSource<'s>>(
+    table: Cow<'a, Table>,
+    rows: S,
+    display_info: Vec<ColumnDisplayInfo>,
+) -> BorderedLines<'a, 's, S> {
+    let mut table = table;
+    let header_rows = if table.header.is_some() { 1 } else { 0 };
+    let footer_start = table.footer_start();
+    let section_styles = if table.section_styles.is_empty() {
+        None
+    } else {
+        Some(section_styles(table.to_mut(), footer_start))
+    };
+    let has_spans = table
+        .header
+        .iter()
+        .chain(table.rows.iter())
+        .flat_map(|row| row.cells.iter())
+        .any(|cell| cell.colspan() > 1 || cell.rowspan() > 1);
+    let measure = LineMeasure::new(&table);
+    let base_style = section_styles.as_ref().map(|_| table.style.clone());
+    BorderedLines {
         table,
+        rows,
+        next_row: None,
+        base_style,
         display_info,
-        &mut span_tracker,
+        has_spans,
+        separator: None,
+        span_tracker: SpanTracker::new(),
         header_rows,
-    );
-
-    if should_draw_bottom_border(table) {
-        // Get the last row's first line to detect colspan for bottom border
-        let last_row_line = rows
-            .last()
-            .and_then(|row| row.first().map(|line| line.as_slice()));
-        // Calculate the last row index for rowspan detection
-        let last_row_index = if rows.is_empty() { 0 } else { rows.len() - 1 };
-        lines.push(draw_bottom_border(
-            table,
-            display_info,
-            last_row_line,
-            &span_tracker,
-            last_row_index,
-        ));
+        footer_start,
+        section_styles,
+        section: None,
+        row_index: 0,
+        last_row_line: None,
+        pending: VecDeque::new(),
+        started: false,
+        finished: false,
+        bottom_border: true,
+        measure,
+        line_width: 0,
     }
+}
 
-    lines
+/// An iterator over all lines of a table, as returned by [draw_borders].
+pub(crate) struct BorderedLines<'a, 's, S: RowSource<'s>> {
+    table: Cow<'a, Table>,
+    rows: S,
+    /// The row below the one that's drawn, which determines the line between both.
+    next_row: Option<Vec<Vec<Cow<'s, str>>>>,
+    /// The style of the whole table, while the style of a section is used to draw its lines.
+    /// Rows are always formatted with the style of the whole table.
+    base_style: Option<HashMap<TableComponent, char>>,
+    display_info: Vec<ColumnDisplayInfo>,
+    /// Whether any cell spans multiple columns or rows.
+    /// Tables without spans take a fast path, which doesn't need to track any span information.
+    has_spans: bool,
+    /// The horizontal line between rows of a table without spans, as it's the same for all rows.
+    separator: Option<String>,
+    /// Span information for border drawing
+    span_tracker: SpanTracker,
+    header_rows: usize,
+    /// The index of the first footer row, including the header.
+    footer_start: usize,
+    /// The style of each section, if any section has a style of its own.
+    section_styles: Option<BTreeMap<Section, HashMap<TableComponent, char>>>,
+    /// The section, whose style is currently used to draw lines.
+    section: Option<Section>,
+    /// The index of the next row, including the header.
+    row_index: usize,
+    /// The first line of the previous row, which is needed to detect colspans in the bottom border.
+    last_row_line: Option<Vec<Cow<'s, str>>>,
+    /// Lines that have been drawn, but haven't been returned yet.
+    pending: VecDeque<String>,
+    started: bool,
+    finished: bool,
+    /// Whether the bottom border is drawn after the last row, if the style has one.
+    bottom_border: bool,
+    measure: LineMeasure,
+    /// The display width of all lines, shorter lines are padded to it.
+    line_width: usize,
 }
 
-/// Build a map of which columns are colspan continuations (not the first column of a cell).
-/// Returns (continuation_map, all_cells_have_colspan).
-fn build_colspan_continuation_map(
-    row_cells: Option<&crate::row::Row>,
-    num_columns: usize,
-) -> (Vec<bool>, bool) {
-    let mut continuation: Vec<bool> = vec![false; num_columns];
-    let mut all_have_colspan = true;
+impl<'s, S: RowSource<'s>> Iterator for BorderedLines<'_, 's, S> {
+    type Item = String;
 
-    if let Some(row) = row_cells {
-        let mut col_index = 0;
-        for cell in &row.cells {
-            let colspan = cell.colspan() as usize;
-            if colspan == 1 {
-                all_have_colspan = false;
+    fn next(&mut self) -> Option<String> {
+        loop {
+            if let Some(line) = self.pending.pop_front() {
+                let width = self.measure.width(&line);
+                return Some(pad_line(line, width, self.line_width));
             }
-            for i in 1..colspan {
-                if col_index + i < continuation.len() {
-                    continuation[col_index + i] = true;
+            if self.finished {
+                return None;
+            }
+
+            if !self.started {
+                self.started = true;
+                self.enter_section(0);
+                self.line_width = line_width(&self.table, &self.display_info);
+                self.next_row = self.fetch_row();
+                if should_draw_top_border(&self.table) {
+                    let line = draw_top_border(&self.table, &self.display_info);
+                    let position = SeparatorPosition {
+                        kind: SeparatorKind::Top,
+                        row_above: None,
+                        above: None,
+                        below: self
+                            .next_row
+                            .as_ref()
+                            .and_then(|row| row.first())
+                            .map(Vec::as_slice),
+                    };
+                    self.pending.push_back(render_separator(
+                        &self.table,
+                        &self.display_info,
+                        &self.span_tracker,
+                        position,
+                        line,
+                    ));
+                }
+                continue;
+            }
+
+            match self.take_row() {
+                Some(row) if self.has_spans => self.draw_row(row),
+                Some(row) => self.draw_plain_row(row),
+                None => {
+                    self.finished = true;
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!(rows = self.row_index, "Drew all rows");
+                    if !self.bottom_border || !should_draw_bottom_border(&self.table) {
+                        continue;
+                    }
+                    let line = if !self.has_spans {
+                        let table: &Table = &self.table;
+                        draw_plain_line(
+                            table,
+                            &self.display_info,
+                            &table.style_or_default(TableComponent::BottomLeftCorner),
+                            &table.style_or_default(TableComponent::BottomBorder),
+                            &table.style_or_default(TableComponent::BottomBorderIntersections),
+                            &table.style_or_default(TableComponent::BottomRightCorner),
+                        )
+                    } else {
+                        // Calculate the last row index for rowspan detection
+                        let last_row_index = self.row_index.saturating_sub(1);
+                        draw_bottom_border(
+                            &self.table,
+                            &self.display_info,
+                            self.last_row_line.as_deref(),
+                            &self.span_tracker,
+                            last_row_index,
+                        )
+                    };
+                    let position = SeparatorPosition {
+                        kind: SeparatorKind::Bottom,
+                        row_above: self.row_index.checked_sub(1),
+                        above: self.last_row_line.as_deref(),
+                        below: None,
+                    };
+                    self.pending.push_back(render_separator(
+                        &self.table,
+                        &self.display_info,
+                        &self.span_tracker,
+                        position,
+                        line,
+                    ));
                 }
             }
-            col_index += colspan;
         }
-    } else {
-        all_have_colspan = false;
     }
-
-    (continuation, all_have_colspan)
 }
 
-fn draw_top_border(table: &Table, display_info: &[ColumnDisplayInfo]) -> String {
-    let left_corner = table.style_or_default(TableComponent::TopLeftCorner);
-    let top_border = table.style_or_default(TableComponent::TopBorder);
-    let intersection = table.style_or_default(TableComponent::TopBorderIntersections);
-    let right_corner = table.style_or_default(TableComponent::TopRightCorner);
+impl<'s, S: RowSource<'s>> BorderedLines<'_, 's, S> {
+    /// Stop after the last row, e.g. to draw the header of a table, whose rows follow later.
+    pub(crate) fn without_bottom_border(mut self) -> Self {
+        self.bottom_border = false;
+        self
+    }
 
-    let (header_colspan_continuation, all_header_cells_have_colspan) =
-        build_colspan_continuation_map(table.header.as_ref(), display_info.len());
+    /// Request the next row from the source of rows.
+    fn fetch_row(&mut self) -> Option<Vec<Vec<Cow<'s, str>>>> {
+        let Some(base_style) = self.base_style.as_mut() else {
+            return self.rows.next_row(&self.table);
+        };
+        let table = self.table.to_mut();
+        std::mem::swap(&mut table.style, base_style);
+        let row = self.rows.next_row(table);
+        std::mem::swap(&mut table.style, base_style);
+        row
+    }
 
-    // Merge header colspans in top border unless:
-    // - All header cells have colspan > 1 (suggests header doesn't define column structure)
-    // - Dynamic content arrangement (column widths are calculated dynamically)
-    let is_dynamic = matches!(
-        table.content_arrangement(),
-        crate::ContentArrangement::Dynamic | crate::ContentArrangement::DynamicFullWidth
-    );
-    let should_merge_header_colspan = !all_header_cells_have_colspan && !is_dynamic;
+    /// Take the next row to draw it, and request the one below it.
+    fn take_row(&mut self) -> Option<Vec<Vec<Cow<'s, str>>>> {
+        let row = self.next_row.take();
+        if row.is_some() {
+            self.next_row = self.fetch_row();
+        }
+        row
+    }
 
-    let mut line = String::new();
-    // We only need the top left corner, if we need to draw a left border
-    if should_draw_left_border(table) {
-        line += &left_corner;
+    /// Use the style of the section of a row to draw the following lines.
+    fn enter_section(&mut self, row_index: usize) {
+        let Some(styles) = &self.section_styles else {
+            return;
+        };
+        let section = if row_index < self.header_rows {
+            Section::Header
+        } else if row_index >= self.footer_start {
+            Section::Footer
+        } else {
+            Section::Body
+        };
+        if self.section != Some(section) {
+            self.section = Some(section);
+            self.table.to_mut().style = styles[&section].clone();
+            // The cached line between rows has been drawn in the style of another section.
+            self.separator = None;
+        }
     }
 
-    // Build the top border line. Merge where header has colspan (unless all cells have colspan).
-    let mut first = true;
-    for (col_index, info) in display_info.iter().enumerate() {
-        if !info.is_hidden {
-            if !first {
-                // Check if header has colspan at this position
-                let header_has_colspan = col_index < header_colspan_continuation.len()
-                    && header_colspan_continuation[col_index];
+    /// The line above the footer belongs to the footer.
+    fn enter_separator_section(&mut self, row_index: usize) {
+        if row_index >= self.header_rows
+            && row_index + 1 == self.footer_start
+            && self.next_row.as_ref().is_some()
+        {
+            self.enter_section(row_index + 1);
+        }
+    }
 
-                if should_merge_header_colspan && header_has_colspan {
-                    // Use top_border to continue the line (merge)
-                    line += &top_border;
-                } else {
-                    line += &intersection;
-                }
-            }
-            line += &top_border.repeat(info.width().into());
-            first = false;
+    /// The kind of line that's drawn below a data row, if the next row is separated by a line.
+    fn line_below(&self, row_index: usize) -> Option<RowLine> {
+        let table: &Table = &self.table;
+        let section_break = row_index
+            .checked_sub(self.header_rows)
+            .and_then(|index| table.rows.get(index))
+            .is_some_and(|row| row.section_break);
+        let footer = row_index >= self.header_rows && row_index + 1 == self.footer_start;
+
+        if section_break
+            && (should_draw_header(table) || table.style_exists(TableComponent::SectionSeparator))
+        {
+            Some(RowLine::Section)
+        } else if footer && table.style_exists(TableComponent::FooterLines) {
+            Some(RowLine::Footer)
+        } else if should_draw_horizontal_lines(table) {
+            Some(RowLine::Row)
+        } else {
+            None
         }
     }
 
-    // We only need the top right corner, if we need to draw a right border
-    if should_draw_right_border(table) {
-        line += &right_corner;
+    /// Draw all lines of a single row of a table without spans.
+    fn draw_plain_row(&mut self, row: Vec<Vec<Cow<'s, str>>>) {
+        let row_index = self.row_index;
+        self.row_index += 1;
+        self.enter_section(row_index);
+        let table: &Table = &self.table;
+        table.emit(RenderEvent::RowStart { row_index });
+
+        for line_parts in row.iter() {
+            self.pending
+                .push_back(embed_line(line_parts, table, row_index, &self.span_tracker));
+        }
+
+        self.enter_separator_section(row_index);
+        let line_below = self.line_below(row_index);
+        let table: &Table = &self.table;
+
+        if row_index == 0 && table.header.is_some() {
+            if should_draw_header(table) {
+                let styles = BorderStyles::for_row(table, true);
+                let line = draw_plain_line(
+                    table,
+                    &self.display_info,
+                    &styles.left_intersection,
+                    &styles.horizontal,
+                    &styles.middle_intersection,
+                    &styles.right_intersection,
+                );
+                let position = SeparatorPosition {
+                    kind: SeparatorKind::Header,
+                    row_above: Some(row_index),
+                    above: row.first().map(Vec::as_slice),
+                    below: self
+                        .next_row
+                        .as_ref()
+                        .and_then(|row| row.first())
+                        .map(Vec::as_slice),
+                };
+                self.pending.push_back(render_separator(
+                    table,
+                    &self.display_info,
+                    &self.span_tracker,
+                    position,
+                    line,
+                ));
+            }
+        } else if let Some(line_kind) = line_below.filter(|_| self.next_row.as_ref().is_some()) {
+            let draw_line = || {
+                let styles = BorderStyles::for_line(table, line_kind);
+                draw_plain_line(
+                    table,
+                    &self.display_info,
+                    &styles.left_intersection,
+                    &styles.horizontal,
+                    &styles.middle_intersection,
+                    &styles.right_intersection,
+                )
+            };
+            // Only regular lines between rows are the same for all rows.
+            let separator = match line_kind {
+                RowLine::Row => self.separator.get_or_insert_with(draw_line).clone(),
+                _ => draw_line(),
+            };
+            let position = SeparatorPosition {
+                kind: SeparatorKind::Row,
+                row_above: Some(row_index),
+                above: row.first().map(Vec::as_slice),
+                below: self
+                    .next_row
+                    .as_ref()
+                    .and_then(|row| row.first())
+                    .map(Vec::as_slice),
+            };
+            self.pending.push_back(render_separator(
+                table,
+                &self.display_info,
+                &self.span_tracker,
+                position,
+                separator,
+            ));
+        }
+
+        self.last_row_line = row.into_iter().next();
     }
 
-    line
-}
+    /// Draw all lines of a single row, including the horizontal line below it.
+    fn draw_row(&mut self, row: Vec<Vec<Cow<'s, str>>>) {
+        let header_rows = self.header_rows;
+        let row_index = self.row_index;
+        self.row_index += 1;
+        self.enter_section(row_index);
+        let table: &Table = &self.table;
+        table.emit(RenderEvent::RowStart { row_index });
 
-fn draw_rows(
-    lines: &mut Vec<String>,
-    rows: &[Vec<Vec<String>>],
-    table: &Table,
-    display_info: &[ColumnDisplayInfo],
-    span_tracker: &mut SpanTracker,
-    header_rows: usize,
-) {
-    // Iterate over all rows
-    let mut row_iter = rows.iter().enumerate().peekable();
-    while let Some((row_index, row)) = row_iter.next() {
         let actual_row_index = if row_index < header_rows {
             row_index
         } else {
@@ -264,31 +621,51 @@ fn draw_rows(
 
         // Concatenate the line parts and insert the vertical borders if needed
         for line_parts in row.iter() {
-            lines.push(embed_line(
+            self.pending.push_back(embed_line(
                 line_parts,
                 table,
                 actual_row_index,
-                span_tracker,
+                &self.span_tracker,
             ));
         }
 
-        // Draw the horizontal header line if desired, otherwise continue to the next iteration
+        self.enter_separator_section(row_index);
+        let line_below = self.line_below(row_index);
+        let table: &Table = &self.table;
+        let display_info = &self.display_info;
+        let span_tracker = &mut self.span_tracker;
+
+        // Draw the horizontal header line if desired, otherwise continue to the next row
         if row_index == 0 && table.header.is_some() {
             if should_draw_header(table) {
                 // Header separator should match the header content width (widest line)
                 // Draw all physical columns separately (like top border)
                 // Get next row's first line to detect colspan transitions
-                let next_row_line = row_iter
-                    .peek()
-                    .and_then(|(_, next_row)| next_row.first().map(|line| line.as_slice()));
-                lines.push(draw_horizontal_lines(
+                let next_row_line = self
+                    .next_row
+                    .as_ref()
+                    .and_then(|next_row| next_row.first().map(|line| line.as_slice()));
+                let line = draw_horizontal_lines(
                     table,
                     display_info,
-                    true,
+                    RowLine::Header,
                     0,
                     span_tracker,
                     row.first().map(|line| line.as_slice()).unwrap_or(&[]),
                     next_row_line,
+                );
+                let position = SeparatorPosition {
+                    kind: SeparatorKind::Header,
+                    row_above: Some(0),
+                    above: row.first().map(Vec::as_slice),
+                    below: next_row_line,
+                };
+                self.pending.push_back(render_separator(
+                    table,
+                    display_info,
+                    span_tracker,
+                    position,
+                    line,
                 ));
             }
             // Register rowspans from header for border drawing (we only need position info, not content)
@@ -302,14 +679,14 @@ fn draw_rows(
                             cell.rowspan(),
                             cell.colspan(),
                             None,
-                            crate::style::VerticalAlignment::Top,
                         );
                     }
                     col_index += cell.colspan() as usize;
                 }
             }
             span_tracker.advance_row(1);
-            continue;
+            self.last_row_line = row.into_iter().next();
+            return;
         }
 
         // Register rowspans from data rows for border drawing
@@ -334,7 +711,6 @@ fn draw_rows(
                         cell.rowspan(),
                         cell.colspan(),
                         None,
-                        crate::style::VerticalAlignment::Top,
                     );
                 }
                 col_index += cell.colspan() as usize;
@@ -344,34 +720,180 @@ fn draw_rows(
         // Draw a horizontal line, if we desired and if we aren't in the last row of the table.
         // When drawing the border after a row, we need to check for rowspans that continue into the next row.
         // So we check at the current row_index (the row we just processed).
-        if let Some(next_row) = row_iter.peek() {
-            if should_draw_horizontal_lines(table) {
+        if let Some(next_row) = self.next_row.as_ref() {
+            if let Some(line_kind) = line_below {
                 // Draw all physical columns separately (like top border), not based on row structure
                 let border_line = row.first().map(|line| line.as_slice()).unwrap_or(&[]);
                 // Get next row's first line to detect colspan transitions
-                let next_row_line = next_row.1.first().map(|line| line.as_slice());
+                let next_row_line = next_row.first().map(|line| line.as_slice());
                 // Check for rowspans at the current row_index (row we just processed)
                 // Rowspans that started at this row or earlier and still have remaining_rows should skip borders
-                lines.push(draw_horizontal_lines(
+                let line = draw_horizontal_lines(
                     table,
                     display_info,
-                    false,
+                    line_kind,
                     actual_row_index + header_rows,
                     span_tracker,
                     border_line,
                     next_row_line,
+                );
+                let position = SeparatorPosition {
+                    kind: SeparatorKind::Row,
+                    row_above: Some(row_index),
+                    above: Some(border_line),
+                    below: next_row_line,
+                };
+                self.pending.push_back(render_separator(
+                    table,
+                    display_info,
+                    span_tracker,
+                    position,
+                    line,
                 ));
             }
         }
 
         span_tracker.advance_row(actual_row_index + header_rows + 1);
+        self.last_row_line = row.into_iter().next();
+    }
+}
+
+/// Determine the style of each section of the table, that contains rows.
+///
+/// Vertical borders and lines, that are drawn in any section, are drawn as spaces in all other
+/// sections, so all lines have the same width.
+fn section_styles(
+    table: &mut Table,
+    footer_start: usize,
+) -> BTreeMap<Section, HashMap<TableComponent, char>> {
+    let row_count = usize::from(table.header.is_some()) + table.rows.len();
+    let mut sections = Vec::new();
+    if table.header.is_some() {
+        sections.push(Section::Header);
+    }
+    if footer_start > usize::from(table.header.is_some()) || row_count == 0 {
+        sections.push(Section::Body);
+    }
+    if footer_start < row_count {
+        sections.push(Section::Footer);
+    }
+
+    let table_style = table.style.clone();
+    let mut styles: BTreeMap<_, _> = sections
+        .into_iter()
+        .map(|section| (section, table.section_style(section).clone()))
+        .collect();
+    let checks = [
+        (
+            should_draw_left_border as fn(&Table) -> bool,
+            TableComponent::LeftBorder,
+        ),
+        (should_draw_right_border, TableComponent::RightBorder),
+        (should_draw_vertical_lines, TableComponent::VerticalLines),
+    ];
+    for (should_draw, component) in checks {
+        let drawn = styles.values().any(|style| {
+            table.style = style.clone();
+            should_draw(table)
+        });
+        if drawn {
+            for style in styles.values_mut() {
+                style.entry(component).or_insert(' ');
+            }
+        }
+    }
+    table.style = table_style;
+
+    styles
+}
+
+/// Build a map of which columns are colspan continuations (not the first column of a cell).
+/// Returns (continuation_map, all_cells_have_colspan).
+fn build_colspan_continuation_map(
+    row_cells: Option<&crate::row::Row>,
+    num_columns: usize,
+) -> (Vec<bool>, bool) {
+    let mut continuation: Vec<bool> = vec![false; num_columns];
+    let mut all_have_colspan = true;
+
+    if let Some(row) = row_cells {
+        let mut col_index = 0;
+        for cell in &row.cells {
+            let colspan = cell.colspan() as usize;
+            if colspan == 1 {
+                all_have_colspan = false;
+            }
+            for i in 1..colspan {
+                if col_index + i < continuation.len() {
+                    continuation[col_index + i] = true;
+                }
+            }
+            col_index += colspan;
+        }
+    } else {
+        all_have_colspan = false;
     }
+
+    (continuation, all_have_colspan)
+}
+
+fn draw_top_border(table: &Table, display_info: &[ColumnDisplayInfo]) -> String {
+    let left_corner = table.style_or_default(TableComponent::TopLeftCorner);
+    let top_border = table.style_or_default(TableComponent::TopBorder);
+    let intersection = table.style_or_default(TableComponent::TopBorderIntersections);
+    let right_corner = table.style_or_default(TableComponent::TopRightCorner);
+    let merge_intersection = table.style_or_fallback(TableComponent::TopBorderColspanIntersections);
+
+    let (header_colspan_continuation, all_header_cells_have_colspan) =
+        build_colspan_continuation_map(table.header.as_ref(), display_info.len());
+
+    // Merge header colspans in top border unless:
+    // - All header cells have colspan > 1 (suggests header doesn't define column structure)
+    // - Dynamic content arrangement (column widths are calculated dynamically)
+    let is_dynamic = matches!(
+        table.content_arrangement(),
+        crate::ContentArrangement::Dynamic | crate::ContentArrangement::DynamicFullWidth
+    );
+    let should_merge_header_colspan = !all_header_cells_have_colspan && !is_dynamic;
+
+    let mut line = String::new();
+    // We only need the top left corner, if we need to draw a left border
+    if should_draw_left_border(table) {
+        line += &left_corner;
+    }
+
+    // Build the top border line. Merge where header has colspan (unless all cells have colspan).
+    let mut first = true;
+    for (col_index, info) in display_info.iter().enumerate() {
+        if !info.is_hidden {
+            if !first {
+                // Check if header has colspan at this position
+                let header_has_colspan = col_index < header_colspan_continuation.len()
+                    && header_colspan_continuation[col_index];
+
+                if should_merge_header_colspan && header_has_colspan {
+                    line += &merge_intersection;
+                } else {
+                    line += &intersection;
+                }
+            }
+            line += &top_border.repeat(info.width());
+            first = false;
+        }
+    }
+
+    // We only need the top right corner, if we need to draw a right border
+    if should_draw_right_border(table) {
+        line += &right_corner;
+    }
+
+    line
 }
 
 // Takes the parts of a single line, surrounds them with borders and adds vertical lines.
 // Skips vertical borders within colspan cells (detected by empty strings).
 fn embed_line(
-    line_parts: &[String],
+    line_parts: &[Cow<'_, str>],
     table: &Table,
     _row_index: usize,
     _span_tracker: &SpanTracker,
@@ -410,23 +932,29 @@ fn embed_line(
 fn draw_horizontal_lines(
     table: &Table,
     display_info: &[ColumnDisplayInfo],
-    header: bool,
+    line_kind: RowLine,
     row_index: usize,
     span_tracker: &SpanTracker,
-    row_line: &[String],
-    next_row_line: Option<&[String]>,
+    row_line: &[Cow<'_, str>],
+    next_row_line: Option<&[Cow<'_, str>]>,
 ) -> String {
     // Pre-compute border info for all columns
+    let row_cells = row_cells(table, display_info, row_index, span_tracker);
+    let next_row_cells =
+        next_row_line.and(next_row_cells(table, display_info, row_index, span_tracker));
     let column_infos = compute_column_border_info(
         display_info,
         row_index,
         span_tracker,
         row_line,
+        row_cells.as_deref(),
         next_row_line,
+        next_row_cells.as_deref(),
     );
 
-    // Get style characters based on header vs data row
-    let styles = BorderStyles::for_row(table, header);
+    // Get style characters based on the kind of line
+    let styles = BorderStyles::for_line(table, line_kind);
+    let header = line_kind == RowLine::Header;
 
     let mut line = String::new();
     let mut previous_was_rowspan = false;
@@ -463,10 +991,19 @@ fn draw_horizontal_lines(
             let start_col = col
                 .rowspan_start_col
                 .expect("rowspan_start_col must be Some when is_rowspan_continuing is true");
+            if !first {
+                // The line either ends at the rowspan or runs between two rowspans.
+                if previous_was_rowspan {
+                    line += &styles.vertical;
+                } else {
+                    line += &styles.rowspan_right_intersection;
+                }
+            }
             let (spaces, cols_consumed) =
-                draw_rowspan_space(display_info, start_col, col.rowspan_colspan);
+                draw_rowspan_space(table, display_info, start_col, col.rowspan_colspan);
             line += &spaces;
-            col_idx += cols_consumed;
+            // The rowspan may start in a hidden column left of this one.
+            col_idx = start_col + cols_consumed;
             first = false;
             previous_was_rowspan = true;
             continue;
@@ -489,7 +1026,7 @@ fn draw_horizontal_lines(
                 header,
             );
             line += &border;
-            col_idx += cols_consumed;
+            col_idx = start_col + cols_consumed;
             first = false;
             previous_was_rowspan = false;
             continue;
@@ -505,7 +1042,7 @@ fn draw_horizontal_lines(
         // Case 4: Normal column or colspan start - draw intersection + border
         // First, count how many following columns are colspan continuations
         let mut colspan_count = 1;
-        let mut total_width = col.width;
+        let mut merged = styles.horizontal.repeat(col.width);
         while col_idx + colspan_count < column_infos.len() {
             let next = &column_infos[col_idx + colspan_count];
             if next.is_hidden {
@@ -516,7 +1053,13 @@ fn draw_horizontal_lines(
                 && !next.is_rowspan_continuing
                 && !next.is_rowspan_ending
             {
-                total_width += 1 + next.width; // +1 for merged separator
+                // The columns may be separated in the row below.
+                if next_row_line.is_some() && !next.next_row_has_colspan {
+                    merged += &styles.split_intersection;
+                } else {
+                    merged += &styles.horizontal;
+                }
+                merged += &styles.horizontal.repeat(next.width);
                 colspan_count += 1;
             } else {
                 break;
@@ -531,7 +1074,7 @@ fn draw_horizontal_lines(
         }
 
         // Draw the border
-        line += &styles.horizontal.repeat(total_width);
+        line += &merged;
         col_idx += colspan_count;
         first = false;
         previous_was_rowspan = false;
@@ -539,48 +1082,241 @@ fn draw_horizontal_lines(
 
     // Draw right border/intersection
     if should_draw_right_border(table) {
-        line += &styles.right_intersection;
+        if !header && previous_was_rowspan {
+            line += &styles.right_border;
+        } else {
+            line += &styles.right_intersection;
+        }
+    }
+
+    line
+}
+
+/// Where a horizontal line is drawn, relative to the rows around it.
+#[derive(Clone, Copy)]
+struct SeparatorPosition<'a> {
+    kind: SeparatorKind,
+    /// The index of the row above the line, including the header.
+    row_above: Option<usize>,
+    /// The first line of the row above the line.
+    above: Option<&'a [Cow<'a, str>]>,
+    /// The first line of the row below the line.
+    below: Option<&'a [Cow<'a, str>]>,
+}
+
+/// Pass a drawn horizontal line through the table's [BorderRenderer](crate::BorderRenderer),
+/// if it has one.
+fn render_separator(
+    table: &Table,
+    display_info: &[ColumnDisplayInfo],
+    span_tracker: &SpanTracker,
+    position: SeparatorPosition<'_>,
+    line: String,
+) -> String {
+    let line = match table.border_renderer.as_ref() {
+        Some(renderer) => {
+            let separator = separator_layout(table, display_info, span_tracker, position, &line);
+            renderer.draw_separator(&separator)
+        }
+        None => line,
+    };
+
+    table.emit(RenderEvent::SeparatorDrawn {
+        kind: position.kind,
+        line: &line,
+    });
+
+    line
+}
+
+/// Describe the layout of a horizontal line for a [BorderRenderer](crate::BorderRenderer).
+fn separator_layout<'a>(
+    table: &'a Table,
+    display_info: &[ColumnDisplayInfo],
+    span_tracker: &SpanTracker,
+    position: SeparatorPosition<'_>,
+    line: &'a str,
+) -> Separator<'a> {
+    // Empty parts of a line mark columns that are covered by a colspan.
+    let is_joined = |parts: Option<&[Cow<'_, str>]>, visible_index: usize| {
+        parts
+            .and_then(|parts| parts.get(visible_index))
+            .is_some_and(|part| part.is_empty())
+    };
+
+    let columns = display_info
+        .iter()
+        .enumerate()
+        .filter(|(_, info)| !info.is_hidden)
+        .enumerate()
+        .map(|(visible_index, (col_index, info))| {
+            let rowspan = matches!(position.kind, SeparatorKind::Header | SeparatorKind::Row)
+                && position.row_above.is_some_and(|row_index| {
+                    span_tracker
+                        .get_rowspan_start_at_row(row_index, col_index)
+                        .is_some()
+                });
+            SeparatorColumn {
+                width: info.width(),
+                joined_above: is_joined(position.above, visible_index),
+                joined_below: is_joined(position.below, visible_index),
+                rowspan,
+            }
+        })
+        .collect();
+
+    Separator {
+        table,
+        kind: position.kind,
+        row_above: position.row_above,
+        columns,
+        left_border: should_draw_left_border(table),
+        right_border: should_draw_right_border(table),
+        default_line: line,
+    }
+}
+
+/// Draw a horizontal line for a table without any spans.
+///
+/// Without spans, every column is separated by the same intersection, so none of the span
+/// information that's needed by [draw_horizontal_lines] has to be computed.
+fn draw_plain_line(
+    table: &Table,
+    display_info: &[ColumnDisplayInfo],
+    left: &str,
+    horizontal: &str,
+    intersection: &str,
+    right: &str,
+) -> String {
+    let mut line = String::new();
+    if should_draw_left_border(table) {
+        line += left;
+    }
+
+    let mut first = true;
+    for info in display_info.iter().filter(|info| !info.is_hidden) {
+        if !first {
+            line += intersection;
+        }
+        line += &horizontal.repeat(info.width());
+        first = false;
+    }
+
+    if should_draw_right_border(table) {
+        line += right;
     }
 
     line
 }
 
+/// The lines that meet at a junction of a horizontal line.
+#[derive(Debug, Clone, Copy)]
+struct Junction {
+    /// A vertical line ends at the junction from the row above.
+    up: bool,
+    /// A vertical line starts at the junction in the row below.
+    down: bool,
+    /// The horizontal line continues left of the junction.
+    /// It doesn't, if a rowspan continues there.
+    left: bool,
+    /// The horizontal line continues right of the junction.
+    right: bool,
+}
+
+/// The kind of a horizontal line between two rows, which determines its characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RowLine {
+    /// The line between the header and the first row.
+    Header,
+    /// A regular line between two rows.
+    Row,
+    /// The line between the body and the footer.
+    Footer,
+    /// The line below a row with a [section break](Table::add_section_break).
+    Section,
+}
+
 /// Style characters for border drawing
 struct BorderStyles {
     left_intersection: String,
     left_border: String,
+    right_border: String,
+    vertical: String,
     horizontal: String,
     middle_intersection: String,
     merge_intersection: String,
-    left_border_intersection: String,
+    /// Used below a colspan, where the columns are separated in the row below.
+    split_intersection: String,
+    rowspan_left_intersection: String,
+    rowspan_right_intersection: String,
     right_intersection: String,
 }
 
 impl BorderStyles {
+    fn for_line(table: &Table, line: RowLine) -> Self {
+        match line {
+            RowLine::Header => Self::for_row(table, true),
+            RowLine::Row => Self::for_row(table, false),
+            RowLine::Footer => {
+                Self::for_row(table, false).with_horizontal(table, TableComponent::FooterLines)
+            }
+            RowLine::Section => {
+                Self::for_row(table, true).with_horizontal(table, TableComponent::SectionSeparator)
+            }
+        }
+    }
+
+    /// Draw the line with another component.
+    /// Below a colspan, the line continues, unless a split intersection has been set.
+    fn with_horizontal(mut self, table: &Table, component: TableComponent) -> Self {
+        let split = match component {
+            TableComponent::SectionSeparator => TableComponent::MiddleHeaderSplitIntersection,
+            _ => TableComponent::MiddleSplitIntersection,
+        };
+        self.horizontal = table.style_or_fallback(component);
+        if !table.style_exists(split) {
+            self.split_intersection = self.horizontal.clone();
+        }
+
+        self
+    }
+
     fn for_row(table: &Table, header: bool) -> Self {
         if header {
             Self {
                 left_intersection: table.style_or_default(TableComponent::LeftHeaderIntersection),
                 left_border: table.style_or_default(TableComponent::LeftBorder),
+                right_border: table.style_or_default(TableComponent::RightBorder),
+                vertical: table.style_or_default(TableComponent::VerticalLines),
                 horizontal: table.style_or_default(TableComponent::HeaderLines),
                 middle_intersection: table
                     .style_or_default(TableComponent::MiddleHeaderIntersections),
                 merge_intersection: table
                     .style_or_default(TableComponent::MiddleHeaderMergeIntersection),
-                left_border_intersection: table
-                    .style_or_default(TableComponent::LeftBorderIntersections),
+                split_intersection: table
+                    .style_or_fallback(TableComponent::MiddleHeaderSplitIntersection),
+                rowspan_left_intersection: table
+                    .style_or_fallback(TableComponent::RowspanLeftIntersection),
+                rowspan_right_intersection: table
+                    .style_or_fallback(TableComponent::RowspanRightIntersection),
                 right_intersection: table.style_or_default(TableComponent::RightHeaderIntersection),
             }
         } else {
             Self {
                 left_intersection: table.style_or_default(TableComponent::LeftBorderIntersections),
                 left_border: table.style_or_default(TableComponent::LeftBorder),
+                right_border: table.style_or_default(TableComponent::RightBorder),
+                vertical: table.style_or_default(TableComponent::VerticalLines),
                 horizontal: table.style_or_default(TableComponent::HorizontalLines),
                 middle_intersection: table.style_or_default(TableComponent::MiddleIntersections),
                 merge_intersection: table
-                    .style_or_default(TableComponent::BottomBorderIntersections),
-                left_border_intersection: table
-                    .style_or_default(TableComponent::LeftBorderIntersections),
+                    .style_or_fallback(TableComponent::MiddleMergeIntersection),
+                split_intersection: table
+                    .style_or_fallback(TableComponent::MiddleSplitIntersection),
+                rowspan_left_intersection: table
+                    .style_or_fallback(TableComponent::RowspanLeftIntersection),
+                rowspan_right_intersection: table
+                    .style_or_fallback(TableComponent::RowspanRightIntersection),
                 right_intersection: table
                     .style_or_default(TableComponent::RightBorderIntersections),
             }
@@ -591,7 +1327,21 @@ impl BorderStyles {
         match typ {
             IntersectionType::Normal => &self.middle_intersection,
             IntersectionType::Merge => &self.merge_intersection,
-            IntersectionType::LeftBorderAfterRowspan => &self.left_border_intersection,
+            IntersectionType::LeftBorderAfterRowspan => &self.rowspan_left_intersection,
+        }
+    }
+
+    /// Look up the character of a junction by the lines that meet at it.
+    fn get_junction(&self, junction: Junction) -> &str {
+        match (junction.up, junction.down, junction.left, junction.right) {
+            (true, true, true, true) => &self.middle_intersection,
+            (true, false, true, true) => &self.merge_intersection,
+            (false, true, true, true) => &self.split_intersection,
+            (false, false, true, true) => &self.horizontal,
+            // A rowspan continues on one side, so the vertical line runs through.
+            (_, _, false, true) => &self.rowspan_left_intersection,
+            (_, _, true, false) => &self.rowspan_right_intersection,
+            (_, _, false, false) => &self.vertical,
         }
     }
 }
@@ -599,22 +1349,16 @@ impl BorderStyles {
 /// Draw spaces for a continuing rowspan area.
 /// Returns (spaces_string, number_of_columns_consumed).
 fn draw_rowspan_space(
+    table: &Table,
     display_info: &[ColumnDisplayInfo],
     start_col: usize,
     colspan: u16,
 ) -> (String, usize) {
     let end_col = start_col + colspan as usize;
-    let mut width: usize = 0;
-    let mut visible_count: usize = 0;
-
-    for info in display_info.iter().take(end_col).skip(start_col) {
-        if !info.is_hidden {
-            width += info.width() as usize;
-            visible_count += 1;
-        }
-    }
-    // Add separators between columns
-    width += visible_count.saturating_sub(1);
+    let width = spanned_width(
+        should_draw_vertical_lines(table),
+        display_info.iter().take(end_col).skip(start_col),
+    );
 
     (" ".repeat(width), end_col.saturating_sub(start_col))
 }
@@ -645,26 +1389,34 @@ fn draw_ending_rowspan_border(
         return (result, end_col - start_col);
     }
 
-    // Draw intersection at start (if not first column)
+    // The junctions are resolved by the lines that meet at them.
+    // A vertical line ends at the left edge of the rowspan, but none inside of it.
+    // Below, the next row may start new cells anywhere.
     if !first {
-        let next_row_has_colspan = column_infos
+        let down = column_infos
             .get(current_idx)
-            .map(|c| c.next_row_has_colspan)
-            .unwrap_or(false);
-        let intersection_type =
-            select_intersection_type(header, previous_was_rowspan, next_row_has_colspan);
-        result += styles.get_intersection(intersection_type);
+            .map(|c| !c.next_row_has_colspan)
+            .unwrap_or(true);
+        result += styles.get_junction(Junction {
+            up: true,
+            down,
+            left: header || !previous_was_rowspan,
+            right: true,
+        });
     }
 
-    // Draw first column border
     result += &styles
         .horizontal
-        .repeat(display_info[visible_cols[0]].width().into());
+        .repeat(display_info[visible_cols[0]].width());
 
-    // Draw remaining columns with continuous horizontal lines (merged)
     for &col in &visible_cols[1..] {
-        result += &styles.horizontal; // Use horizontal line instead of intersection
-        result += &styles.horizontal.repeat(display_info[col].width().into());
+        result += styles.get_junction(Junction {
+            up: false,
+            down: column_infos[col].next_row_has_border,
+            left: true,
+            right: true,
+        });
+        result += &styles.horizontal.repeat(display_info[col].width());
     }
 
     (result, end_col - start_col)
@@ -673,7 +1425,7 @@ fn draw_ending_rowspan_border(
 fn draw_bottom_border(
     table: &Table,
     display_info: &[ColumnDisplayInfo],
-    last_row_line: Option<&[String]>,
+    last_row_line: Option<&[Cow<'_, str>]>,
     span_tracker: &SpanTracker,
     last_row_index: usize,
 ) -> String {
@@ -714,23 +1466,23 @@ fn draw_bottom_border(
                 .filter(|&i| i < display_info.len() && !display_info[i].is_hidden)
                 .count();
 
-            // For bottom border: draw continuous border across the rowspan area
+            // The rowspan is separated from the previous column, but its columns are merged.
             if !first && visible_cols_in_rowspan > 0 {
-                // Use merge intersection at the start of rowspan area (columns are merging)
-                line += &merge_intersection;
-            }
-
-            // Draw the border for the first column in rowspan
-            if visible_cols_in_rowspan > 0 {
-                line += &bottom_border.repeat(display_info[start_col].width().into());
+                line += &intersection;
             }
 
-            // Draw continuous borders for remaining columns in rowspan
-            for i in (start_col + 1)..(start_col + rowspan_colspan as usize) {
-                if i < display_info.len() && !display_info[i].is_hidden {
+            // Draw continuous borders for all visible columns in rowspan.
+            // Hidden columns are skipped, even if the rowspan starts in one of them.
+            let visible_infos = display_info
+                .iter()
+                .take(start_col + rowspan_colspan as usize)
+                .skip(start_col)
+                .filter(|info| !info.is_hidden);
+            for (index, info) in visible_infos.enumerate() {
+                if index > 0 {
                     line += &merge_intersection;
-                    line += &bottom_border.repeat(display_info[i].width().into());
                 }
+                line += &bottom_border.repeat(info.width());
             }
 
             col_index = start_col + rowspan_colspan as usize;
@@ -749,9 +1501,13 @@ fn draw_bottom_border(
                 .map(|parts| visible_col_index < parts.len() && parts[visible_col_index].is_empty())
                 .unwrap_or(false);
 
-            // Merge if last row has colspan AND (header also has colspan OR table has few rows)
-            let few_data_rows = table.rows.len() <= 2;
-            let should_merge = is_lastrow_colspan && (is_header_colspan || few_data_rows);
+            let should_merge = is_lastrow_colspan
+                && match table.colspan_border_policy {
+                    // Merge if the header also has a colspan or the table has few rows.
+                    ColspanBorderPolicy::Auto => is_header_colspan || table.rows.len() <= 2,
+                    ColspanBorderPolicy::AlwaysMerge => true,
+                    ColspanBorderPolicy::NeverMerge => false,
+                };
 
             if should_merge {
                 // Use merge intersection (continuous border) for colspan
@@ -761,7 +1517,7 @@ fn draw_bottom_border(
             }
         }
 
-        line += &bottom_border.repeat(info.width().into());
+        line += &bottom_border.repeat(info.width());
         first = false;
         visible_col_index += 1;
         col_index += 1;
@@ -836,6 +1592,24 @@ fn should_draw_horizontal_lines(table: &Table) -> bool {
     false
 }
 
+/// The width of a cell that spans the given columns.
+/// Hidden columns are skipped, the vertical lines between the visible columns belong to the cell.
+pub(crate) fn spanned_width<'a>(
+    vertical_lines: bool,
+    infos: impl IntoIterator<Item = &'a ColumnDisplayInfo>,
+) -> usize {
+    let mut width = 0;
+    let mut visible_count: usize = 0;
+    for info in infos.into_iter().filter(|info| !info.is_hidden) {
+        width += info.width();
+        visible_count += 1;
+    }
+    if vertical_lines {
+        width += visible_count.saturating_sub(1);
+    }
+    width
+}
+
 pub fn should_draw_vertical_lines(table: &Table) -> bool {
     if table.style_exists(TableComponent::TopBorderIntersections)
         || table.style_exists(TableComponent::MiddleHeaderIntersections)
@@ -860,3 +1634,122 @@ fn should_draw_header(table: &Table) -> bool {
 
     false
 }
+
+/// The display width of all lines of a table, which consists of the width of all visible
+/// columns and the borders and lines between them.
+fn line_width(table: &Table, display_info: &[ColumnDisplayInfo]) -> usize {
+    let visible_columns = display_info.iter().filter(|info| !info.is_hidden).count();
+    let border_columns = count_border_columns(table, visible_columns);
+    display_info
+        .iter()
+        .filter(|info| !info.is_hidden)
+        .map(ColumnDisplayInfo::width)
+        .sum::<usize>()
+        + border_columns
+}
+
+/// Pad a line with spaces, if it's shorter than the other lines of the table.
+///
+/// Lines of a table are always drawn with the same width, but custom
+/// [border renderers](crate::BorderRenderer) may return shorter lines.
+fn pad_line(mut line: String, width: usize, line_width: usize) -> String {
+    if width < line_width {
+        line.extend(std::iter::repeat_n(' ', line_width - width));
+    }
+
+    line
+}
+
+/// The display width of a line, without any escape sequences of styled content.
+pub(crate) fn visible_width(line: &str, ambiguous_width: AmbiguousWidth) -> usize {
+    // Plain ASCII lines don't contain any escape sequences or wide characters.
+    if is_printable_ascii(line) {
+        return line.len();
+    }
+
+    str_width(&strip_escapes(line), ambiguous_width)
+}
+
+/// Measures the display width of the lines of a table.
+///
+/// The layout of a table reserves a single column for every border character, as horizontal
+/// lines repeat their character once per column of content.
+/// Border characters with an ambiguous width are therefore counted as a single column, even if
+/// the table [displays them wide](AmbiguousWidth::Wide).
+#[derive(Clone, Debug)]
+pub(crate) struct LineMeasure {
+    ambiguous_width: AmbiguousWidth,
+    /// The border characters of the table, that are wider than a single column.
+    wide_borders: Vec<char>,
+}
+
+impl LineMeasure {
+    pub(crate) fn new(table: &Table) -> Self {
+        let ambiguous_width = table.ambiguous_width;
+        let mut wide_borders: Vec<char> = table
+            .style
+            .values()
+            .chain(table.section_styles.values().flat_map(HashMap::values))
+            .copied()
+            .filter(|character| {
+                char_width(*character, ambiguous_width)
+                    > char_width(*character, AmbiguousWidth::Narrow)
+            })
+            .collect();
+        wide_borders.sort_unstable();
+        wide_borders.dedup();
+
+        Self {
+            ambiguous_width,
+            wide_borders,
+        }
+    }
+
+    /// The display width of a line, without any escape sequences of styled content.
+    pub(crate) fn width(&self, line: &str) -> usize {
+        if self.wide_borders.is_empty() || is_printable_ascii(line) {
+            return visible_width(line, self.ambiguous_width);
+        }
+
+        let visible = strip_escapes(line);
+        let surplus: usize = visible
+            .chars()
+            .filter(|character| self.wide_borders.binary_search(character).is_ok())
+            .map(|character| char_width(character, self.ambiguous_width).unwrap_or(1) - 1)
+            .sum();
+        str_width(&visible, self.ambiguous_width) - surplus
+    }
+}
+
+/// Remove all escape sequences of styled content from a line.
+fn strip_escapes(line: &str) -> String {
+    let mut visible = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(character) = chars.next() {
+        if character != '\x1b' {
+            visible.push(character);
+            continue;
+        }
+        match chars.next() {
+            // Control sequences, e.g. colors, end with a character in the range of `@` to `~`.
+            Some('[') => {
+                for character in chars.by_ref() {
+                    if ('@'..='~').contains(&character) {
+                        break;
+                    }
+                }
+            }
+            // Operating system commands, e.g. hyperlinks, end with a bell or a string terminator.
+            Some(']') => {
+                while let Some(character) = chars.next() {
+                    if character == '\x07' || (character == '\x1b' && chars.next() == Some('\\')) {
+                        break;
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+
+    visible
+}
@@ -1,20 +1,46 @@
-#[cfg(feature = "tty")]
-use crossterm::style::{Stylize, style};
+use std::borrow::Cow;
+use std::collections::{HashMap, VecDeque};
+
 use unicode_segmentation::UnicodeSegmentation;
-use unicode_width::UnicodeWidthStr;
 
-use super::content_split::measure_text_width;
-use super::content_split::split_line;
+use super::borders::{
+    should_draw_left_border, should_draw_right_border, should_draw_vertical_lines, spanned_width,
+};
+use super::content_split::{
+    char_width, is_printable_ascii, measure_text_width, split_line, str_width,
+};
+use super::display::{display_lines, displayed_unit, grapheme_width};
+#[cfg(feature = "tty")]
+use super::gradient::{line_gradient, style_gradient_line};
+#[cfg(feature = "tty")]
+use super::highlight::{
+    Highlight, clip_highlights, find_highlights, split_highlights, style_highlighted_line,
+};
 
+#[cfg(feature = "tty")]
+use crate::Attribute;
 use crate::cell::Cell;
+use crate::column::Column;
+use crate::render::{LossReason, LostContent, RenderEvent};
 use crate::row::Row;
-use crate::style::{CellAlignment, VerticalAlignment};
-#[cfg(feature = "tty")]
-use crate::style::{map_attribute, map_color};
+use crate::style::{CellAlignment, FillPolicy, Overflow, VerticalAlignment};
 use crate::table::Table;
 use crate::utils::ColumnDisplayInfo;
 use crate::utils::spanning::SpanTracker;
 
+/// The formatted lines of a single cell.
+///
+/// Most cells only consist of a single line.
+/// With the `smallvec` feature, that line is stored inline instead of in a separate allocation.
+#[cfg(feature = "smallvec")]
+pub(crate) type CellLines = smallvec::SmallVec<[String; 1]>;
+#[cfg(not(feature = "smallvec"))]
+pub(crate) type CellLines = Vec<String>;
+
+fn single_line(line: String) -> CellLines {
+    std::iter::once(line).collect()
+}
+
 pub fn delimiter(cell: &Cell, info: &ColumnDisplayInfo, table: &Table) -> char {
     // Determine, which delimiter should be used
     if let Some(delimiter) = cell.delimiter {
@@ -44,67 +70,547 @@ pub fn delimiter(cell: &Cell, info: &ColumnDisplayInfo, table: &Table) -> char {
 /// ```
 ///
 /// The strings for each row will be padded and aligned according to their respective column.
-pub fn format_content(table: &Table, display_info: &[ColumnDisplayInfo]) -> Vec<Vec<Vec<String>>> {
-    // The content of the whole table
-    let mut table_content = Vec::with_capacity(table.rows.len() + 1);
+///
+/// Rows are formatted lazily, while the returned iterator is consumed.
+/// See [RowFormatter] for details.
+pub fn format_content<'a>(
+    table: &'a Table,
+    display_info: &[ColumnDisplayInfo],
+) -> FormattedContent<'a> {
+    FormattedContent {
+        table,
+        formatter: RowFormatter::new(table, display_info),
+    }
+}
+
+/// Returns the content of each cell of the table, as it's displayed.
+///
+/// In contrast to [format_content], the lines of a cell are neither padded nor aligned.
+/// Content is split into lines that fit into its column, and truncated if the height of its
+/// row is limited. Cells that aren't displayed at all, e.g. due to hidden columns, are empty.
+///
+/// The cells are listed row by row in the order they have been added, starting with the header.
+pub(crate) fn rendered_content(
+    table: &Table,
+    display_infos: &[ColumnDisplayInfo],
+) -> Vec<Vec<String>> {
+    let ascii_only = is_ascii_only(table);
     let mut span_tracker = SpanTracker::new();
+    let mut rendered_rows = Vec::new();
+    let rows = table.header().into_iter().chain(table.rows.iter());
+    for (row_index, row) in rows.enumerate() {
+        let is_header = row_index == 0 && table.header.is_some();
+        let mut rendered_cells = Vec::with_capacity(row.cells.len());
+        let mut col_index = 0;
+        for cell in &row.cells {
+            // Skip column positions that are occupied by rowspan from above
+            while col_index < display_infos.len()
+                && span_tracker.is_col_occupied_by_rowspan(row_index, col_index)
+            {
+                col_index += 1;
+            }
 
-    // Format table header if it exists
-    if let Some(header) = table.header() {
-        table_content.push(format_row(
-            header,
-            display_info,
-            table,
-            0,
-            &mut span_tracker,
-        ));
-        span_tracker.advance_row(1);
+            let content = display_lines(cell, table.columns.get(col_index), is_header);
+            let info = if col_index < display_infos.len() {
+                spanned_info(
+                    table,
+                    row,
+                    cell,
+                    display_infos,
+                    col_index,
+                    &content,
+                    ascii_only,
+                )
+            } else {
+                None
+            };
+            let rendered = match info {
+                Some(info) => {
+                    #[cfg(feature = "tty")]
+                    let mut highlights = Vec::new();
+                    wrap_cell(
+                        table,
+                        span_max_height(table, row, row_index, cell.rowspan()),
+                        cell,
+                        &content,
+                        &info,
+                        None,
+                        #[cfg(feature = "tty")]
+                        &mut highlights,
+                    )
+                    .join("\n")
+                }
+                None => String::new(),
+            };
+            rendered_cells.push(rendered);
+
+            let colspan = cell.colspan();
+            let rowspan = cell.rowspan();
+            if rowspan > 1 {
+                span_tracker.register_rowspan(row_index, col_index, rowspan, colspan, None);
+            }
+            col_index += usize::from(colspan);
+        }
+        rendered_rows.push(rendered_cells);
+        span_tracker.advance_row(row_index + 1);
     }
 
-    for (row_index, row) in table.rows.iter().enumerate() {
-        let actual_row_index = if table.header.is_some() {
-            row_index + 1
-        } else {
-            row_index
-        };
-        table_content.push(format_row(
-            row,
-            display_info,
-            table,
-            actual_row_index,
-            &mut span_tracker,
-        ));
+    rendered_rows
+}
+
+/// Returns all cells, whose content isn't displayed completely.
+///
+/// Content is lost, if it's truncated to the maximum height of its rows or by the
+/// [overflow](crate::Column::set_overflow) of its column, or if all columns of a cell have been
+/// hidden by the arrangement. Columns with a [hidden](crate::ColumnConstraint::Hidden)
+/// constraint are hidden on purpose and don't lose any content.
+pub(crate) fn lost_content(table: &Table, display_infos: &[ColumnDisplayInfo]) -> Vec<LostContent> {
+    let ascii_only = is_ascii_only(table);
+    let mut span_tracker = SpanTracker::new();
+    let mut lost = Vec::new();
+    let rows = table.header().into_iter().chain(table.rows.iter());
+    for (row_index, row) in rows.enumerate() {
+        let is_header = row_index == 0 && table.header.is_some();
+        let mut col_index = 0;
+        for cell in &row.cells {
+            // Skip column positions that are occupied by rowspan from above
+            while col_index < display_infos.len()
+                && span_tracker.is_col_occupied_by_rowspan(row_index, col_index)
+            {
+                col_index += 1;
+            }
+            if col_index >= display_infos.len() {
+                break;
+            }
+
+            let colspan = cell.colspan();
+            let rowspan = cell.rowspan();
+            let content = display_lines(cell, table.columns.get(col_index), is_header);
+            let info = spanned_info(
+                table,
+                row,
+                cell,
+                display_infos,
+                col_index,
+                &content,
+                ascii_only,
+            );
+            let reason = match info {
+                Some(info) => {
+                    #[cfg(feature = "tty")]
+                    let mut highlights = Vec::new();
+                    let lines = wrap_cell(
+                        table,
+                        None,
+                        cell,
+                        &content,
+                        &info,
+                        None,
+                        #[cfg(feature = "tty")]
+                        &mut highlights,
+                    );
+                    let truncated_lines =
+                        matches!(info.overflow, Overflow::Truncate | Overflow::TruncateMiddle)
+                            && content.iter().any(|line| {
+                                measure_text_width(line, info.ambiguous_width)
+                                    > usize::from(info.content_width)
+                            });
+                    let truncated_rows = span_max_height(table, row, row_index, rowspan)
+                        .is_some_and(|height| lines.len() > height);
+                    (truncated_lines || truncated_rows).then_some(LossReason::Truncated)
+                }
+                None => {
+                    let end = (col_index + usize::from(colspan)).min(table.columns.len());
+                    let hidden_by_arrangement = table.columns[col_index..end]
+                        .iter()
+                        .any(|column| !column.is_hidden());
+                    let has_content = content.iter().any(|line| !line.is_empty());
+                    (hidden_by_arrangement && has_content).then_some(LossReason::HiddenColumn)
+                }
+            };
+            if let Some(reason) = reason {
+                lost.push(LostContent {
+                    row_index,
+                    column_index: col_index,
+                    reason,
+                });
+            }
+
+            if rowspan > 1 {
+                span_tracker.register_rowspan(row_index, col_index, rowspan, colspan, None);
+            }
+            col_index += usize::from(colspan);
+        }
+        span_tracker.advance_row(row_index + 1);
+    }
+
+    lost
+}
+
+/// Check whether the displayed content of all cells of a table is printable ASCII.
+///
+/// Masks and text transformations keep ASCII content ASCII, so only the raw content, units
+/// and sort indicators have to be checked.
+fn is_ascii_only(table: &Table) -> bool {
+    let indicators_are_ascii = table
+        .columns
+        .iter()
+        .filter_map(|column| column.sort_indicator_glyph())
+        .chain(table.columns.iter().filter_map(Column::unit))
+        .all(is_printable_ascii);
+    // The placeholder of collapsed columns isn't ascii.
+    let collapsed = table.columns.iter().any(Column::is_collapsed);
+
+    indicators_are_ascii
+        && !collapsed
+        && table
+            .header
+            .iter()
+            .chain(table.rows.iter())
+            .flat_map(|row| row.cells.iter())
+            .all(|cell| {
+                cell.content.iter().all(|line| is_printable_ascii(line))
+                    && cell.prefix.iter().all(|prefix| is_printable_ascii(prefix))
+                    && cell.suffix.iter().all(|suffix| is_printable_ascii(suffix))
+            })
+}
+
+/// An iterator over the lines of each row of the table, as returned by [format_rows](crate::render::format_rows).
+pub struct FormattedContent<'a> {
+    table: &'a Table,
+    formatter: RowFormatter,
+}
+
+impl<'a> Iterator for FormattedContent<'a> {
+    type Item = Vec<Vec<Cow<'a, str>>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.formatter.next_row(self.table)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.formatter.remaining_rows();
+        (remaining, Some(remaining))
+    }
+}
+
+/// Formats the rows of a table one after another, whenever the next row is requested.
+///
+/// The height of rows, that are spanned by a cell, depends on the content of that cell.
+/// Such rows are formatted together, once the first of them is requested.
+/// All other rows are formatted on their own, so only the rows that are currently drawn are
+/// kept in memory.
+///
+/// The formatter doesn't hold on to the table, so the table can be owned by whoever drives it.
+/// It has to be passed the same table for every row.
+pub(crate) struct RowFormatter {
+    display_info: Vec<ColumnDisplayInfo>,
+    /// If all content is plain ASCII, the width of the content doesn't have to be measured
+    /// for each individual cell.
+    ascii_only: bool,
+    /// Whether vertical lines are drawn between columns, which spanning cells have to cover.
+    vertical_lines: bool,
+    /// The amount of rows that are returned, including the header.
+    row_count: usize,
+    /// Rows that have been formatted, but haven't been returned yet.
+    formatted_rows: VecDeque<FormattedRow>,
+    /// The index of the next row, that's formatted.
+    next_formatted: usize,
+    /// The index of the next row, that's returned.
+    next_returned: usize,
+    /// The height of each row, that has been formatted.
+    row_heights: Vec<usize>,
+    /// The content offset of each rowspan cell, that hasn't been returned yet, by its position.
+    content_offsets: HashMap<(usize, usize), usize>,
+    /// The rowspans, that occupy positions in the rows that are formatted.
+    format_tracker: SpanTracker,
+    /// The rowspans and their content, that's displayed in the rows that are returned.
+    span_tracker: SpanTracker,
+}
+
+impl RowFormatter {
+    pub(crate) fn new(table: &Table, display_info: &[ColumnDisplayInfo]) -> Self {
+        Self {
+            display_info: display_info.to_vec(),
+            ascii_only: is_ascii_only(table),
+            vertical_lines: should_draw_vertical_lines(table),
+            row_count: usize::from(table.header.is_some()) + table.rows.len(),
+            formatted_rows: VecDeque::new(),
+            next_formatted: 0,
+            next_returned: 0,
+            row_heights: Vec::new(),
+            content_offsets: HashMap::new(),
+            format_tracker: SpanTracker::new(),
+            span_tracker: SpanTracker::new(),
+        }
+    }
+
+    /// Stop after the given amount of rows, including the header.
+    pub(crate) fn take_rows(mut self, count: usize) -> Self {
+        self.row_count = self.row_count.min(count);
+        self
+    }
+
+    pub(crate) fn remaining_rows(&self) -> usize {
+        self.row_count.saturating_sub(self.next_returned)
+    }
+
+    /// Format the next row of the table and return its lines.
+    pub(crate) fn next_row(&mut self, table: &Table) -> Option<Vec<Vec<Cow<'static, str>>>> {
+        if self.next_returned >= self.row_count {
+            return None;
+        }
+        if self.formatted_rows.is_empty() {
+            self.format_rows(table);
+        }
+        let mut formatted_row = self.formatted_rows.pop_front()?;
+        let row_index = self.next_returned;
+        self.next_returned += 1;
+
+        // Register rowspans, moving the formatted content to the tracker for subsequent rows.
+        for rowspan in formatted_row.rowspans.iter() {
+            let content = formatted_row.content[rowspan.col_index]
+                .as_mut()
+                .map(std::mem::take);
+            self.span_tracker.register_rowspan(
+                row_index,
+                rowspan.col_index,
+                rowspan.rowspan,
+                rowspan.colspan,
+                content,
+            );
+            let offset = self
+                .content_offsets
+                .remove(&(row_index, rowspan.col_index))
+                .unwrap_or(0);
+            self.span_tracker.set_rowspan_layout(
+                row_index,
+                rowspan.col_index,
+                offset,
+                rowspan.fill_line.clone(),
+            );
+        }
+
+        let row_content = assemble_row(
+            formatted_row,
+            &self.display_info,
+            row_index,
+            &self.row_heights,
+            &self.span_tracker,
+            self.vertical_lines,
+        );
         // Advance row AFTER processing, so rowspan content is available for the next row
-        span_tracker.advance_row(actual_row_index + 1);
+        self.span_tracker.advance_row(row_index + 1);
+
+        Some(row_content)
+    }
+
+    /// Format the next row and all following rows, that are spanned by any of the formatted
+    /// cells, so the height of all of them is known.
+    fn format_rows(&mut self, table: &Table) {
+        let first_row = self.next_formatted;
+        let mut end = first_row + 1;
+        let mut rows = Vec::new();
+        while self.next_formatted < end {
+            let row_index = self.next_formatted;
+            let Some(row) = row_at(table, row_index) else {
+                break;
+            };
+            let formatted_row = format_row(
+                row,
+                &self.display_info,
+                table,
+                row_index,
+                &mut self.format_tracker,
+                self.ascii_only,
+            );
+            self.format_tracker.advance_row(row_index + 1);
+            for rowspan in formatted_row.rowspans.iter() {
+                end = end.max(row_index + usize::from(rowspan.rowspan));
+            }
+            rows.push(formatted_row);
+            self.next_formatted += 1;
+        }
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            first_row,
+            rows = rows.len(),
+            ascii_only = self.ascii_only,
+            "Formatted rows"
+        );
+
+        let (row_heights, content_offsets) = rowspan_layout(&rows);
+        self.row_heights.extend(row_heights);
+        self.content_offsets.extend(
+            content_offsets
+                .into_iter()
+                .map(|((row_index, col_index), offset)| {
+                    ((first_row + row_index, col_index), offset)
+                }),
+        );
+        self.formatted_rows.extend(rows);
+    }
+}
+
+/// The row at an index, including the header.
+fn row_at(table: &Table, row_index: usize) -> Option<&Row> {
+    match (&table.header, row_index) {
+        (Some(header), 0) => Some(header),
+        (Some(_), row_index) => table.rows.get(row_index - 1),
+        (None, row_index) => table.rows.get(row_index),
+    }
+}
+
+/// The formatted cells of a single row, before they're assembled into lines.
+struct FormattedRow {
+    /// The aligned lines of each cell, stored at the column the cell starts in.
+    /// Positions that are occupied by a rowspan from above contain a single empty line.
+    content: Vec<Option<CellLines>>,
+    /// Track which columns are part of a colspan (maps col_index -> colspan).
+    /// Columns that are covered by a colspan, but aren't its first column, are `Some(0)`.
+    colspans: Vec<Option<usize>>,
+    /// Track vertical alignment for each column (for applying after we know the row's height)
+    vertical_alignments: Vec<Option<VerticalAlignment>>,
+    /// All cells of this row that span multiple rows.
+    rowspans: Vec<RowspanCell>,
+    /// The maximum amount of lines of this row, if it's limited.
+    max_height: Option<usize>,
+}
+
+/// A cell that spans multiple rows.
+struct RowspanCell {
+    col_index: usize,
+    rowspan: u16,
+    colspan: u16,
+    vertical_alignment: VerticalAlignment,
+    /// The line that's displayed in lines that aren't covered by the content.
+    fill_line: Option<String>,
+}
+
+impl FormattedRow {
+    /// The amount of lines of this row, without considering the cells that span multiple rows.
+    fn own_height(&self) -> usize {
+        self.content
+            .iter()
+            .enumerate()
+            .filter(|(col_index, _)| {
+                !self
+                    .rowspans
+                    .iter()
+                    .any(|rowspan| rowspan.col_index == *col_index)
+            })
+            .filter_map(|(_, cell)| cell.as_ref().map(|lines| lines.len()))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// Determine the height of each row and where the content of each rowspan cell starts.
+///
+/// The content of a rowspan cell is positioned within all lines of the spanned rows, depending
+/// on its vertical alignment.
+/// If the content is taller than all spanned rows combined, the spanned rows grow up to their
+/// maximum height, starting with the first one, until they fit the whole content.
+/// Rows without a maximum height take all remaining lines.
+///
+/// Returns the height of each row and the content offset of each rowspan cell, by its position.
+fn rowspan_layout(rows: &[FormattedRow]) -> (Vec<usize>, HashMap<(usize, usize), usize>) {
+    let mut heights: Vec<usize> = rows.iter().map(FormattedRow::own_height).collect();
+    // Rowspans whose content is taller than their rows. Their content always starts at the top.
+    let mut overflowing = Vec::new();
+
+    for (row_index, row) in rows.iter().enumerate() {
+        for rowspan in row.rowspans.iter() {
+            let content_height = rowspan_content_height(row, rowspan);
+            let end = (row_index + rowspan.rowspan as usize).min(rows.len());
+            let available: usize = heights[row_index..end].iter().sum();
+            if content_height > available {
+                let mut missing = content_height - available;
+                for (height, spanned_row) in heights[row_index..end]
+                    .iter_mut()
+                    .zip(&rows[row_index..end])
+                {
+                    let room = spanned_row
+                        .max_height
+                        .map_or(missing, |max_height| max_height.saturating_sub(*height));
+                    let grow = room.min(missing);
+                    *height += grow;
+                    missing -= grow;
+                }
+                // The content has been truncated to the maximum height of all spanned rows,
+                // so this only happens, if rows are taller than their maximum height anyway.
+                heights[row_index] += missing;
+                overflowing.push((row_index, rowspan.col_index));
+            }
+        }
+    }
+
+    let mut offsets = HashMap::new();
+    for (row_index, row) in rows.iter().enumerate() {
+        for rowspan in row.rowspans.iter() {
+            let position = (row_index, rowspan.col_index);
+            let offset = if overflowing.contains(&position) {
+                0
+            } else {
+                let content_height = rowspan_content_height(row, rowspan);
+                let end = (row_index + rowspan.rowspan as usize).min(rows.len());
+                let available: usize = heights[row_index..end].iter().sum();
+                let padding = available.saturating_sub(content_height);
+                match rowspan.vertical_alignment {
+                    VerticalAlignment::Top | VerticalAlignment::Baseline(_) => 0,
+                    VerticalAlignment::Middle => padding / 2,
+                    VerticalAlignment::Bottom => padding,
+                }
+            };
+            offsets.insert(position, offset);
+        }
     }
-    table_content
+
+    (heights, offsets)
 }
 
-pub(crate) fn format_row(
+fn rowspan_content_height(row: &FormattedRow, rowspan: &RowspanCell) -> usize {
+    row.content[rowspan.col_index]
+        .as_ref()
+        .map(|lines| lines.len())
+        .unwrap_or(0)
+}
+
+fn format_row(
     row: &Row,
     display_infos: &[ColumnDisplayInfo],
     table: &Table,
     row_index: usize,
     span_tracker: &mut SpanTracker,
-) -> Vec<Vec<String>> {
+    ascii_only: bool,
+) -> FormattedRow {
     // The content of this specific row
     // We'll build a vector where each element represents a column position
     // For colspan cells, we'll store the formatted content once and mark the spanned positions
-    let mut temp_row_content: Vec<Option<Vec<String>>> = vec![None; display_infos.len()];
+    let mut temp_row_content: Vec<Option<CellLines>> = vec![None; display_infos.len()];
     // Track which columns are part of a colspan (maps col_index -> colspan)
     let mut colspan_map: Vec<Option<usize>> = vec![None; display_infos.len()];
     // Track vertical alignment for each column (for applying after we know max_lines)
     let mut vertical_alignments: Vec<Option<VerticalAlignment>> = vec![None; display_infos.len()];
+    let mut rowspans = Vec::new();
+    // The cells with a baseline alignment by column, with their baseline and their width.
+    let mut baselines: Vec<(usize, usize, usize)> = Vec::new();
     let mut col_index = 0;
 
     // Process each cell in the row
     for cell in &row.cells {
+        // Row colors and attributes apply to all cells that don't have their own.
+        #[cfg(feature = "tty")]
+        let cell = &*row.style_cell(cell);
+
         // Skip column positions that are occupied by rowspan from above
         while col_index < display_infos.len()
             && span_tracker.is_col_occupied_by_rowspan(row_index, col_index)
         {
             // This position is occupied by a rowspan, mark it as such
-            temp_row_content[col_index] = Some(vec!["".to_string()]);
+            if !display_infos[col_index].is_hidden {
+                temp_row_content[col_index] = Some(single_line(String::new()));
+            }
             col_index += 1;
         }
 
@@ -115,207 +621,120 @@ pub(crate) fn format_row(
         let colspan = cell.colspan() as usize;
         let rowspan = cell.rowspan();
 
-        // Get the spanned column infos
-        let spanned_infos: Vec<&ColumnDisplayInfo> = display_infos
-            .iter()
-            .skip(col_index)
-            .take(colspan)
-            .filter(|info| !info.is_hidden)
-            .collect();
-
-        if spanned_infos.is_empty() {
+        let is_header = row_index == 0 && table.header.is_some();
+        let content = display_lines(cell, table.columns.get(col_index), is_header);
+        let Some(spanned_info) = spanned_info(
+            table,
+            row,
+            cell,
+            display_infos,
+            col_index,
+            &content,
+            ascii_only,
+        ) else {
+            // The cell isn't displayed, but its rowspan still occupies the columns below it.
+            if rowspan > 1 {
+                span_tracker.register_rowspan(row_index, col_index, rowspan, colspan as u16, None);
+            }
             col_index += colspan;
             continue;
-        }
-
-        // Calculate combined width for colspan cells
-        // Sum the content widths, PLUS add 3 chars per span for the missing borders " | " between columns
-        // If there were 2 separate cells, they'd have " | " (3 chars) between them
-        // Use the number of visible columns, not the logical colspan (hidden columns don't need border compensation)
-        let visible_colspan_count = spanned_infos.len();
-        let borders_between = (visible_colspan_count.saturating_sub(1)) as u16 * 3;
-        let combined_content_width: u16 = spanned_infos
-            .iter()
-            .map(|info| info.content_width)
-            .sum::<u16>()
-            + borders_between;
-        let combined_padding_left: u16 = spanned_infos
-            .first()
-            .map(|info| info.padding.0)
-            .unwrap_or(0);
-        let combined_padding_right: u16 =
-            spanned_infos.last().map(|info| info.padding.1).unwrap_or(0);
-
-        // Create a temporary ColumnDisplayInfo for the spanned cell
-        let spanned_info = ColumnDisplayInfo {
-            padding: (combined_padding_left, combined_padding_right),
-            delimiter: spanned_infos[0].delimiter,
-            content_width: combined_content_width,
-            cell_alignment: cell.alignment.or(spanned_infos[0].cell_alignment),
-            vertical_alignment: cell
-                .vertical_alignment
-                .or(spanned_infos[0].vertical_alignment),
-            is_hidden: false,
         };
 
         // Format the cell content
-        let mut cell_lines = Vec::new();
-        let cell_delimiter = delimiter(cell, &spanned_info, table);
-
-        // Iterate over each line and split it into multiple lines if necessary.
-        // Newlines added by the user will be preserved.
-        for line in cell.content.iter() {
-            if measure_text_width(line) > combined_content_width.into() {
-                let mut parts = split_line(line, &spanned_info, cell_delimiter);
-                cell_lines.append(&mut parts);
-            } else {
-                cell_lines.push(line.into());
-            }
-        }
-
-        // Remove all unneeded lines of this cell, if the row's height is capped to a certain
-        // amount of lines and there're too many lines in this cell.
-        // This then truncates and inserts a '...' string at the end of the last line to indicate
-        // that the cell has been truncated.
-        if let Some(lines) = row.max_height {
-            if cell_lines.len() > lines {
-                // We already have to many lines. Cut off the surplus lines.
-                let _ = cell_lines.split_off(lines);
-
-                // Directly access the last line.
-                let last_line = cell_lines
-                    .get_mut(lines - 1)
-                    .expect("We know it's this long.");
-
-                // Truncate any ansi codes, as the following cutoff might break ansi code
-                // otherwise anyway. This could be handled smarter, but it's simple and just works.
-                #[cfg(feature = "custom_styling")]
-                {
-                    let stripped = console::strip_ansi_codes(last_line).to_string();
-                    *last_line = stripped;
-                }
-
-                let max_width: usize = combined_content_width.into();
-                let indicator_width = table.truncation_indicator.width();
-
-                let mut truncate_at = 0;
-                // Start the accumulated_width with the indicator_width, which is the minimum width
-                // we may show anyway.
-                let mut accumulated_width = indicator_width;
-                let mut full_string_fits = false;
-
-                // Leave these print statements in here in case we ever have to debug this annoying
-                // stuff again.
-                //println!("\nSTART:");
-                //println!("\nMax width: {max_width}, Indicator width: {indicator_width}");
-                //println!("Full line hex: {last_line}");
-                //println!(
-                //    "Full line hex: {}",
-                //    last_line
-                //        .as_bytes()
-                //        .iter()
-                //        .map(|byte| format!("{byte:02x}"))
-                //        .collect::<Vec<String>>()
-                //        .join(", ")
-                //);
-
-                // Iterate through the UTF-8 graphemes.
-                // Check the `split_long_word` inline function docs to see why we're using
-                // graphemes.
-                // **Note:** The `index` here is the **byte** index. So we cannot just
-                //    String::truncate afterwards. We have to convert to a byte vector to perform
-                //    the truncation first.
-                let mut grapheme_iter = last_line.grapheme_indices(true).peekable();
-                while let Some((index, grapheme)) = grapheme_iter.next() {
-                    // Leave these print statements in here in case we ever have to debug this
-                    // annoying stuff again
-                    //println!(
-                    //    "Current index: {index}, Next grapheme: {grapheme} (width: {})",
-                    //    grapheme.width()
-                    //);
-                    //println!(
-                    //    "Next grapheme hex: {}",
-                    //    grapheme
-                    //        .as_bytes()
-                    //        .iter()
-                    //        .map(|byte| format!("{byte:02x}"))
-                    //        .collect::<Vec<String>>()
-                    //        .join(", ")
-                    //);
-
-                    // Immediately save where to truncate in case this grapheme doesn't fit.
-                    // The index is just before the current grapheme actually starts.
-                    truncate_at = index;
-                    // Check if the next grapheme would break the boundary of the allowed line
-                    // length.
-                    let new_width = accumulated_width + grapheme.width();
-                    //println!(
-                    //    "Next width: {new_width}/{max_width} ({accumulated_width} + {})",
-                    //    grapheme.width()
-                    //);
-                    if new_width > max_width {
-                        //println!(
-                        //    "Breaking: {:?}",
-                        //    accumulated_width + grapheme.width() > max_width
-                        //);
-                        break;
-                    }
-
-                    // The grapheme seems to fit. Save the index and check the next one.
-                    accumulated_width += grapheme.width();
-
-                    // This is a special case.
-                    // We reached the last char, meaning that full last line + the indicator fit.
-                    if grapheme_iter.peek().is_none() {
-                        full_string_fits = true
-                    }
-                }
-
-                // Only do any truncation logic if the line doesn't fit.
-                if !full_string_fits {
-                    // Truncate the string at the byte index just behind the last valid grapheme
-                    // and overwrite the last line with the new truncated string.
-                    let mut last_line_bytes = last_line.clone().into_bytes();
-                    last_line_bytes.truncate(truncate_at);
-                    let new_last_line = String::from_utf8(last_line_bytes)
-                        .expect("We cut at an exact char boundary");
-                    *last_line = new_last_line;
-                }
-
-                // Push the truncation indicator.
-                last_line.push_str(&table.truncation_indicator);
-            }
-        }
+        // The highlighted ranges of each line in `cell_lines`.
+        #[cfg(feature = "tty")]
+        let mut cell_highlights: Vec<Vec<Highlight>> = Vec::new();
+        // The line of the content, that each wrapped line belongs to.
+        let mut line_sources = Vec::new();
+        let cell_lines = wrap_cell(
+            table,
+            span_max_height(table, row, row_index, rowspan),
+            cell,
+            &content,
+            &spanned_info,
+            Some(&mut line_sources),
+            #[cfg(feature = "tty")]
+            &mut cell_highlights,
+        );
 
         // Iterate over all generated lines of this cell and align them
-        let aligned_cell_lines: Vec<String> = cell_lines
+        let aligned_cell_lines: CellLines = cell_lines
             .iter()
-            .map(|line| align_line(table, &spanned_info, cell, line.to_string()))
+            .enumerate()
+            .map(|(index, line)| {
+                let alignment =
+                    line_alignment(&spanned_info, cell, line_sources.get(index).copied());
+                #[cfg(feature = "tty")]
+                if let Some(highlights) = cell_highlights.get(index).filter(|h| !h.is_empty()) {
+                    return align_highlighted_line(
+                        table,
+                        &spanned_info,
+                        cell,
+                        alignment,
+                        line,
+                        highlights,
+                    );
+                }
+                align_line(table, &spanned_info, cell, alignment, line.to_string())
+            })
             .collect();
 
+        // The unit of the column is displayed on separate lines below the header cell.
+        let mut aligned_cell_lines = aligned_cell_lines;
+        if let Some(unit) = displayed_unit(table.columns.get(col_index), is_header) {
+            aligned_cell_lines.extend(unit_lines(table, row, cell, unit, &spanned_info));
+        }
+
         // Track vertical alignment for this cell
-        // Cell setting overrides column setting, default is Top
-        let v_align = cell
+        // Cell setting overrides column setting, which overrides row setting, default is Top
+        let v_align = spanned_info
             .vertical_alignment
-            .or(spanned_infos[0].vertical_alignment)
             .unwrap_or(VerticalAlignment::Top);
         vertical_alignments[col_index] = Some(v_align);
+        if let VerticalAlignment::Baseline(line) = v_align {
+            let baseline = line_sources
+                .iter()
+                .position(|source| *source == line)
+                .filter(|baseline| *baseline < aligned_cell_lines.len());
+            if let Some(baseline) = baseline.filter(|_| rowspan <= 1) {
+                baselines.push((col_index, baseline, spanned_info.width()));
+            }
+        }
+
+        table.emit(RenderEvent::CellFormatted {
+            row_index,
+            column_index: col_index,
+            row,
+            cell,
+            lines: &aligned_cell_lines,
+        });
 
         // Store content in temp_row_content for this row
-        // Clone for rowspan registration if needed
-        let content_for_storage = aligned_cell_lines.clone();
         temp_row_content[col_index] = Some(aligned_cell_lines);
 
-        // Register rowspan if needed, caching the formatted content for subsequent rows
+        // Remember rowspans, their content is positioned once all rows are formatted.
         if rowspan > 1 {
-            span_tracker.register_rowspan(
-                row_index,
+            // Register the rowspan, so the following rows know which positions are occupied.
+            span_tracker.register_rowspan(row_index, col_index, rowspan, colspan as u16, None);
+            let fill_line = match cell.rowspan_fill {
+                FillPolicy::Blank => None,
+                FillPolicy::Repeat(character) => {
+                    let character_width = char_width(character, table.ambiguous_width)
+                        .unwrap_or(1)
+                        .max(1);
+                    let count = usize::from(spanned_info.content_width) / character_width;
+                    let fill = character.to_string().repeat(count);
+                    let alignment = line_alignment(&spanned_info, cell, None);
+                    Some(align_line(table, &spanned_info, cell, alignment, fill))
+                }
+            };
+            rowspans.push(RowspanCell {
                 col_index,
                 rowspan,
-                colspan as u16,
-                Some(content_for_storage),
-                v_align,
-            );
+                colspan: colspan as u16,
+                vertical_alignment: v_align,
+                fill_line,
+            });
         }
 
         for i in 0..colspan {
@@ -332,26 +751,519 @@ pub(crate) fn format_row(
         col_index += colspan;
     }
 
+    // Move the content of cells with a baseline alignment down, until all baselines are on the
+    // same line. The remaining lines are filled like the ones of top aligned cells.
+    let max_baseline = baselines.iter().map(|(_, baseline, _)| *baseline).max();
+    for (col_index, baseline, width) in baselines {
+        if let Some(lines) = temp_row_content[col_index].as_mut() {
+            let offset = max_baseline.unwrap_or(baseline) - baseline;
+            *lines = std::iter::repeat_n(" ".repeat(width), offset)
+                .chain(std::mem::take(lines))
+                .collect();
+        }
+    }
+
     // Fill in any remaining positions that weren't covered (shouldn't happen in valid tables)
     for i in col_index..display_infos.len() {
         if temp_row_content[i].is_none() && !span_tracker.is_col_occupied_by_rowspan(row_index, i) {
             if display_infos[i].is_hidden {
                 continue;
             }
-            temp_row_content[i] = Some(vec![" ".repeat(display_infos[i].width().into())]);
+            temp_row_content[i] = Some(single_line(" ".repeat(display_infos[i].width())));
         }
     }
 
-    // Now convert from column-based to line-based structure
-    // Find the maximum number of lines across all cells
-    let max_lines = temp_row_content
+    FormattedRow {
+        content: temp_row_content,
+        colspans: colspan_map,
+        vertical_alignments,
+        rowspans,
+        max_height: row.max_height,
+    }
+}
+
+/// The maximum height of a cell, which is the sum of the maximum heights of all rows it spans.
+///
+/// Cells that span a row without a maximum height aren't limited.
+fn span_max_height(table: &Table, row: &Row, row_index: usize, rowspan: u16) -> Option<usize> {
+    if rowspan <= 1 {
+        return row.max_height;
+    }
+
+    table
+        .header()
+        .into_iter()
+        .chain(table.rows.iter())
+        .skip(row_index)
+        .take(usize::from(rowspan))
+        .map(|row| row.max_height)
+        .sum()
+}
+
+/// Combine the display infos of all visible columns a cell spans into a single one.
+///
+/// Returns `None`, if all spanned columns are hidden.
+fn spanned_info(
+    table: &Table,
+    row: &Row,
+    cell: &Cell,
+    display_infos: &[ColumnDisplayInfo],
+    col_index: usize,
+    content: &[String],
+    ascii_only: bool,
+) -> Option<ColumnDisplayInfo> {
+    let colspan = cell.colspan() as usize;
+
+    // Get the spanned column infos
+    let spanned_infos: Vec<&ColumnDisplayInfo> = display_infos
         .iter()
-        .filter_map(|cell| cell.as_ref().map(|lines| lines.len()))
-        .max()
-        .unwrap_or(0);
+        .skip(col_index)
+        .take(colspan)
+        .filter(|info| !info.is_hidden)
+        .collect();
+
+    if spanned_infos.is_empty() {
+        return None;
+    }
+
+    // The content of a colspan cell may use the whole width of all spanned columns,
+    // including the padding and vertical lines between them.
+    // Only the outer padding of the first and last spanned column is kept.
+    // Annotations don't belong to any column, they only keep a space next to the borders.
+    let (combined_padding_left, combined_padding_right) = if row.annotation {
+        (
+            u16::from(should_draw_left_border(table)),
+            u16::from(should_draw_right_border(table)),
+        )
+    } else {
+        (
+            spanned_infos
+                .first()
+                .map(|info| info.padding.0)
+                .unwrap_or(0),
+            spanned_infos.last().map(|info| info.padding.1).unwrap_or(0),
+        )
+    };
+    let combined_width = spanned_width(
+        should_draw_vertical_lines(table),
+        spanned_infos.iter().copied(),
+    );
+    let combined_content_width = combined_width
+        .saturating_sub(usize::from(combined_padding_left) + usize::from(combined_padding_right));
+    let combined_content_width = u16::try_from(combined_content_width).unwrap_or(u16::MAX);
+
+    // Annotations ignore the alignment, delimiter and overflow of the first column.
+    let column_info = if row.annotation {
+        None
+    } else {
+        Some(spanned_infos[0])
+    };
+    // Headers and cells that span multiple columns are always wrapped without an indent.
+    let is_header = table
+        .header
+        .as_ref()
+        .is_some_and(|header| std::ptr::eq(header, row));
+    let single_column = column_info.filter(|_| spanned_infos.len() == 1 && !is_header);
+    let overflow = single_column.map_or(Overflow::Wrap, |info| info.overflow);
+    let hanging_indent = single_column.map_or(0, |info| info.hanging_indent);
+
+    // Create a temporary ColumnDisplayInfo for the spanned cell
+    Some(ColumnDisplayInfo {
+        padding: (combined_padding_left, combined_padding_right),
+        delimiter: column_info.and_then(|info| info.delimiter),
+        content_width: combined_content_width,
+        cell_alignment: cell
+            .alignment
+            .or(column_info.and_then(|info| info.cell_alignment))
+            .or(row.cell_alignment),
+        vertical_alignment: cell
+            .vertical_alignment
+            .or(column_info.and_then(|info| info.vertical_alignment))
+            .or(row.vertical_alignment),
+        is_hidden: false,
+        overflow,
+        hanging_indent,
+        ascii_only: ascii_only || content.iter().all(|line| is_printable_ascii(line)),
+        ambiguous_width: table.ambiguous_width,
+    })
+}
+
+/// Split and align the unit of a column, which is displayed dimmed below its header cell.
+fn unit_lines(
+    table: &Table,
+    row: &Row,
+    cell: &Cell,
+    unit: &str,
+    info: &ColumnDisplayInfo,
+) -> CellLines {
+    #[cfg(feature = "tty")]
+    let cell = &{
+        let mut cell = cell.clone();
+        cell.attributes.push(Attribute::Dim);
+        cell
+    };
+    let info = &ColumnDisplayInfo {
+        ascii_only: is_printable_ascii(unit),
+        ..info.clone()
+    };
+    #[cfg(feature = "tty")]
+    let mut highlights = Vec::new();
+
+    let alignment = line_alignment(info, cell, None);
+    wrap_cell(
+        table,
+        row.max_height,
+        cell,
+        &[unit.to_string()],
+        info,
+        None,
+        #[cfg(feature = "tty")]
+        &mut highlights,
+    )
+    .into_iter()
+    .map(|line| align_line(table, info, cell, alignment, line))
+    .collect()
+}
+
+/// Split the displayed content of a cell into lines that fit into its column.
+///
+/// If the cell's height is limited to `max_height`, surplus lines are removed and the truncation
+/// indicator is added to the last line.
+/// The index of the content line each returned line belongs to is pushed to `line_sources`.
+/// The highlighted ranges of each returned line are pushed to `cell_highlights`.
+#[cfg_attr(not(feature = "tty"), allow(clippy::needless_lifetimes))]
+fn wrap_cell<'a>(
+    table: &'a Table,
+    max_height: Option<usize>,
+    cell: &Cell,
+    content: &[String],
+    info: &ColumnDisplayInfo,
+    mut line_sources: Option<&mut Vec<usize>>,
+    #[cfg(feature = "tty")] cell_highlights: &mut Vec<Vec<Highlight<'a>>>,
+) -> CellLines {
+    let mut cell_lines = CellLines::new();
+    let cell_delimiter = delimiter(cell, info, table);
+
+    // Iterate over each line and split it into multiple lines if necessary.
+    // Newlines added by the user will be preserved.
+    for (source, line) in content.iter().enumerate() {
+        #[cfg(feature = "tty")]
+        let highlights = find_highlights(table, line);
+        let width = if info.ascii_only {
+            line.len()
+        } else {
+            measure_text_width(line, info.ambiguous_width)
+        };
+        if width > info.content_width.into()
+            && matches!(info.overflow, Overflow::Truncate | Overflow::TruncateMiddle)
+        {
+            let middle = info.overflow == Overflow::TruncateMiddle;
+            #[cfg(feature = "tty")]
+            cell_highlights.push(Vec::new());
+            cell_lines.push(truncate_line(
+                table,
+                line,
+                info.content_width.into(),
+                middle,
+            ));
+        } else if width > info.content_width.into() {
+            let indent = usize::from(info.hanging_indent);
+            let hanging = (line_alignment(info, cell, Some(source)) == CellAlignment::Left)
+                .then(|| split_hanging(line, info, cell_delimiter, indent))
+                .flatten();
+            let indent = if hanging.is_some() { indent } else { 0 };
+            let mut parts = hanging.unwrap_or_else(|| split_line(line, info, cell_delimiter));
+            #[cfg(feature = "tty")]
+            let mut highlights = split_highlights(line, &parts, highlights);
+            if indent > 0 {
+                let indentation = " ".repeat(indent);
+                for part in parts.iter_mut().skip(1) {
+                    part.insert_str(0, &indentation);
+                }
+                #[cfg(feature = "tty")]
+                for (range, _) in highlights.iter_mut().skip(1).flatten() {
+                    *range = range.start + indent..range.end + indent;
+                }
+            }
+            #[cfg(feature = "tty")]
+            cell_highlights.append(&mut highlights);
+            cell_lines.extend(parts);
+        } else {
+            #[cfg(feature = "tty")]
+            cell_highlights.push(highlights);
+            cell_lines.push(line.into());
+        }
+        if let Some(line_sources) = line_sources.as_deref_mut() {
+            line_sources.resize(cell_lines.len(), source);
+        }
+    }
+
+    // Remove all unneeded lines of this cell, if the cell's height is capped to a certain
+    // amount of lines and there're too many lines in this cell.
+    // This then truncates and inserts a '...' string at the end of the last line to indicate
+    // that the cell has been truncated.
+    if let Some(lines) = max_height {
+        if cell_lines.len() > lines {
+            // We already have to many lines. Cut off the surplus lines.
+            cell_lines.truncate(lines);
+            #[cfg(feature = "tty")]
+            cell_highlights.truncate(lines);
+
+            // Directly access the last line.
+            let last_line = cell_lines
+                .get_mut(lines - 1)
+                .expect("We know it's this long.");
+
+            // Truncate any ansi codes, as the following cutoff might break ansi code
+            // otherwise anyway. This could be handled smarter, but it's simple and just works.
+            #[cfg(feature = "custom_styling")]
+            {
+                let stripped = console::strip_ansi_codes(last_line).to_string();
+                // The byte offsets of the highlights are no longer valid.
+                if stripped != *last_line {
+                    cell_highlights[lines - 1].clear();
+                }
+                *last_line = stripped;
+            }
+
+            let max_width: usize = info.content_width.into();
+            let (indicator, indicator_width) = clamp_indicator(table, max_width);
+
+            let mut truncate_at = 0;
+            // Start the accumulated_width with the indicator_width, which is the minimum width
+            // we may show anyway.
+            let mut accumulated_width = indicator_width;
+            let mut full_string_fits = false;
+
+            // Leave these print statements in here in case we ever have to debug this annoying
+            // stuff again.
+            //println!("\nSTART:");
+            //println!("\nMax width: {max_width}, Indicator width: {indicator_width}");
+            //println!("Full line hex: {last_line}");
+            //println!(
+            //    "Full line hex: {}",
+            //    last_line
+            //        .as_bytes()
+            //        .iter()
+            //        .map(|byte| format!("{byte:02x}"))
+            //        .collect::<Vec<String>>()
+            //        .join(", ")
+            //);
+
+            // Iterate through the UTF-8 graphemes.
+            // Check the `split_long_word` inline function docs to see why we're using
+            // graphemes.
+            // **Note:** The `index` here is the **byte** index. So we cannot just
+            //    String::truncate afterwards. We have to convert to a byte vector to perform
+            //    the truncation first.
+            let mut grapheme_iter = last_line.grapheme_indices(true).peekable();
+            while let Some((index, grapheme)) = grapheme_iter.next() {
+                // Leave these print statements in here in case we ever have to debug this
+                // annoying stuff again
+                //println!(
+                //    "Current index: {index}, Next grapheme: {grapheme} (width: {})",
+                //    grapheme.width()
+                //);
+                //println!(
+                //    "Next grapheme hex: {}",
+                //    grapheme
+                //        .as_bytes()
+                //        .iter()
+                //        .map(|byte| format!("{byte:02x}"))
+                //        .collect::<Vec<String>>()
+                //        .join(", ")
+                //);
+
+                // Immediately save where to truncate in case this grapheme doesn't fit.
+                // The index is just before the current grapheme actually starts.
+                truncate_at = index;
+                // Check if the next grapheme would break the boundary of the allowed line
+                // length.
+                let new_width = accumulated_width + str_width(grapheme, table.ambiguous_width);
+                //println!(
+                //    "Next width: {new_width}/{max_width} ({accumulated_width} + {})",
+                //    grapheme.width()
+                //);
+                if new_width > max_width {
+                    //println!(
+                    //    "Breaking: {:?}",
+                    //    accumulated_width + grapheme.width() > max_width
+                    //);
+                    break;
+                }
+
+                // The grapheme seems to fit. Save the index and check the next one.
+                accumulated_width += str_width(grapheme, table.ambiguous_width);
+
+                // This is a special case.
+                // We reached the last char, meaning that full last line + the indicator fit.
+                if grapheme_iter.peek().is_none() {
+                    full_string_fits = true
+                }
+            }
+
+            // Only do any truncation logic if the line doesn't fit.
+            if !full_string_fits {
+                // Truncate the string at the byte index just behind the last valid grapheme
+                // and overwrite the last line with the new truncated string.
+                let mut last_line_bytes = last_line.clone().into_bytes();
+                last_line_bytes.truncate(truncate_at);
+                let new_last_line =
+                    String::from_utf8(last_line_bytes).expect("We cut at an exact char boundary");
+                *last_line = new_last_line;
+            }
+
+            // Don't highlight anything that has been cut off or the indicator itself.
+            #[cfg(feature = "tty")]
+            clip_highlights(&mut cell_highlights[lines - 1], last_line.len());
+
+            // Push the truncation indicator.
+            last_line.push_str(indicator);
+        }
+    }
+
+    cell_lines
+}
+
+/// Cut the truncation indicator of a table to the graphemes, that fit into `max_width`.
+///
+/// Returns the indicator and its width.
+fn clamp_indicator(table: &Table, max_width: usize) -> (&str, usize) {
+    let indicator = table.truncation_indicator.as_str();
+    let mut width = 0;
+    for (index, grapheme) in indicator.grapheme_indices(true) {
+        let grapheme_width = str_width(grapheme, table.ambiguous_width);
+        if width + grapheme_width > max_width {
+            return (&indicator[..index], width);
+        }
+        width += grapheme_width;
+    }
+
+    (indicator, width)
+}
+
+/// Split a line like [split_line], but leave space for a [hanging indent](Column::set_hanging_indent)
+/// of `indent` characters on all lines except for the first one.
+///
+/// The returned lines aren't indented yet.
+/// Returns `None`, if there's no indent or the lines can't be split with an indent.
+fn split_hanging(
+    line: &str,
+    info: &ColumnDisplayInfo,
+    delimiter: char,
+    indent: usize,
+) -> Option<Vec<String>> {
+    if indent == 0 || indent >= usize::from(info.content_width) {
+        return None;
+    }
+
+    let mut parts = split_line(line, info, delimiter);
+    // The first line is split with the full width, the rest is split again with less space.
+    let rest = line.strip_prefix(parts.first()?.as_str())?;
+    let rest = rest.trim_start_matches(delimiter);
+    parts.truncate(1);
+    if rest.is_empty() {
+        return Some(parts);
+    }
+
+    let indented = ColumnDisplayInfo {
+        content_width: info.content_width - indent as u16,
+        ascii_only: info.ascii_only || is_printable_ascii(rest),
+        ..info.clone()
+    };
+    // Graphemes are never split, so the widest one has to fit next to the indent.
+    if grapheme_width(rest, info.ambiguous_width) > usize::from(indented.content_width) {
+        return None;
+    }
+    if measure_text_width(rest, info.ambiguous_width) <= usize::from(indented.content_width) {
+        parts.push(rest.to_string());
+    } else {
+        parts.extend(split_line(rest, &indented, delimiter));
+    }
+
+    Some(parts)
+}
+
+/// Shorten a line, that's wider than `max_width`, and add the truncation indicator.
+///
+/// The indicator is added at the end of the line, or in its middle if `middle` is set.
+/// It's left out, if it doesn't fit into `max_width` by itself.
+fn truncate_line(table: &Table, line: &str, max_width: usize, middle: bool) -> String {
+    // Ansi codes might be cut in half, so they're removed.
+    #[cfg(feature = "custom_styling")]
+    let line = &console::strip_ansi_codes(line);
+
+    let mut indicator = table.truncation_indicator.as_str();
+    let mut indicator_width = str_width(indicator, table.ambiguous_width);
+    if indicator_width > max_width {
+        indicator = "";
+        indicator_width = 0;
+    }
+    let available = max_width - indicator_width;
+    let head_width = if middle {
+        available - available / 2
+    } else {
+        available
+    };
+
+    let graphemes: Vec<&str> = line.graphemes(true).collect();
+    let mut used = 0;
+    let mut head_end = 0;
+    for grapheme in &graphemes {
+        let width = str_width(grapheme, table.ambiguous_width);
+        if used + width > head_width {
+            break;
+        }
+        used += width;
+        head_end += 1;
+    }
+
+    let mut tail_start = graphemes.len();
+    if middle {
+        while tail_start > head_end {
+            let width = str_width(graphemes[tail_start - 1], table.ambiguous_width);
+            if used + width > available {
+                break;
+            }
+            used += width;
+            tail_start -= 1;
+        }
+    }
+
+    let mut truncated = graphemes[..head_end].concat();
+    truncated.push_str(indicator);
+    truncated.push_str(&graphemes[tail_start..].concat());
+    truncated
+}
+
+/// Convert the formatted cells of a row from a column-based to a line-based structure.
+///
+/// `row_heights` contains the amount of lines of each row.
+/// The lines of cells are moved into the returned parts, blank parts don't allocate.
+fn assemble_row(
+    formatted_row: FormattedRow,
+    display_infos: &[ColumnDisplayInfo],
+    row_index: usize,
+    row_heights: &[usize],
+    span_tracker: &SpanTracker,
+    vertical_lines: bool,
+) -> Vec<Vec<Cow<'static, str>>> {
+    let FormattedRow {
+        content: mut temp_row_content,
+        colspans: colspan_map,
+        vertical_alignments,
+        rowspans,
+        ..
+    } = formatted_row;
+
+    let max_lines = row_heights[row_index];
 
     // Apply vertical alignment to each cell
+    // The content of rowspans is positioned across all spanned rows instead.
     for (col_idx, cell_content) in temp_row_content.iter_mut().enumerate() {
+        if rowspans.iter().any(|rowspan| rowspan.col_index == col_idx) {
+            continue;
+        }
         if let Some(lines) = cell_content {
             let v_align = vertical_alignments[col_idx].unwrap_or(VerticalAlignment::Top);
             let content_height = lines.len();
@@ -361,21 +1273,17 @@ pub(crate) fn format_row(
                 let colspan = colspan_map[col_idx].unwrap_or(1);
                 let line_width = if colspan > 1 {
                     // Colspan cell - calculate combined width
-                    let visible_cols: Vec<&ColumnDisplayInfo> = display_infos
-                        [col_idx..(col_idx + colspan).min(display_infos.len())]
-                        .iter()
-                        .filter(|info| !info.is_hidden)
-                        .collect();
-                    let width_sum: usize =
-                        visible_cols.iter().map(|info| info.width() as usize).sum();
-                    let visible_colspan_count = visible_cols.len();
-                    width_sum + visible_colspan_count.saturating_sub(1)
+                    spanned_width(
+                        vertical_lines,
+                        &display_infos[col_idx..(col_idx + colspan).min(display_infos.len())],
+                    )
                 } else {
                     // Normal cell - use column width
-                    display_infos[col_idx].width() as usize
+                    display_infos[col_idx].width()
                 };
                 let empty_line = " ".repeat(line_width);
-                *lines = apply_vertical_alignment(lines.clone(), max_lines, v_align, empty_line);
+                *lines =
+                    apply_vertical_alignment(std::mem::take(lines), max_lines, v_align, empty_line);
             }
         }
     }
@@ -388,7 +1296,13 @@ pub(crate) fn format_row(
         let mut current_col = 0;
 
         while current_col < display_infos.len() {
-            if display_infos[current_col].is_hidden {
+            // Cells that start in a hidden column are displayed in their visible spanned columns.
+            if display_infos[current_col].is_hidden
+                && (temp_row_content[current_col].is_none()
+                    || span_tracker
+                        .get_rowspan_start(row_index, current_col)
+                        .is_some())
+            {
                 current_col += 1;
                 continue;
             }
@@ -399,114 +1313,70 @@ pub(crate) fn format_row(
                 span_tracker.get_rowspan_start(row_index, current_col)
             {
                 // Calculate combined width for this rowspan
-                let spanned_infos: Vec<&ColumnDisplayInfo> = display_infos
-                    .iter()
-                    .skip(start_col)
-                    .take(colspan as usize)
-                    .filter(|info| !info.is_hidden)
-                    .collect();
-                let width_sum: usize = spanned_infos.iter().map(|info| info.width() as usize).sum();
-                let combined_width = width_sum + (colspan as usize - 1);
-                let empty_line = " ".repeat(combined_width);
-
-                // Get content and calculate display position based on vertical alignment
-                if let Some(cached_content) = span_tracker.get_rowspan_content(row_index, start_col)
-                {
-                    let content_height = cached_content.len();
-                    let row_within_span = row_index - start_row;
-                    let content_offset = span_tracker.get_rowspan_content_offset(
-                        start_row,
-                        start_col,
-                        content_height,
-                    );
-
-                    // Check if this row should show content based on vertical alignment
-                    // Content is shown starting at row `content_offset` within the span
-                    if row_within_span == content_offset {
-                        // This row shows the content - display the appropriate line
-                        if let Some(content) = cached_content.get(line_index) {
-                            line.push(content.clone());
-                        } else {
-                            // More lines in this row than content has
-                            line.push(empty_line.clone());
-                        }
-                    } else {
-                        // This row is padding (before or after content based on alignment)
-                        line.push(empty_line.clone());
-                    }
-                } else {
-                    // Fallback: empty space
-                    line.push(empty_line);
+                let combined_width = spanned_width(
+                    vertical_lines,
+                    display_infos.iter().skip(start_col).take(colspan as usize),
+                );
+
+                // The index of this line within all lines of the spanned rows
+                let span_line =
+                    row_heights[start_row..row_index].iter().sum::<usize>() + line_index;
+                match span_tracker.get_rowspan_line(start_row, start_col, span_line) {
+                    Some(content) => line.push(Cow::Owned(content.clone())),
+                    None => line.push(blank(combined_width)),
                 }
                 // Advance by colspan to skip all columns in the rowspan
-                current_col += colspan as usize;
+                current_col = start_col + colspan as usize;
                 continue;
             }
 
             // Check if this column has content
-            if let Some(cell_lines) = &temp_row_content[current_col] {
+            if let Some(cell_lines) = &mut temp_row_content[current_col] {
                 // Check if this cell spans multiple columns
                 let colspan = colspan_map[current_col].unwrap_or(1);
 
-                // Check if this is a rowspan cell that should display content in a later row
-                // (based on vertical alignment)
-                let should_show_content = if let Some((_, start_col, _)) =
+                // Rowspan cells starting at this row display the first lines of their span.
+                // Each line of a cell is only used once, so it can be moved instead of cloned.
+                let content = if let Some((_, start_col, _)) =
                     span_tracker.get_rowspan_start_including_self(row_index, current_col)
                 {
-                    // This is a rowspan starting at this row
-                    // Check if content should be displayed here based on vertical alignment
-                    let content_height = cell_lines.len();
-                    let content_offset = span_tracker.get_rowspan_content_offset(
-                        row_index,
-                        start_col,
-                        content_height,
-                    );
-                    content_offset == 0 // Only show if content starts at row 0 (this row)
+                    span_tracker
+                        .get_rowspan_line(row_index, start_col, line_index)
+                        .cloned()
                 } else {
-                    true // Normal cell, always show
+                    cell_lines.get_mut(line_index).map(std::mem::take)
                 };
 
                 // Get empty line for this column's width
                 let empty_width = if colspan == 1 {
-                    display_infos[current_col].width() as usize
+                    display_infos[current_col].width()
                 } else {
-                    let visible_cols: Vec<&ColumnDisplayInfo> = display_infos
-                        [current_col..(current_col + colspan).min(display_infos.len())]
-                        .iter()
-                        .filter(|info| !info.is_hidden)
-                        .collect();
-                    let width_sum: usize =
-                        visible_cols.iter().map(|info| info.width() as usize).sum();
-                    let visible_colspan_count = visible_cols.len();
-                    width_sum + visible_colspan_count.saturating_sub(1)
+                    spanned_width(
+                        vertical_lines,
+                        &display_infos
+                            [current_col..(current_col + colspan).min(display_infos.len())],
+                    )
                 };
 
                 if colspan == 1 {
                     // Normal cell
-                    if should_show_content {
-                        if let Some(content) = cell_lines.get(line_index) {
-                            line.push(content.clone());
-                        } else {
-                            line.push(" ".repeat(display_infos[current_col].width().into()));
-                        }
-                    } else {
-                        line.push(" ".repeat(empty_width));
+                    match content {
+                        Some(content) => line.push(Cow::Owned(content)),
+                        None => line.push(blank(empty_width)),
                     }
                     current_col += 1;
                 } else {
                     // Colspan cell - the content is already formatted to the combined width
-                    if should_show_content {
-                        if let Some(content) = cell_lines.get(line_index) {
-                            line.push(content.clone());
-                        } else {
-                            line.push(" ".repeat(empty_width));
-                        }
-                    } else {
-                        line.push(" ".repeat(empty_width));
+                    match content {
+                        Some(content) => line.push(Cow::Owned(content)),
+                        None => line.push(blank(empty_width)),
                     }
                     // Skip the spanned columns - they're already included in the content above
                     // We need to advance through colspan-1 more logical columns
                     // For visible columns, add empty strings (borders will be drawn correctly)
+                    // If the cell starts in a hidden column, its content takes the place of the
+                    // first visible column.
+                    let mut has_visible_part = !display_infos[current_col].is_hidden;
                     let mut logical_cols_skipped = 0;
                     while logical_cols_skipped < colspan - 1
                         && current_col + 1 < display_infos.len()
@@ -515,14 +1385,17 @@ pub(crate) fn format_row(
                         logical_cols_skipped += 1;
                         // Only add empty string for visible columns (hidden ones are skipped by outer loop)
                         if !display_infos[current_col].is_hidden {
-                            line.push("".to_string());
+                            if has_visible_part {
+                                line.push(Cow::Borrowed(""));
+                            }
+                            has_visible_part = true;
                         }
                     }
                     current_col += 1;
                 }
             } else {
                 // No content for this column, fill with spaces
-                line.push(" ".repeat(display_infos[current_col].width().into()));
+                line.push(blank(display_infos[current_col].width()));
                 current_col += 1;
             }
         }
@@ -533,77 +1406,156 @@ pub(crate) fn format_row(
     row_content
 }
 
+/// A blank part of a line, which only allocates if it's wider than most columns.
+fn blank(width: usize) -> Cow<'static, str> {
+    const SPACES: &str = "                                                                ";
+    match SPACES.get(..width) {
+        Some(spaces) => Cow::Borrowed(spaces),
+        None => Cow::Owned(" ".repeat(width)),
+    }
+}
+
 /// Apply the alignment for a column. Alignment can be either Left/Right/Center.
 /// In every case all lines will be exactly the same character length `info.width - padding long`
 /// This is needed, so we can simply insert it into the border frame later on.
 /// Padding is applied in this function as well.
 #[allow(unused_variables)]
-fn align_line(table: &Table, info: &ColumnDisplayInfo, cell: &Cell, mut line: String) -> String {
-    let content_width = info.content_width;
-    let remaining: usize = usize::from(content_width).saturating_sub(measure_text_width(&line));
+fn align_line(
+    table: &Table,
+    info: &ColumnDisplayInfo,
+    cell: &Cell,
+    alignment: CellAlignment,
+    mut line: String,
+) -> String {
+    let (left, right) = alignment_spacing(info, alignment, &line);
+
+    // Lines with a gradient are styled character by character, so only the spacing gets the
+    // regular style of the cell.
+    #[cfg(feature = "tty")]
+    if let Some(gradient) = line_gradient(table, cell, &line) {
+        let mut left = fill_spacing(cell, &line, left, info.padding.0, true);
+        let mut right = fill_spacing(cell, &line, right, info.padding.1, false);
+        if !table.style_text_only {
+            left = cell.style().apply(left);
+            right = cell.style().apply(right);
+        }
+        return left + &style_gradient_line(&line, cell, gradient) + &right;
+    }
 
     // Apply the styling before aligning the line, if the user requests it.
     // That way non-delimiter whitespaces won't have stuff like underlines.
     #[cfg(feature = "tty")]
     if table.should_style() && table.style_text_only {
-        line = style_line(line, cell);
+        line = cell.style().apply(line);
     }
 
-    // Determine the alignment of the column cells.
-    // Cell settings overwrite the columns Alignment settings.
-    // Default is Left
-    let alignment = if let Some(alignment) = cell.alignment {
-        alignment
-    } else if let Some(alignment) = info.cell_alignment {
-        alignment
-    } else {
-        CellAlignment::Left
-    };
-
-    // Apply left/right/both side padding depending on the alignment of the column
-    match alignment {
-        CellAlignment::Left => {
-            line += &" ".repeat(remaining);
-        }
-        CellAlignment::Right => {
-            line = " ".repeat(remaining) + &line;
-        }
-        CellAlignment::Center => {
-            let left_padding = (remaining as f32 / 2f32).ceil() as usize;
-            let right_padding = (remaining as f32 / 2f32).floor() as usize;
-            line = " ".repeat(left_padding) + &line + &" ".repeat(right_padding);
-        }
-    }
-
-    line = pad_line(&line, info);
+    let left = fill_spacing(cell, &line, left, info.padding.0, true);
+    let right = fill_spacing(cell, &line, right, info.padding.1, false);
+    line = left + &line + &right;
 
     #[cfg(feature = "tty")]
     if table.should_style() && !table.style_text_only {
-        return style_line(line, cell);
+        return cell.style().apply(line);
     }
 
     line
 }
 
-/// Apply the column's padding to this line
-fn pad_line(line: &str, info: &ColumnDisplayInfo) -> String {
-    let mut padded_line = String::new();
+/// Same as [align_line], but for lines that contain highlighted ranges.
+///
+/// Highlighted and non-highlighted text are styled separately, as the reset of a highlight
+/// would otherwise also reset the style of the remaining line.
+#[cfg(feature = "tty")]
+fn align_highlighted_line(
+    table: &Table,
+    info: &ColumnDisplayInfo,
+    cell: &Cell,
+    alignment: CellAlignment,
+    line: &str,
+    highlights: &[Highlight],
+) -> String {
+    let (left, right) = alignment_spacing(info, alignment, line);
+
+    let mut left = fill_spacing(cell, line, left, info.padding.0, true);
+    let mut right = fill_spacing(cell, line, right, info.padding.1, false);
+    if !table.style_text_only {
+        left = cell.style().apply(left);
+        right = cell.style().apply(right);
+    }
 
-    padded_line += &" ".repeat(info.padding.0.into());
-    padded_line += line;
-    padded_line += &" ".repeat(info.padding.1.into());
+    left + &style_highlighted_line(line, cell, highlights) + &right
+}
 
-    padded_line
+/// Determine the alignment of a line of a cell.
+///
+/// `source` is the line of the cell's content, that the line belongs to.
+/// The alignment of that line overwrites the cell's settings, which overwrite the column's
+/// settings, which overwrite the row's settings. Default is Left.
+fn line_alignment(info: &ColumnDisplayInfo, cell: &Cell, source: Option<usize>) -> CellAlignment {
+    source
+        .and_then(|source| cell.line_alignments.get(source).copied())
+        .or(cell.alignment)
+        .or(info.cell_alignment)
+        .unwrap_or(CellAlignment::Left)
+}
+
+/// Determine how many spaces have to be added on the left and right side of a line, to respect
+/// the alignment and the padding of its column.
+fn alignment_spacing(
+    info: &ColumnDisplayInfo,
+    alignment: CellAlignment,
+    line: &str,
+) -> (usize, usize) {
+    let content_width = info.content_width;
+    let remaining: usize =
+        usize::from(content_width).saturating_sub(measure_text_width(line, info.ambiguous_width));
+
+    // Apply left/right/both side padding depending on the alignment of the column
+    let (left, right) = match alignment {
+        CellAlignment::Left => (0, remaining),
+        CellAlignment::Right => (remaining, 0),
+        CellAlignment::Center => {
+            let left_padding = (remaining as f32 / 2f32).ceil() as usize;
+            let right_padding = (remaining as f32 / 2f32).floor() as usize;
+            (left_padding, right_padding)
+        }
+    };
+
+    (
+        left + usize::from(info.padding.0),
+        right + usize::from(info.padding.1),
+    )
+}
+
+/// Build the spacing on one side of a line.
+///
+/// The spacing consists of the padding of the column, which is next to the border, and the
+/// space that's caused by the alignment of the line.
+/// The latter is filled with the [fill char](Cell::set_fill_char) of the cell, if it has one.
+fn fill_spacing(cell: &Cell, line: &str, spacing: usize, padding: u16, left: bool) -> String {
+    let padding = usize::from(padding).min(spacing);
+    let fill_width = spacing - padding;
+    match cell.fill_char {
+        Some(fill_char) if fill_width > 0 && !line.is_empty() => {
+            let fill = fill_char.to_string().repeat(fill_width);
+            if left {
+                " ".repeat(padding) + &fill
+            } else {
+                fill + &" ".repeat(padding)
+            }
+        }
+        _ => " ".repeat(spacing),
+    }
 }
 
 /// Apply vertical alignment to cell content.
 /// Pads the content with empty lines at top/bottom to achieve the desired alignment.
 fn apply_vertical_alignment(
-    content_lines: Vec<String>,
+    content_lines: CellLines,
     total_height: usize,
     alignment: VerticalAlignment,
     empty_line: String,
-) -> Vec<String> {
+) -> CellLines {
     let content_height = content_lines.len();
     if content_height >= total_height {
         return content_lines;
@@ -612,48 +1564,22 @@ fn apply_vertical_alignment(
     let padding_needed = total_height - content_height;
 
     match alignment {
-        VerticalAlignment::Top => {
+        // Baselines have already been aligned while formatting the row.
+        VerticalAlignment::Top | VerticalAlignment::Baseline(_) => {
             let mut result = content_lines;
             result.resize(total_height, empty_line);
             result
         }
-        VerticalAlignment::Bottom => {
-            let mut result = vec![empty_line; padding_needed];
-            result.extend(content_lines);
-            result
-        }
+        VerticalAlignment::Bottom => std::iter::repeat_n(empty_line, padding_needed)
+            .chain(content_lines)
+            .collect(),
         VerticalAlignment::Middle => {
             let top_padding = padding_needed / 2;
-            let mut result = vec![empty_line.clone(); top_padding];
-            result.extend(content_lines);
+            let mut result: CellLines = std::iter::repeat_n(empty_line.clone(), top_padding)
+                .chain(content_lines)
+                .collect();
             result.resize(total_height, empty_line);
             result
         }
     }
 }
-
-#[cfg(feature = "tty")]
-fn style_line(line: String, cell: &Cell) -> String {
-    // Just return the line, if there's no need to style.
-    if cell.fg.is_none() && cell.bg.is_none() && cell.attributes.is_empty() {
-        return line;
-    }
-
-    let mut content = style(line);
-
-    // Apply text color
-    if let Some(color) = cell.fg {
-        content = content.with(map_color(color));
-    }
-
-    // Apply background color
-    if let Some(color) = cell.bg {
-        content = content.on(map_color(color));
-    }
-
-    for attribute in cell.attributes.iter() {
-        content = content.attribute(map_attribute(*attribute));
-    }
-
-    content.to_string()
-}
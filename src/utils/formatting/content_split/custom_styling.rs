@@ -1,13 +1,19 @@
 use ansi_str::AnsiStr;
 use unicode_segmentation::UnicodeSegmentation;
-use unicode_width::UnicodeWidthStr;
+
+use super::str_width;
+use crate::style::AmbiguousWidth;
 
 const ANSI_RESET: &str = "\u{1b}[0m";
 
 /// Returns printed length of string, takes into account escape codes
 #[inline(always)]
-pub fn measure_text_width(s: &str) -> usize {
-    s.ansi_strip().width()
+pub fn measure_text_width(s: &str, ambiguous_width: AmbiguousWidth) -> usize {
+    // Printable ASCII can't contain any escape sequences.
+    if super::is_printable_ascii(s) {
+        return s.len();
+    }
+    str_width(&s.ansi_strip(), ambiguous_width)
 }
 
 /// Split the line by the given deliminator without breaking ansi codes that contain the delimiter
@@ -42,7 +48,11 @@ pub fn split_line_by_delimiter(line: &str, delimiter: char) -> Vec<String> {
 }
 
 /// Splits a long word at a given character width. Inserting the needed ansi codes to preserve style.
-pub fn split_long_word(allowed_width: usize, word: &str) -> (String, String) {
+pub fn split_long_word(
+    allowed_width: usize,
+    word: &str,
+    ambiguous_width: AmbiguousWidth,
+) -> (String, String) {
     // A buffer for the first half of the split str, which will take up at most `allowed_len` characters when printed to the terminal.
     let mut head = String::with_capacity(word.len());
     // A buffer for the second half of the split str
@@ -71,7 +81,7 @@ pub fn split_long_word(allowed_width: usize, word: &str) -> (String, String) {
 
         let slice_len = match is_esc {
             true => 0,
-            false => str_slice.width(),
+            false => str_width(str_slice, ambiguous_width),
         };
 
         if head_len + slice_len <= allowed_width {
@@ -87,7 +97,7 @@ pub fn split_long_word(allowed_width: usize, word: &str) -> (String, String) {
             assert!(!is_esc);
             let mut graphmes = str_slice.graphemes(true).peekable();
             while let Some(c) = graphmes.peek() {
-                let character_width = c.width();
+                let character_width = str_width(c, ambiguous_width);
                 if allowed_width < head_len + character_width {
                     break;
                 }
@@ -183,10 +193,11 @@ mod test {
     #[cfg(not(feature = "custom_styling"))]
     fn measure_text_width_osc8_test() {
         use super::measure_text_width;
+        use crate::style::AmbiguousWidth;
         use unicode_width::UnicodeWidthStr;
 
         let text = "\x1b]8;;https://github.com\x1b\\This is a link\x1b]8;;\x1b";
-        let width = measure_text_width(text);
+        let width = measure_text_width(text, AmbiguousWidth::Narrow);
 
         assert_eq!(text.width(), 41);
         assert_eq!(width, 14);
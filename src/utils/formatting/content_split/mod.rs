@@ -1,3 +1,6 @@
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::style::AmbiguousWidth;
 use crate::utils::ColumnDisplayInfo;
 
 #[cfg(feature = "custom_styling")]
@@ -10,6 +13,33 @@ pub use custom_styling::*;
 #[cfg(not(feature = "custom_styling"))]
 pub use normal::*;
 
+/// Check whether a text only consists of printable ASCII characters.
+///
+/// Each of those characters is exactly one column wide, so the width of such a text is its
+/// length in bytes, which is a lot cheaper to determine than its unicode width.
+pub fn is_printable_ascii(text: &str) -> bool {
+    text.bytes().all(|byte| matches!(byte, b' '..=b'~'))
+}
+
+/// Returns the display width of a text without any escape sequences.
+#[inline(always)]
+pub fn str_width(text: &str, ambiguous_width: AmbiguousWidth) -> usize {
+    match ambiguous_width {
+        AmbiguousWidth::Narrow => text.width(),
+        AmbiguousWidth::Wide => text.width_cjk(),
+    }
+}
+
+/// Returns the display width of a single character.
+/// Control characters don't have a width.
+#[inline(always)]
+pub fn char_width(character: char, ambiguous_width: AmbiguousWidth) -> Option<usize> {
+    match ambiguous_width {
+        AmbiguousWidth::Narrow => character.width(),
+        AmbiguousWidth::Wide => character.width_cjk(),
+    }
+}
+
 /// Split a line if it's longer than the allowed columns (width - padding).
 ///
 /// This function tries to do this in a smart way, by splitting the content
@@ -23,6 +53,14 @@ pub use normal::*;
 pub fn split_line(line: &str, info: &ColumnDisplayInfo, delimiter: char) -> Vec<String> {
     let mut lines = Vec::new();
     let content_width = usize::from(info.content_width);
+    let ambiguous_width = info.ambiguous_width;
+    let measure_text_width = |text: &str| {
+        if info.ascii_only {
+            text.len()
+        } else {
+            measure_text_width(text, ambiguous_width)
+        }
+    };
 
     // Split the line by the given deliminator and turn the content into a stack.
     // Also clone it and convert it into a Vec<String>. Otherwise, we get some burrowing problems
@@ -59,7 +97,8 @@ pub fn split_line(line: &str, info: &ColumnDisplayInfo, delimiter: char) -> Vec<
             current_line += &next;
 
             // Already complete the current line, if there isn't space for more than two chars
-            current_line = check_if_full(&mut lines, content_width, current_line);
+            current_line =
+                check_if_full(&mut lines, content_width, current_line, measure_text_width);
             continue;
         }
 
@@ -97,7 +136,12 @@ pub fn split_line(line: &str, info: &ColumnDisplayInfo, delimiter: char) -> Vec<
                 current_line.push(delimiter);
             }
 
-            let (mut next, mut remaining) = split_long_word(remaining_width, &next);
+            let (mut next, mut remaining) = if info.ascii_only {
+                let (next, remaining) = next.split_at(remaining_width.min(next.len()));
+                (next.to_string(), remaining.to_string())
+            } else {
+                split_long_word(remaining_width, &next, ambiguous_width)
+            };
 
             // This is an ugly hack, but it's needed for now.
             //
@@ -129,7 +173,7 @@ pub fn split_line(line: &str, info: &ColumnDisplayInfo, delimiter: char) -> Vec<
         // Push the current line and initialize the next line with the element.
         lines.push(current_line);
         current_line = next.to_string();
-        current_line = check_if_full(&mut lines, content_width, current_line);
+        current_line = check_if_full(&mut lines, content_width, current_line, measure_text_width);
     }
 
     if !current_line.is_empty() {
@@ -149,7 +193,12 @@ const MIN_FREE_CHARS: usize = 2;
 /// Check if the current line is too long and whether we should start a new one
 /// If it's too long, we add the current line to the list of lines and return a new [String].
 /// Otherwise, we simply return the current line and basically don't do anything.
-fn check_if_full(lines: &mut Vec<String>, content_width: usize, current_line: String) -> String {
+fn check_if_full(
+    lines: &mut Vec<String>,
+    content_width: usize,
+    current_line: String,
+    measure_text_width: impl Fn(&str) -> usize,
+) -> String {
     // Already complete the current line, if there isn't space for more than two chars
     if measure_text_width(&current_line) > content_width.saturating_sub(MIN_FREE_CHARS) {
         lines.push(current_line);
@@ -171,7 +220,7 @@ mod tests {
         assert_eq!(emoji.chars().count(), 4);
         assert_eq!(emoji.width(), 2);
 
-        let (word, remaining) = split_long_word(emoji.width(), emoji);
+        let (word, remaining) = split_long_word(emoji.width(), emoji, AmbiguousWidth::Narrow);
 
         assert_eq!(word, "\u{1F642}\u{200D}\u{2195}\u{FE0F}");
         assert_eq!(word.len(), 13);
@@ -180,4 +229,29 @@ mod tests {
 
         assert!(remaining.is_empty());
     }
+
+    #[test]
+    fn test_ascii_width_fast_path() {
+        for text in [
+            "",
+            "Plain ASCII ~ text 123",
+            "tab\tcontrol",
+            "Ünïcödé",
+            "🙂 emoji",
+        ] {
+            assert_eq!(
+                measure_text_width(text, AmbiguousWidth::Narrow),
+                text.width(),
+                "{text:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_ambiguous_width() {
+        assert_eq!(str_width("±1 → 2", AmbiguousWidth::Narrow), 6);
+        assert_eq!(str_width("±1 → 2", AmbiguousWidth::Wide), 8);
+        assert_eq!(str_width("plain", AmbiguousWidth::Wide), 5);
+        assert_eq!(char_width('±', AmbiguousWidth::Wide), Some(2));
+    }
 }
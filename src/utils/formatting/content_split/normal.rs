@@ -1,11 +1,16 @@
 use unicode_segmentation::UnicodeSegmentation;
-use unicode_width::UnicodeWidthStr;
+
+use super::str_width;
+use crate::style::AmbiguousWidth;
 
 /// returns printed length of string
 /// if ansi feature enabled, takes into account escape codes
 #[inline(always)]
-pub fn measure_text_width(s: &str) -> usize {
-    s.width()
+pub fn measure_text_width(s: &str, ambiguous_width: AmbiguousWidth) -> usize {
+    if super::is_printable_ascii(s) {
+        return s.len();
+    }
+    str_width(s, ambiguous_width)
 }
 
 /// Split a line into its individual parts along the given delimiter.
@@ -19,7 +24,11 @@ pub fn split_line_by_delimiter(line: &str, delimiter: char) -> Vec<String> {
 /// This needs some special logic, as we have to take multi-character UTF-8 symbols into account.
 /// When simply splitting at a certain char position, we might end up with a string that's has a
 /// wider display width than allowed.
-pub fn split_long_word(allowed_width: usize, word: &str) -> (String, String) {
+pub fn split_long_word(
+    allowed_width: usize,
+    word: &str,
+    ambiguous_width: AmbiguousWidth,
+) -> (String, String) {
     let mut current_width = 0;
     let mut parts = String::new();
 
@@ -35,14 +44,14 @@ pub fn split_long_word(allowed_width: usize, word: &str) -> (String, String) {
     // [0]: https://en.wikipedia.org/wiki/Zero-width_joiner
     // [1]: https://en.wikipedia.org/wiki/Variation_Selectors_(Unicode_block)
     while let Some(c) = graphmes.peek() {
-        if (current_width + c.width()) > allowed_width {
+        if (current_width + str_width(c, ambiguous_width)) > allowed_width {
             break;
         }
 
         // We can unwrap, as we just checked that a suitable grapheme is next in line.
         let c = graphmes.next().unwrap();
 
-        let character_width = c.width();
+        let character_width = str_width(c, ambiguous_width);
         current_width += character_width;
         parts.push_str(c);
     }
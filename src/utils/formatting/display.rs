@@ -0,0 +1,236 @@
+use std::borrow::Cow;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use super::content_split::{is_printable_ascii, measure_text_width, split_line_by_delimiter};
+use crate::cell::Cell;
+use crate::column::Column;
+use crate::style::{AmbiguousWidth, MaskPolicy, Orientation, TextTransform};
+
+/// The content of each non-empty cell in a [collapsed](Column::set_collapsed) column.
+const COLLAPSED_PLACEHOLDER: &str = "…";
+
+/// Returns the lines of a cell, as they should be displayed.
+///
+/// Some column settings change the displayed content of a cell at render time, without
+/// touching the content that's stored in the cell.
+/// This function applies all of those, so the width calculation and the actual formatting
+/// always work with the same content.
+///
+/// `column` is the column the cell starts in.
+/// `header` indicates whether the cell is part of the table's header.
+pub(crate) fn display_lines<'a>(
+    cell: &'a Cell,
+    column: Option<&Column>,
+    header: bool,
+) -> Cow<'a, [String]> {
+    let lines = horizontal_lines(cell, column, header);
+    match cell.orientation {
+        Orientation::Horizontal => lines,
+        Orientation::Vertical => Cow::Owned(vertical_lines(&lines)),
+    }
+}
+
+/// Returns the displayed lines of a cell, as they're written from left to right.
+fn horizontal_lines<'a>(
+    cell: &'a Cell,
+    column: Option<&Column>,
+    header: bool,
+) -> Cow<'a, [String]> {
+    let mut lines = Cow::Borrowed(cell.content.as_slice());
+
+    // Collapsed columns only show, whether a cell has any content.
+    if column.is_some_and(Column::is_collapsed) {
+        if lines.iter().all(String::is_empty) {
+            return lines;
+        }
+        return Cow::Owned(vec![COLLAPSED_PLACEHOLDER.to_string()]);
+    }
+
+    let Some(column) = column else {
+        add_affixes(cell, &mut lines);
+        return lines;
+    };
+
+    // Masking works on the raw content, so hashes don't depend on any presentational changes.
+    let mask = column.mask().filter(|_| !header);
+    let transform = if header {
+        column.header_text_transform()
+    } else {
+        column.text_transform()
+    };
+    if let Some(mask) = mask {
+        lines = Cow::Owned(mask_lines(mask, &lines));
+    } else if let Some(transform) = transform {
+        lines = Cow::Owned(
+            lines
+                .iter()
+                .map(|line| transform_line(transform, line))
+                .collect(),
+        );
+    }
+
+    add_affixes(cell, &mut lines);
+
+    if header {
+        if let Some(indicator) = column.sort_indicator_glyph() {
+            let lines = lines.to_mut();
+            match lines.first_mut() {
+                Some(first) if !first.is_empty() => {
+                    first.push(' ');
+                    first.push_str(indicator);
+                }
+                Some(first) => first.push_str(indicator),
+                None => lines.push(indicator.to_string()),
+            }
+        }
+    }
+
+    lines
+}
+
+/// Write each grapheme of the lines on its own line.
+///
+/// The lines are separated by an empty line.
+/// Any ansi codes are removed beforehand, as they can't be split into graphemes.
+fn vertical_lines(lines: &[String]) -> Vec<String> {
+    let mut vertical = Vec::new();
+    for (index, line) in lines.iter().enumerate() {
+        #[cfg(feature = "custom_styling")]
+        let line = console::strip_ansi_codes(line);
+        if index > 0 {
+            vertical.push(String::new());
+        }
+        vertical.extend(
+            line.graphemes(true)
+                .map(|grapheme| grapheme.trim().to_string()),
+        );
+    }
+
+    vertical
+}
+
+/// Add the prefix of a cell to its first line and the suffix to its last line.
+fn add_affixes(cell: &Cell, lines: &mut Cow<'_, [String]>) {
+    if cell.prefix.is_none() && cell.suffix.is_none() {
+        return;
+    }
+
+    let lines = lines.to_mut();
+    if lines.is_empty() {
+        lines.push(String::new());
+    }
+    if let (Some(prefix), Some(first)) = (&cell.prefix, lines.first_mut()) {
+        first.insert_str(0, prefix);
+    }
+    if let (Some(suffix), Some(last)) = (&cell.suffix, lines.last_mut()) {
+        last.push_str(suffix);
+    }
+}
+
+/// Mask the lines of a cell.
+///
+/// The lines are masked as a whole, so hashes are calculated over the full content.
+/// Any ansi codes are removed beforehand, as they would be masked like normal text otherwise.
+fn mask_lines(mask: MaskPolicy, lines: &[String]) -> Vec<String> {
+    let content = lines.join("\n");
+    #[cfg(feature = "custom_styling")]
+    let content = console::strip_ansi_codes(&content).to_string();
+
+    mask.mask(&content)
+        .split('\n')
+        .map(ToString::to_string)
+        .collect()
+}
+
+/// Change the casing of a line.
+///
+/// Ansi codes are kept as they are, as their meaning would change otherwise.
+fn transform_line(transform: TextTransform, line: &str) -> String {
+    #[cfg(feature = "custom_styling")]
+    {
+        let mut word_start = true;
+        console::AnsiCodeIterator::new(line)
+            .map(|(part, is_ansi)| {
+                if is_ansi {
+                    part.to_string()
+                } else {
+                    transform.apply_continued(part, &mut word_start)
+                }
+            })
+            .collect()
+    }
+
+    #[cfg(not(feature = "custom_styling"))]
+    transform.apply(line)
+}
+
+/// Returns the width of the widest displayed line of a cell.
+pub(crate) fn display_width(
+    cell: &Cell,
+    column: Option<&Column>,
+    header: bool,
+    ambiguous_width: AmbiguousWidth,
+) -> usize {
+    display_lines(cell, column, header)
+        .iter()
+        .map(String::as_str)
+        .chain(displayed_unit(column, header))
+        .map(|line| measure_text_width(line, ambiguous_width))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Returns the width of the widest word of the displayed lines of a cell.
+pub(crate) fn longest_word_width(
+    cell: &Cell,
+    column: Option<&Column>,
+    header: bool,
+    delimiter: char,
+    ambiguous_width: AmbiguousWidth,
+) -> usize {
+    display_lines(cell, column, header)
+        .iter()
+        .flat_map(|line| split_line_by_delimiter(line, delimiter))
+        .map(|word| measure_text_width(&word, ambiguous_width))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Returns the width of the widest grapheme of the displayed lines of a cell.
+///
+/// Lines are never split within a grapheme, so the content of a cell needs at least this width.
+pub(crate) fn widest_grapheme_width(
+    cell: &Cell,
+    column: Option<&Column>,
+    header: bool,
+    ambiguous_width: AmbiguousWidth,
+) -> usize {
+    display_lines(cell, column, header)
+        .iter()
+        .map(String::as_str)
+        .chain(displayed_unit(column, header))
+        .map(|line| grapheme_width(line, ambiguous_width))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Returns the width of the widest grapheme of a line.
+pub(crate) fn grapheme_width(line: &str, ambiguous_width: AmbiguousWidth) -> usize {
+    if is_printable_ascii(line) {
+        return usize::from(!line.is_empty());
+    }
+    line.graphemes(true)
+        .map(|grapheme| measure_text_width(grapheme, ambiguous_width))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Returns the [unit](Column::set_unit) of a column, if it's displayed below the given cell.
+///
+/// Units are only displayed in the header.
+pub(crate) fn displayed_unit(column: Option<&Column>, header: bool) -> Option<&str> {
+    column
+        .filter(|column| header && !column.is_collapsed())
+        .and_then(Column::unit)
+}
@@ -0,0 +1,37 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::cell::Cell;
+use crate::style::Color;
+use crate::style::palettes::Palette;
+use crate::table::Table;
+
+/// The colors of the [gradient](Cell::set_gradient), that a line of a cell is styled with.
+///
+/// Returns `None`, if the cell doesn't have a gradient, the table isn't styled or doesn't
+/// support 24-bit colors, or the line is already styled with ANSI escape sequences.
+pub(crate) fn line_gradient(table: &Table, cell: &Cell, line: &str) -> Option<(Color, Color)> {
+    let gradient = cell.gradient?;
+    if !table.should_style() || !table.supports_truecolor() || line.contains('\x1b') {
+        return None;
+    }
+
+    Some(gradient)
+}
+
+/// Style each character of a line with its color of the gradient.
+///
+/// The background and the attributes of the cell are kept.
+pub(crate) fn style_gradient_line(line: &str, cell: &Cell, (start, end): (Color, Color)) -> String {
+    let scale = Palette::new(vec![start, end]);
+    let graphemes: Vec<&str> = line.graphemes(true).collect();
+    let steps = graphemes.len().saturating_sub(1).max(1) as f64;
+
+    let mut style = cell.style();
+    let mut styled = String::new();
+    for (index, grapheme) in graphemes.into_iter().enumerate() {
+        style.fg = Some(scale.scale(index as f64 / steps));
+        styled += &style.apply(grapheme.to_string());
+    }
+
+    styled
+}
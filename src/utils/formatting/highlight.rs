@@ -0,0 +1,153 @@
+use std::ops::Range;
+
+use crate::cell::Cell;
+use crate::style::Style;
+use crate::table::Table;
+
+/// A highlighted byte range of a line and the style it should be rendered with.
+pub(crate) type Highlight<'a> = (Range<usize>, &'a Style);
+
+/// Find all highlighted ranges in a line of a cell, sorted by their position.
+///
+/// If matches of multiple patterns overlap, the match of the pattern added first wins.
+pub(crate) fn find_highlights<'a>(table: &'a Table, line: &str) -> Vec<Highlight<'a>> {
+    if table.highlights.is_empty() || !table.should_style() {
+        return Vec::new();
+    }
+
+    let mut highlights: Vec<Highlight<'a>> = Vec::new();
+    for (pattern, style) in table.highlights.iter() {
+        for range in pattern.find_ranges(line) {
+            let overlaps = highlights
+                .iter()
+                .any(|(other, _)| range.start < other.end && other.start < range.end);
+            if !overlaps {
+                highlights.push((range, style));
+            }
+        }
+    }
+    highlights.sort_by_key(|(range, _)| range.start);
+
+    highlights
+}
+
+/// Distribute the highlights of a line onto the parts that line has been split into.
+///
+/// Splitting only ever removes delimiters in between the parts, so each part can be found in
+/// the original line after the end of the previous part.
+/// The returned ranges are relative to their respective part.
+pub(crate) fn split_highlights<'a>(
+    line: &str,
+    parts: &[String],
+    highlights: Vec<Highlight<'a>>,
+) -> Vec<Vec<Highlight<'a>>> {
+    if highlights.is_empty() {
+        return vec![Vec::new(); parts.len()];
+    }
+
+    let mut cursor = 0;
+    parts
+        .iter()
+        .map(|part| {
+            // Parts might have been modified while splitting, for instance by re-adding ansi codes.
+            // We cannot map the highlights in those cases.
+            let Some(offset) = line[cursor..].find(part.as_str()) else {
+                return Vec::new();
+            };
+            let start = cursor + offset;
+            let end = start + part.len();
+            cursor = end;
+
+            highlights
+                .iter()
+                .filter(|(range, _)| range.start < end && start < range.end)
+                .map(|(range, style)| {
+                    (
+                        range.start.max(start) - start..range.end.min(end) - start,
+                        *style,
+                    )
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Drop all highlights that exceed the given length of a line.
+/// Highlights that are only partially cut off are shortened.
+pub(crate) fn clip_highlights(highlights: &mut Vec<Highlight<'_>>, len: usize) {
+    highlights.retain(|(range, _)| range.start < len);
+    for (range, _) in highlights.iter_mut() {
+        range.end = range.end.min(len);
+    }
+}
+
+/// Style a line of a cell, with all highlighted ranges layered on top of the cell's style.
+pub(crate) fn style_highlighted_line(
+    line: &str,
+    cell: &Cell,
+    highlights: &[Highlight<'_>],
+) -> String {
+    let cell_style = cell.style();
+    let mut styled = String::new();
+    let mut cursor = 0;
+
+    for (range, style) in highlights {
+        // Don't panic on malformed ranges, but rather skip the highlight.
+        if range.start < cursor || !line.is_char_boundary(range.start) {
+            continue;
+        }
+        if !line.is_char_boundary(range.end) {
+            continue;
+        }
+
+        if cursor < range.start {
+            styled += &cell_style.apply(line[cursor..range.start].to_string());
+        }
+        styled += &cell_style
+            .merge(style)
+            .apply(line[range.start..range.end].to_string());
+        cursor = range.end;
+    }
+
+    if cursor < line.len() {
+        styled += &cell_style.apply(line[cursor..].to_string());
+    }
+
+    styled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn highlights_are_split_across_parts() {
+        let style = Style::new();
+        let line = "some quite long text";
+        let parts = vec!["some quite".to_string(), "long text".to_string()];
+        // "quite long" spans both parts.
+        let highlights = vec![(5..15, &style)];
+
+        let split = split_highlights(line, &parts, highlights);
+        assert_eq!(
+            split[0].iter().map(|h| h.0.clone()).collect::<Vec<_>>(),
+            vec![5..10]
+        );
+        assert_eq!(
+            split[1].iter().map(|h| h.0.clone()).collect::<Vec<_>>(),
+            vec![0..4]
+        );
+    }
+
+    #[test]
+    fn clipped_highlights() {
+        let style = Style::new();
+        let mut highlights = vec![(0..2, &style), (3..8, &style), (6..9, &style)];
+        clip_highlights(&mut highlights, 5);
+
+        assert_eq!(
+            highlights.iter().map(|h| h.0.clone()).collect::<Vec<_>>(),
+            vec![0..2, 3..5]
+        );
+    }
+}
@@ -1,3 +1,8 @@
 pub mod borders;
 pub mod content_format;
 pub mod content_split;
+pub mod display;
+#[cfg(feature = "tty")]
+pub(crate) mod gradient;
+#[cfg(feature = "tty")]
+pub mod highlight;
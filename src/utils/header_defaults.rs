@@ -0,0 +1,33 @@
+use std::borrow::Cow;
+
+use crate::table::Table;
+
+/// Apply the [HeaderDefaults](crate::HeaderDefaults) of a table to its header.
+///
+/// Cells and columns keep their own settings, the defaults only fill in the missing ones.
+pub(crate) fn apply_header_defaults(table: &mut Cow<'_, Table>) {
+    if table.header_defaults.is_none() || table.header.is_none() {
+        return;
+    }
+
+    let styled = table.to_mut();
+    let Some(defaults) = styled.header_defaults.as_ref() else {
+        return;
+    };
+    if let Some(header) = styled.header.as_mut() {
+        for cell in header.cells.iter_mut() {
+            cell.alignment = cell.alignment.or(defaults.alignment);
+            #[cfg(feature = "tty")]
+            for attribute in &defaults.attributes {
+                if !cell.attributes.contains(attribute) {
+                    cell.attributes.push(*attribute);
+                }
+            }
+        }
+    }
+    if let Some(transform) = defaults.transform {
+        for column in styled.columns.iter_mut() {
+            column.header_text_transform = column.header_text_transform.or(Some(transform));
+        }
+    }
+}
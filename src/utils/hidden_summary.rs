@@ -0,0 +1,132 @@
+use std::borrow::Cow;
+
+use super::ColumnDisplayInfo;
+use super::arrangement::arrange_content;
+use super::spanning::SpanTracker;
+use crate::cell::Cell;
+use crate::column::Column;
+use crate::style::{ColumnConstraint, HiddenColumnsSummary};
+use crate::table::Table;
+
+/// How often the table is arranged again, if the summary column hides other columns.
+const MAX_ARRANGEMENTS: usize = 3;
+
+/// Arrange the content of a table and add the [summary of its hidden
+/// columns](Table::set_hidden_columns_summary), if there are any.
+///
+/// The summary column takes up space itself, which may hide further columns.
+/// In that case, the summary is created again for the columns, that are hidden then.
+pub(crate) fn arrange_with_summary(
+    table: Cow<'_, Table>,
+) -> (Cow<'_, Table>, Vec<ColumnDisplayInfo>) {
+    let display_info = arrange_content(&table);
+    let Some(summary) = table.hidden_columns_summary else {
+        return (table, display_info);
+    };
+
+    let mut hidden = hidden_columns(&display_info, table.columns.len());
+    if hidden.is_empty() {
+        return (table, display_info);
+    }
+
+    let mut summarized = summarize_hidden_columns(&table, summary, &hidden);
+    let mut display_info = arrange_content(&summarized);
+    for _ in 1..MAX_ARRANGEMENTS {
+        let now_hidden = hidden_columns(&display_info, table.columns.len());
+        if now_hidden == hidden {
+            break;
+        }
+        hidden = now_hidden;
+        summarized = summarize_hidden_columns(&table, summary, &hidden);
+        display_info = arrange_content(&summarized);
+    }
+
+    (Cow::Owned(summarized), display_info)
+}
+
+/// The indices of all hidden columns among the first `count` columns.
+fn hidden_columns(display_info: &[ColumnDisplayInfo], count: usize) -> Vec<usize> {
+    display_info
+        .iter()
+        .take(count)
+        .enumerate()
+        .filter(|(_, info)| info.is_hidden)
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Add a trailing column to a copy of the table, which displays the amount of hidden columns.
+fn summarize_hidden_columns(
+    table: &Table,
+    summary: HiddenColumnsSummary,
+    hidden: &[usize],
+) -> Table {
+    let mut summarized = table.with_rows(table.rows.clone());
+    let column_count = summarized.columns.len();
+
+    let mut span_tracker = SpanTracker::new();
+    let has_header = summarized.header.is_some();
+    let rows = summarized
+        .header
+        .iter_mut()
+        .chain(summarized.rows.iter_mut())
+        .enumerate();
+    for (row_index, row) in rows {
+        // Annotations span the summary column as well.
+        if row.annotation {
+            if let Some(cell) = row.cells.first_mut() {
+                cell.colspan = Some(cell.colspan().saturating_add(1));
+            }
+            span_tracker.advance_row(row_index + 1);
+            continue;
+        }
+        // Count the hidden cells with content, while looking for the end of the row.
+        let mut hidden_cells = 0;
+        let mut col_index = 0;
+        for cell in row.cells.iter() {
+            while span_tracker.is_col_occupied_by_rowspan(row_index, col_index) {
+                col_index += 1;
+            }
+            if hidden.contains(&col_index) && cell.content.iter().any(|line| !line.is_empty()) {
+                hidden_cells += 1;
+            }
+            if cell.rowspan() > 1 {
+                span_tracker.register_rowspan(
+                    row_index,
+                    col_index,
+                    cell.rowspan(),
+                    cell.colspan(),
+                    None,
+                );
+            }
+            col_index += usize::from(cell.colspan());
+        }
+        // Fill up short rows, so the summary ends up in the last column.
+        while col_index < column_count {
+            if !span_tracker.is_col_occupied_by_rowspan(row_index, col_index) {
+                row.cells.push(Cell::new(""));
+            }
+            col_index += 1;
+        }
+        span_tracker.advance_row(row_index + 1);
+
+        // Without a header, the first row displays the amount of hidden columns instead.
+        let is_header = has_header && row_index == 0;
+        let content = if is_header || (summary == HiddenColumnsSummary::Header && row_index == 0) {
+            format!("+{}", hidden.len())
+        } else if summary == HiddenColumnsSummary::Rows && hidden_cells > 0 {
+            format!("+{hidden_cells}")
+        } else {
+            String::new()
+        };
+        row.cells.push(Cell::new(content));
+    }
+
+    let mut column = Column::new(column_count);
+    column
+        .set_constraint(ColumnConstraint::ContentWidth)
+        .set_truncation_priority(u8::MAX);
+    summarized.columns.push(column);
+
+    summarized
+}
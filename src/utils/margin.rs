@@ -0,0 +1,131 @@
+use std::borrow::Cow;
+use std::iter::Peekable;
+
+use super::formatting::borders::{LineMeasure, visible_width};
+use crate::render::LineDecorator;
+use crate::style::Margins;
+use crate::table::Table;
+
+/// Reduce the width of a table with [margins](Table::set_margin) or a
+/// [line prefix](Table::set_line_prefix), so the table including its margins and prefix fits
+/// into its width.
+///
+/// The table is left untouched, if nothing is added in front of or after the lines, or its
+/// width is unknown.
+pub(crate) fn subtract_outer_width(table: &mut Cow<'_, Table>) {
+    let margins = table.margin.unwrap_or_default();
+    let prefix = table
+        .line_prefix
+        .as_deref()
+        .map_or(0, |prefix| visible_width(prefix, table.ambiguous_width));
+    let prefix = u16::try_from(prefix).unwrap_or(u16::MAX);
+    let outer = margins
+        .left
+        .saturating_add(margins.right)
+        .saturating_add(prefix);
+    if outer == 0 {
+        return;
+    }
+    let Some(width) = table.width() else {
+        return;
+    };
+
+    table.to_mut().set_width(width.saturating_sub(outer));
+}
+
+/// Put the [line prefix](Table::set_line_prefix) in front of all lines and pass them to the
+/// [line decorator](Table::set_line_decorator).
+pub(crate) fn decorate_lines<I: Iterator<Item = String>>(
+    prefix: Option<String>,
+    decorator: Option<LineDecorator>,
+    lines: I,
+) -> impl Iterator<Item = String> {
+    lines.enumerate().map(move |(index, line)| {
+        let line = match &prefix {
+            Some(prefix) => format!("{prefix}{line}"),
+            None => line,
+        };
+        match &decorator {
+            Some(decorator) => (decorator.0)(index, &line),
+            None => line,
+        }
+    })
+}
+
+/// Surround the lines of a table with its [margins](Table::set_margin).
+pub(crate) fn add_margin<I: Iterator<Item = String>>(
+    margins: Option<Margins>,
+    measure: LineMeasure,
+    lines: I,
+) -> MarginLines<I> {
+    let margins = margins.unwrap_or_default();
+    MarginLines {
+        lines: lines.peekable(),
+        margins,
+        measure,
+        width: None,
+        top: margins.top,
+        bottom: margins.bottom,
+    }
+}
+
+/// An iterator over the lines of a table including its margins, as returned by [add_margin].
+pub(crate) struct MarginLines<I: Iterator<Item = String>> {
+    lines: Peekable<I>,
+    margins: Margins,
+    measure: LineMeasure,
+    /// The display width of the first line of the table.
+    width: Option<usize>,
+    /// The remaining blank lines above the table.
+    top: u16,
+    /// The remaining blank lines below the table.
+    bottom: u16,
+}
+
+impl<I: Iterator<Item = String>> MarginLines<I> {
+    /// A line of spaces, that's as wide as the table including its margins.
+    fn blank_line(&mut self) -> String {
+        let width = *self.width.get_or_insert_with(|| {
+            self.lines
+                .peek()
+                .map(|line| self.measure.width(line))
+                .unwrap_or(0)
+        });
+
+        " ".repeat(usize::from(self.margins.left) + width + usize::from(self.margins.right))
+    }
+}
+
+impl<I: Iterator<Item = String>> Iterator for MarginLines<I> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        if self.top > 0 {
+            self.top -= 1;
+            return Some(self.blank_line());
+        }
+
+        if let Some(line) = self.lines.next() {
+            if self.width.is_none() {
+                self.width = Some(self.measure.width(&line));
+            }
+            if self.margins.left == 0 && self.margins.right == 0 {
+                return Some(line);
+            }
+            let left = usize::from(self.margins.left);
+            let right = usize::from(self.margins.right);
+            let mut padded = String::with_capacity(left + line.len() + right);
+            padded.extend(std::iter::repeat_n(' ', left));
+            padded.push_str(&line);
+            padded.extend(std::iter::repeat_n(' ', right));
+            return Some(padded);
+        }
+
+        if self.bottom > 0 {
+            self.bottom -= 1;
+            return Some(self.blank_line());
+        }
+
+        None
+    }
+}
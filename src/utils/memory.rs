@@ -0,0 +1,57 @@
+use std::borrow::Cow;
+
+use super::replace_rows;
+use crate::cell::Cell;
+use crate::row::Row;
+use crate::table::Table;
+
+/// Enforce the memory limit of a table.
+///
+/// Only the rows that fit into the limit are kept.
+/// If any rows have been left out, either now or while building the table, a notice row is
+/// appended instead.
+///
+/// The table is left untouched, if it can be rendered as it is.
+pub(crate) fn limit_memory(table: &mut Cow<'_, Table>) {
+    if table.memory_limit.is_none() {
+        return;
+    }
+
+    let fitting_rows = fitting_rows(table);
+    let omitted_rows = table.omitted_rows + table.rows.len() - fitting_rows;
+    if omitted_rows == 0 {
+        return;
+    }
+
+    let mut rows = table.rows[..fitting_rows].to_vec();
+    let noun = if omitted_rows == 1 { "row" } else { "rows" };
+    let notice = Cell::new(format!(
+        "{} {omitted_rows} more {noun} omitted",
+        table.truncation_indicator
+    ))
+    .set_colspan_rest();
+    let mut notice = Row::from(vec![notice]);
+    notice.index = Some(rows.len());
+    rows.push(notice);
+
+    replace_rows(table, rows);
+}
+
+/// Count the rows of a table that are displayed within its memory limit.
+pub(crate) fn fitting_rows(table: &Table) -> usize {
+    let Some(limit) = table.memory_limit else {
+        return table.rows.len();
+    };
+
+    let mut size = table.header.as_ref().map_or(0, Row::content_size);
+    let mut fitting_rows = 0;
+    for row in table.rows.iter() {
+        size += row.content_size();
+        if size > limit {
+            break;
+        }
+        fitting_rows += 1;
+    }
+
+    fitting_rows
+}
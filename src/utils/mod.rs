@@ -1,13 +1,72 @@
+pub(crate) mod alignment_gap;
 pub mod arrangement;
+#[cfg(feature = "tty")]
+pub(crate) mod attributes;
+pub(crate) mod audit;
+pub(crate) mod booleans;
+pub(crate) mod chunks;
+#[cfg(feature = "tty")]
+pub(crate) mod column_styles;
+pub(crate) mod compact;
+pub(crate) mod constraint_conflict;
+#[cfg(feature = "tty")]
+pub(crate) mod contrast;
+pub(crate) mod debug_layout;
+pub(crate) mod dedup;
+pub(crate) mod default_constraints;
 pub mod formatting;
+pub(crate) mod header_defaults;
+pub(crate) mod hidden_summary;
+pub(crate) mod margin;
+pub(crate) mod memory;
+pub(crate) mod null_values;
+pub(crate) mod numbers;
+#[cfg(feature = "tty")]
+pub(crate) mod palette;
+pub(crate) mod row_order;
+#[cfg(feature = "tty")]
+pub(crate) mod semantic_styles;
 pub mod spanning;
+pub(crate) mod templates;
+pub(crate) mod validation;
 
-use crate::style::{CellAlignment, ColumnConstraint, VerticalAlignment};
-use crate::{Column, Table};
+use std::borrow::Cow;
 
+use crate::render::RenderError;
+use crate::style::{
+    AmbiguousWidth, CellAlignment, ColumnConstraint, Margins, Overflow, VerticalAlignment,
+};
+use crate::{Column, Row, Table};
+
+use alignment_gap::apply_alignment_gap;
 use arrangement::arrange_content;
-use formatting::borders::draw_borders;
-use formatting::content_format::format_content;
+#[cfg(feature = "tty")]
+use attributes::sanitize_attributes;
+use booleans::format_booleans;
+#[cfg(feature = "tty")]
+use column_styles::style_columns;
+use compact::compact_table;
+use constraint_conflict::resolve_constraint_conflict;
+#[cfg(feature = "tty")]
+use contrast::apply_auto_contrast;
+use dedup::dedup_values;
+use default_constraints::{apply_default_max_cell_width, collapse_columns};
+use formatting::borders::{LineMeasure, draw_borders};
+use formatting::content_format::RowFormatter;
+use header_defaults::apply_header_defaults;
+use hidden_summary::arrange_with_summary;
+use margin::{add_margin, decorate_lines, subtract_outer_width};
+use memory::limit_memory;
+use null_values::style_null_values;
+use numbers::format_numbers;
+#[cfg(feature = "tty")]
+use palette::apply_palette;
+use row_order::reverse_rows;
+#[cfg(feature = "tty")]
+use semantic_styles::apply_semantic_styles;
+use spanning::resolve_spans;
+use templates::fill_templates;
+use validation::{validate_arrangement, validate_content, validate_table};
 
 /// This struct is ONLY used when table.to_string() is called.
 /// It's purpose is to store intermediate results, information on how to
@@ -26,6 +85,15 @@ pub struct ColumnDisplayInfo {
     /// The vertical content alignment of cells in this column
     pub vertical_alignment: Option<VerticalAlignment>,
     pub(crate) is_hidden: bool,
+    /// What happens to content, that's wider than this column.
+    pub(crate) overflow: Overflow,
+    /// The indent of wrapped lines of cells in this column.
+    pub(crate) hanging_indent: u16,
+    /// Whether the content of all cells in this column is printable ASCII.
+    /// In that case, the width of the content equals its length in bytes.
+    pub(crate) ascii_only: bool,
+    /// How characters of ambiguous East Asian width are measured.
+    pub(crate) ambiguous_width: AmbiguousWidth,
 }
 
 impl ColumnDisplayInfo {
@@ -41,18 +109,206 @@ impl ColumnDisplayInfo {
             cell_alignment: column.cell_alignment,
             vertical_alignment: column.vertical_alignment,
             is_hidden: matches!(column.constraint, Some(ColumnConstraint::Hidden)),
+            overflow: column.overflow.unwrap_or_default(),
+            hanging_indent: column.hanging_indent,
+            ascii_only: false,
+            ambiguous_width: AmbiguousWidth::Narrow,
         }
     }
 
-    pub fn width(&self) -> u16 {
-        self.content_width
-            .saturating_add(self.padding.0)
-            .saturating_add(self.padding.1)
+    /// Returns whether this column is hidden and thereby not displayed at all.
+    pub fn is_hidden(&self) -> bool {
+        self.is_hidden
+    }
+
+    /// The width of this column including its padding.
+    /// The width may exceed `u16::MAX` for huge paddings, so it isn't saturated.
+    pub fn width(&self) -> usize {
+        usize::from(self.content_width) + usize::from(self.padding.0) + usize::from(self.padding.1)
     }
 }
 
 pub fn build_table(table: &Table) -> impl Iterator<Item = String> {
-    let display_info = arrange_content(table);
-    let content = format_content(table, &display_info);
-    draw_borders(table, &content, &display_info).into_iter()
+    let footnotes = description_footnotes(table);
+    let table = prepare_table(table);
+    let (table, display_info) = arrange_with_summary(table);
+    let rows = RowFormatter::new(&table, &display_info);
+    let margin = table.margin;
+    let measure = LineMeasure::new(&table);
+    let prefix = table.line_prefix.clone();
+    let decorator = table.line_decorator.clone();
+    decorate_lines(
+        prefix,
+        decorator,
+        add_margin(
+            margin,
+            measure,
+            draw_borders(table, rows, display_info).chain(footnotes),
+        ),
+    )
+}
+
+/// Draw only the top border, the header and the line below the header of a table.
+///
+/// The columns are arranged as if the whole table was drawn, so the header fits the rows.
+/// The bottom margin and the description footnotes are left out.
+pub(crate) fn build_header(table: &Table) -> impl Iterator<Item = String> {
+    let table = prepare_table(table);
+    let (table, display_info) = arrange_with_summary(table);
+    let header_rows = usize::from(table.header.is_some());
+    let rows = RowFormatter::new(&table, &display_info).take_rows(header_rows);
+    let margin = table.margin.map(|margin| Margins {
+        bottom: 0,
+        ..margin
+    });
+    let measure = LineMeasure::new(&table);
+    let prefix = table.line_prefix.clone();
+    let decorator = table.line_decorator.clone();
+    decorate_lines(
+        prefix,
+        decorator,
+        add_margin(
+            margin,
+            measure,
+            draw_borders(table, rows, display_info).without_bottom_border(),
+        ),
+    )
+}
+
+/// Like [build_table], but check the arranged table for problems before anything is drawn.
+pub(crate) fn try_build_table(table: &Table) -> Result<Vec<String>, RenderError> {
+    let table = prepare_table(table);
+    validate_table(&table)?;
+    let (table, display_info) = arrange_with_summary(table);
+    validate_arrangement(&table, &display_info)?;
+    validate_content(&table, &display_info)?;
+    let rows = RowFormatter::new(&table, &display_info);
+    let footnotes = description_footnotes(&table);
+    let margin = table.margin;
+    let measure = LineMeasure::new(&table);
+    let prefix = table.line_prefix.clone();
+    let decorator = table.line_decorator.clone();
+    Ok(decorate_lines(
+        prefix,
+        decorator,
+        add_margin(
+            margin,
+            measure,
+            draw_borders(table, rows, display_info).chain(footnotes),
+        ),
+    )
+    .collect())
+}
+
+/// Panic, if the lines of a rendered table don't all have the same display width.
+///
+/// The description footnotes below the table aren't part of the table.
+pub(crate) fn assert_rectangular(table: &Table) {
+    let footnotes = description_footnotes(table).len();
+    let bottom = table.margin.map_or(0, |margin| usize::from(margin.bottom));
+    let mut lines: Vec<String> = build_table(table).collect();
+    let end = lines.len() - bottom;
+    lines.drain(end - footnotes..end);
+    let table_lines = &lines;
+
+    let Some(first_line) = table_lines.first() else {
+        return;
+    };
+    let measure = LineMeasure::new(table);
+    let expected = measure.width(first_line);
+    for (index, line) in table_lines.iter().enumerate() {
+        let width = measure.width(line);
+        assert_eq!(
+            width, expected,
+            "Line {index} {line:?} is {width} columns wide, but the first line is {expected} columns wide"
+        );
+    }
+}
+
+/// Returns the lines that describe the columns of a table, if they should be displayed.
+fn description_footnotes(table: &Table) -> Vec<String> {
+    if !table.description_footnotes {
+        return Vec::new();
+    }
+
+    table
+        .columns
+        .iter()
+        .filter(|column| !column.is_hidden())
+        .filter_map(|column| describe_column(table, column))
+        .collect()
+}
+
+/// Returns the description of a column, prefixed by the name of the column.
+///
+/// The name is the header of the column or its position, if there's no header.
+pub(crate) fn describe_column(table: &Table, column: &Column) -> Option<String> {
+    let description = column.description()?;
+    let name = table
+        .column_cells_with_header_iter(column.index)
+        .next()
+        .flatten()
+        .map(|cell| cell.content().replace('\n', " "))
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| format!("Column {}", column.index + 1));
+    Some(format!("{name}: {description}"))
+}
+
+/// Returns the displayed content of each cell, row by row, starting with the header.
+pub(crate) fn rendered_content(table: &Table) -> Vec<Vec<String>> {
+    let table = prepare_table(table);
+    let display_info = arrange_content(&table);
+    formatting::content_format::rendered_content(&table, &display_info)
+}
+
+/// Apply all changes to a table, that have to be done before it's arranged.
+///
+/// The table is copied by the first step that has to change it, all later steps change that
+/// copy in place. Returns the borrowed table, if nothing had to be changed.
+pub(crate) fn prepare_table(table: &Table) -> Cow<'_, Table> {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::debug_span!("prepare_table").entered();
+
+    let mut table = Cow::Borrowed(table);
+    limit_memory(&mut table);
+    reverse_rows(&mut table);
+    fill_templates(&mut table);
+    subtract_outer_width(&mut table);
+    resolve_spans(&mut table);
+    collapse_columns(&mut table);
+    apply_default_max_cell_width(&mut table);
+    format_numbers(&mut table, true);
+    format_booleans(&mut table);
+    style_null_values(&mut table);
+    dedup_values(&mut table);
+    apply_header_defaults(&mut table);
+    compact_table(&mut table);
+    apply_alignment_gap(&mut table);
+
+    #[cfg(feature = "tty")]
+    {
+        apply_semantic_styles(&mut table);
+        style_columns(&mut table);
+        // The palette replaces the colors of all previous steps.
+        apply_palette(&mut table);
+        apply_auto_contrast(&mut table);
+        sanitize_attributes(&mut table);
+    }
+
+    // Constraints are checked last, as the previous steps change the content and the padding.
+    if let Some((_, Some(columns))) = resolve_constraint_conflict(&table) {
+        table.to_mut().columns = columns;
+    }
+
+    table
+}
+
+/// Replace all rows of a table, that's being [prepared](prepare_table).
+///
+/// A borrowed table is copied without its old rows.
+pub(crate) fn replace_rows(table: &mut Cow<'_, Table>, rows: Vec<Row>) {
+    match table {
+        Cow::Borrowed(borrowed) => *table = Cow::Owned(borrowed.with_rows(rows)),
+        Cow::Owned(owned) => owned.rows = rows,
+    }
 }
@@ -0,0 +1,31 @@
+use std::borrow::Cow;
+
+use crate::cell::Cell;
+use crate::table::Table;
+use crate::value::CellValue;
+
+/// Apply the [NullStyle](crate::NullStyle) of a table to all cells with a `NULL` value.
+///
+/// Empty cells display the text of the style, while cells with content keep it.
+pub(crate) fn style_null_values(table: &mut Cow<'_, Table>) {
+    let is_null = |cell: &Cell| cell.value.as_ref().is_some_and(CellValue::is_null);
+    if table.null_style.is_none() || !table.rows.iter().any(|row| row.cells.iter().any(is_null)) {
+        return;
+    }
+
+    let styled = table.to_mut();
+    let Some(style) = styled.null_style.as_ref() else {
+        return;
+    };
+    let cells = styled.rows.iter_mut().flat_map(|row| row.cells.iter_mut());
+    for cell in cells.filter(|cell| is_null(cell)) {
+        if cell.content.iter().all(String::is_empty) {
+            cell.content = vec![style.text.clone()];
+        }
+        #[cfg(feature = "tty")]
+        {
+            cell.fg = cell.fg.or(style.fg);
+            cell.attributes.extend(style.attributes.iter().copied());
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use std::borrow::Cow;
+
+use crate::column::Column;
+use crate::row::Row;
+use crate::style::{CellAlignment, DecimalPrecision, NegativeFormat};
+use crate::table::Table;
+use crate::utils::spanning::SpanTracker;
+use crate::value::CellValue;
+
+/// Apply the number formatting of all columns.
+///
+/// This includes the [DecimalPrecision] of numbers, as well as the [NegativeFormat] and style
+/// of negative numbers.
+/// With `align_digits`, positive numbers leave space for the closing parenthesis of negative
+/// ones, which is only useful, if the table is displayed.
+pub(crate) fn format_numbers(table: &mut Cow<'_, Table>, align_digits: bool) {
+    for column_index in 0..table.columns.len() {
+        if !formats_numbers(&table.columns[column_index]) {
+            continue;
+        }
+
+        // The positions and values of all numeric cells of this column.
+        let numbers: Vec<(usize, usize, CellValue)> = cell_positions(table, column_index)
+            .into_iter()
+            .filter_map(|(row_index, cell_index)| {
+                let value = table.rows[row_index].cells[cell_index].typed_value();
+                is_finite_number(&value).then_some((row_index, cell_index, value))
+            })
+            .collect();
+        if numbers.is_empty() {
+            continue;
+        }
+
+        let formatted = table.to_mut();
+        let column = &formatted.columns[column_index];
+        if let Some(precision) = column.decimal_precision {
+            apply_precision(&mut formatted.rows, column, precision, &numbers);
+        }
+
+        for (row_index, cell_index, value) in &numbers {
+            let negative = value.as_f64().is_some_and(|number| number < 0.0);
+            let cell = &mut formatted.rows[*row_index].cells[*cell_index];
+            if column.negative_format == NegativeFormat::Parentheses {
+                match cell.content.as_mut_slice() {
+                    [line] if negative => {
+                        if let Some(number) = line.strip_prefix('-') {
+                            *line = format!("({number})");
+                        }
+                    }
+                    // Positive numbers leave space for the closing parenthesis, so that the
+                    // digits of all numbers line up.
+                    [line] if align_digits => line.push(' '),
+                    _ => {}
+                }
+            }
+
+            #[cfg(feature = "tty")]
+            if let Some(style) = column.negative_style.as_ref().filter(|_| negative) {
+                cell.fg = style.fg.or(cell.fg);
+                cell.bg = style.bg.or(cell.bg);
+                cell.attributes.extend(style.attributes.iter().copied());
+            }
+        }
+    }
+}
+
+/// Returns whether a column changes the way its numbers are displayed.
+fn formats_numbers(column: &Column) -> bool {
+    #[cfg(feature = "tty")]
+    if column.negative_style.is_some() {
+        return true;
+    }
+
+    column.decimal_precision.is_some() || column.negative_format != NegativeFormat::Minus
+}
+
+/// Display all numbers of a column with the same amount of decimal places.
+fn apply_precision(
+    rows: &mut [Row],
+    column: &Column,
+    precision: DecimalPrecision,
+    numbers: &[(usize, usize, CellValue)],
+) {
+    let needed = numbers
+        .iter()
+        .map(|(_, _, value)| decimal_places(value))
+        .max()
+        .unwrap_or(0);
+    let places = match precision {
+        DecimalPrecision::Auto => needed,
+        DecimalPrecision::Max(max) => needed.min(max),
+    };
+
+    for (row_index, cell_index, value) in numbers {
+        let row = &mut rows[*row_index];
+        let row_alignment = row.cell_alignment;
+        let cell = &mut row.cells[*cell_index];
+        cell.content = vec![format_number(value, places)];
+        // Decimal points only line up, if numbers are right aligned.
+        if cell.alignment.is_none() && column.cell_alignment.is_none() && row_alignment.is_none() {
+            cell.alignment = Some(CellAlignment::Right);
+        }
+    }
+}
+
+/// Find the row and cell index of all cells that start in a specific column.
+///
+/// [Annotations](Table::add_annotation_row) don't belong to any column and are skipped.
+pub(crate) fn cell_positions(table: &Table, column_index: usize) -> Vec<(usize, usize)> {
+    let header_rows = usize::from(table.header.is_some());
+    let mut span_tracker = SpanTracker::new();
+    let mut positions = Vec::new();
+    for (row_index, row) in table.rows.iter().enumerate() {
+        let tracked_index = row_index + header_rows;
+        if row.annotation {
+            span_tracker.advance_row(tracked_index + 1);
+            continue;
+        }
+        let mut col_index = 0;
+        for (cell_index, cell) in row.cells.iter().enumerate() {
+            while span_tracker.is_col_occupied_by_rowspan(tracked_index, col_index) {
+                col_index += 1;
+            }
+            if col_index == column_index {
+                positions.push((row_index, cell_index));
+            }
+            if cell.rowspan() > 1 {
+                span_tracker.register_rowspan(
+                    tracked_index,
+                    col_index,
+                    cell.rowspan(),
+                    cell.colspan(),
+                    None,
+                );
+            }
+            col_index += usize::from(cell.colspan());
+        }
+        span_tracker.advance_row(tracked_index + 1);
+    }
+
+    positions
+}
+
+fn is_finite_number(value: &CellValue) -> bool {
+    match value {
+        CellValue::Int(_) => true,
+        CellValue::Float(float) => float.is_finite(),
+        _ => false,
+    }
+}
+
+/// The amount of decimal places that are needed to display a number without losing precision.
+fn decimal_places(value: &CellValue) -> usize {
+    match value {
+        CellValue::Float(float) => float
+            .to_string()
+            .split_once('.')
+            .map_or(0, |(_, decimals)| decimals.len()),
+        _ => 0,
+    }
+}
+
+fn format_number(value: &CellValue, places: usize) -> String {
+    match value {
+        // Integers are formatted directly, as large ones can't be represented as floats.
+        CellValue::Int(int) if places == 0 => int.to_string(),
+        CellValue::Int(int) => format!("{int}.{}", "0".repeat(places)),
+        CellValue::Float(float) => format!("{float:.places$}"),
+        _ => value.to_display_string(),
+    }
+}
@@ -0,0 +1,34 @@
+use std::borrow::Cow;
+
+use crate::table::Table;
+
+/// Replace all colors by the most similar colors of the [palette](Table::set_palette).
+///
+/// This runs after all other styling steps, so colors that depend on the content of cells are
+/// replaced as well.
+pub(crate) fn apply_palette(table: &mut Cow<'_, Table>) {
+    if table.palette.is_none() {
+        return;
+    }
+
+    let remapped = table.to_mut();
+    let Some(palette) = remapped.palette.as_ref() else {
+        return;
+    };
+    let rows = remapped.header.iter_mut().chain(remapped.rows.iter_mut());
+    for row in rows {
+        row.fg = row.fg.map(|color| palette.remap(color));
+        row.bg = row.bg.map(|color| palette.remap(color));
+        for cell in row.cells.iter_mut() {
+            cell.fg = cell.fg.map(|color| palette.remap(color));
+            cell.bg = cell.bg.map(|color| palette.remap(color));
+            cell.gradient = cell
+                .gradient
+                .map(|(start, end)| (palette.remap(start), palette.remap(end)));
+        }
+    }
+    for (_, style) in remapped.highlights.iter_mut() {
+        style.fg = style.fg.map(|color| palette.remap(color));
+        style.bg = style.bg.map(|color| palette.remap(color));
+    }
+}
@@ -0,0 +1,153 @@
+use std::borrow::Cow;
+
+use crate::cell::Cell;
+use crate::row::Row;
+use crate::table::Table;
+use crate::utils::replace_rows;
+use crate::utils::spanning::SpanTracker;
+
+/// Reverse the order of the rows of a table, if [Table::set_reversed] is set.
+///
+/// Footer rows stay at the bottom of the table.
+/// Cells that span multiple rows are moved to the last row they span, which is the first one
+/// once the rows are reversed. Section breaks stay between the same rows.
+pub(crate) fn reverse_rows(table: &mut Cow<'_, Table>) {
+    if !table.reversed || table.rows.len() < 2 {
+        return;
+    }
+
+    let body_rows = table.footer_start() - usize::from(table.header.is_some());
+    if body_rows == 0 {
+        return;
+    }
+    let mut placed: Vec<Vec<(usize, Cell)>> = vec![Vec::new(); body_rows];
+    for (row_index, cells) in positioned_cells(table.header.as_ref(), &table.rows)
+        .into_iter()
+        .take(body_rows)
+        .enumerate()
+    {
+        for (col_index, cell) in cells {
+            let last_row = (row_index + usize::from(cell.rowspan()) - 1).min(body_rows - 1);
+            placed[last_row].push((col_index, cell));
+        }
+    }
+
+    let mut rows = Vec::with_capacity(table.rows.len());
+    for (row_index, cells) in placed.into_iter().enumerate().rev() {
+        let mut row = table.rows[row_index].clone();
+        row.cells = sorted_cells(cells);
+        // The line below a row is now drawn above it, so it moves to the row above.
+        let above = row_index.checked_sub(1).unwrap_or(body_rows - 1);
+        row.section_break = table.rows[above].section_break;
+        rows.push(row);
+    }
+    rows.extend(table.rows[body_rows..].iter().cloned());
+
+    replace_rows(table, rows);
+}
+
+/// Create a copy of a table with only the last `count` rows, see [Table::tail].
+///
+/// Cells of left out rows, whose rowspan reaches into the remaining rows, are moved to the first
+/// remaining row and only span the remaining rows.
+pub(crate) fn tail(table: &Table, count: usize) -> Table {
+    let body_rows = table.footer_start() - usize::from(table.header.is_some());
+    let start = body_rows.saturating_sub(count);
+    let mut rows = table.rows[start..].to_vec();
+    if let Some(cells) = carried_cells(table.header.as_ref(), &table.rows, start) {
+        rows[0].cells = cells;
+    }
+
+    for (index, row) in rows.iter_mut().enumerate() {
+        row.index = Some(index);
+    }
+    table.with_rows(rows)
+}
+
+/// Remove the first `count` rows, see [Table::set_max_retained_rows].
+///
+/// Cells of removed rows, whose rowspan reaches into the remaining rows, are moved to the first
+/// remaining row and only span the remaining rows.
+pub(crate) fn remove_leading_rows(header: Option<&Row>, rows: &mut Vec<Row>, count: usize) {
+    let count = count.min(rows.len());
+    if let Some(cells) = carried_cells(header, rows, count) {
+        rows[count].cells = cells;
+    }
+    rows.drain(..count);
+
+    for (index, row) in rows.iter_mut().enumerate() {
+        row.index = Some(index);
+    }
+}
+
+/// The cells of the row at `start`, once all rows above it are left out.
+///
+/// Returns `None`, if no rowspan of the left out rows reaches into the row at `start`.
+fn carried_cells(header: Option<&Row>, rows: &[Row], start: usize) -> Option<Vec<Cell>> {
+    if start == 0 || start >= rows.len() {
+        return None;
+    }
+
+    let positioned = positioned_cells(header, &rows[..=start]);
+    let mut cells: Vec<(usize, Cell)> = Vec::new();
+    for (row_index, row) in positioned.iter().enumerate().take(start) {
+        for (col_index, cell) in row {
+            let end = row_index + usize::from(cell.rowspan());
+            if end > start {
+                let rowspan = u16::try_from(end - start).unwrap_or(u16::MAX);
+                cells.push((*col_index, cell.clone().set_rowspan(rowspan)));
+            }
+        }
+    }
+    if cells.is_empty() {
+        return None;
+    }
+
+    cells.extend(positioned[start].iter().cloned());
+    Some(sorted_cells(cells))
+}
+
+/// Returns the cells of each row together with the column they start in.
+///
+/// The header is only needed for rowspans, that reach from the header into the rows.
+/// [Annotations](Table::add_annotation_row) don't belong to any column and start in the first one.
+fn positioned_cells(header: Option<&Row>, rows: &[Row]) -> Vec<Vec<(usize, Cell)>> {
+    let header_rows = usize::from(header.is_some());
+    let mut span_tracker = SpanTracker::new();
+    let mut positioned = Vec::with_capacity(rows.len() + header_rows);
+    for (row_index, row) in header.into_iter().chain(rows.iter()).enumerate() {
+        if row.annotation {
+            positioned.push(row.cells.iter().map(|cell| (0, cell.clone())).collect());
+            span_tracker.advance_row(row_index + 1);
+            continue;
+        }
+        let mut cells = Vec::with_capacity(row.cells.len());
+        let mut col_index = 0;
+        for cell in row.cells.iter() {
+            while span_tracker.is_col_occupied_by_rowspan(row_index, col_index) {
+                col_index += 1;
+            }
+            if cell.rowspan() > 1 {
+                span_tracker.register_rowspan(
+                    row_index,
+                    col_index,
+                    cell.rowspan(),
+                    cell.colspan(),
+                    None,
+                );
+            }
+            cells.push((col_index, cell.clone()));
+            col_index += usize::from(cell.colspan());
+        }
+        positioned.push(cells);
+        span_tracker.advance_row(row_index + 1);
+    }
+
+    positioned.split_off(header_rows)
+}
+
+/// Order the cells of a row by the column they start in.
+fn sorted_cells(mut cells: Vec<(usize, Cell)>) -> Vec<Cell> {
+    cells.sort_by_key(|(col_index, _)| *col_index);
+    cells.into_iter().map(|(_, cell)| cell).collect()
+}
@@ -0,0 +1,36 @@
+use std::borrow::Cow;
+
+use crate::cell::Cell;
+use crate::table::Table;
+
+/// Apply the terminal styles of the [StyleMap](crate::StyleMap) of a table to all cells with a
+/// semantic style or a role.
+///
+/// Cells keep their own colors, while the attributes of the style are added to theirs.
+pub(crate) fn apply_semantic_styles(table: &mut Cow<'_, Table>) {
+    let Some(style_map) = table.style_map.as_ref() else {
+        return;
+    };
+    let has_style = |cell: &Cell| {
+        cell.style_name()
+            .is_some_and(|name| style_map.terminal(name).is_some())
+    };
+    let mut rows = table.header.iter().chain(table.rows.iter());
+    if !rows.any(|row| row.cells.iter().any(has_style)) {
+        return;
+    }
+
+    let styled = table.to_mut();
+    let Some(style_map) = styled.style_map.as_ref() else {
+        return;
+    };
+    let rows = styled.header.iter_mut().chain(styled.rows.iter_mut());
+    for cell in rows.flat_map(|row| row.cells.iter_mut()) {
+        let Some(style) = cell.style_name().and_then(|name| style_map.terminal(name)) else {
+            continue;
+        };
+        cell.fg = cell.fg.or(style.fg);
+        cell.bg = cell.bg.or(style.bg);
+        cell.attributes.extend(style.attributes.iter().copied());
+    }
+}
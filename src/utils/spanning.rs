@@ -1,6 +1,8 @@
+use std::borrow::Cow;
 use std::collections::HashMap;
 
-use crate::style::VerticalAlignment;
+use crate::table::Table;
+use crate::utils::formatting::content_format::CellLines;
 
 /// Information about an active rowspan.
 #[derive(Debug, Clone)]
@@ -14,9 +16,12 @@ struct RowSpanInfo {
     /// Number of columns this span covers
     colspan: u16,
     /// Cached formatted content lines for this rowspan cell (None for border drawing)
-    formatted_content: Option<Vec<String>>,
-    /// Vertical alignment of the content within the rowspan
-    vertical_alignment: VerticalAlignment,
+    formatted_content: Option<CellLines>,
+    /// The line within all lines of the spanned rows, at which the content starts.
+    content_offset: usize,
+    /// The line that's displayed in lines that aren't covered by the content.
+    /// `None` results in empty lines.
+    fill_line: Option<String>,
 }
 
 /// Tracks active row spans across rows during table rendering.
@@ -64,8 +69,7 @@ impl SpanTracker {
         col_index: usize,
         rowspan: u16,
         colspan: u16,
-        formatted_content: Option<Vec<String>>,
-        vertical_alignment: VerticalAlignment,
+        formatted_content: Option<CellLines>,
     ) {
         if rowspan > 1 {
             self.active_spans.insert(
@@ -76,60 +80,47 @@ impl SpanTracker {
                     remaining_rows: rowspan - 1, // Will appear in rowspan - 1 more rows
                     colspan,
                     formatted_content,
-                    vertical_alignment,
+                    content_offset: 0,
+                    fill_line: None,
                 },
             );
         }
     }
 
-    /// Get the cached formatted content for a rowspan cell.
-    /// This INCLUDES the starting row (when row_index == start_row).
+    /// Set where the content of a rowspan cell is displayed and how the remaining lines are
+    /// filled.
     ///
-    /// Returns the formatted content lines if the position is part of a rowspan.
-    pub(crate) fn get_rowspan_content(
-        &self,
-        row_index: usize,
-        col_index: usize,
-    ) -> Option<&Vec<String>> {
-        for ((start_row, start_col), info) in &self.active_spans {
-            if *start_row <= row_index {
-                // Check if this position falls within the colspan range
-                if *start_col <= col_index && col_index < *start_col + info.colspan as usize {
-                    return info.formatted_content.as_ref();
-                }
-            }
+    /// `content_offset` is the line within all lines of the spanned rows, at which the content
+    /// starts. The lines of the spanned rows are counted without the separators between them.
+    pub(crate) fn set_rowspan_layout(
+        &mut self,
+        start_row: usize,
+        start_col: usize,
+        content_offset: usize,
+        fill_line: Option<String>,
+    ) {
+        if let Some(info) = self.active_spans.get_mut(&(start_row, start_col)) {
+            info.content_offset = content_offset;
+            info.fill_line = fill_line;
         }
-        None
     }
 
-    /// Calculate which row within the rowspan should display content based on vertical alignment.
-    /// Returns the row offset (0-based) where content should start being displayed.
+    /// Get the line that should be displayed by a rowspan cell.
+    ///
+    /// `line` is the index within all lines of the spanned rows,
+    /// the separators between those rows not included.
     ///
-    /// For a 3-row rowspan with 1 line of content:
-    /// - Top alignment: offset = 0 (content in first row)
-    /// - Middle alignment: offset = 1 (content in middle row)
-    /// - Bottom alignment: offset = 2 (content in last row)
-    pub(crate) fn get_rowspan_content_offset(
+    /// Returns `None` if the line is empty.
+    pub(crate) fn get_rowspan_line(
         &self,
         start_row: usize,
-        col_index: usize,
-        content_height: usize,
-    ) -> usize {
-        for ((row, start_col), info) in &self.active_spans {
-            if *row == start_row
-                && *start_col <= col_index
-                && col_index < *start_col + info.colspan as usize
-            {
-                let total_rows = info.original_rowspan as usize;
-                let padding_rows = total_rows.saturating_sub(content_height);
-                return match info.vertical_alignment {
-                    VerticalAlignment::Top => 0,
-                    VerticalAlignment::Middle => padding_rows / 2,
-                    VerticalAlignment::Bottom => padding_rows,
-                };
-            }
-        }
-        0 // Default to top
+        start_col: usize,
+        line: usize,
+    ) -> Option<&String> {
+        let info = self.active_spans.get(&(start_row, start_col))?;
+        line.checked_sub(info.content_offset)
+            .and_then(|index| info.formatted_content.as_ref()?.get(index))
+            .or(info.fill_line.as_ref())
     }
 
     /// Decrement rowspan counters and remove expired spans.
@@ -289,3 +280,149 @@ impl SpanTracker {
         None
     }
 }
+
+/// Count the columns that are needed to display all cells of a table.
+///
+/// Unlike the number of cells in a row, this considers positions that are occupied by rowspans
+/// from previous rows, which push the remaining cells of a row further to the right.
+pub(crate) fn span_aware_column_count(table: &Table) -> usize {
+    let mut span_tracker = SpanTracker::new();
+    let mut column_count = 0;
+
+    for (row_index, row) in table.header.iter().chain(table.rows.iter()).enumerate() {
+        let mut col_index = 0;
+        for cell in row.cells.iter() {
+            while span_tracker.is_col_occupied_by_rowspan(row_index, col_index) {
+                col_index += 1;
+            }
+            if cell.rowspan() > 1 {
+                span_tracker.register_rowspan(
+                    row_index,
+                    col_index,
+                    cell.rowspan(),
+                    cell.colspan(),
+                    None,
+                );
+            }
+            col_index += cell.colspan() as usize;
+        }
+        column_count = column_count.max(col_index);
+        span_tracker.advance_row(row_index + 1);
+    }
+
+    column_count
+}
+
+/// The cell, that covers each position of a table, once its spans have been resolved.
+///
+/// Rows are indexed including the header. Each position contains the row and column the
+/// covering cell starts in, or `None`, if no cell covers it, e.g. at the end of short rows.
+/// This allows tests to check where spans are placed, without parsing the rendered table.
+///
+/// This is only exposed via the `_integration_test` feature and may change at any time.
+#[cfg(feature = "_integration_test")]
+pub fn occupancy_grid(table: &Table) -> Vec<Vec<Option<(usize, usize)>>> {
+    let table = super::prepare_table(table);
+    let column_count = table.columns.len();
+    let mut span_tracker = SpanTracker::new();
+    let mut grid = Vec::new();
+
+    for (row_index, row) in table.header.iter().chain(table.rows.iter()).enumerate() {
+        // Positions covered by rowspans from rows above.
+        let mut positions: Vec<Option<(usize, usize)>> = (0..column_count)
+            .map(|col_index| {
+                span_tracker
+                    .get_rowspan_start(row_index, col_index)
+                    .map(|(start_row, start_col, _)| (start_row, start_col))
+            })
+            .collect();
+
+        let mut col_index = 0;
+        for cell in row.cells.iter() {
+            while col_index < column_count
+                && span_tracker.is_col_occupied_by_rowspan(row_index, col_index)
+            {
+                col_index += 1;
+            }
+            if col_index >= column_count {
+                break;
+            }
+
+            let colspan = cell.colspan();
+            for position in positions
+                .iter_mut()
+                .skip(col_index)
+                .take(usize::from(colspan))
+            {
+                *position = Some((row_index, col_index));
+            }
+            if cell.rowspan() > 1 {
+                span_tracker.register_rowspan(row_index, col_index, cell.rowspan(), colspan, None);
+            }
+            col_index += usize::from(colspan);
+        }
+
+        grid.push(positions);
+        span_tracker.advance_row(row_index + 1);
+    }
+
+    grid
+}
+
+/// Prepare the spans of a table for rendering.
+///
+/// - Columns that are only needed due to rowspans are added to the table.
+/// - The colspan of all cells that [span the rest of their row](crate::Cell::set_colspan_rest)
+///   is resolved. Each of those cells spans from its starting column to the last column of the
+///   table, or up to the next column that's occupied by a rowspan from a previous row.
+pub(crate) fn resolve_spans(table: &mut Cow<'_, Table>) {
+    let has_rest_cells = table
+        .header
+        .iter()
+        .chain(table.rows.iter())
+        .any(|row| row.cells.iter().any(|cell| cell.colspan_rest));
+    let missing_columns = span_aware_column_count(table) > table.columns.len();
+    if !has_rest_cells && !missing_columns {
+        return;
+    }
+
+    let table = table.to_mut();
+    table.discover_columns();
+    if !has_rest_cells {
+        return;
+    }
+
+    let column_count = table.columns.len();
+    let mut span_tracker = SpanTracker::new();
+
+    let header = table.header.iter_mut();
+    for (row_index, row) in header.chain(table.rows.iter_mut()).enumerate() {
+        let mut col_index = 0;
+        for cell in row.cells.iter_mut() {
+            while col_index < column_count
+                && span_tracker.is_col_occupied_by_rowspan(row_index, col_index)
+            {
+                col_index += 1;
+            }
+
+            if cell.colspan_rest {
+                let free_columns = (col_index..column_count)
+                    .take_while(|col| !span_tracker.is_col_occupied_by_rowspan(row_index, *col))
+                    .count();
+                cell.colspan = Some(free_columns.max(1) as u16);
+            }
+
+            if cell.rowspan() > 1 {
+                span_tracker.register_rowspan(
+                    row_index,
+                    col_index,
+                    cell.rowspan(),
+                    cell.colspan(),
+                    None,
+                );
+            }
+            col_index += cell.colspan() as usize;
+        }
+        span_tracker.advance_row(row_index + 1);
+    }
+}
@@ -0,0 +1,140 @@
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::cell::Cell;
+use crate::row::Row;
+use crate::table::Table;
+use crate::user_data::UserData;
+
+/// The content of a [templated](crate::Cell::templated) cell, with the values that are used, if
+/// the user data of its row doesn't have a field of the same name.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct Template {
+    pub(crate) text: String,
+    pub(crate) defaults: Vec<(String, String)>,
+}
+
+impl Template {
+    /// Replace all placeholders of the template.
+    ///
+    /// Fields of the row's user data take precedence over the defaults of the cell.
+    /// Placeholders without a value are kept as they are, `{{` and `}}` are displayed as single
+    /// braces.
+    pub(crate) fn fill(&self, user_data: Option<&UserData>) -> String {
+        let lookup = |name: &str| {
+            field(user_data, name).or_else(|| {
+                self.defaults
+                    .iter()
+                    .find(|(key, _)| key == name)
+                    .map(|(_, value)| value.as_str())
+            })
+        };
+
+        let mut filled = String::with_capacity(self.text.len());
+        let mut rest = self.text.as_str();
+        while let Some(position) = rest.find(['{', '}']) {
+            filled.push_str(&rest[..position]);
+            rest = &rest[position..];
+            if rest.starts_with("{{") || rest.starts_with("}}") {
+                filled.push_str(&rest[..1]);
+                rest = &rest[2..];
+                continue;
+            }
+
+            let placeholder = rest
+                .strip_prefix('{')
+                .and_then(|inner| inner.find('}').map(|end| &inner[..end]));
+            match placeholder.and_then(|name| Some((name, lookup(name)?))) {
+                Some((name, value)) => {
+                    filled.push_str(value);
+                    rest = &rest[name.len() + 2..];
+                }
+                None => {
+                    filled.push_str(&rest[..1]);
+                    rest = &rest[1..];
+                }
+            }
+        }
+        filled.push_str(rest);
+
+        filled
+    }
+}
+
+/// Get a field of the user data of a row.
+///
+/// Only user data that maps names to values, like a `HashMap<String, String>` or a
+/// `BTreeMap<String, String>`, has fields.
+fn field<'a>(user_data: Option<&'a UserData>, name: &str) -> Option<&'a str> {
+    let user_data = user_data?;
+    if let Some(fields) = user_data.get::<HashMap<String, String>>() {
+        return fields.get(name).map(String::as_str);
+    }
+    user_data
+        .get::<BTreeMap<String, String>>()
+        .and_then(|fields| fields.get(name))
+        .map(String::as_str)
+}
+
+/// Fill the [templates](crate::Cell::templated) of all cells of a row with its user data.
+pub(crate) fn fill_row(row: &mut Row) {
+    for cell in row.cells.iter_mut() {
+        if let Some(template) = &cell.template {
+            // The filled content is split into lines like the content of a new cell.
+            cell.content = Cell::new_owned(template.fill(row.user_data.as_ref())).content;
+        }
+    }
+}
+
+/// Fill the [templates](crate::Cell::templated) of all cells with the user data of their rows.
+///
+/// Cells are already filled once they're added to a row, but rows and cells can change
+/// afterwards.
+pub(crate) fn fill_templates(table: &mut Cow<'_, Table>) {
+    let has_templates = table
+        .header
+        .iter()
+        .chain(table.rows.iter())
+        .any(|row| row.cells.iter().any(|cell| cell.template.is_some()));
+    if !has_templates {
+        return;
+    }
+
+    let filled = table.to_mut();
+    for row in filled.header.iter_mut().chain(filled.rows.iter_mut()) {
+        fill_row(row);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fill() {
+        let template = Template {
+            text: "{name} ({count}) {missing} {{literal}}".to_string(),
+            defaults: vec![("count".to_string(), "0".to_string())],
+        };
+        assert_eq!(template.fill(None), "{name} (0) {missing} {literal}");
+
+        let mut fields = BTreeMap::new();
+        fields.insert("name".to_string(), "db-1".to_string());
+        let user_data = UserData::new(fields.clone());
+        assert_eq!(
+            template.fill(Some(&user_data)),
+            "db-1 (0) {missing} {literal}"
+        );
+
+        fields.insert("count".to_string(), "3".to_string());
+        let user_data = UserData::new(fields.into_iter().collect::<HashMap<_, _>>());
+        assert_eq!(
+            template.fill(Some(&user_data)),
+            "db-1 (3) {missing} {literal}"
+        );
+
+        // Other user data doesn't have any fields.
+        let user_data = UserData::new(3);
+        assert_eq!(template.fill(Some(&user_data)), template.fill(None));
+    }
+}
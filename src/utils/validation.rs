@@ -0,0 +1,124 @@
+use super::ColumnDisplayInfo;
+use super::arrangement::constraint::{absolute_value_from_width, max};
+use super::arrangement::helper::{count_border_columns, count_visible_columns};
+use super::formatting::content_format::lost_content;
+use super::spanning::SpanTracker;
+use crate::render::{InvalidSpanReason, RenderError};
+use crate::style::{ColumnConstraint::Absolute, ContentArrangement};
+use crate::table::Table;
+
+/// Check whether a prepared table can be arranged and rendered properly.
+///
+/// All spans have to be resolved already.
+pub(crate) fn validate_table(table: &Table) -> Result<(), RenderError> {
+    validate_spans(table)?;
+
+    // A constraint, that doesn't leave any space for content after padding, would silently be
+    // widened to a single character.
+    let visible_columns = count_visible_columns(&table.columns);
+    for column in table.columns.iter().filter(|column| !column.is_hidden()) {
+        let width = match &column.constraint {
+            Some(Absolute(width)) => absolute_value_from_width(table, width, visible_columns),
+            _ => max(table, &column.constraint, visible_columns),
+        };
+        if width.is_some_and(|width| width <= column.padding_width()) {
+            return Err(RenderError::ZeroWidthColumn {
+                column_index: column.index,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Check whether the arranged columns of a table fit into the width of the table.
+pub(crate) fn validate_arrangement(
+    table: &Table,
+    display_infos: &[ColumnDisplayInfo],
+) -> Result<(), RenderError> {
+    // Tables without a dynamic arrangement are allowed to grow beyond their width.
+    if matches!(table.arrangement, ContentArrangement::Disabled) {
+        return Ok(());
+    }
+    let Some(table_width) = table.width() else {
+        return Ok(());
+    };
+
+    // Columns may have been hidden by the arrangement as well.
+    let visible_infos = display_infos.iter().filter(|info| !info.is_hidden);
+    let required_width = count_border_columns(table, visible_infos.clone().count())
+        + visible_infos.map(|info| info.width()).sum::<usize>();
+    if required_width > usize::from(table_width) {
+        return Err(RenderError::ConstraintConflict {
+            required_width,
+            table_width,
+        });
+    }
+
+    Ok(())
+}
+
+/// Check that the content of all cells of a [strict](Table::set_strict) table is displayed
+/// completely.
+pub(crate) fn validate_content(
+    table: &Table,
+    display_infos: &[ColumnDisplayInfo],
+) -> Result<(), RenderError> {
+    if !table.strict {
+        return Ok(());
+    }
+
+    let cells = lost_content(table, display_infos);
+    if !cells.is_empty() {
+        return Err(RenderError::ContentLost { cells });
+    }
+
+    Ok(())
+}
+
+/// Check that every span covers at least one column and row and doesn't collide with rowspans.
+fn validate_spans(table: &Table) -> Result<(), RenderError> {
+    let rows: Vec<_> = table.header.iter().chain(table.rows.iter()).collect();
+    let mut span_tracker = SpanTracker::new();
+
+    for (row_index, row) in rows.iter().enumerate() {
+        let mut col_index = 0;
+        for cell in row.cells.iter() {
+            // Skip positions that are occupied by rowspans from rows above.
+            while span_tracker.is_col_occupied_by_rowspan(row_index, col_index) {
+                col_index += 1;
+            }
+
+            let invalid = |reason| RenderError::InvalidSpan {
+                row_index,
+                column_index: col_index,
+                reason,
+            };
+            let colspan = cell.colspan();
+            let rowspan = cell.rowspan();
+            if colspan == 0 {
+                return Err(invalid(InvalidSpanReason::ZeroColspan));
+            }
+            if rowspan == 0 {
+                return Err(invalid(InvalidSpanReason::ZeroRowspan));
+            }
+            if row_index + usize::from(rowspan) > rows.len() {
+                return Err(invalid(InvalidSpanReason::RowspanExceedsTable));
+            }
+            let end = col_index + usize::from(colspan);
+            if (col_index + 1..end)
+                .any(|col| span_tracker.is_col_occupied_by_rowspan(row_index, col))
+            {
+                return Err(invalid(InvalidSpanReason::Overlap));
+            }
+
+            if rowspan > 1 {
+                span_tracker.register_rowspan(row_index, col_index, rowspan, colspan, None);
+            }
+            col_index = end;
+        }
+        span_tracker.advance_row(row_index + 1);
+    }
+
+    Ok(())
+}
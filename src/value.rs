@@ -0,0 +1,562 @@
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// A typed value, that can be stored alongside the display content of a [Cell](crate::Cell).
+///
+/// The display content of a cell is always a string.
+/// Keeping the typed value around allows features such as sorting, aggregation, alignment
+/// defaults or exports to work with the actual data instead of re-parsing the displayed text.
+///
+/// ```
+/// use super_table::{Cell, CellValue};
+///
+/// let cell = Cell::from_value(42);
+/// assert_eq!(cell.content(), "42");
+/// assert_eq!(cell.value(), Some(&CellValue::Int(42)));
+/// ```
+#[derive(Clone, Debug)]
+pub enum CellValue {
+    /// Plain text.
+    Str(String),
+    /// A signed integer.
+    Int(i64),
+    /// A floating point number.
+    Float(f64),
+    /// A boolean.
+    Bool(bool),
+    /// A point in time. Displayed as an RFC 3339 timestamp in UTC.
+    DateTime(SystemTime),
+    /// The absence of a value.
+    Null,
+}
+
+impl CellValue {
+    /// Parse a typed value from some text.
+    ///
+    /// The following rules are applied in this order:
+    /// - An empty string or `null` (case-insensitive) becomes [CellValue::Null].
+    /// - `true` and `false` (case-insensitive) become a [CellValue::Bool].
+    /// - Integers become a [CellValue::Int].
+    /// - Other finite numbers become a [CellValue::Float].
+    /// - Dates (`YYYY-MM-DD`) and date times (`YYYY-MM-DDTHH:MM:SS`, optionally with a
+    ///   trailing `Z`) become a [CellValue::DateTime].
+    /// - Everything else stays a [CellValue::Str].
+    ///
+    /// ```
+    /// use super_table::CellValue;
+    ///
+    /// assert_eq!(CellValue::parse("12"), CellValue::Int(12));
+    /// assert_eq!(CellValue::parse("1.5"), CellValue::Float(1.5));
+    /// assert_eq!(CellValue::parse("TRUE"), CellValue::Bool(true));
+    /// assert_eq!(CellValue::parse(""), CellValue::Null);
+    /// assert_eq!(CellValue::parse("text"), CellValue::Str("text".to_string()));
+    /// ```
+    pub fn parse(text: &str) -> Self {
+        let trimmed = text.trim();
+        if trimmed.is_empty() || trimmed.eq_ignore_ascii_case("null") {
+            return CellValue::Null;
+        }
+        if trimmed.eq_ignore_ascii_case("true") {
+            return CellValue::Bool(true);
+        }
+        if trimmed.eq_ignore_ascii_case("false") {
+            return CellValue::Bool(false);
+        }
+        if let Ok(int) = trimmed.parse::<i64>() {
+            return CellValue::Int(int);
+        }
+        // Only accept things that actually look like numbers.
+        // `f64::from_str` also accepts words such as "inf" or "NaN".
+        if trimmed
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '.' | '-' | '+' | 'e' | 'E'))
+        {
+            if let Ok(float) = trimmed.parse::<f64>() {
+                if float.is_finite() {
+                    return CellValue::Float(float);
+                }
+            }
+        }
+        if let Some(time) = parse_datetime(trimmed) {
+            return CellValue::DateTime(time);
+        }
+
+        CellValue::Str(text.to_string())
+    }
+
+    /// Return the string representation of this value.
+    ///
+    /// This is the content that's displayed when a cell is created via
+    /// [Cell::from_value](crate::Cell::from_value).
+    /// [CellValue::Null] is displayed as an empty string.
+    pub fn to_display_string(&self) -> String {
+        match self {
+            CellValue::Str(string) => string.clone(),
+            CellValue::Int(int) => int.to_string(),
+            CellValue::Float(float) => float.to_string(),
+            CellValue::Bool(boolean) => boolean.to_string(),
+            CellValue::DateTime(time) => format_datetime(*time),
+            CellValue::Null => String::new(),
+        }
+    }
+
+    /// Returns whether this value is [CellValue::Null].
+    pub fn is_null(&self) -> bool {
+        matches!(self, CellValue::Null)
+    }
+
+    /// Returns whether this value is a [CellValue::Int] or a [CellValue::Float].
+    pub fn is_numeric(&self) -> bool {
+        matches!(self, CellValue::Int(_) | CellValue::Float(_))
+    }
+
+    /// Get the numeric value as a float, if this is a number.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            CellValue::Int(int) => Some(*int as f64),
+            CellValue::Float(float) => Some(*float),
+            _ => None,
+        }
+    }
+
+    /// Get the value as an integer, if this is a [CellValue::Int].
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            CellValue::Int(int) => Some(*int),
+            _ => None,
+        }
+    }
+
+    /// Get the value as a boolean, if this is a [CellValue::Bool].
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            CellValue::Bool(boolean) => Some(*boolean),
+            _ => None,
+        }
+    }
+
+    /// Get the text, if this is a [CellValue::Str].
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            CellValue::Str(string) => Some(string),
+            _ => None,
+        }
+    }
+
+    /// Compare two values for sorting purposes.
+    ///
+    /// Numbers are compared numerically (also between [CellValue::Int] and [CellValue::Float]).
+    /// Values of different kinds are ordered by kind:
+    /// `Null < Bool < numbers < DateTime < Str`.
+    pub fn sort_cmp(&self, other: &CellValue) -> Ordering {
+        if let (Some(left), Some(right)) = (self.as_f64(), other.as_f64()) {
+            if let (CellValue::Int(left), CellValue::Int(right)) = (self, other) {
+                return left.cmp(right);
+            }
+            return left.total_cmp(&right);
+        }
+
+        match (self, other) {
+            (CellValue::Str(left), CellValue::Str(right)) => left.cmp(right),
+            (CellValue::Bool(left), CellValue::Bool(right)) => left.cmp(right),
+            (CellValue::DateTime(left), CellValue::DateTime(right)) => left.cmp(right),
+            _ => self.kind_rank().cmp(&other.kind_rank()),
+        }
+    }
+
+    fn kind_rank(&self) -> u8 {
+        match self {
+            CellValue::Null => 0,
+            CellValue::Bool(_) => 1,
+            CellValue::Int(_) | CellValue::Float(_) => 2,
+            CellValue::DateTime(_) => 3,
+            CellValue::Str(_) => 4,
+        }
+    }
+}
+
+/// The type of data in a column, as determined by [Table::infer_column_types](crate::Table::infer_column_types).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ColumnType {
+    /// The column contains no values at all (only empty or `null` cells).
+    Empty,
+    /// The column contains text or a mix of different kinds of values.
+    Text,
+    /// All values in the column are integers.
+    Integer,
+    /// All values in the column are numbers, at least one of them is a float.
+    Float,
+    /// All values in the column are booleans.
+    Boolean,
+    /// All values in the column are dates or date times.
+    DateTime,
+}
+
+impl ColumnType {
+    /// Determine the type of a column from the values it contains.
+    /// [CellValue::Null] values are ignored.
+    pub(crate) fn from_values<'a>(values: impl IntoIterator<Item = &'a CellValue>) -> Self {
+        let mut column_type = ColumnType::Empty;
+        for value in values {
+            let value_type = match value {
+                CellValue::Null => continue,
+                CellValue::Str(_) => ColumnType::Text,
+                CellValue::Int(_) => ColumnType::Integer,
+                CellValue::Float(_) => ColumnType::Float,
+                CellValue::Bool(_) => ColumnType::Boolean,
+                CellValue::DateTime(_) => ColumnType::DateTime,
+            };
+
+            column_type = match (column_type, value_type) {
+                (ColumnType::Empty, value_type) => value_type,
+                (current, value_type) if current == value_type => current,
+                (ColumnType::Integer, ColumnType::Float)
+                | (ColumnType::Float, ColumnType::Integer) => ColumnType::Float,
+                _ => return ColumnType::Text,
+            };
+        }
+
+        column_type
+    }
+
+    /// Returns whether this column contains numbers.
+    pub fn is_numeric(&self) -> bool {
+        matches!(self, ColumnType::Integer | ColumnType::Float)
+    }
+}
+
+/// Returns whether all values of a column are identifiers like hashes, IDs or paths.
+///
+/// Identifiers are single words, which contain digits or separators like `-`, `_`, `/`, `.` or
+/// `:`. [CellValue::Null] values are ignored.
+pub(crate) fn is_identifier_column(values: &[CellValue]) -> bool {
+    let mut words = values
+        .iter()
+        .filter(|value| **value != CellValue::Null)
+        .map(CellValue::to_display_string)
+        .peekable();
+    words.peek().is_some()
+        && words.all(|word| {
+            !word.contains(char::is_whitespace)
+                && word
+                    .chars()
+                    .any(|character| character.is_ascii_digit() || "-_/.:".contains(character))
+        })
+}
+
+/// Floats are compared by their bit pattern.
+/// That way `CellValue` can be `Eq` and `Hash`, which is required by [Cell](crate::Cell).
+impl PartialEq for CellValue {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (CellValue::Str(left), CellValue::Str(right)) => left == right,
+            (CellValue::Int(left), CellValue::Int(right)) => left == right,
+            (CellValue::Float(left), CellValue::Float(right)) => left.to_bits() == right.to_bits(),
+            (CellValue::Bool(left), CellValue::Bool(right)) => left == right,
+            (CellValue::DateTime(left), CellValue::DateTime(right)) => left == right,
+            (CellValue::Null, CellValue::Null) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for CellValue {}
+
+impl Hash for CellValue {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            CellValue::Str(string) => string.hash(state),
+            CellValue::Int(int) => int.hash(state),
+            CellValue::Float(float) => float.to_bits().hash(state),
+            CellValue::Bool(boolean) => boolean.hash(state),
+            CellValue::DateTime(time) => time.hash(state),
+            CellValue::Null => {}
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for CellValue {
+                fn from(value: $int) -> Self {
+                    CellValue::Int(i64::from(value))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i8, i16, i32, i64, u8, u16, u32);
+
+macro_rules! impl_from_unsigned {
+    ($($int:ty),*) => {
+        $(
+            /// Values above [i64::MAX] don't fit into a [CellValue::Int] and become a
+            /// [CellValue::Float] instead.
+            impl From<$int> for CellValue {
+                fn from(value: $int) -> Self {
+                    i64::try_from(value).map_or(CellValue::Float(value as f64), CellValue::Int)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_unsigned!(u64, usize);
+
+impl From<f32> for CellValue {
+    fn from(value: f32) -> Self {
+        CellValue::Float(f64::from(value))
+    }
+}
+
+impl From<f64> for CellValue {
+    fn from(value: f64) -> Self {
+        CellValue::Float(value)
+    }
+}
+
+impl From<bool> for CellValue {
+    fn from(value: bool) -> Self {
+        CellValue::Bool(value)
+    }
+}
+
+impl From<String> for CellValue {
+    fn from(value: String) -> Self {
+        CellValue::Str(value)
+    }
+}
+
+impl From<&str> for CellValue {
+    fn from(value: &str) -> Self {
+        CellValue::Str(value.to_string())
+    }
+}
+
+impl From<SystemTime> for CellValue {
+    fn from(value: SystemTime) -> Self {
+        CellValue::DateTime(value)
+    }
+}
+
+impl<T: Into<CellValue>> From<Option<T>> for CellValue {
+    fn from(value: Option<T>) -> Self {
+        match value {
+            Some(value) => value.into(),
+            None => CellValue::Null,
+        }
+    }
+}
+
+/// Format a [SystemTime] as an RFC 3339 timestamp in UTC, e.g. `2024-02-29T13:37:00Z`.
+/// Times before the unix epoch are clamped to the epoch.
+fn format_datetime(time: SystemTime) -> String {
+    let seconds_of_day = seconds_since_epoch(time) % 86_400;
+
+    format!(
+        "{}T{:02}:{:02}:{:02}Z",
+        format_date(time),
+        seconds_of_day / 3600,
+        (seconds_of_day % 3600) / 60,
+        seconds_of_day % 60
+    )
+}
+
+/// Format the date part of a [SystemTime] in UTC, e.g. `2024-02-29`.
+pub(crate) fn format_date(time: SystemTime) -> String {
+    let days = (seconds_since_epoch(time) / 86_400) as i64;
+    let (year, month, day) = civil_from_days(days);
+
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// Returns whether a [SystemTime] lies exactly at midnight UTC.
+pub(crate) fn is_midnight(time: SystemTime) -> bool {
+    seconds_since_epoch(time) % 86_400 == 0
+}
+
+fn seconds_since_epoch(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
+
+/// Parse `YYYY-MM-DD` or `YYYY-MM-DD[T ]HH:MM:SS[Z]` as a UTC point in time.
+fn parse_datetime(text: &str) -> Option<SystemTime> {
+    let text = text.strip_suffix('Z').unwrap_or(text);
+    let (date, time) = match text.split_once(['T', ' ']) {
+        Some((date, time)) => (date, Some(time)),
+        None => (text, None),
+    };
+
+    let mut date_parts = date.split('-');
+    let year: i64 = parse_fixed_digits(date_parts.next()?, 4)?;
+    let month: u32 = parse_fixed_digits(date_parts.next()?, 2)?;
+    let day: u32 = parse_fixed_digits(date_parts.next()?, 2)?;
+    if date_parts.next().is_some()
+        || !(1..=12).contains(&month)
+        || day == 0
+        || day > days_in_month(year, month)
+    {
+        return None;
+    }
+
+    let mut seconds_of_day = 0;
+    if let Some(time) = time {
+        let mut time_parts = time.split(':');
+        let hours: u64 = parse_fixed_digits(time_parts.next()?, 2)?;
+        let minutes: u64 = parse_fixed_digits(time_parts.next()?, 2)?;
+        let seconds: u64 = parse_fixed_digits(time_parts.next()?, 2)?;
+        if time_parts.next().is_some() || hours > 23 || minutes > 59 || seconds > 59 {
+            return None;
+        }
+        seconds_of_day = hours * 3600 + minutes * 60 + seconds;
+    }
+
+    let days = days_from_civil(year, month, day);
+    // We don't support dates before the unix epoch.
+    let days = u64::try_from(days).ok()?;
+
+    Some(UNIX_EPOCH + Duration::from_secs(days * 86_400 + seconds_of_day))
+}
+
+fn parse_fixed_digits<T: std::str::FromStr>(text: &str, digits: usize) -> Option<T> {
+    if text.len() != digits || !text.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    text.parse().ok()
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Convert days since the unix epoch to a (year, month, day) tuple.
+/// This is Howard Hinnant's `civil_from_days` algorithm.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    (year, month, day)
+}
+
+/// Convert a (year, month, day) tuple to days since the unix epoch.
+/// This is Howard Hinnant's `days_from_civil` algorithm.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = i64::from(month);
+    let month_index = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * month_index + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(CellValue::parse("  -3 "), CellValue::Int(-3));
+        assert_eq!(CellValue::parse("1e3"), CellValue::Float(1000.0));
+        assert_eq!(CellValue::parse("NULL"), CellValue::Null);
+        assert_eq!(CellValue::parse("False"), CellValue::Bool(false));
+        assert_eq!(CellValue::parse("inf"), CellValue::Str("inf".to_string()));
+        assert_eq!(CellValue::parse("1-2"), CellValue::Str("1-2".to_string()));
+        assert_eq!(
+            CellValue::parse("2024-02-30"),
+            CellValue::Str("2024-02-30".to_string())
+        );
+    }
+
+    #[test]
+    fn test_datetime_roundtrip() {
+        let value = CellValue::parse("2024-02-29T13:37:05Z");
+        assert!(matches!(value, CellValue::DateTime(_)));
+        assert_eq!(value.to_display_string(), "2024-02-29T13:37:05Z");
+
+        let value = CellValue::parse("1970-01-01");
+        assert_eq!(value, CellValue::DateTime(UNIX_EPOCH));
+        assert_eq!(value.to_display_string(), "1970-01-01T00:00:00Z");
+    }
+
+    #[test]
+    fn test_column_type_from_values() {
+        let values = [CellValue::Int(1), CellValue::Null, CellValue::Float(2.5)];
+        assert_eq!(ColumnType::from_values(&values), ColumnType::Float);
+
+        let values = [CellValue::Int(1), CellValue::Bool(true)];
+        assert_eq!(ColumnType::from_values(&values), ColumnType::Text);
+
+        assert_eq!(
+            ColumnType::from_values(&[CellValue::Null]),
+            ColumnType::Empty
+        );
+    }
+
+    #[test]
+    fn test_is_identifier_column() {
+        let values = [
+            CellValue::Str("9f3c2a1e".into()),
+            CellValue::Null,
+            CellValue::Str("/usr/lib".into()),
+        ];
+        assert!(is_identifier_column(&values));
+
+        let values = [
+            CellValue::Str("db-1".into()),
+            CellValue::Str("Apples".into()),
+        ];
+        assert!(!is_identifier_column(&values));
+        assert!(!is_identifier_column(&[CellValue::Str("ID 42".into())]));
+        assert!(!is_identifier_column(&[CellValue::Null]));
+    }
+
+    #[test]
+    fn test_from_unsigned() {
+        assert_eq!(CellValue::from(42_u64), CellValue::Int(42));
+        assert_eq!(CellValue::from(7_usize), CellValue::Int(7));
+        assert_eq!(CellValue::from(i64::MAX as u64), CellValue::Int(i64::MAX));
+        assert_eq!(CellValue::from(u64::MAX), CellValue::Float(u64::MAX as f64));
+    }
+
+    #[test]
+    fn test_sort_cmp() {
+        assert_eq!(
+            CellValue::Int(2).sort_cmp(&CellValue::Float(10.5)),
+            Ordering::Less
+        );
+        assert_eq!(
+            CellValue::Null.sort_cmp(&CellValue::Str("a".into())),
+            Ordering::Less
+        );
+        assert_eq!(
+            CellValue::Str("b".into()).sort_cmp(&CellValue::Str("a".into())),
+            Ordering::Greater
+        );
+    }
+}
@@ -47,6 +47,50 @@ fn cell_alignment() {
     assert_eq!(expected, "\n".to_string() + &table.to_string());
 }
 
+#[test]
+/// Rows can specify an alignment for all of their cells.
+/// Alignment settings on Cells and Columns overwrite the settings of Rows
+fn row_cell_alignment() {
+    let mut row = Row::from(vec![
+        Cell::new("Left").set_alignment(CellAlignment::Left),
+        Cell::new("Center"),
+        Cell::new("Right"),
+    ]);
+    row.set_cell_alignment(CellAlignment::Right);
+
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Long Header1", "Long Header2", "Long Header3"])
+        .add_row(row);
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_cell_alignment(CellAlignment::Center);
+
+    println!("{table}");
+    let expected = "
++--------------+--------------+--------------+
+| Long Header1 | Long Header2 | Long Header3 |
++============================================+
+| Left         |    Center    |        Right |
++--------------+--------------+--------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    let row = table.row(0).unwrap();
+    let styles: Vec<EffectiveStyle> = row
+        .cell_iter()
+        .map(|cell| cell.effective_style(&table).unwrap())
+        .collect();
+    assert_eq!(styles[0].alignment, CellAlignment::Left);
+    assert_eq!(styles[1].alignment, CellAlignment::Center);
+    assert_eq!(styles[2].alignment, CellAlignment::Right);
+    assert_eq!(styles[2].vertical_alignment, VerticalAlignment::Top);
+    assert_eq!(styles[2].padding, (1, 1));
+
+    // Cells that aren't part of the table have no effective style.
+    assert_eq!(Cell::new("Loose").effective_style(&table), None);
+}
+
 #[test]
 fn vertical_alignment_top() {
     let mut table = Table::new();
@@ -175,6 +219,39 @@ fn vertical_alignment_with_rowspan() {
     assert_eq!(expected, "\n".to_string() + &table.to_string());
 }
 
+#[test]
+fn vertical_alignment_with_rowspan_over_multiline_rows() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["H1", "H2", "H3"])
+        .add_row(vec![
+            Cell::new("Mid")
+                .set_rowspan(3)
+                .set_vertical_alignment(VerticalAlignment::Middle),
+            Cell::new("1\n2\n3\n4"),
+            Cell::new("A"),
+        ])
+        .add_row(vec![Cell::new("5"), Cell::new("B")])
+        .add_row(vec![Cell::new("6"), Cell::new("C")]);
+
+    // The content is centered within all lines of the spanned rows,
+    // not within the amount of spanned rows.
+    let expected = "
++-----+----+----+
+| H1  | H2 | H3 |
++===============+
+|     | 1  | A  |
+|     | 2  |    |
+| Mid | 3  |    |
+|     | 4  |    |
+|     |----+----|
+|     | 5  | B  |
+|     |----+----|
+|     | 6  | C  |
++-----+----+----+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
 #[test]
 fn vertical_alignment_default_is_top() {
     let mut table = Table::new();
@@ -225,3 +302,158 @@ fn column_vertical_alignment() {
 +----+----+----+";
     assert_eq!(expected, "\n".to_string() + &table.to_string());
 }
+
+#[test]
+fn fill_char() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Left", "Right", "Center"])
+        .add_row(vec![
+            Cell::new("Chapter 1").set_fill_char('.'),
+            Cell::new("12")
+                .set_alignment(CellAlignment::Right)
+                .set_fill_char('.'),
+            Cell::new("mid")
+                .set_alignment(CellAlignment::Center)
+                .set_fill_char('-'),
+        ])
+        .add_row(vec![
+            Cell::new("Multi\nline").set_fill_char('.'),
+            Cell::new("A somewhat longer entry"),
+            Cell::new("Not filled"),
+        ]);
+    println!("{table}");
+
+    let expected = "
++-----------+-------------------------+------------+
+| Left      | Right                   | Center     |
++==================================================+
+| Chapter 1 | .....................12 | ----mid--- |
+|-----------+-------------------------+------------|
+| Multi.... | A somewhat longer entry | Not filled |
+| line..... |                         |            |
++-----------+-------------------------+------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Wrapped lines keep the alignment of the line they belong to, while lines without an
+/// alignment use the one of their column.
+#[test]
+fn line_alignments() {
+    let mut table = Table::new();
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(20)
+        .add_row(vec![
+            Cell::new("A rather long title\nleft\nright\ncolumn").set_line_alignments(vec![
+                CellAlignment::Center,
+                CellAlignment::Left,
+                CellAlignment::Right,
+            ]),
+        ]);
+    table
+        .column_mut(0)
+        .unwrap()
+        .set_cell_alignment(CellAlignment::Right);
+
+    let expected = "
++---------------+
+| A rather long |
+|     title     |
+| left          |
+|         right |
+|        column |
++---------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Cells that are shorter than their row, because other cells have been wrapped, use the
+/// vertical alignment of their row, unless the cell or column has one.
+#[test]
+fn row_vertical_alignment() {
+    let mut table = Table::new();
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(30)
+        .set_header(vec!["H1", "H2", "H3"]);
+    let mut row = Row::from(vec![
+        Cell::new("Middle"),
+        Cell::new("This text is wrapped to several lines"),
+        Cell::new("Top").set_vertical_alignment(VerticalAlignment::Top),
+    ]);
+    row.set_vertical_alignment(VerticalAlignment::Middle);
+    table.add_row(row);
+
+    let expected = "
++--------+-------------+-----+
+| H1     | H2          | H3  |
++============================+
+|        | This text   | Top |
+| Middle | is wrapped  |     |
+|        | to several  |     |
+|        | lines       |     |
++--------+-------------+-----+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Colspan cells are aligned vertically within the height of their row, while their wrapped
+/// content determines the height for the other cells of the row.
+#[test]
+fn vertical_alignment_dynamic_colspan() {
+    let mut table = Table::new();
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(30)
+        .set_header(vec!["H1", "H2", "H3"]);
+    let mut row = Row::from(vec![
+        Cell::new("A colspan cell, that is wrapped to three lines").set_colspan(2),
+        Cell::new("Mid"),
+    ]);
+    row.set_vertical_alignment(VerticalAlignment::Middle);
+    table.add_row(row);
+    table.add_row(vec![
+        Cell::new("Bottom")
+            .set_colspan(2)
+            .set_vertical_alignment(VerticalAlignment::Bottom),
+        Cell::new("Three\nlines\nhere"),
+    ]);
+
+    let expected = "
++----------+---------+-------+
+| H1       | H2      | H3    |
++============================+
+| A colspan cell,    |       |
+| that is wrapped to | Mid   |
+| three lines        |       |
+|--------------------+-------|
+|                    | Three |
+|                    | lines |
+| Bottom             | here  |
++--------------------+-------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// The baselines of cells are aligned across the row, even if lines before them are wrapped.
+#[test]
+fn vertical_alignment_baseline() {
+    let mut table = Table::new();
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(40)
+        .add_row(vec![
+            Cell::new("Label\n1024").set_vertical_alignment(VerticalAlignment::Baseline(1)),
+            Cell::new("A label, which is wrapped\n3")
+                .set_vertical_alignment(VerticalAlignment::Baseline(1)),
+            Cell::new("Total\namount\n42").set_vertical_alignment(VerticalAlignment::Baseline(2)),
+            Cell::new("Top"),
+        ]);
+
+    let expected = "
++-------+----------+--------+-----+
+|       | A label, |        | Top |
+|       | which is | Total  |     |
+| Label | wrapped  | amount |     |
+| 1024  | 3        | 42     |     |
++-------+----------+--------+-----+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
@@ -0,0 +1,113 @@
+use pretty_assertions::assert_eq;
+
+use super_table::*;
+
+/// Draws every line with the same char and marks the position of each column boundary.
+#[derive(Debug)]
+struct MarkerRenderer;
+
+impl BorderRenderer for MarkerRenderer {
+    fn draw_separator(&self, separator: &Separator) -> String {
+        let horizontal = match separator.kind {
+            SeparatorKind::Top => '^',
+            SeparatorKind::Header => '=',
+            SeparatorKind::Row => '-',
+            SeparatorKind::Bottom => 'v',
+        };
+
+        let mut line = String::new();
+        if separator.left_border {
+            line.push(separator.component(TableComponent::LeftBorder).unwrap());
+        }
+        for (index, column) in separator.columns.iter().enumerate() {
+            if index > 0 {
+                // Keep the boundary open, if a cell spans across it.
+                if column.joined_above || column.joined_below {
+                    line.push(horizontal);
+                } else {
+                    line.push('+');
+                }
+            }
+            let fill = if column.rowspan { ' ' } else { horizontal };
+            line.extend(std::iter::repeat_n(fill, column.width));
+        }
+        if separator.right_border {
+            line.push(separator.component(TableComponent::RightBorder).unwrap());
+        }
+
+        line
+    }
+}
+
+fn table() -> Table {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Header2", "Header3"])
+        .add_row(vec![
+            Cell::new("Rowspan").set_rowspan(2),
+            Cell::new("Spans two columns").set_colspan(2),
+        ])
+        .add_row(vec!["One", "Two"]);
+
+    table
+}
+
+#[test]
+fn custom_border_renderer() {
+    let mut table = table();
+    table.set_border_renderer(MarkerRenderer);
+
+    let expected = "
+|^^^^^^^^^+^^^^^^^^^^^^+^^^^^^^^^^^|
+| Header1 | Header2    | Header3   |
+|=========+========================|
+| Rowspan | Spans two columns      |
+|         +------------------------|
+|         | One        | Two       |
+|vvvvvvvvv+vvvvvvvvvvvv+vvvvvvvvvvv|";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn default_border_renderer() {
+    let mut table = table();
+    let default = table.to_string();
+
+    table.set_border_renderer(DefaultBorderRenderer);
+    assert_eq!(default, table.to_string());
+
+    table
+        .set_border_renderer(MarkerRenderer)
+        .remove_border_renderer();
+    assert_eq!(default, table.to_string());
+}
+
+/// Only draws a short marker for each line.
+#[derive(Debug)]
+struct ShortRenderer;
+
+impl BorderRenderer for ShortRenderer {
+    fn draw_separator(&self, _separator: &Separator) -> String {
+        "~~".to_string()
+    }
+}
+
+#[test]
+fn short_lines_are_padded() {
+    let mut table = table();
+    table.set_border_renderer(ShortRenderer);
+    table.assert_rectangular();
+
+    let expected = "
+~~..................................
+|.Header1.|.Header2....|.Header3...|
+~~..................................
+|.Rowspan.|.Spans.two.columns......|
+~~..................................
+|.........|.One........|.Two.......|
+~~..................................";
+    assert_eq!(
+        expected,
+        "\n".to_string() + &table.to_string().replace(' ', ".")
+    );
+}
@@ -0,0 +1,54 @@
+use pretty_assertions::assert_eq;
+
+use super_table::*;
+
+#[test]
+fn builder_is_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<TableBuilder>();
+}
+
+#[test]
+fn rows_are_ordered_by_key() {
+    let mut table = Table::new();
+    table.set_header(vec!["Key", "Value"]);
+
+    let builder = TableBuilder::new(table);
+    std::thread::scope(|scope| {
+        for key in (0..3).rev() {
+            let builder = &builder;
+            scope.spawn(move || {
+                builder.add_row(key, vec![key.to_string(), format!("value {key}")]);
+            });
+        }
+    });
+    // Rows with the same key keep their insertion order.
+    builder
+        .add_row(1, vec!["1", "first duplicate"])
+        .add_row(1, vec!["1", "second duplicate"]);
+    assert_eq!(builder.row_count(), 5);
+
+    let table = builder.build();
+    let expected = "
++-----+------------------+
+| Key | Value            |
++========================+
+| 0   | value 0          |
+|-----+------------------|
+| 1   | value 1          |
+|-----+------------------|
+| 1   | first duplicate  |
+|-----+------------------|
+| 1   | second duplicate |
+|-----+------------------|
+| 2   | value 2          |
++-----+------------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn empty_builder() {
+    let builder = TableBuilder::from(Table::new());
+    assert!(builder.is_empty());
+    assert_eq!(builder.build().row_count(), 0);
+}
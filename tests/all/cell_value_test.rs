@@ -0,0 +1,252 @@
+use pretty_assertions::assert_eq;
+
+use super_table::*;
+
+#[test]
+fn typed_cells_display_their_value() {
+    let mut table = Table::new();
+    table.set_header(vec!["Name", "Count", "Ratio", "Active", "Note"]);
+    table.add_row(vec![
+        Cell::from_value("apples"),
+        Cell::from_value(12),
+        Cell::from_value(0.25),
+        Cell::from_value(true),
+        Cell::from_value(CellValue::Null),
+    ]);
+
+    let expected = "
++--------+-------+-------+--------+------+
+| Name   | Count | Ratio | Active | Note |
++========================================+
+| apples | 12    | 0.25  | true   |      |
++--------+-------+-------+--------+------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    let row = table.row(0).unwrap();
+    let values: Vec<CellValue> = row.cell_iter().map(Cell::typed_value).collect();
+    assert_eq!(
+        values,
+        vec![
+            CellValue::Str("apples".to_string()),
+            CellValue::Int(12),
+            CellValue::Float(0.25),
+            CellValue::Bool(true),
+            CellValue::Null,
+        ]
+    );
+}
+
+#[test]
+fn value_is_kept_alongside_formatted_content() {
+    let cell = Cell::new("1.2k").set_value(1200);
+    assert_eq!(cell.content(), "1.2k");
+    assert_eq!(cell.value(), Some(&CellValue::Int(1200)));
+
+    // Cells without an explicit value parse their content on demand.
+    let cell = Cell::new("3.5");
+    assert_eq!(cell.value(), None);
+    assert_eq!(cell.typed_value(), CellValue::Float(3.5));
+}
+
+#[test]
+fn infer_column_types() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Count", "Ratio", "Active", "Since"])
+        .add_row(vec!["apples", "12", "0.5", "true", "2024-01-05"])
+        .add_row(vec!["pears", "3", "12", "false", "2023-12-24T00:00:00Z"])
+        .add_row(vec!["plums", "", "", "", ""]);
+
+    let report = table.infer_column_types();
+    assert_eq!(
+        report,
+        vec![
+            ColumnType::Text,
+            ColumnType::Integer,
+            ColumnType::Float,
+            ColumnType::Boolean,
+            ColumnType::DateTime,
+        ]
+    );
+    assert_eq!(
+        table.column(1).unwrap().column_type(),
+        Some(ColumnType::Integer)
+    );
+
+    let expected = "
++--------+-------+-------+--------+------------+
+| Name   | Count | Ratio | Active | Since      |
++==============================================+
+| apples |    12 |   0.5 |  true  | 2024-01-05 |
+|--------+-------+-------+--------+------------|
+| pears  |     3 |    12 |  false | 2023-12-24 |
+|--------+-------+-------+--------+------------|
+| plums  |       |       |        |            |
++--------+-------+-------+--------+------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Dates are formatted in the column they're displayed in, even if cells span multiple columns
+/// or rows.
+#[test]
+fn infer_date_columns_with_spans() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Host", "Since", "Until"])
+        .add_row(vec![
+            Cell::new("db-1").set_colspan(2),
+            Cell::new("2024-01-05"),
+        ])
+        .add_row(vec![
+            Cell::new("web-1").set_rowspan(2),
+            Cell::new("2024-02-01T00:00:00Z"),
+            Cell::new("2024-03-01"),
+        ])
+        .add_row(vec!["2024-02-03T00:00:00Z", "2024-03-02T00:00:00Z"]);
+
+    let report = table.infer_column_types();
+    assert_eq!(
+        report,
+        vec![ColumnType::Text, ColumnType::DateTime, ColumnType::DateTime]
+    );
+
+    let expected = "
++-------+------------+------------+
+| Host  | Since      | Until      |
++=================================+
+| db-1               | 2024-01-05 |
+|--------------------+------------|
+| web-1 | 2024-02-01 | 2024-03-01 |
+|       |------------+------------|
+|       | 2024-02-03 | 2024-03-02 |
++-------+------------+------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn infer_column_types_keeps_explicit_alignment() {
+    let mut table = Table::new();
+    table.add_row(vec!["1"]).add_row(vec!["2"]);
+    table
+        .column_mut(0)
+        .unwrap()
+        .set_cell_alignment(CellAlignment::Left);
+
+    assert_eq!(table.infer_column_types(), vec![ColumnType::Integer]);
+
+    let expected = "
++---+
+| 1 |
+|---|
+| 2 |
++---+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn infer_column_overflows() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Commit", "Message", "Lines", "Path"])
+        .add_row(vec![
+            "9f3c2a1e77b44d0c",
+            "Fix the rendering of wide tables",
+            "1204311",
+            "src/utils/mod.rs",
+        ])
+        .add_row(vec!["e1d2c3b4a5968778", "Add tests", "42", "tests/all.rs"])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(60);
+    // Explicit overflows are kept.
+    table
+        .column_mut(3)
+        .unwrap()
+        .set_overflow(Overflow::Truncate);
+
+    table.infer_column_types();
+    assert_eq!(
+        table.column(0).unwrap().overflow(),
+        Some(Overflow::TruncateMiddle)
+    );
+    assert_eq!(table.column(1).unwrap().overflow(), Some(Overflow::Wrap));
+    assert_eq!(table.column(2).unwrap().overflow(), Some(Overflow::NoWrap));
+    assert_eq!(
+        table.column(3).unwrap().overflow(),
+        Some(Overflow::Truncate)
+    );
+
+    let expected = "
++----------------+---------------+---------+---------------+
+| Commit         | Message       |   Lines | Path          |
++==========================================================+
+| 9f3c2a...44d0c | Fix the       | 1204311 | src/utils/... |
+|                | rendering of  |         |               |
+|                | wide tables   |         |               |
+|----------------+---------------+---------+---------------|
+| e1d2c3...68778 | Add tests     |      42 | tests/all.rs  |
++----------------+---------------+---------+---------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn auto_precision() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Price", "Ratio"])
+        .add_row(vec![
+            Cell::new("Apples"),
+            Cell::from_value(2),
+            Cell::from_value(0.333333),
+        ])
+        .add_row(vec![
+            Cell::new("Pears"),
+            Cell::new("1.5"),
+            Cell::from_value(1.0),
+        ])
+        .add_row(vec!["Plums", "n/a", "0.25"]);
+    table.column_mut(1).unwrap().set_auto_precision();
+    table
+        .column_mut(2)
+        .unwrap()
+        .set_decimal_precision(DecimalPrecision::Max(3));
+
+    let expected = "
++--------+-------+-------+
+| Name   | Price | Ratio |
++========================+
+| Apples |   2.0 | 0.333 |
+|--------+-------+-------|
+| Pears  |   1.5 | 1.000 |
+|--------+-------+-------|
+| Plums  | n/a   | 0.250 |
++--------+-------+-------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    // The stored content isn't changed.
+    assert_eq!(
+        table.row(1).unwrap().cell_iter().nth(1).unwrap().content(),
+        "1.5"
+    );
+}
+
+#[test]
+fn negative_parentheses_with_precision() {
+    let mut table = Table::new();
+    table
+        .add_row(vec![Cell::from_value(-1.5)])
+        .add_row(vec![Cell::from_value(20)])
+        .add_row(vec![Cell::new("n/a")]);
+    let column = table.column_mut(0).unwrap();
+    column
+        .set_auto_precision()
+        .set_negative_format(NegativeFormat::Parentheses);
+
+    let expected = "
++-------+
+| (1.5) |
+|-------|
+| 20.0  |
+|-------|
+| n/a   |
++-------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
@@ -357,3 +357,98 @@ fn empty_table(#[case] arrangement: ContentArrangement) {
     println!("{expected}");
     assert_eq!(expected, "\n".to_string() + &table.to_string());
 }
+
+fn conflicting_table(policy: ConstraintConflictPolicy) -> Table {
+    let mut table = Table::new();
+    table
+        .add_row(vec!["smol", "This is another text", "Third"])
+        .set_width(40)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_constraint_conflict_policy(policy)
+        .set_constraints(vec![Absolute(Fixed(20)), LowerBoundary(Fixed(20))]);
+
+    table
+}
+
+#[test]
+/// The constraints are kept by default, even if they don't fit into the table.
+fn conflict_error() {
+    let table = conflicting_table(ConstraintConflictPolicy::Error);
+    println!("{table}");
+
+    let expected = "
++--------------------+--------------------+---+
+| smol               | This is another    | T |
+|                    | text               | h |
+|                    |                    | i |
+|                    |                    | r |
+|                    |                    | d |
++--------------------+--------------------+---+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert!(matches!(
+        table.try_lines(),
+        Err(render::RenderError::ConstraintConflict { .. })
+    ));
+}
+
+#[test]
+/// Constrained columns are shrunk proportionally to fit into the table.
+fn conflict_shrink() {
+    let table = conflicting_table(ConstraintConflictPolicy::Shrink);
+    println!("{table}");
+
+    let expected = "
++----------------+----------------+----+
+| smol           | This is        | Th |
+|                | another text   | ir |
+|                |                | d  |
++----------------+----------------+----+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert_table_line_width(&table, 40);
+}
+
+#[test]
+/// The constraint of the rightmost constrained column is dropped first.
+fn conflict_drop_lowest_priority() {
+    let table = conflicting_table(ConstraintConflictPolicy::DropLowestPriority);
+    println!("{table}");
+
+    let expected = "
++--------------------+---------+-------+
+| smol               | This is | Third |
+|                    | another |       |
+|                    | text    |       |
++--------------------+---------+-------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert_table_line_width(&table, 40);
+}
+
+/// The default maximum width only applies to columns without their own constraint.
+#[test]
+fn default_max_cell_width() {
+    let mut table = Table::new();
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(80)
+        .set_default_max_cell_width(13)
+        .set_header(vec!["Name", "Description", "Notes"])
+        .add_row(vec![
+            "A long name",
+            "A description, which is long as well",
+            "Notes with lots of words",
+        ]);
+    table.column_mut(2).unwrap().set_constraint(ContentWidth);
+
+    let expected = "
++-------------+-------------+--------------------------+
+| Name        | Description | Notes                    |
++======================================================+
+| A long name | A descripti | Notes with lots of words |
+|             | on, which   |                          |
+|             | is long as  |                          |
+|             | well        |                          |
++-------------+-------------+--------------------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert_eq!(table.default_max_cell_width(), Some(13));
+    assert!(table.column(0).unwrap().constraint().is_none());
+}
@@ -2,7 +2,7 @@ use pretty_assertions::assert_eq;
 use super_table::ColumnConstraint;
 use super_table::Width;
 
-use super_table::{ContentArrangement, Table};
+use super_table::{Cell, ContentArrangement, Orientation, Table};
 
 use super::assert_table_line_width;
 
@@ -168,6 +168,91 @@ fn dynamic_full_width() {
     assert_eq!(expected, "\n".to_string() + &table.to_string());
 }
 
+/// Columns below a spanning cell get the space the cell needs, before the remaining space is
+/// distributed between all columns.
+#[test]
+fn dynamic_full_width_with_colspan() {
+    let mut table = Table::new();
+    table
+        .set_header(vec![
+            Cell::new("A rather long title spanning two columns").set_colspan(2),
+            Cell::new("C"),
+        ])
+        .set_content_arrangement(ContentArrangement::DynamicFullWidth)
+        .set_width(64)
+        .add_row(vec!["a", "b", "some content here"])
+        .add_row(vec!["aa", "bb", "c"]);
+
+    println!("{table}");
+    let expected = "
++-------------------------------+----------+-------------------+
+| A rather long title spanning two columns | C                 |
++==============================================================+
+| a                             | b        | some content here |
+|-------------------------------+----------+-------------------|
+| aa                            | bb       | c                 |
++-------------------------------+----------+-------------------+";
+    println!("{expected}");
+    assert_table_line_width(&table, 64);
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// The longest word of a cell that must not be wrapped is the minimum width of its column.
+#[test]
+fn dynamic_no_wrap() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Id", "Description"])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(40)
+        .add_row(vec![
+            Cell::new("3f2504e0-4f89-11d3-9a0c").set_no_wrap(),
+            Cell::new("A description that is long enough to be wrapped"),
+        ]);
+
+    println!("{table}");
+    let expected = "
++-------------------------+------------+
+| Id                      | Descriptio |
+|                         | n          |
++======================================+
+| 3f2504e0-4f89-11d3-9a0c | A descript |
+|                         | ion that   |
+|                         | is long    |
+|                         | enough to  |
+|                         | be wrapped |
++-------------------------+------------+";
+    println!("{expected}");
+    assert_table_line_width(&table, 40);
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Spanned columns are widened for a word that must not be wrapped,
+/// even if the table gets wider than its width.
+#[test]
+fn dynamic_no_wrap_colspan() {
+    let mut table = Table::new();
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(30)
+        .add_row(vec!["key", "value"])
+        .add_row(vec![
+            Cell::new("3f2504e0-4f89-11d3-9a0c-0305e82c3301")
+                .set_colspan(2)
+                .set_no_wrap(),
+        ]);
+
+    println!("{table}");
+    let expected = "
++-------------------+-------------------+
+| key               | value             |
+|-------------------+-------------------|
+| 3f2504e0-4f89-11d3-9a0c-0305e82c3301  |
++---------------------------------------+";
+    println!("{expected}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
 /// Test that a table is displayed in its full width, if the `table.width` is set to the exact
 /// width the table has, if it's fully expanded.
 ///
@@ -292,3 +377,135 @@ fn polar_python_test_tbl_width_chars() {
     assert_table_line_width(table, 72);
     assert_eq!(expected, "\n".to_string() + &table.to_string());
 }
+
+#[test]
+/// Columns with a higher truncation priority keep their natural width.
+fn truncation_priority_keeps_natural_width() {
+    let mut table = Table::new();
+    table
+        .set_width(40)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .add_row(vec![
+            "This is a long description",
+            "Important content",
+            "Another long description",
+        ]);
+    table.column_mut(1).unwrap().set_truncation_priority(1);
+    println!("{table}");
+
+    let expected = "
++---------+-------------------+--------+
+| This is | Important content | Anothe |
+| a long  |                   | r long |
+| descrip |                   | descri |
+| tion    |                   | ption  |
++---------+-------------------+--------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+/// Columns with the lowest truncation priority are hidden, if there's no space left for them.
+fn truncation_priority_hides_columns() {
+    let mut table = Table::new();
+    table
+        .set_width(12)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .add_row(vec!["Low", "Important content", "Low"]);
+    table.column_mut(1).unwrap().set_truncation_priority(1);
+    println!("{table}");
+
+    let expected = "
++---+------+
+| L | Impo |
+| o | rtan |
+| w | t    |
+|   | cont |
+|   | ent  |
++---+------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Vertical header cells only need the width of their widest grapheme, so wide matrices fit
+/// into narrow tables without splitting their content.
+#[test]
+fn dynamic_vertical_orientation() {
+    let mut table = Table::new();
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(26)
+        .set_header(vec![
+            Cell::new("User"),
+            Cell::new("Read").set_orientation(Orientation::Vertical),
+            Cell::new("Write").set_orientation(Orientation::Vertical),
+            Cell::new("Delete\nall").set_orientation(Orientation::Vertical),
+            Cell::new("全部").set_orientation(Orientation::Vertical),
+        ])
+        .add_row(vec!["admin", "x", "x", "x", "x"])
+        .add_row(vec!["guest", "x", "", "", ""]);
+
+    let expected = "
++-------+---+---+---+----+
+| User  | R | W | D | 全 |
+|       | e | r | e | 部 |
+|       | a | i | l |    |
+|       | d | t | e |    |
+|       |   | e | t |    |
+|       |   |   | e |    |
+|       |   |   |   |    |
+|       |   |   | a |    |
+|       |   |   | l |    |
+|       |   |   | l |    |
++========================+
+| admin | x | x | x | x  |
+|-------+---+---+---+----|
+| guest | x |   |   |    |
++-------+---+---+---+----+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Wrapped lines of left-aligned cells are indented, other alignments and the header aren't.
+#[test]
+fn hanging_indent() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Event details of the deployment", "Status"])
+        .add_row(vec![
+            Cell::new("reason: rollout of the new release\nhost: web-1 in eu-west"),
+            Cell::new("waiting for approval").set_alignment(super_table::CellAlignment::Right),
+        ])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(40);
+    table.column_mut(0).unwrap().set_hanging_indent(8);
+    table.column_mut(1).unwrap().set_hanging_indent(8);
+
+    let expected = "
++------------------------+-------------+
+| Event details of the   | Status      |
+| deployment             |             |
++======================================+
+| reason: rollout of the | waiting for |
+|         new release    |    approval |
+| host: web-1 in eu-west |             |
++------------------------+-------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Lines aren't indented, if a wide character wouldn't fit next to the indent.
+#[test]
+fn hanging_indent_with_wide_characters() {
+    let mut table = Table::new();
+    table.add_row(vec!["ab 你好"]);
+    let column = table.column_mut(0).unwrap();
+    column.set_hanging_indent(2);
+    column.set_constraint(ColumnConstraint::Absolute(Width::Fixed(5)));
+
+    let expected = "
++-----+
+| ab  |
+| 你  |
+| 好  |
++-----+";
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    table.assert_rectangular();
+}
@@ -53,3 +53,32 @@ fn test_is_empty() {
     table.add_row(vec!["Foo", "Bar"]);
     assert_eq!(table.is_empty(), false);
 }
+
+#[test]
+fn test_col_count_spans() {
+    let mut table = Table::new();
+    table.add_row(vec![Cell::new("Wide").set_colspan(3)]);
+    assert_eq!(table.column_count(), 3);
+
+    // The second row is pushed to the right by the rowspan.
+    table.add_row(vec![Cell::new("Tall").set_rowspan(2), Cell::new("A")]);
+    table.add_row(vec!["B", "C", "D"]);
+    assert_eq!(table.column_count(), 4);
+}
+
+#[test]
+fn test_shape() {
+    let mut table = Table::new();
+    assert_eq!(table.shape(), (0, 0));
+
+    table.add_row(vec!["Foo", "Bar"]);
+    assert_eq!(table.shape(), (1, 2));
+
+    table.set_header(vec!["Header"]);
+    assert_eq!(table.shape(), (2, 2));
+
+    table
+        .add_row(vec![Cell::new("Tall").set_rowspan(2), Cell::new("A")])
+        .add_row(vec!["B", "C"]);
+    assert_eq!(table.shape(), (4, 3));
+}
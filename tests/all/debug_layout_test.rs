@@ -0,0 +1,76 @@
+use pretty_assertions::assert_eq;
+
+use super_table::*;
+
+#[test]
+fn describes_columns_and_spans() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Description", "Secret"])
+        .add_row(vec![
+            Cell::new("super-table"),
+            Cell::new("A library for building tables"),
+            Cell::new("hunter2"),
+        ])
+        .add_row(vec![Cell::new("Spanning all columns").set_colspan(3)]);
+    table.row_mut(0).unwrap().max_height(1);
+
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_constraint(ColumnConstraint::UpperBoundary(Width::Fixed(12)));
+    table
+        .column_mut(2)
+        .unwrap()
+        .set_constraint(ColumnConstraint::Hidden);
+
+    let expected = "
+Table width: unknown (Disabled)
+Columns:
+  0: width 13 (content 11, padding 1+1), max content 11, constraint: none
+  1: width 12 (content 10, padding 1+1), max content 29, constraint: UpperBoundary(Fixed(12)) -> content is wrapped due to constraint
+  2: hidden (constraint: Hidden)
+Spans:
+  row 2, column 0: colspan 3, rowspan 1 (1 of 3 spanned columns hidden)
+Truncated rows:
+  row 0: at most 1 line";
+    assert_eq!(expected, "\n".to_string() + &table.debug_layout());
+}
+
+#[test]
+fn narrowed_by_table_width() {
+    let mut table = Table::new();
+    table
+        .set_width(20)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .add_row(vec!["This content is too long for the table"]);
+
+    let expected = "
+Table width: 20 (Dynamic)
+Columns:
+  0: width 18 (content 16, padding 1+1), max content 38, constraint: none -> content is wrapped due to table width";
+    assert_eq!(expected, "\n".to_string() + &table.debug_layout());
+}
+
+#[test]
+fn reports_constraint_conflicts() {
+    let mut table = Table::new();
+    table
+        .set_width(30)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_constraint_conflict_policy(ConstraintConflictPolicy::DropLowestPriority)
+        .add_row(vec!["Left", "Right"])
+        .set_constraints(vec![
+            ColumnConstraint::Absolute(Width::Fixed(20)),
+            ColumnConstraint::Absolute(Width::Fixed(20)),
+        ]);
+
+    let expected = "
+Table width: 30 (Dynamic)
+Constraint conflict: columns require a width of 43, but the table width is 30
+  DropLowestPriority: changed constraints of columns 1, now requires a width of 26
+Columns:
+  0: width 20 (content 18, padding 1+1), max content 4, constraint: Absolute(Fixed(20))
+  1: width 7 (content 5, padding 1+1), max content 5, constraint: none";
+    assert_eq!(expected, "\n".to_string() + &table.debug_layout());
+}
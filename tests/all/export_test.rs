@@ -0,0 +1,675 @@
+use pretty_assertions::assert_eq;
+use super_table::*;
+
+#[test]
+fn csv_quoting() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Comment"])
+        .add_row(vec!["plain", "delimiter, inside"])
+        .add_row(vec!["quote", "She said \"hi\""])
+        .add_row(vec!["newline", "first\nsecond"])
+        .add_row(vec!["", ""]);
+
+    let expected = "Name,Comment
+plain,\"delimiter, inside\"
+quote,\"She said \"\"hi\"\"\"
+newline,\"first
+second\"
+,
+";
+    assert_eq!(expected, table.to_csv(CsvWriteOptions::new()));
+
+    let expected = "plain;delimiter, inside
+quote;\"She said \"\"hi\"\"\"
+newline;\"first
+second\"
+;
+";
+    let options = CsvWriteOptions::new().delimiter(';').include_header(false);
+    assert_eq!(expected, table.to_csv(options));
+}
+
+/// The raw content is exported, even if it's truncated once the table is displayed.
+#[test]
+fn csv_raw_and_rendered_content() {
+    let mut table = Table::new();
+    let mut row = Row::from(vec!["First\nSecond\nThird"]);
+    row.max_height(2);
+    table.set_header(vec!["Lines"]).add_row(row);
+
+    assert_eq!(
+        "Lines\n\"First\nSecond\nThird\"\n",
+        table.to_csv(CsvWriteOptions::new())
+    );
+
+    table.set_export_content(ExportContent::Rendered);
+    assert_eq!(
+        "Lines\n\"First\nSec...\"\n",
+        table.to_csv(CsvWriteOptions::new())
+    );
+}
+
+/// Only formatted exports format numbers and booleans like the rendered table.
+#[test]
+fn csv_value_formats() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Account", "Balance", "Active"])
+        .add_row(vec![
+            Cell::new("Savings"),
+            Cell::from_value(-1.5),
+            Cell::from_value(true),
+        ])
+        .add_row(vec![
+            Cell::new("Checking"),
+            Cell::from_value(20),
+            Cell::from_value(false),
+        ])
+        .set_bool_format(BoolFormat::yes_no());
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_auto_precision()
+        .set_negative_format(NegativeFormat::Parentheses);
+
+    // The original content is exported as it is.
+    assert_eq!(
+        "Account,Balance,Active\nSavings,-1.5,true\nChecking,20,false\n",
+        table.to_csv(CsvWriteOptions::new())
+    );
+
+    table.set_export_content(ExportContent::Formatted);
+    assert_eq!(
+        "Account,Balance,Active\nSavings,(1.5),yes\nChecking,20.0,no\n",
+        table.to_csv(CsvWriteOptions::new())
+    );
+}
+
+#[test]
+fn csv_span_policy() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Region", "Q1", "Q2"])
+        .add_row(vec![
+            Cell::new("North").set_rowspan(2),
+            Cell::new("1"),
+            Cell::new("2"),
+        ])
+        .add_row(vec!["3", "4"])
+        .add_row(vec![Cell::new("n/a").set_colspan(2), Cell::new("5")]);
+
+    let expected = "Region,Q1,Q2
+North,1,2
+,3,4
+n/a,,5
+";
+    assert_eq!(expected, table.to_csv(CsvWriteOptions::new()));
+
+    let expected = "Region,Q1,Q2
+North,1,2
+North,3,4
+n/a,n/a,5
+";
+    let options = CsvWriteOptions::new().span_policy(SpanPolicy::RepeatValue);
+    assert_eq!(expected, table.to_csv(options));
+}
+
+#[test]
+fn csv_description_comments() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["RSS", "CPU"])
+        .add_row(vec!["512", "3.5"]);
+    table
+        .column_mut(0)
+        .unwrap()
+        .set_description("Resident set size in MiB");
+
+    let expected = "# RSS: Resident set size in MiB
+RSS,CPU
+512,3.5
+";
+    let options = CsvWriteOptions::new().description_comments(true);
+    assert_eq!(expected, table.to_csv(options));
+}
+
+/// A single column with an empty field must not result in an empty line.
+#[test]
+fn csv_single_empty_field() {
+    let mut table = Table::new();
+    table.add_row(vec!["value"]).add_row(vec![""]);
+
+    assert_eq!("value\n\"\"\n", table.to_csv(CsvWriteOptions::new()));
+}
+
+#[test]
+fn org_alignment_and_escaping() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Status", "Load"])
+        .add_row(vec!["web|01", "running\nhealthy", "0.25"])
+        .add_row(vec![Cell::new("db").set_colspan(2), Cell::new("12.5")]);
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_cell_alignment(CellAlignment::Center);
+    table
+        .column_mut(2)
+        .unwrap()
+        .set_cell_alignment(CellAlignment::Right);
+
+    let expected = "
+| Name         |     Status      | Load |
+|--------------+-----------------+------|
+| <l>          |       <c>       |  <r> |
+| web\\vert{}01 | running healthy | 0.25 |
+| db           |                 | 12.5 |
+";
+    assert_eq!(expected, "\n".to_string() + &table.to_org());
+}
+
+#[test]
+fn org_without_header() {
+    let mut table = Table::new();
+    table.add_row(vec!["a", "bc"]).add_row(vec!["def", "g"]);
+
+    let expected = "
+| a   | bc |
+| def | g  |
+";
+    assert_eq!(expected, "\n".to_string() + &table.to_org());
+}
+
+#[test]
+fn jira_escaping() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Comment"])
+        .add_row(vec!["pipe", "a|b"])
+        .add_row(vec!["lines", "first\nsecond"])
+        .add_row(vec!["empty", ""]);
+
+    let expected = "||Name||Comment||
+|pipe|a\\|b|
+|lines|first\\\\second|
+|empty| |
+";
+    assert_eq!(expected, table.to_jira());
+}
+
+#[test]
+fn markdown_escaping_and_alignment() {
+    let mut table = Table::new();
+    table
+        .add_row(vec!["a|b", "first\nsecond", "x"])
+        .add_row(vec!["wide content", "", "y"]);
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_cell_alignment(CellAlignment::Center);
+    table
+        .column_mut(2)
+        .unwrap()
+        .set_cell_alignment(CellAlignment::Left);
+
+    // Tables without a header get an empty one.
+    let expected = "
+|              |                 |     |
+| ------------ | :-------------: | :-- |
+| a\\|b         | first<br>second | x   |
+| wide content |                 | y   |
+";
+    assert_eq!(expected, "\n".to_string() + &table.to_markdown());
+}
+
+#[test]
+fn fixed_width_records() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Account", "Owner", "Balance"])
+        .add_row(vec!["0001", "Alice\nSmith", "1200.50"])
+        .add_row(vec![Cell::new("Closed").set_colspan(2), Cell::new("0.00")]);
+    table
+        .column_mut(2)
+        .unwrap()
+        .set_cell_alignment(CellAlignment::Right);
+
+    // Every record has the same length and the ruler spans all of it.
+    let options = FixedWidthOptions::new().column_gap(3).ruler(true);
+    let expected = "\
+----+----1----+----2----+----3-
+Account   Owner         Balance
+0001      Alice Smith   1200.50
+Closed                     0.00
+";
+    assert_eq!(expected, table.to_fixed_width(options));
+
+    let options = FixedWidthOptions::new().include_header(false);
+    let expected = "\
+0001   Alice Smith 1200.50
+Closed                0.00
+";
+    assert_eq!(expected, table.to_fixed_width(options));
+
+    assert_eq!(Table::new().to_fixed_width(FixedWidthOptions::new()), "");
+}
+
+#[test]
+fn html_spans_and_alignment() {
+    let mut table = Table::new();
+    table
+        .set_header(vec![Cell::new("Group <a>").set_colspan(2), Cell::new("C")])
+        .add_row(vec![
+            Cell::new("span\nlines").set_rowspan(2),
+            Cell::new("b"),
+            Cell::new("c"),
+        ])
+        .add_row(vec!["b2", "c2"]);
+    table
+        .column_mut(2)
+        .unwrap()
+        .set_cell_alignment(CellAlignment::Right);
+
+    let expected = "
+<table>
+  <thead>
+    <tr><th colspan=\"2\">Group &lt;a&gt;</th><th style=\"text-align: right\">C</th></tr>
+  </thead>
+  <tbody>
+    <tr><td rowspan=\"2\">span<br>lines</td><td>b</td><td style=\"text-align: right\">c</td></tr>
+    <tr><td>b2</td><td style=\"text-align: right\">c2</td></tr>
+  </tbody>
+</table>
+";
+    assert_eq!(
+        expected,
+        "\n".to_string() + &table.to_html(HtmlOptions::new())
+    );
+
+    // Covered positions become cells of their own, with the attributes of the spanning cell.
+    let options = HtmlOptions::new().span_policy(SpanPolicy::RepeatValue);
+    let expected = "
+<table>
+  <thead>
+    <tr><th>Group &lt;a&gt;</th><th>Group &lt;a&gt;</th><th style=\"text-align: right\">C</th></tr>
+  </thead>
+  <tbody>
+    <tr><td>span<br>lines</td><td>b</td><td style=\"text-align: right\">c</td></tr>
+    <tr><td>span<br>lines</td><td>b2</td><td style=\"text-align: right\">c2</td></tr>
+  </tbody>
+</table>
+";
+    assert_eq!(expected, "\n".to_string() + &table.to_html(options));
+
+    let options = HtmlOptions::new().span_policy(SpanPolicy::BlankFill);
+    let expected = "
+<table>
+  <thead>
+    <tr><th>Group &lt;a&gt;</th><th></th><th style=\"text-align: right\">C</th></tr>
+  </thead>
+  <tbody>
+    <tr><td>span<br>lines</td><td>b</td><td style=\"text-align: right\">c</td></tr>
+    <tr><td></td><td>b2</td><td style=\"text-align: right\">c2</td></tr>
+  </tbody>
+</table>
+";
+    assert_eq!(expected, "\n".to_string() + &table.to_html(options));
+}
+
+#[test]
+fn csv_loading() {
+    let input =
+        "# Exported hosts\r\nHost;Comment\r\ndb-1;\"Line one\nLine \"\"two\"\"\"\r\n\r\nweb-1\r\n";
+    let options = CsvReadOptions::new().delimiter(';').skip_comments(true);
+    let table = Table::from_csv(input, options).unwrap();
+
+    // The empty line is a row without any cells.
+    let expected = "
++-------+------------+
+| Host  | Comment    |
++====================+
+| db-1  | Line one   |
+|       | Line \"two\" |
+|-------+------------|
+|       |            |
+|-------+------------|
+| web-1 |            |
++-------+------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    assert_eq!(
+        Table::from_csv("a,\"b\nc", CsvReadOptions::new()).unwrap_err(),
+        LoadError::UnclosedQuote { line: 1 }
+    );
+    assert_eq!(
+        Table::from_csv("a\n\"b\"c", CsvReadOptions::new()).unwrap_err(),
+        LoadError::UnexpectedCharacter { line: 2 }
+    );
+}
+
+#[test]
+fn lines_loading() {
+    let output = "PID   USER  COMMAND\n\n1     root  /sbin/init\n4242  www   nginx -g\n";
+    let mut table = Table::from_lines(output.lines(), SplitPolicy::Whitespace);
+    table.load_preset(presets::NOTHING);
+    // The arguments of the command end up in a column of their own.
+    let expected = "
+ PID   USER  COMMAND
+ 1     root  /sbin/init
+ 4242  www   nginx       -g";
+    assert_eq!(expected, "\n".to_string() + &table.trim_fmt());
+
+    let widths = SplitPolicy::FixedWidths(vec![6, 6]);
+    let mut table = Table::from_lines(output.lines(), widths);
+    table.load_preset(presets::NOTHING);
+    let expected = "
+ PID   USER  COMMAND
+ 1     root  /sbin/init
+ 4242  www   nginx -g";
+    assert_eq!(expected, "\n".to_string() + &table.trim_fmt());
+
+    let table = Table::from_lines(["a;b;;c", "d"], SplitPolicy::Delimiter(';'));
+    let expected = "
++---+---+---+---+
+| a | b |   | c |
+|---+---+---+---|
+| d |   |   |   |
++---+---+---+---+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn lines_loading_with_regex() {
+    let regex = regex::Regex::new(r"\s*[|,]\s*").unwrap();
+    let table = Table::from_lines(["a | b, c", "d,e"], SplitPolicy::Regex(regex));
+    let expected = "
++---+---+---+
+| a | b | c |
+|---+---+---|
+| d | e |   |
++---+---+---+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn json_escaping() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Key", "Value"])
+        .add_row(vec!["path", "C:\\temp\t\"new\"\n\u{7}"]);
+
+    let expected = r#"{
+  "header": ["Key", "Value"],
+  "rows": [
+    ["path", "C:\\temp\t\"new\"\n\u0007"]
+  ]
+}
+"#;
+    assert_eq!(expected, table.to_json());
+
+    let loaded = Table::from_json(expected).unwrap();
+    assert_eq!(loaded.to_json(), expected);
+
+    let loaded = Table::from_json(r#"{"rows": [["\u00e9\ud83d\ude00", "\/"]]}"#).unwrap();
+    assert!(loaded.header().is_none());
+    assert_eq!(
+        loaded.to_json(),
+        "{\n  \"rows\": [\n    [\"é😀\", \"/\"]\n  ]\n}\n"
+    );
+
+    assert_eq!(
+        Table::from_json(r#"{"header": ["a"]}"#).unwrap_err(),
+        LoadError::InvalidJson {
+            offset: 17,
+            reason: "the table has no `rows`"
+        }
+    );
+    assert_eq!(
+        Table::from_json(r#"{"rows": [[1]]}"#).unwrap_err(),
+        LoadError::InvalidJson {
+            offset: 11,
+            reason: "expected a string"
+        }
+    );
+}
+
+/// Spans are lost, but their content is restored at the position of the spanning cell.
+#[test]
+fn round_trip_with_spans() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Region", "Q1", "Q2"])
+        .add_row(vec![
+            Cell::new("North").set_rowspan(2),
+            "1".into(),
+            "2".into(),
+        ])
+        .add_row(vec!["3", "4"]);
+    assert_eq!(table.round_trip(), RoundTrip::SpansExpanded);
+
+    let loaded = Table::from_json(&table.to_json()).unwrap();
+    assert_eq!(loaded.round_trip(), RoundTrip::Lossless);
+    let expected = "
++--------+----+----+
+| Region | Q1 | Q2 |
++==================+
+| North  | 1  | 2  |
+|--------+----+----|
+|        | 3  | 4  |
++--------+----+----+";
+    assert_eq!(expected, "\n".to_string() + &loaded.to_string());
+
+    table.set_export_content(ExportContent::Rendered);
+    assert_eq!(table.round_trip(), RoundTrip::Rendered);
+}
+
+/// Masks, templates and formats change the exported values, which isn't lossless.
+#[test]
+fn round_trip_with_changed_content() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Balance", "Active"])
+        .add_row(vec![
+            Cell::new("Alice"),
+            Cell::from_value(-1.5),
+            Cell::from_value(true),
+        ]);
+    assert_eq!(table.round_trip(), RoundTrip::Lossless);
+
+    // Formats only change the values of formatted exports.
+    table
+        .set_bool_format(BoolFormat::yes_no())
+        .column_mut(1)
+        .unwrap()
+        .set_negative_format(NegativeFormat::Parentheses);
+    assert_eq!(table.round_trip(), RoundTrip::Lossless);
+    table.set_export_content(ExportContent::Formatted);
+    assert_eq!(table.round_trip(), RoundTrip::ContentChanged);
+    table.set_export_content(ExportContent::Original);
+
+    table.column_mut(0).unwrap().set_mask(MaskPolicy::Full);
+    assert_eq!(table.round_trip(), RoundTrip::ContentChanged);
+    table.column_mut(0).unwrap().remove_mask();
+    assert_eq!(table.round_trip(), RoundTrip::Lossless);
+
+    table.add_row(vec![Cell::templated("{name}", [("name", "Bob")])]);
+    assert_eq!(table.round_trip(), RoundTrip::ContentChanged);
+}
+
+/// Only semantic styles with a CSS class are written to HTML.
+#[test]
+fn html_semantic_styles() {
+    let mut table = Table::new();
+    table
+        .set_style_map(StyleMap::new().add("warning", StyleTarget::HtmlClass("warn \"x\"".into())))
+        .set_header(vec![Cell::new("Disk").set_semantic_style("warning")])
+        .add_row(vec![Cell::new("95%").set_semantic_style("warning")])
+        .add_row(vec![Cell::new("12%").set_semantic_style("ok")]);
+
+    let expected = "
+<table>
+  <thead>
+    <tr><th class=\"warn &quot;x&quot;\">Disk</th></tr>
+  </thead>
+  <tbody>
+    <tr><td class=\"warn &quot;x&quot;\">95%</td></tr>
+    <tr><td>12%</td></tr>
+  </tbody>
+</table>
+";
+    assert_eq!(
+        expected,
+        "\n".to_string() + &table.to_html(HtmlOptions::new())
+    );
+    assert_eq!("Disk\n95%\n12%\n", table.to_csv(CsvWriteOptions::new()));
+}
+
+#[test]
+fn html_cell_roles() {
+    let mut table = Table::new();
+    table
+        .set_style_map(StyleMap::new().add("summary", StyleTarget::HtmlClass("total".into())))
+        .set_header(vec![
+            Cell::new("Host"),
+            Cell::new("Note").set_role(CellRole::Data),
+        ])
+        .add_row(vec![
+            Cell::new("web-1").set_role(CellRole::Header),
+            Cell::new("restarted").set_role(CellRole::Annotation),
+        ])
+        .add_row(vec![
+            Cell::new("Total").set_role(CellRole::Summary),
+            Cell::new("1")
+                .set_semantic_style("ok")
+                .set_role(CellRole::Summary),
+        ])
+        .add_row(vec![
+            Cell::new("Generated at noon")
+                .set_colspan(2)
+                .set_role(CellRole::Footer),
+        ]);
+
+    let expected = "
+<table>
+  <thead>
+    <tr><th>Host</th><td>Note</td></tr>
+  </thead>
+  <tbody>
+    <tr><th scope=\"row\">web-1</th><td>restarted</td></tr>
+    <tr><td class=\"total\">Total</td><td>1</td></tr>
+  </tbody>
+  <tfoot>
+    <tr><td colspan=\"2\">Generated at noon</td></tr>
+  </tfoot>
+</table>
+";
+    assert_eq!(
+        expected,
+        "\n".to_string() + &table.to_html(HtmlOptions::new())
+    );
+    assert_eq!(
+        table.row(1).unwrap().cell(0).unwrap().role(),
+        Some(CellRole::Summary)
+    );
+}
+
+/// Bold and italic cells use the markup of the format, other attributes are dropped.
+#[cfg(feature = "tty")]
+#[test]
+fn markdown_and_html_emphasis() {
+    let mut table = Table::new();
+    table
+        .set_header(vec![
+            Cell::new("Name").add_attribute(Attribute::Bold),
+            Cell::new("Note"),
+        ])
+        .add_row(vec![
+            Cell::new("Alice").add_attribute(Attribute::Italic),
+            Cell::new("both")
+                .add_attribute(Attribute::Bold)
+                .add_attribute(Attribute::Italic),
+        ])
+        .add_row(vec![
+            Cell::new("Bob").add_attribute(Attribute::SlowBlink),
+            Cell::new("").add_attribute(Attribute::Bold),
+        ]);
+
+    let expected = "
+| **Name** | Note       |
+| -------- | ---------- |
+| *Alice*  | ***both*** |
+| Bob      |            |
+";
+    assert_eq!(expected, "\n".to_string() + &table.to_markdown());
+
+    let expected = "
+<table>
+  <thead>
+    <tr><th><strong>Name</strong></th><th>Note</th></tr>
+  </thead>
+  <tbody>
+    <tr><td><em>Alice</em></td><td><strong><em>both</em></strong></td></tr>
+    <tr><td>Bob</td><td></td></tr>
+  </tbody>
+</table>
+";
+    assert_eq!(
+        expected,
+        "\n".to_string() + &table.to_html(HtmlOptions::new())
+    );
+}
+
+/// Column descriptions are tooltips of their header cells.
+#[test]
+fn html_column_descriptions() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "RSS"])
+        .add_row(vec!["nginx", "512"]);
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_description("Resident set size in \"MiB\"");
+
+    let expected = "
+<table>
+  <thead>
+    <tr><th>Name</th><th title=\"Resident set size in &quot;MiB&quot;\">RSS</th></tr>
+  </thead>
+  <tbody>
+    <tr><td>nginx</td><td>512</td></tr>
+  </tbody>
+</table>
+";
+    assert_eq!(
+        expected,
+        "\n".to_string() + &table.to_html(HtmlOptions::new())
+    );
+}
+
+#[test]
+fn html_row_headers() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Mon", "Tue"])
+        .add_row(vec!["3", "5"])
+        .set_row_headers(vec!["Alice"])
+        .set_corner_cell("Name");
+
+    let expected = "
+<table>
+  <thead>
+    <tr><th>Name</th><th>Mon</th><th>Tue</th></tr>
+  </thead>
+  <tbody>
+    <tr><th scope=\"row\">Alice</th><td>3</td><td>5</td></tr>
+  </tbody>
+</table>
+";
+    assert_eq!(
+        expected,
+        "\n".to_string() + &table.to_html(HtmlOptions::new())
+    );
+}
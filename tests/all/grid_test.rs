@@ -0,0 +1,39 @@
+use pretty_assertions::assert_eq;
+use super_table::grid::Grid;
+use super_table::*;
+
+/// All cells have the same size, no matter their content.
+#[test]
+fn grid_cell_size() {
+    let mut grid = Grid::new(Table::new(), 3);
+    grid.set_cell_size(4, 2)
+        .add_cells(vec!["1", "Two\nlines", "Three lines\nof\ncontent"])
+        .skip(1)
+        .add_cell("5");
+    assert_eq!(grid.cell_count(), 5);
+
+    let expected = "
++------+------+------+
+| 1    | Two  | Thre |
+|      | l... | e... |
+|------+------+------|
+|      | 5    |      |
+|      |      |      |
++------+------+------+";
+    assert_eq!(expected, "\n".to_string() + &grid.build().to_string());
+}
+
+/// Grids with fewer cells than columns still have all of their columns.
+#[test]
+fn grid_fewer_cells_than_columns() {
+    let mut template = Table::new();
+    template.set_truncation_indicator("");
+    let mut grid = Grid::new(template, 4);
+    grid.add_cells(vec!["100", "7"]);
+
+    let expected = "
++----+----+----+----+
+| 10 | 7  |    |    |
++----+----+----+----+";
+    assert_eq!(expected, "\n".to_string() + &grid.build().to_string());
+}
@@ -0,0 +1,321 @@
+use pretty_assertions::assert_eq;
+
+use super_table::*;
+
+#[test]
+fn sort_indicator() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Age"])
+        .add_row(vec!["Alice", "30"])
+        .add_row(vec!["Bob", "4"]);
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_sort_indicator(SortOrder::Descending);
+
+    let expected = "
++-------+-------+
+| Name  | Age ▼ |
++===============+
+| Alice | 30    |
+|-------+-------|
+| Bob   | 4     |
++-------+-------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // The stored header content stays untouched.
+    assert_eq!(
+        table
+            .header()
+            .unwrap()
+            .cell_iter()
+            .nth(1)
+            .unwrap()
+            .content(),
+        "Age"
+    );
+}
+
+#[test]
+fn sort_indicator_custom_glyphs() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Age"])
+        .add_row(vec!["Alice", "30"]);
+    table
+        .column_mut(0)
+        .unwrap()
+        .set_sort_indicator_glyphs("asc", "desc")
+        .set_sort_indicator(SortOrder::Ascending);
+
+    let expected = "
++----------+-----+
+| Name asc | Age |
++================+
+| Alice    | 30  |
++----------+-----+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    table.column_mut(0).unwrap().remove_sort_indicator();
+    assert_eq!(table.column(0).unwrap().sort_indicator(), None);
+}
+
+#[test]
+fn sort_indicator_with_dynamic_arrangement() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Description"])
+        .add_row(vec!["Alice", "Some longer text that needs wrapping"])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(30);
+    table
+        .column_mut(0)
+        .unwrap()
+        .set_sort_indicator(SortOrder::Ascending);
+
+    let expected = "
++--------+-------------------+
+| Name ▲ | Description       |
++============================+
+| Alice  | Some longer text  |
+|        | that needs        |
+|        | wrapping          |
++--------+-------------------+";
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn description_footnotes() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Memory\nusage", "CPU"])
+        .add_row(vec!["nginx", "12", "0.5"])
+        .set_description_footnotes(true);
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_description("Resident set size in MiB");
+    table
+        .column_mut(2)
+        .unwrap()
+        .set_description("Share of a single core")
+        .set_constraint(ColumnConstraint::Hidden);
+
+    let expected = "
++-------+--------+
+| Name  | Memory |
+|       | usage  |
++================+
+| nginx | 12     |
++-------+--------+
+Memory usage: Resident set size in MiB";
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // Descriptions are only displayed on demand.
+    table.set_description_footnotes(false);
+    assert!(!table.to_string().contains("Resident"));
+}
+
+#[test]
+fn set_column_header() {
+    let mut table = Table::new();
+    table
+        .add_row(vec!["nginx", "12", "www-data"])
+        .set_column_header(1, "RSS")
+        .set_column_header(2, "User");
+    let cell = table.header_cell_mut(1).unwrap();
+    *cell = cell.clone().set_alignment(CellAlignment::Right);
+
+    let expected = "
++-------+-----+----------+
+|       | RSS | User     |
++========================+
+| nginx | 12  | www-data |
++-------+-----+----------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // Columns, that are covered by a spanning header cell, can't be changed.
+    let mut table = Table::new();
+    table
+        .set_header(vec![Cell::new("Process").set_colspan(2)])
+        .set_column_header(1, "Ignored");
+    assert_eq!(table.header().unwrap().cell_count(), 1);
+    assert!(table.header_cell(1).is_none());
+    assert_eq!(table.header_cell(0).unwrap().content(), "Process");
+}
+
+#[test]
+fn units_row() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Latency"])
+        .set_units_row(vec!["", "[milliseconds]"])
+        .add_row(vec!["api", "12"]);
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_cell_alignment(CellAlignment::Right);
+
+    // Units are considered for the width of columns, but aren't part of the header's content.
+    let expected = "
++------+----------------+
+| Name |        Latency |
+|      | [milliseconds] |
++=======================+
+| api  |             12 |
++------+----------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert_eq!(table.header_cell(1).unwrap().content(), "Latency");
+    assert_eq!(
+        table.to_csv(CsvWriteOptions::new()),
+        "Name,Latency\napi,12\n"
+    );
+}
+
+/// Header defaults only fill in settings, that the cells and columns don't have themselves.
+#[test]
+fn header_defaults() {
+    let mut table = Table::new();
+    table
+        .set_header_defaults(
+            HeaderDefaults::new()
+                .alignment(CellAlignment::Center)
+                .transform(TextTransform::Uppercase),
+        )
+        .set_header(vec![
+            Cell::new("name"),
+            Cell::new("state").set_alignment(CellAlignment::Left),
+            Cell::new("restarts"),
+        ])
+        .add_row(vec!["nginx-frontend", "running", "3"]);
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_header_text_transform(TextTransform::TitleCase);
+    table
+        .column_mut(2)
+        .unwrap()
+        .set_cell_alignment(CellAlignment::Right);
+
+    let expected = "
++----------------+---------+----------+
+|      NAME      | State   | RESTARTS |
++=====================================+
+| nginx-frontend | running |        3 |
++----------------+---------+----------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // The stored header isn't changed, but the effective style considers the defaults.
+    let header = table.header().unwrap();
+    assert_eq!(header.cell(0).unwrap().content(), "name");
+    let alignments: Vec<CellAlignment> = header
+        .cell_iter()
+        .map(|cell| cell.effective_style(&table).unwrap().alignment)
+        .collect();
+    assert_eq!(
+        alignments,
+        vec![
+            CellAlignment::Center,
+            CellAlignment::Left,
+            CellAlignment::Center
+        ]
+    );
+    let cell = table.row(0).unwrap().cell(0).unwrap();
+    assert_eq!(
+        cell.effective_style(&table).unwrap().alignment,
+        CellAlignment::Left
+    );
+}
+
+#[cfg(feature = "tty")]
+#[test]
+fn header_default_attributes() {
+    let mut table = Table::new();
+    table
+        .set_header_defaults(HeaderDefaults::new().add_attribute(Attribute::Bold))
+        .set_header(vec![Cell::new("Name").add_attribute(Attribute::Italic)])
+        .add_row(vec!["Alice"]);
+
+    let header_cell = table.header().unwrap().cell(0).unwrap();
+    assert_eq!(
+        header_cell.effective_style(&table).unwrap().attributes,
+        vec![Attribute::Italic, Attribute::Bold]
+    );
+    let cell = table.row(0).unwrap().cell(0).unwrap();
+    assert!(cell.effective_style(&table).unwrap().attributes.is_empty());
+
+    table.force_no_tty().enforce_styling();
+    let expected = "
++-------+
+|\u{1b}[1m\u{1b}[3m Name  \u{1b}[0m|
++=======+
+| Alice |
++-------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Row headers are inserted in front of rowspans and keep the settings of existing columns.
+#[test]
+fn row_headers() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Zone", "Load"])
+        .add_row(vec![Cell::new("eu").set_rowspan(2), Cell::new("12%")])
+        .add_row(vec!["95%"]);
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_cell_alignment(CellAlignment::Right);
+    table
+        .set_row_headers(vec!["web-1", "web-2", "db-1"])
+        .set_corner_cell("Host");
+
+    let expected = "
++-------+------+------+
+| Host  | Zone | Load |
++=====================+
+| web-1 | eu   |  12% |
+|-------|      |------|
+| web-2 |      |  95% |
+|-------+------+------|
+| db-1  |      |      |
++-------+------+------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert!(table.has_row_headers());
+    assert_eq!(table.column_count(), 3);
+    assert_eq!(
+        table.row(2).unwrap().cell(0).unwrap().role(),
+        Some(CellRole::Header)
+    );
+}
+
+/// Columns are wide enough for the widest character of their unit.
+#[test]
+fn units_row_with_wide_characters() {
+    let mut table = Table::new();
+    table
+        .set_ambiguous_width(AmbiguousWidth::Wide)
+        .set_header(vec!["Area"])
+        .set_units_row(vec!["m²"])
+        .add_row(vec!["12"]);
+    table
+        .column_mut(0)
+        .unwrap()
+        .set_constraint(ColumnConstraint::UpperBoundary(Width::Fixed(3)));
+
+    let expected = "
++----+
+| Ar |
+| ea |
+| m  |
+| ² |
++====+
+| 12 |
++----+";
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    table.assert_rectangular();
+}
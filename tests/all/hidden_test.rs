@@ -123,3 +123,119 @@ fn only_hidden_columns() {
 └┘";
     assert_eq!(expected, "\n".to_string() + &table.to_string());
 }
+
+/// Frozen columns are repeated in every chunk, while hidden columns stay hidden.
+#[test]
+fn column_chunks_with_frozen_columns() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Id", "Host", "Secret", "CPU", "Memory", "Disk"])
+        .add_row(vec![
+            Cell::new("1"),
+            Cell::new("db-1"),
+            Cell::new("hunter2"),
+            Cell::new("12%"),
+            Cell::new("4.1G"),
+            Cell::new("81%"),
+        ])
+        .add_row(vec![Cell::new("Total").set_colspan(4), Cell::new("8.2G")]);
+    table.column_mut(0).unwrap().set_frozen(true);
+    table.column_mut(1).unwrap().set_frozen(true);
+    table
+        .column_mut(2)
+        .unwrap()
+        .set_constraint(ColumnConstraint::Hidden);
+
+    let chunks = table.column_chunks(28);
+    assert_eq!(chunks.len(), 2);
+    let expected = "
++----+------+-----+--------+
+| Id | Host | CPU | Memory |
++==========================+
+| 1  | db-1 | 12% | 4.1G   |
+|----+------+-----+--------|
+| Total           | 8.2G   |
++-----------------+--------+
++----+------+------+
+| Id | Host | Disk |
++==================+
+| 1  | db-1 | 81%  |
+|----+------+------|
+| Total     |      |
++-----------+------+";
+    let rendered: Vec<String> = chunks.iter().map(Table::to_string).collect();
+    assert_eq!(expected, "\n".to_string() + &rendered.join("\n"));
+
+    // Without any room, every chunk contains a single column that isn't frozen.
+    assert_eq!(table.column_chunks(0).len(), 3);
+    // The original table isn't changed.
+    assert!(!table.column(3).unwrap().is_hidden());
+}
+
+/// Collapsed columns ignore their constraint and unit, while hidden columns stay hidden.
+#[test]
+fn collapsed_columns() {
+    let mut table = Table::new();
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(40)
+        .set_header(vec!["Name", "Latency", "Token", "Notes"])
+        .set_units_row(vec!["", "[ms]", "", ""])
+        .add_row(vec!["db-1", "12", "secret", ""])
+        .add_row(vec!["db-2", "", "secret", "Restarted twice"]);
+    let column = table.column_mut(1).unwrap();
+    column
+        .set_constraint(ColumnConstraint::LowerBoundary(Width::Fixed(20)))
+        .set_collapsed(true);
+    table
+        .column_mut(2)
+        .unwrap()
+        .set_hidden(true)
+        .set_collapsed(true);
+    table.column_mut(3).unwrap().set_collapsed(true);
+
+    let expected = "
++------+---+---+
+| Name | … | … |
++==============+
+| db-1 | … |   |
+|------+---+---|
+| db-2 |   | … |
++------+---+---+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert!(table.column(1).unwrap().constraint().is_some());
+}
+
+/// Columns, that are hidden because they don't fit, are summarized as well.
+#[test]
+fn hidden_columns_summary() {
+    let mut table = Table::new();
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(12)
+        .set_hidden_columns_summary(HiddenColumnsSummary::Rows)
+        .set_header(vec!["Host", "Description", "Zone"])
+        .add_row(vec![
+            Cell::new("db-1"),
+            Cell::new("The primary database of the cluster"),
+            Cell::new("eu").set_rowspan(2),
+        ])
+        .add_row(vec!["db-2", ""]);
+    table.column_mut(0).unwrap().set_truncation_priority(10);
+    table.column_mut(2).unwrap().set_truncation_priority(10);
+
+    let expected = "
++----+---+----+
+| Ho | Z | +1 |
+| st | o |    |
+|    | n |    |
+|    | e |    |
++=============+
+| db | e | +1 |
+| -1 | u |    |
+|----|   |----|
+| db |   |    |
+| -2 |   |    |
++----+---+----+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
@@ -0,0 +1,143 @@
+use pretty_assertions::assert_eq;
+
+use super_table::*;
+
+fn style() -> Style {
+    Style::new().fg(Color::Red)
+}
+
+#[test]
+fn highlight_substring() {
+    let mut table = Table::new();
+    table
+        .force_no_tty()
+        .enforce_styling()
+        .set_header(vec!["Service", "Status"])
+        .add_row(vec!["database", "error: connection refused"])
+        .add_row(vec!["cache", "ok"])
+        .highlight_matches("error", style());
+
+    println!("{table}");
+    let expected = "
++----------+---------------------------+
+| Service  | Status                    |
++======================================+
+| database | \u{1b}[38;5;9merror\u{1b}[39m: connection refused |
+|----------+---------------------------|
+| cache    | ok                        |
++----------+---------------------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn highlight_keeps_cell_style() {
+    let mut table = Table::new();
+    table
+        .force_no_tty()
+        .enforce_styling()
+        .add_row(vec![Cell::new("a match b").bg(Color::Blue)])
+        .highlight_matches("match", style());
+
+    println!("{table}");
+    let expected = "
++-----------+
+|\u{1b}[48;5;12m \u{1b}[49m\u{1b}[48;5;12ma \u{1b}[49m\u{1b}[48;5;12m\u{1b}[38;5;9mmatch\u{1b}[49m\u{1b}[39m\u{1b}[48;5;12m b\u{1b}[49m\u{1b}[48;5;12m \u{1b}[49m|
++-----------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn highlight_without_styling() {
+    let mut table = Table::new();
+    table
+        .force_no_tty()
+        .add_row(vec!["error"])
+        .highlight_matches("error", style());
+
+    let expected = "
++-------+
+| error |
++-------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn highlight_wrapped_match() {
+    let mut table = Table::new();
+    table
+        .force_no_tty()
+        .enforce_styling()
+        .set_width(14)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .add_row(vec!["needle in a haystack"])
+        .highlight_matches("in a", style());
+
+    println!("{table}");
+    let expected = "
++------------+
+| needle \u{1b}[38;5;9min\u{1b}[39m  |
+| \u{1b}[38;5;9ma\u{1b}[39m haystack |
++------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn highlight_truncated_match() {
+    let mut row = Row::from(vec!["find the needle"]);
+    row.max_height(1);
+
+    let mut table = Table::new();
+    table
+        .force_no_tty()
+        .enforce_styling()
+        .set_width(14)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .add_row(row)
+        .highlight_matches("the", style());
+
+    println!("{table}");
+    let expected = "
++------------+
+| find \u{1b}[38;5;9mth\u{1b}[39m... |
++------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn highlight_regex() {
+    let mut table = Table::new();
+    table
+        .force_no_tty()
+        .enforce_styling()
+        .add_row(vec!["id 42 and 7"])
+        .highlight_matches(regex::Regex::new(r"\d+").unwrap(), style());
+
+    println!("{table}");
+    let expected = "
++-------------+
+| id \u{1b}[38;5;9m42\u{1b}[39m and \u{1b}[38;5;9m7\u{1b}[39m |
++-------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn highlight_with_hanging_indent() {
+    let mut table = Table::new();
+    table
+        .force_no_tty()
+        .enforce_styling()
+        .add_row(vec!["status: failed with error"])
+        .highlight_matches("error", style());
+    let column = table.column_mut(0).unwrap();
+    column.set_hanging_indent(8);
+    column.set_constraint(ColumnConstraint::Absolute(Width::Fixed(16)));
+
+    let expected = "
++----------------+
+| status: failed |
+|         with   |
+|         \u{1b}[38;5;9merror\u{1b}[39m  |
++----------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
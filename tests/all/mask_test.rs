@@ -0,0 +1,181 @@
+use pretty_assertions::assert_eq;
+
+use super_table::*;
+
+fn get_table() -> Table {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Card", "Token"])
+        .add_row(vec!["Alice", "4111111111111111", "secret"])
+        .add_row(vec!["Bob", "5500000000000004", "multi\nline"]);
+
+    table
+}
+
+#[test]
+fn mask_policies() {
+    let mut table = get_table();
+    table.column_mut(1).unwrap().set_mask(MaskPolicy::Partial {
+        visible_prefix: 0,
+        visible_suffix: 4,
+    });
+    table.column_mut(2).unwrap().set_mask(MaskPolicy::Full);
+
+    println!("{table}");
+    let expected = "
++-------+------------------+--------+
+| Name  | Card             | Token  |
++===================================+
+| Alice | ************1111 | ****** |
+|-------+------------------+--------|
+| Bob   | ************0004 | *****  |
+|       |                  | ****   |
++-------+------------------+--------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // The content of the cells is untouched.
+    let cell = table.row(0).unwrap().cell_iter().nth(2).unwrap();
+    assert_eq!(cell.content(), "secret");
+}
+
+/// Exports of the original content are masked as well, except for the header.
+#[test]
+fn mask_exports() {
+    let mut table = get_table();
+    table.column_mut(1).unwrap().set_mask(MaskPolicy::Partial {
+        visible_prefix: 0,
+        visible_suffix: 4,
+    });
+    table.column_mut(2).unwrap().set_mask(MaskPolicy::Full);
+
+    let expected =
+        "Name,Card,Token\nAlice,************1111,******\nBob,************0004,\"*****\n****\"\n";
+    assert_eq!(expected, table.to_csv(CsvWriteOptions::default()));
+
+    let expected = "
+| Name  | Card             | Token         |
+| ----- | ---------------- | ------------- |
+| Alice | ************1111 | ******        |
+| Bob   | ************0004 | *****<br>**** |
+";
+    assert_eq!(expected, "\n".to_string() + &table.to_markdown());
+
+    for export in [table.to_html(HtmlOptions::new()), table.to_json()] {
+        assert!(!export.contains("4111111111111111"));
+        assert!(!export.contains("secret"));
+    }
+}
+
+#[test]
+fn mask_hash() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["User"])
+        .add_row(vec!["alice"])
+        .add_row(vec!["bob"])
+        .add_row(vec!["alice"])
+        .add_row(vec![""]);
+    table.column_mut(0).unwrap().set_mask(MaskPolicy::Hash);
+
+    println!("{table}");
+    let expected = "
++------------------+
+| User             |
++==================+
+| 508b2abb65a03907 |
+|------------------|
+| 004d4419134a0a54 |
+|------------------|
+| 508b2abb65a03907 |
+|------------------|
+|                  |
++------------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn partial_mask_hides_short_content() {
+    let policy = MaskPolicy::Partial {
+        visible_prefix: 2,
+        visible_suffix: 2,
+    };
+    assert_eq!(policy.mask("abcd"), "****");
+    assert_eq!(policy.mask("abcde"), "ab*de");
+}
+
+#[test]
+fn mask_with_dynamic_arrangement() {
+    let mut table = get_table();
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(30);
+    table.column_mut(1).unwrap().set_mask(MaskPolicy::Full);
+
+    println!("{table}");
+    let expected = "
++-------+-----------+--------+
+| Name  | Card      | Token  |
++============================+
+| Alice | ********* | secret |
+|       | *******   |        |
+|-------+-----------+--------|
+| Bob   | ********* | multi  |
+|       | *******   | line   |
++-------+-----------+--------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Repeated values are replaced by the dedup marker, until a new group starts.
+#[test]
+fn dedup_display() {
+    let mut table = Table::new();
+    table
+        .load_preset(presets::ASCII_BORDERS_ONLY_CONDENSED)
+        .set_header(vec!["Region", "Host", "State"])
+        .add_row(vec!["eu-west", "db-1", "up"])
+        .add_row(vec!["eu-west", "db-2", "up"])
+        .add_row(vec!["eu-west", "db-3", "up"])
+        .add_section_break()
+        .add_row(vec!["eu-west", "web-1", "up"])
+        .add_annotation_row("Maintenance window")
+        .add_row(vec![
+            Cell::new("eu-west"),
+            Cell::new("web-2"),
+            Cell::new("down").set_rowspan(2),
+        ])
+        .add_row(vec!["eu-west", "web-3"])
+        .add_row(vec![
+            Cell::new("eu-west").set_role(CellRole::Footer),
+            Cell::new("7 hosts").set_role(CellRole::Footer),
+            Cell::new("").set_role(CellRole::Footer),
+        ]);
+    table
+        .column_mut(0)
+        .unwrap()
+        .set_dedup_display(true)
+        .set_dedup_marker("\"");
+    table.column_mut(2).unwrap().set_dedup_display(true);
+
+    let expected = "
++---------------------------+
+| Region    Host      State |
++===========================+
+| eu-west   db-1      up    |
+| \"         db-2            |
+| \"         db-3            |
++===========================+
+| eu-west   web-1     up    |
+| Maintenance window        |
+| eu-west   web-2     down  |
+| \"         web-3           |
+| eu-west   7 hosts         |
++---------------------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // Exports of the original content keep all values.
+    assert!(
+        table
+            .to_csv(CsvWriteOptions::default())
+            .contains("eu-west,db-2,up")
+    );
+}
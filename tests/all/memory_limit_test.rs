@@ -0,0 +1,77 @@
+use pretty_assertions::assert_eq;
+
+use super_table::*;
+
+#[test]
+fn rows_are_discarded_while_building() {
+    let mut table = Table::new();
+    table
+        .set_memory_limit(20)
+        .set_header(vec!["Name", "Role"])
+        .add_row(vec!["Alice", "Admin"])
+        .add_row(vec!["Bob", "User"])
+        // This row would fit, but rows are never skipped.
+        .add_row(vec!["", ""]);
+
+    assert_eq!(table.row_count(), 1);
+    assert_eq!(table.omitted_rows(), 2);
+
+    let expected = "
++---------------+--------------+
+| Name          | Role         |
++==============================+
+| Alice         | Admin        |
+|---------------+--------------|
+| ... 2 more rows omitted      |
++------------------------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn rows_are_limited_when_rendering() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Role"])
+        .add_row(vec!["Alice", "Admin"])
+        .add_row(vec!["Bob", "User"]);
+
+    // The limit is applied to rows that have been added beforehand as well.
+    table.set_memory_limit(20);
+    assert_eq!(table.omitted_rows(), 0);
+
+    let expected = "
++--------------+--------------+
+| Name         | Role         |
++=============================+
+| Alice        | Admin        |
+|--------------+--------------|
+| ... 1 more row omitted      |
++-----------------------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    table.remove_memory_limit();
+    assert_eq!(table.lines().count(), 7);
+}
+
+#[test]
+fn set_row_respects_limit() {
+    let mut table = Table::new();
+    table
+        .set_memory_limit(20)
+        .set_header(vec!["Name", "Role"])
+        .set_row(1, vec!["Bob", "User"])
+        // Replacing a row only counts the difference in size.
+        .set_row(1, vec!["Bob", "Admin"])
+        // The previous row is kept, if the new one doesn't fit.
+        .set_row(1, vec!["Bob", "Administrator"]);
+
+    assert_eq!(table.row_count(), 2);
+    assert_eq!(table.omitted_rows(), 1);
+    let row = table.row(1).unwrap();
+    assert_eq!(row.cell_iter().nth(1).unwrap().content(), "Admin");
+
+    // Once a row has been discarded, no rows are appended anymore.
+    table.set_row(2, vec![""]);
+    assert_eq!(table.row_count(), 2);
+    assert_eq!(table.omitted_rows(), 2);
+}
@@ -3,24 +3,40 @@ use unicode_width::UnicodeWidthStr;
 
 mod add_predicate;
 mod alignment_test;
+mod border_renderer_test;
+mod builder_test;
+mod cell_value_test;
 #[cfg(feature = "tty")]
 mod combined_test;
 mod constraints_test;
 mod content_arrangement_test;
 mod counts;
 mod custom_delimiter_test;
+mod debug_layout_test;
 mod edge_cases;
+mod export_test;
+mod grid_test;
+mod header_test;
 mod hidden_test;
+#[cfg(feature = "tty")]
+mod highlight_test;
 #[cfg(feature = "custom_styling")]
 mod inner_style_test;
+mod mask_test;
+mod memory_limit_test;
 mod modifiers_test;
 mod padding_test;
 mod presets_test;
 mod property_test;
+mod render_test;
+mod report_test;
+#[cfg(feature = "serde")]
+mod serialize_test;
 mod simple_test;
 mod spanning_test;
 #[cfg(feature = "tty")]
 mod styling_test;
+mod text_transform_test;
 mod truncation;
 mod utf_8_characters;
 
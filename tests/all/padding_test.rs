@@ -35,3 +35,101 @@ fn custom_padding() {
 +-------------------+-----------+-----------+";
     assert_eq!(expected, "\n".to_string() + &table.to_string());
 }
+
+#[test]
+/// Columns without padding opposite to their alignment get a gap, if requested.
+/// Columns that already have a gap keep their padding.
+fn alignment_gap() {
+    let mut table = Table::new();
+    table
+        .set_alignment_gap(true)
+        .set_header(vec!["Left", "Right", "Center", "Padded"])
+        .add_row(vec!["One", "Two", "Three", "Four"]);
+
+    table.column_mut(0).unwrap().set_padding((0, 0));
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_padding((0, 0))
+        .set_cell_alignment(CellAlignment::Right);
+    table
+        .column_mut(2)
+        .unwrap()
+        .set_padding((0, 0))
+        .set_cell_alignment(CellAlignment::Center);
+    table.column_mut(3).unwrap().set_padding((0, 2));
+
+    let expected = "
++-----+------+--------+--------+
+|Left | Right| Center |Padded  |
++==============================+
+|One  |   Two|  Three |Four    |
++-----+------+--------+--------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+/// Margins surround the table with blank space, which is part of the table's width.
+fn margin() {
+    let mut table = Table::new();
+    table
+        .set_margin(Margins {
+            left: 2,
+            right: 1,
+            top: 1,
+            bottom: 1,
+        })
+        .set_width(24)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec!["Host", "State"])
+        .add_row(vec!["db-1", "up and running"]);
+    table.assert_rectangular();
+
+    let expected = "
+.....................
+..+------+---------+.
+..|.Host.|.State...|.
+..+================+.
+..|.db-1.|.up.and..|.
+..|......|.running.|.
+..+------+---------+.
+.....................";
+    assert_eq!(
+        expected,
+        "\n".to_string() + &table.to_string().replace(' ', ".")
+    );
+}
+
+#[test]
+/// Line prefixes are placed in front of the margin and count towards the table's width.
+/// The line decorator receives the complete lines.
+fn line_prefix_and_decorator() {
+    let mut table = Table::new();
+    table
+        .set_line_prefix("// ")
+        .set_margin(Margins {
+            left: 1,
+            ..Margins::default()
+        })
+        .set_line_decorator(|index, line| {
+            if index == 4 {
+                line.to_string()
+            } else {
+                line.to_uppercase()
+            }
+        })
+        .set_width(24)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec!["Host", "State"])
+        .add_row(vec!["db-1", "up and running"]);
+    assert_eq!(table.line_prefix(), Some("// "));
+
+    let expected = "
+//  +------+-----------+
+//  | HOST | STATE     |
+//  +==================+
+//  | DB-1 | UP AND    |
+//  |      | running   |
+//  +------+-----------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
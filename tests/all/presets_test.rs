@@ -190,6 +190,36 @@ fn test_utf8_horizontal_only() {
     assert_eq!(expected, "\n".to_string() + &table.trim_fmt());
 }
 
+#[test]
+fn test_ascii_mysql() {
+    let mut table = get_preset_table();
+    table.load_preset(ASCII_MYSQL);
+    println!("{table}");
+    let expected = "
++-------+-------+
+| Hello | there |
++-------+-------+
+| a     | b     |
+| c     | d     |
++-------+-------+";
+    println!("{expected}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn test_ascii_psql() {
+    let mut table = get_preset_table();
+    table.load_preset(ASCII_PSQL);
+    println!("{table}");
+    let expected = "
+ Hello | there
+-------+-------
+ a     | b
+ c     | d";
+    println!("{expected}");
+    assert_eq!(expected, "\n".to_string() + &table.trim_fmt());
+}
+
 #[test]
 fn test_nothing() {
     let mut table = get_preset_table();
@@ -217,3 +247,190 @@ c      d";
     println!("{expected}");
     assert_eq!(expected, "\n".to_string() + &table.trim_fmt());
 }
+
+/// Older presets were padded with two characters, which don't style the intersections next to
+/// rowspans.
+#[test]
+fn legacy_preset_with_padding() {
+    // The 23 character version of ASCII_FULL before rowspan intersections could be styled.
+    let legacy_ascii_full = "||--+==+|-+||++-=++++++";
+    let mut table = Table::new();
+    table
+        .load_preset(legacy_ascii_full)
+        .add_row(vec![Cell::new("a").set_rowspan(2), Cell::new("b")])
+        .add_row(vec!["c"])
+        .add_row(vec!["d", "e"]);
+
+    let expected = "
++---+---+
+| a | b |
+|   |---|
+|   | c |
+|---+---|
+| d | e |
++---+---+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert_eq!(table.style(TableComponent::RowspanLeftIntersection), None);
+    assert_eq!(ASCII_FULL, table.current_style_as_preset());
+
+    // Newer components follow after the padding.
+    let preset = format!("{ASCII_FULL}  ><");
+    table.load_preset(&preset);
+    assert_eq!(
+        table.style(TableComponent::RowspanLeftIntersection),
+        Some('>')
+    );
+    assert_eq!(
+        table.style(TableComponent::RowspanRightIntersection),
+        Some('<')
+    );
+    assert_eq!(preset, table.current_style_as_preset());
+}
+
+/// Compact tables keep their borders, but lose their padding and the lines between rows.
+#[test]
+fn compact_utf8() {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_compact(true)
+        .set_header(vec![Cell::new("Host"), Cell::new("State").set_colspan(2)])
+        .add_row(vec![
+            Cell::new("db-1").set_rowspan(2),
+            Cell::new("up"),
+            Cell::new("12d"),
+        ])
+        .add_row(vec!["down", "3h"]);
+
+    let expected = "
+┌───────────────┐
+│Host State     │
+╞═══════════════╡
+│db-1 up    12d │
+│     down  3h  │
+└───────────────┘";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Styles can be snapshotted, changed for a dense table and restored afterwards.
+#[test]
+fn restore_style_snapshot() {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header(vec!["Host", "State"])
+        .add_row(vec!["db-1", "up"])
+        .add_row(vec!["db-2", "down"]);
+    let full = table.to_string();
+    let snapshot = table.current_style();
+
+    for component in [
+        TableComponent::HorizontalLines,
+        TableComponent::MiddleIntersections,
+        TableComponent::LeftBorderIntersections,
+        TableComponent::RightBorderIntersections,
+    ] {
+        assert!(table.remove_style(component).is_some());
+    }
+    let expected = "
+┌──────┬───────┐
+│ Host ┆ State │
+╞══════╪═══════╡
+│ db-1 ┆ up    │
+│ db-2 ┆ down  │
+└──────┴───────┘";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    for (component, character) in snapshot {
+        table.set_style(component, character);
+    }
+    assert_eq!(full, table.to_string());
+}
+
+/// Each section can have its own preset, which also works with spans.
+/// The line above the footer belongs to the footer.
+#[test]
+fn section_presets() {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL_CONDENSED)
+        .load_preset_for(Section::Header, ASCII_FULL)
+        .load_preset_for(Section::Footer, UTF8_FULL)
+        .set_header(vec!["Host", "State"])
+        .add_row(vec![Cell::new("db-1").set_rowspan(2), Cell::new("up")])
+        .add_row(vec!["down"])
+        .add_row(vec![
+            Cell::new("Total").set_role(CellRole::Footer),
+            Cell::new("2").set_role(CellRole::Footer),
+        ]);
+
+    let expected = "
++-------+-------+
+| Host  | State |
++===============+
+│ db-1  ┆ up    │
+│       ┆ down  │
+├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+│ Total ┆ 2     │
+└───────┴───────┘";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Section breaks are drawn like the header separator and the footer can have its own line.
+#[test]
+fn section_separators() {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_style(TableComponent::FooterLines, '╌')
+        .set_header(vec!["Host", "State"])
+        .add_row(vec!["db-1", "up"])
+        .add_section_break()
+        .add_row(vec!["web-1", "down"])
+        .add_row(vec![
+            Cell::new("Total").set_role(CellRole::Footer),
+            Cell::new("2").set_role(CellRole::Footer),
+        ]);
+
+    let expected = "
+┌───────┬───────┐
+│ Host  ┆ State │
+╞═══════╪═══════╡
+│ db-1  ┆ up    │
+╞═══════╪═══════╡
+│ web-1 ┆ down  │
+├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+│ Total ┆ 2     │
+└───────┴───────┘";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Section breaks next to spans use the intersections of the header separator.
+#[test]
+fn section_separators_with_spans() {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_style(TableComponent::SectionSeparator, '━')
+        .set_header(vec!["Host", "State", "Uptime"])
+        .add_row(vec![
+            Cell::new("db-1").set_rowspan(2),
+            Cell::new("up"),
+            Cell::new("12d"),
+        ])
+        .add_row(vec!["up", "3d"])
+        .add_section_break()
+        .add_row(vec![Cell::new("web-1 is down").set_colspan(3)]);
+
+    let expected = "
+┌───────┬───────┬────────┐
+│ Host  ┆ State ┆ Uptime │
+╞═══════╪═══════╪════════╡
+│ db-1  ┆ up    ┆ 12d    │
+│       ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌┤
+│       ┆ up    ┆ 3d     │
+╞━━━━━━━╧━━━━━━━╧━━━━━━━━╡
+│ web-1 is down          │
+└───────┴───────┴────────┘";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
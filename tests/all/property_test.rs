@@ -168,6 +168,7 @@ proptest! {
 
         // Make sure the table builds without any panics
         let formatted = table.to_string();
+        table.assert_rectangular();
 
         // We'll take a look at each individual line to ensure they all share some properties.
         let lines: Vec<String> = formatted.split_terminator('\n').map(|line| line.to_owned()).collect();
@@ -453,3 +454,150 @@ pub fn absolute_width(table: &Table, width: &Width) -> u16 {
 
     std::cmp::max(1, computed_width)
 }
+
+prop_compose! {
+    /// A table with multiple words per cell, which have to be wrapped by the width solver.
+    fn wrapped_table()
+        (arrangement in prop_oneof![
+            Just(ContentArrangement::Dynamic),
+            Just(ContentArrangement::DynamicFullWidth),
+        ],
+        rows in ::proptest::collection::vec(
+            ::proptest::collection::vec("[A-Za-z_]{1,12}( [A-Za-z_]{1,12}){0,5}", 1..6),
+            1..6,
+        ),
+        constraints in ::proptest::collection::vec(column_constraint(), 6)) -> Table {
+
+        let mut table = Table::new();
+        table.add_rows(rows);
+        for (column, constraint) in table.column_iter_mut().zip(constraints) {
+            if let Some(constraint) = constraint {
+                column.set_constraint(constraint);
+            }
+        }
+
+        table.set_content_arrangement(arrangement);
+        table
+    }
+}
+
+#[cfg(feature = "_integration_test")]
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(512))]
+    /// Check the widths the solver assigns to each column, before anything is drawn.
+    #[test]
+    fn solver_widths(mut table in wrapped_table(), table_width in table_width()) {
+        use super_table::utils::arrangement::arrange_content;
+        use super_table::utils::arrangement::helper::count_border_columns;
+
+        table.set_width(table_width);
+        let infos = arrange_content(&table);
+        let context = format!("{infos:#?}\n{table}");
+
+        // No visible column may be collapsed to nothing.
+        for (index, info) in infos.iter().enumerate() {
+            if !info.is_hidden() && info.content_width == 0 {
+                return build_error(&context, &format!("Column {index} has no content width"));
+            }
+        }
+
+        // The total width stays within the target, unless constraints force the table to be wider.
+        let visible_columns = infos.iter().filter(|info| !info.is_hidden()).count();
+        let total_width: usize = infos
+            .iter()
+            .filter(|info| !info.is_hidden())
+            .map(|info| info.width())
+            .sum::<usize>()
+            + count_border_columns(&table, visible_columns);
+        // Widths beyond u16::MAX are saturated, just like the constraints.
+        let total_width = total_width.min(usize::from(u16::MAX));
+        let expected_max = determine_max_table_width(&table);
+        if total_width > usize::from(expected_max) {
+            return build_error(
+                &context,
+                &format!("Total width {total_width} exceeds the maximum of {expected_max}"),
+            );
+        }
+
+        // The full width arrangement may add space to all columns.
+        if !matches!(table.content_arrangement(), ContentArrangement::Dynamic) {
+            return Ok(());
+        }
+
+        // Constraints are honored.
+        for (column, info) in table.column_iter().zip(infos.iter()) {
+            let index = column.index;
+            let width = u16::try_from(info.width()).unwrap_or(u16::MAX);
+            // The minimal amount of chars per column is the padding and 1 char content.
+            let min_width = column.padding_width() + 1;
+            match column.constraint() {
+                Some(ColumnConstraint::Absolute(absolute)) => {
+                    let expected = absolute_width(&table, absolute).max(min_width);
+                    if width != expected {
+                        return build_error(
+                            &context,
+                            &format!("Column {index} has width {width} instead of {expected}"),
+                        );
+                    }
+                }
+                Some(ColumnConstraint::LowerBoundary(lower)) => {
+                    let expected = absolute_width(&table, lower);
+                    if width < expected {
+                        return build_error(
+                            &context,
+                            &format!("Column {index} has width {width} below its lower bound {expected}"),
+                        );
+                    }
+                }
+                Some(ColumnConstraint::UpperBoundary(upper)) => {
+                    let expected = absolute_width(&table, upper).max(min_width);
+                    if width > expected {
+                        return build_error(
+                            &context,
+                            &format!("Column {index} has width {width} above its upper bound {expected}"),
+                        );
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+}
+
+/// Fields with all characters, that need to be quoted or escaped by any data format.
+fn field() -> impl Strategy<Value = String> {
+    "[a-z ,;\"'#\\\\\n\r\t\u{1}é新]{0,6}"
+}
+
+prop_compose! {
+    fn plain_table()(
+        header in proptest::option::of(proptest::collection::vec(field(), 0..5)),
+        rows in proptest::collection::vec(proptest::collection::vec(field(), 0..5), 0..6),
+    ) -> Table {
+        let mut table = Table::new();
+        if let Some(header) = header {
+            table.set_header(header);
+        }
+        table.add_rows(rows);
+        table
+    }
+}
+
+proptest! {
+    /// Plain tables are restored exactly, when they're exported as CSV or JSON and loaded again.
+    #[test]
+    fn export_round_trip(table in plain_table()) {
+        prop_assert_eq!(table.round_trip(), RoundTrip::Lossless);
+        let has_header = table.header().is_some();
+
+        let csv = table.to_csv(CsvWriteOptions::new());
+        let loaded = Table::from_csv(&csv, CsvReadOptions::new().has_header(has_header)).unwrap();
+        prop_assert_eq!(loaded.to_csv(CsvWriteOptions::new()), csv);
+        prop_assert_eq!(loaded.to_json(), table.to_json());
+
+        let json = table.to_json();
+        let loaded = Table::from_json(&json).unwrap();
+        prop_assert_eq!(loaded.to_json(), json);
+        prop_assert_eq!(loaded.to_csv(CsvWriteOptions::new()), table.to_csv(CsvWriteOptions::new()));
+    }
+}
@@ -0,0 +1,284 @@
+use pretty_assertions::assert_eq;
+
+use super_table::*;
+
+fn table() -> Table {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Header2", "Header3"])
+        .add_row(vec![
+            Cell::new("This spans two columns").set_colspan(2),
+            Cell::new("Rowspan").set_rowspan(2),
+        ])
+        .add_row(vec!["One", "Two"]);
+
+    table
+}
+
+#[test]
+fn stages_match_lines() {
+    let table = table();
+
+    let prepared = render::prepare(&table);
+    let columns = render::arrange_columns(&prepared);
+    let rows = render::format_rows(&prepared, &columns);
+    let lines: Vec<String> = render::draw_borders(&prepared, rows, &columns).collect();
+
+    assert_eq!(table.lines().collect::<Vec<_>>(), lines);
+}
+
+#[test]
+fn intercept_formatted_rows() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Password", "Internal"])
+        .add_row(vec!["alice", "hunter2", "1"])
+        .add_row(vec!["bob", "letmein", "2"]);
+    table
+        .column_mut(2)
+        .unwrap()
+        .set_constraint(ColumnConstraint::Hidden);
+
+    let columns = render::arrange_columns(&table);
+    assert!(columns[2].is_hidden());
+
+    // Replace the formatted passwords, while keeping the layout of the table.
+    let rows = render::format_rows(&table, &columns)
+        .enumerate()
+        .map(|(index, mut row)| {
+            if index > 0 {
+                let width = columns[1].width();
+                row[0][1] = format!("{:^width$}", "***").into();
+            }
+            row
+        });
+    let lines: Vec<String> = render::draw_borders(&table, rows, &columns).collect();
+
+    let expected = "
++-------+----------+
+| Name  | Password |
++==================+
+| alice |   ***    |
+|-------+----------|
+| bob   |   ***    |
++-------+----------+";
+    assert_eq!(expected, "\n".to_string() + &lines.join("\n"));
+}
+
+#[test]
+fn render_hook_events() {
+    use std::sync::{Arc, Mutex};
+
+    use render::RenderEvent;
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let recorded = events.clone();
+
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header"])
+        .add_row(vec!["Some\ncontent"])
+        .set_render_hook(move |event| {
+            let description = match event {
+                RenderEvent::CellFormatted {
+                    row_index,
+                    column_index,
+                    lines,
+                    ..
+                } => format!("cell {row_index}:{column_index} {lines:?}"),
+                RenderEvent::RowStart { row_index } => format!("row {row_index}"),
+                RenderEvent::SeparatorDrawn { kind, line } => format!("{kind:?} {line}"),
+                _ => unreachable!(),
+            };
+            recorded.lock().unwrap().push(description);
+        });
+
+    let _ = table.to_string();
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec![
+            "cell 0:0 [\" Header  \"]",
+            "Top +---------+",
+            "cell 1:0 [\" Some    \", \" content \"]",
+            "row 0",
+            "Header +=========+",
+            "row 1",
+            "Bottom +---------+",
+        ]
+    );
+
+    // Events are only emitted while rendering.
+    table.remove_render_hook();
+    let count = events.lock().unwrap().len();
+    let _ = table.to_string();
+    assert_eq!(events.lock().unwrap().len(), count);
+}
+
+#[test]
+fn render_hook_user_data() {
+    use std::sync::{Arc, Mutex};
+
+    use render::RenderEvent;
+
+    struct Status {
+        failed: bool,
+    }
+
+    let failed_cells = Arc::new(Mutex::new(Vec::new()));
+    let recorded = failed_cells.clone();
+
+    let mut failed = Row::from(vec![
+        Cell::new("backup"),
+        Cell::new("failed").set_user_data(3u8),
+    ]);
+    failed.set_user_data(Status { failed: true });
+    let mut ok = Row::from(vec!["cleanup", "ok"]);
+    ok.set_user_data(Status { failed: false });
+
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Job", "Status"])
+        .add_row(failed)
+        .add_row(ok)
+        .set_render_hook(move |event| {
+            if let RenderEvent::CellFormatted {
+                row_index,
+                column_index,
+                row,
+                cell,
+                ..
+            } = event
+            {
+                if row
+                    .user_data::<Status>()
+                    .is_some_and(|status| status.failed)
+                {
+                    let retries = cell.user_data::<u8>().copied();
+                    recorded
+                        .lock()
+                        .unwrap()
+                        .push((row_index, column_index, retries));
+                }
+            }
+        });
+
+    let _ = table.to_string();
+    assert_eq!(
+        *failed_cells.lock().unwrap(),
+        vec![(1, 0, None), (1, 1, Some(3))]
+    );
+}
+
+#[test]
+fn try_lines_matches_lines() {
+    let table = table();
+
+    assert_eq!(table.try_lines(), Ok(table.lines().collect::<Vec<_>>()));
+}
+
+#[test]
+fn try_lines_invalid_spans() {
+    let mut table = Table::new();
+    table
+        .add_row(vec![Cell::new("Zero"), Cell::new("Colspan").set_colspan(0)])
+        .add_row(vec!["One", "Two"]);
+    assert_eq!(
+        table.try_lines(),
+        Err(render::RenderError::InvalidSpan {
+            row_index: 0,
+            column_index: 1,
+            reason: render::InvalidSpanReason::ZeroColspan,
+        })
+    );
+
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Header2"])
+        .add_row(vec![
+            Cell::new("Too many rows").set_rowspan(3),
+            Cell::new("One"),
+        ])
+        .add_row(vec!["Two"]);
+    assert_eq!(
+        table.try_lines(),
+        Err(render::RenderError::InvalidSpan {
+            row_index: 1,
+            column_index: 0,
+            reason: render::InvalidSpanReason::RowspanExceedsTable,
+        })
+    );
+
+    let mut table = Table::new();
+    table
+        .add_row(vec![
+            Cell::new("One"),
+            Cell::new("Two"),
+            Cell::new("Rowspan").set_rowspan(2),
+        ])
+        .add_row(vec![Cell::new("Overlaps the rowspan").set_colspan(3)]);
+    assert_eq!(
+        table.try_lines(),
+        Err(render::RenderError::InvalidSpan {
+            row_index: 1,
+            column_index: 0,
+            reason: render::InvalidSpanReason::Overlap,
+        })
+    );
+}
+
+#[test]
+fn try_lines_zero_width_column() {
+    let mut table = Table::new();
+    table.add_row(vec!["One", "Two"]);
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_constraint(ColumnConstraint::Absolute(Width::Fixed(2)));
+
+    let error = table.try_lines().unwrap_err();
+    assert_eq!(
+        error,
+        render::RenderError::ZeroWidthColumn { column_index: 1 }
+    );
+    assert_eq!(error.to_string(), "column 1 has a width of zero");
+}
+
+/// Strict tables list all cells, whose content is truncated or hidden due to the table width.
+#[test]
+fn try_lines_strict() {
+    let mut table = Table::new();
+    let mut limited = Row::from(vec!["One\nTwo", "", "Three"]);
+    limited.max_height(1);
+    table
+        .set_width(8)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec!["Name", "Details", "Secret"])
+        .add_row(vec!["backup", "failed", "hunter2"])
+        .add_row(limited);
+    table.column_mut(0).unwrap().set_truncation_priority(1);
+    table
+        .column_mut(2)
+        .unwrap()
+        .set_constraint(ColumnConstraint::Hidden);
+    assert_eq!(table.try_lines(), Ok(table.lines().collect::<Vec<_>>()));
+
+    table.set_strict(true);
+    assert!(table.is_strict());
+    let lost = |row_index, column_index, reason| render::LostContent {
+        row_index,
+        column_index,
+        reason,
+    };
+    let error = table.try_lines().unwrap_err();
+    assert_eq!(
+        error,
+        render::RenderError::ContentLost {
+            cells: vec![
+                lost(0, 1, render::LossReason::HiddenColumn),
+                lost(1, 1, render::LossReason::HiddenColumn),
+                lost(2, 0, render::LossReason::Truncated),
+            ],
+        }
+    );
+    assert_eq!(error.to_string(), "3 cells aren't displayed completely");
+}
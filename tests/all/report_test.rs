@@ -0,0 +1,105 @@
+use pretty_assertions::assert_eq;
+use super_table::presets::UTF8_FULL_CONDENSED;
+use super_table::*;
+
+fn tables() -> (Table, Table) {
+    let mut small = Table::new();
+    small.set_header(vec!["Job"]).add_row(vec!["backup"]);
+
+    let mut wide = Table::new();
+    wide.set_header(vec!["Host", "Description"])
+        .add_row(vec!["db-1", "The primary database"]);
+
+    (small, wide)
+}
+
+#[test]
+fn report_with_fixed_width() {
+    let (small, wide) = tables();
+    let mut report = Report::new();
+    report
+        .load_preset(UTF8_FULL_CONDENSED)
+        .set_width(20)
+        .add_table(small)
+        .add_text("Hosts")
+        .add_table(wide);
+    assert_eq!(report.table_count(), 2);
+    assert_eq!(report.width(), Some(20));
+
+    // All tables share the width and the theme of the report.
+    let expected = "
+┌──────────────────┐
+│ Job              │
+╞══════════════════╡
+│ backup           │
+└──────────────────┘
+
+Hosts
+
+┌──────┬───────────┐
+│ Host ┆ Descripti │
+│      ┆ on        │
+╞══════╪═══════════╡
+│ db-1 ┆ The       │
+│      ┆ primary   │
+│      ┆ database  │
+└──────┴───────────┘";
+    assert_eq!(expected, "\n".to_string() + &report.to_string());
+}
+
+#[test]
+fn report_without_tables() {
+    let mut report = Report::new();
+    assert_eq!(report.width(), None);
+    report.add_text("Nothing to report");
+
+    assert_eq!("Nothing to report", report.to_string());
+}
+
+#[test]
+fn report_export() {
+    let (small, wide) = tables();
+    let mut report = Report::new();
+    report
+        .add_text("# Jobs")
+        .add_table(small)
+        .add_text("Hosts & <more>")
+        .add_table(wide);
+
+    let expected = "
+# Jobs
+
+| Job    |
+| ------ |
+| backup |
+
+Hosts & <more>
+
+| Host | Description          |
+| ---- | -------------------- |
+| db-1 | The primary database |
+";
+    assert_eq!(expected, "\n".to_string() + &report.to_markdown());
+
+    let expected = "
+<p># Jobs</p>
+<table>
+  <thead>
+    <tr><th>Job</th></tr>
+  </thead>
+  <tbody>
+    <tr><td>backup</td></tr>
+  </tbody>
+</table>
+<p>Hosts &amp; &lt;more&gt;</p>
+<table>
+  <thead>
+    <tr><th>Host</th><th>Description</th></tr>
+  </thead>
+  <tbody>
+    <tr><td>db-1</td><td>The primary database</td></tr>
+  </tbody>
+</table>
+";
+    assert_eq!(expected, "\n".to_string() + &report.to_html());
+}
@@ -0,0 +1,109 @@
+use std::collections::BTreeMap;
+
+use pretty_assertions::assert_eq;
+use serde::Serialize;
+use super_table::*;
+
+#[derive(Serialize)]
+struct Address {
+    city: &'static str,
+    zip: u32,
+}
+
+#[derive(Serialize)]
+enum Role {
+    Admin,
+    Guest { until: &'static str },
+}
+
+#[derive(Serialize)]
+struct User {
+    name: &'static str,
+    age: Option<u8>,
+    address: Address,
+    tags: Vec<&'static str>,
+    role: Role,
+}
+
+#[test]
+fn nested_structs() {
+    let users = vec![
+        User {
+            name: "Alice",
+            age: Some(30),
+            address: Address {
+                city: "Oslo",
+                zip: 150,
+            },
+            tags: vec!["ops", "dev"],
+            role: Role::Admin,
+        },
+        User {
+            name: "Bob",
+            age: None,
+            address: Address {
+                city: "Rome",
+                zip: 118,
+            },
+            tags: Vec::new(),
+            role: Role::Guest { until: "May" },
+        },
+    ];
+    let mut table = Table::from_serialize(&users).unwrap();
+    table.set_null_style(NullStyle::new("-"));
+
+    // Nested structs are flattened, which also applies to struct variants.
+    let expected = "
++-------+-----+--------------+-------------+------------+-------+------------------+
+| name  | age | address.city | address.zip | tags       | role  | role.Guest.until |
++==================================================================================+
+| Alice | 30  | Oslo         | 150         | [ops, dev] | Admin | -                |
+|-------+-----+--------------+-------------+------------+-------+------------------|
+| Bob   | -   | Rome         | 118         | []         | -     | May              |
++-------+-----+--------------+-------------+------------+-------+------------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // Typed values are kept.
+    assert_eq!(
+        table.row(0).unwrap().cell_iter().nth(1).unwrap().value(),
+        Some(&CellValue::Int(30))
+    );
+}
+
+#[test]
+fn maps_with_missing_keys() {
+    let rows = vec![
+        BTreeMap::from([(1, "one"), (3, "three")]),
+        BTreeMap::from([(2, "two")]),
+    ];
+    let table = Table::from_serialize(&rows).unwrap();
+
+    assert_eq!(
+        table
+            .header()
+            .unwrap()
+            .cell_iter()
+            .map(Cell::content)
+            .collect::<Vec<_>>(),
+        vec!["1", "3", "2"]
+    );
+    assert_eq!(table.row(1).unwrap().cell_count(), 3);
+}
+
+#[test]
+fn invalid_values() {
+    assert_eq!(
+        Table::from_serialize(&42).unwrap_err(),
+        SerializeError::NotASequence
+    );
+    assert_eq!(
+        Table::from_serialize(&vec![1, 2]).unwrap_err(),
+        SerializeError::NotARecord { row_index: 0 }
+    );
+
+    let rows = vec![BTreeMap::from([(vec![1], "list as key")])];
+    assert_eq!(
+        Table::from_serialize(&rows).unwrap_err(),
+        SerializeError::InvalidKey
+    );
+}
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use pretty_assertions::assert_eq;
 
 use super_table::*;
@@ -101,3 +103,262 @@ fn lines() {
 
     assert_eq!(actual.collect::<Vec<String>>(), expected);
 }
+
+#[test]
+fn render_header_only() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Size"])
+        .add_row(vec!["archive.tar.gz", "12 MB"])
+        .set_margin(Margins {
+            left: 1,
+            right: 1,
+            top: 0,
+            bottom: 1,
+        });
+
+    // The columns are as wide as in the full table, the bottom margin is left out.
+    let expected = "
+ +----------------+-------+ 
+ | Name           | Size  | 
+ +========================+ ";
+    assert_eq!(expected, "\n".to_string() + &table.render_header_only());
+    let lines: Vec<String> = table.lines().take(3).collect();
+    assert_eq!(table.render_header_only(), lines.join("\n"));
+
+    let mut table = Table::new();
+    table.add_row(vec!["No header"]);
+    assert_eq!(table.render_header_only(), "");
+}
+
+#[test]
+fn set_row_out_of_order() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Index", "Value"])
+        .set_row(2, vec!["2", "Third"])
+        .set_row(0, vec!["0", "First"]);
+
+    let expected = "
++-------+-------+
+| Index | Value |
++===============+
+| 0     | First |
+|-------+-------|
+|       |       |
+|-------+-------|
+| 2     | Third |
++-------+-------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // Existing rows are replaced.
+    table.set_row(1, vec!["1", "Second"]);
+    let expected = "
++-------+--------+
+| Index | Value  |
++================+
+| 0     | First  |
+|-------+--------|
+| 1     | Second |
+|-------+--------|
+| 2     | Third  |
++-------+--------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Indices far behind the last row still grow the table, nothing is discarded.
+#[test]
+fn set_row_far_behind_last_row() {
+    let mut table = Table::new();
+    table.set_row(20_000, vec!["Last"]);
+
+    assert_eq!(table.row_count(), 20_001);
+    assert_eq!(table.row(19_999).unwrap().cell_count(), 0);
+    assert_eq!(table.row(20_000).unwrap().cell_count(), 1);
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn set_row_index_too_large() {
+    let mut table = Table::new();
+    table.set_row(usize::MAX, vec!["Unreachable"]);
+}
+
+#[test]
+fn prefix_and_suffix() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Status", "Price"])
+        .add_row(vec![
+            Cell::new("ok").set_prefix("✅ "),
+            Cell::new("12.5").set_prefix("$"),
+        ])
+        .add_row(vec![
+            Cell::new("failed\ntwice").set_prefix("❌ ").set_suffix("!"),
+            Cell::new("1250").set_prefix("$").set_suffix(".00"),
+        ]);
+
+    // The raw content is used for type inference.
+    assert_eq!(
+        table.infer_column_types(),
+        vec![ColumnType::Text, ColumnType::Float]
+    );
+    assert_eq!(
+        table.row(0).unwrap().cell_iter().next().unwrap().content(),
+        "ok"
+    );
+    println!("{table}");
+
+    let expected = "
++-----------+----------+
+| Status    |    Price |
++======================+
+| ✅ ok     |    $12.5 |
+|-----------+----------|
+| ❌ failed | $1250.00 |
+| twice!    |          |
++-----------+----------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn key_value_pairs() {
+    let config = vec![
+        ("threads", 8.to_string()),
+        ("log level", "debug".to_string()),
+    ];
+    let mut table = Table::from_pairs(config);
+    assert_eq!(table.shape(), (2, 2));
+
+    let expected = "
+   threads  8
+ log level  debug";
+    assert_eq!(expected, "\n".to_string() + &table.trim_fmt());
+
+    // The table can be styled like any other one.
+    table.load_preset(presets::ASCII_FULL);
+    let expected = "
++-----------+-------+
+|   threads | 8     |
+|-----------+-------|
+| log level | debug |
++-----------+-------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn map_of_maps_in_first_seen_order() {
+    let services = vec![
+        ("api", vec![("status", "up"), ("latency", "12ms")]),
+        ("queue", vec![("backlog", "3"), ("status", "down")]),
+    ];
+    let mut table = Table::from_map_of_maps(services, KeyOrder::FirstSeen);
+    table
+        .set_null_style(NullStyle::new("-"))
+        .set_column_header(0, "Service");
+
+    let expected = "
++---------+--------+---------+---------+
+| Service | status | latency | backlog |
++======================================+
+| api     | up     | 12ms    | -       |
+|---------+--------+---------+---------|
+| queue   | down   | -       | 3       |
++---------+--------+---------+---------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn build_table_by_columns() {
+    let mut table = Table::new();
+    table
+        .set_null_style(NullStyle::new("-"))
+        .add_column("Name", vec!["Alice", "Bob"])
+        .add_column("Age", vec![30])
+        .add_column("City", vec!["Oslo", "Rome", "Lima"]);
+
+    let expected = "
++-------+-----+------+
+| Name  | Age | City |
++====================+
+| Alice | 30  | Oslo |
+|-------+-----+------|
+| Bob   | -   | Rome |
+|-------+-----+------|
+| -     | -   | Lima |
++-------+-----+------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn templated_cells() {
+    let fields = |pairs: &[(&str, &str)]| -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    };
+    let mut header = Row::from(vec![
+        Cell::new("Host"),
+        Cell::templated("Load ({unit})", [("unit", "%")]),
+    ]);
+    header.set_user_data(fields(&[("unit", "cores")]));
+    let load = Cell::templated("{load} of {cores}", [("cores", "?")]);
+
+    let mut table = Table::new();
+    table.set_header(header);
+    for (host, load_value) in [("db-1", "1.5"), ("db-2", "0.2")] {
+        let mut row = Row::new();
+        row.set_user_data(fields(&[("load", load_value)]));
+        row.add_cell(Cell::new(host)).add_cell(load.clone());
+        table.add_row(row);
+    }
+    table
+        .row_mut(1)
+        .unwrap()
+        .set_user_data(fields(&[("load", "0.2"), ("cores", "8")]));
+
+    // The content of cells and exports are filled as well.
+    let cell = table.row(0).unwrap().cell_iter().nth(1).unwrap();
+    assert_eq!(cell.content(), "1.5 of ?");
+    assert_eq!(
+        table.to_csv(CsvWriteOptions::default()),
+        "Host,Load (cores)\ndb-1,1.5 of ?\ndb-2,0.2 of 8\n"
+    );
+
+    let expected = "
++------+--------------+
+| Host | Load (cores) |
++=====================+
+| db-1 | 1.5 of ?     |
+|------+--------------|
+| db-2 | 0.2 of 8     |
++------+--------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Prefixes and suffixes are wrapped with the content, if the column is too narrow for them.
+#[test]
+fn prefix_and_suffix_in_narrow_column() {
+    let mut table = Table::new();
+    table.add_row(vec![
+        Cell::new("ok").set_prefix("✅ ").set_suffix(" (2h ago)"),
+    ]);
+    table
+        .column_mut(0)
+        .unwrap()
+        .set_constraint(ColumnConstraint::Absolute(Width::Fixed(4)));
+
+    let expected = "
++----+
+| ✅ |
+| ok |
+| (2 |
+| h  |
+| ag |
+| o) |
++----+";
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    table.assert_rectangular();
+}
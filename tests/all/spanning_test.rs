@@ -2,6 +2,8 @@ use pretty_assertions::assert_eq;
 
 use super_table::*;
 
+use super::assert_table_line_width;
+
 #[test]
 fn simple_colspan() {
     let mut table = Table::new();
@@ -298,13 +300,64 @@ fn rowspan_with_multiline() {
 +===========================================+
 | Multi   | Cell 2         | Cell 3         |
 | line    |                |                |
-| rowspan |                |                |
 |         |----------------+----------------|
-|         | Cell 2 (row 2) | Cell 3 (row 2) |
+| rowspan | Cell 2 (row 2) | Cell 3 (row 2) |
 +---------+----------------+----------------+";
     assert_eq!(expected, "\n".to_string() + &table.to_string());
 }
 
+/// The content of a rowspan is truncated to the maximum height of all spanned rows combined.
+#[test]
+fn rowspan_with_max_height() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["H1", "H2"])
+        .add_row(vec![
+            Cell::new("One\nTwo\nThree\nFour").set_rowspan(2),
+            Cell::new("Cell 2"),
+        ])
+        .add_row(vec!["Cell 2 (row 2)"]);
+    for row in table.row_iter_mut() {
+        row.max_height(1);
+    }
+
+    let expected = "
++-------+----------------+
+| H1    | H2             |
++========================+
+| One   | Cell 2         |
+|       |----------------|
+| Tw... | Cell 2 (row 2) |
++-------+----------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn rowspan_fill() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["H1", "H2"])
+        .add_row(vec![
+            Cell::new("Bottom")
+                .set_rowspan(2)
+                .set_vertical_alignment(VerticalAlignment::Bottom)
+                .set_rowspan_fill(FillPolicy::Repeat('.')),
+            Cell::new("Line 1\nLine 2"),
+        ])
+        .add_row(vec![Cell::new("Line 3")]);
+
+    let expected = "
++--------+--------+
+| H1     | H2     |
++=================+
+| ...... | Line 1 |
+| ...... | Line 2 |
+|        |--------|
+| Bottom | Line 3 |
++--------+--------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
 #[test]
 fn complex_table_with_multiple_spans() {
     let mut table = Table::new();
@@ -957,3 +1010,698 @@ fn combined_span_with_custom_borders_and_separators() {
 └───────────────┴────────────────┴────────────────┘";
     assert_eq!(expected, "\n".to_string() + &table.to_string());
 }
+
+#[test]
+fn colspan_rest() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Age", "City"])
+        .add_row(vec![Cell::new("Section 1").set_colspan_rest()])
+        .add_row(vec!["Alice", "30", "Berlin"])
+        .add_row(vec![Cell::new("Bob"), Cell::new("Rest").set_colspan_rest()]);
+
+    let expected = "
++-------+------+--------+
+| Name  | Age  | City   |
++=======================+
+| Section 1             |
+|-----------------------|
+| Alice | 30   | Berlin |
+|-------+------+--------|
+| Bob   | Rest          |
++-------+------+--------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // Adding another column later on extends the spanning cells.
+    table.add_row(vec!["Carol", "40", "Paris", "Extra"]);
+    let expected = "
++-------+-----+--------+-------+
+| Name  | Age | City   |       |
++==============================+
+| Section 1                    |
+|------------------------------|
+| Alice | 30  | Berlin |       |
+|-------+-----+--------+-------|
+| Bob   | Rest                 |
+|-------+----------------------|
+| Carol | 40  | Paris  | Extra |
++-------+-----+--------+-------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn colspan_rest_with_rowspan() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["H1", "H2", "H3"])
+        .add_row(vec![
+            Cell::new("Tall").set_rowspan(2),
+            Cell::new("B"),
+            Cell::new("C"),
+        ])
+        .add_row(vec![Cell::new("Rest").set_colspan_rest()]);
+
+    // The rest spans from the first free column to the end of the row.
+    let expected = "
++------+-----+-----+
+| H1   | H2  | H3  |
++==================+
+| Tall | B   | C   |
+|      |-----+-----|
+|      | Rest      |
++------+-----------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn rowspan_pushes_cells_into_new_column() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["H1", "H2"])
+        .add_row(vec![Cell::new("Tall").set_rowspan(2), Cell::new("A")])
+        .add_row(vec!["B", "C"]);
+
+    // The column that's only needed due to the rowspan is added when rendering.
+    let expected = "
++------+----+---+
+| H1   | H2 |   |
++===============+
+| Tall | A  |   |
+|      |----+---|
+|      | B  | C |
++------+----+---+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn column_cells_iter_with_spans() {
+    let mut table = Table::new();
+    table
+        .set_header(vec![Cell::new("H1-2").set_colspan(2), Cell::new("H3")])
+        .add_row(vec![
+            Cell::new("Tall").set_rowspan(2),
+            Cell::new("A"),
+            Cell::new("B"),
+        ])
+        .add_row(vec!["C", "D"])
+        .add_row(vec![Cell::new("Wide").set_colspan(2), Cell::new("E")]);
+
+    let contents = |iter: &mut dyn Iterator<Item = Option<&Cell>>| -> Vec<Option<String>> {
+        iter.map(|cell| cell.map(Cell::content)).collect()
+    };
+
+    assert_eq!(
+        contents(&mut table.column_cells_iter(0)),
+        vec![Some("Tall".to_string()), None, Some("Wide".to_string())]
+    );
+    assert_eq!(
+        contents(&mut table.column_cells_iter(1)),
+        vec![Some("A".to_string()), Some("C".to_string()), None]
+    );
+    assert_eq!(
+        contents(&mut table.column_cells_with_header_iter(2)),
+        vec![
+            Some("H3".to_string()),
+            Some("B".to_string()),
+            Some("D".to_string()),
+            Some("E".to_string())
+        ]
+    );
+    assert_eq!(
+        contents(&mut table.column_cells_with_header_iter(1)),
+        vec![None, Some("A".to_string()), Some("C".to_string()), None]
+    );
+}
+
+#[test]
+fn colspan_border_policy() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["A", "B"])
+        .add_row(vec!["1", "2"])
+        .add_row(vec!["3", "4"])
+        .add_row(vec![Cell::new("Sum").set_colspan(2)]);
+
+    // With more than two rows, the border is only merged below header colspans.
+    let expected = "
++-----+----+
+| A   | B  |
++==========+
+| 1   | 2  |
+|-----+----|
+| 3   | 4  |
+|-----+----|
+| Sum      |
++-----+----+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    table.set_colspan_border_policy(ColspanBorderPolicy::AlwaysMerge);
+    let expected = "
++-----+----+
+| A   | B  |
++==========+
+| 1   | 2  |
+|-----+----|
+| 3   | 4  |
+|-----+----|
+| Sum      |
++----------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Horizontal lines, that end at a continuing rowspan, get an intersection on both sides.
+#[test]
+fn rowspan_intersections() {
+    let mut table = Table::new();
+    table
+        .load_preset(presets::UTF8_FULL)
+        .add_row(vec![
+            Cell::new("a").set_rowspan(2),
+            Cell::new("b").set_rowspan(2),
+            Cell::new("c"),
+        ])
+        .add_row(vec!["c2"])
+        .add_row(vec![
+            Cell::new("d"),
+            Cell::new("tall").set_rowspan(2),
+            Cell::new("e"),
+        ])
+        .add_row(vec!["d2", "e2"])
+        .add_row(vec![
+            Cell::new("f"),
+            Cell::new("g"),
+            Cell::new("h").set_rowspan(2),
+        ])
+        .add_row(vec!["f2", "g2"])
+        .add_row(vec!["i", "j", "k"]);
+
+    let expected = "
+┌────┬──────┬────┐
+│ a  ┆ b    ┆ c  │
+│    ┆      ├╌╌╌╌┤
+│    ┆      ┆ c2 │
+├╌╌╌╌┼╌╌╌╌╌╌┼╌╌╌╌┤
+│ d  ┆ tall ┆ e  │
+├╌╌╌╌┤      ├╌╌╌╌┤
+│ d2 ┆      ┆ e2 │
+├╌╌╌╌┼╌╌╌╌╌╌┼╌╌╌╌┤
+│ f  ┆ g    ┆ h  │
+├╌╌╌╌┼╌╌╌╌╌╌┤    │
+│ f2 ┆ g2   ┆    │
+├╌╌╌╌┼╌╌╌╌╌╌┼╌╌╌╌┤
+│ i  ┆ j    ┆ k  │
+└────┴──────┴────┘";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // The intersections next to rowspans can be styled on their own.
+    table
+        .set_style(TableComponent::RowspanLeftIntersection, '┝')
+        .set_style(TableComponent::RowspanRightIntersection, '┥');
+    let lines: Vec<String> = table.lines().collect();
+    assert_eq!(lines[2], "│    ┆      ┝╌╌╌╌┤");
+    assert_eq!(lines[6], "├╌╌╌╌┥      ┝╌╌╌╌┤");
+    assert_eq!(lines[10], "├╌╌╌╌┼╌╌╌╌╌╌┥    │");
+
+    // Presets without these components reset them.
+    table.load_preset(presets::UTF8_FULL);
+    assert_eq!(table.style(TableComponent::RowspanLeftIntersection), None);
+    assert_eq!(presets::UTF8_FULL, table.current_style_as_preset());
+}
+
+#[test]
+fn span_merge_intersections() {
+    let mut table = Table::new();
+    table
+        .load_preset(presets::UTF8_FULL)
+        .set_header(vec![Cell::new("Group").set_colspan(2), Cell::new("C")])
+        .add_row(vec!["a", "b", "c"])
+        .add_row(vec![Cell::new("wide").set_colspan(2), Cell::new("c2")])
+        .add_row(vec!["d", "e", "f"]);
+
+    // By default, intersections are derived from the other components of the preset.
+    let expected = "
+┌──────────────┬────┐
+│ Group        ┆ C  │
+╞══════════════╪════╡
+│ a     ┆ b    ┆ c  │
+├╌╌╌╌╌╌╌┴╌╌╌╌╌╌┼╌╌╌╌┤
+│ wide         ┆ c2 │
+├╌╌╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌┤
+│ d     ┆ e    ┆ f  │
+└───────┴──────┴────┘";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    table
+        .set_style(TableComponent::TopBorderColspanIntersections, '┄')
+        .set_style(TableComponent::MiddleHeaderSplitIntersection, '╤')
+        .set_style(TableComponent::MiddleMergeIntersection, '┸')
+        .set_style(TableComponent::MiddleSplitIntersection, '┬');
+    let expected = "
+┌───────┄──────┬────┐
+│ Group        ┆ C  │
+╞═══════╤══════╪════╡
+│ a     ┆ b    ┆ c  │
+├╌╌╌╌╌╌╌┸╌╌╌╌╌╌┼╌╌╌╌┤
+│ wide         ┆ c2 │
+├╌╌╌╌╌╌╌┬╌╌╌╌╌╌┼╌╌╌╌┤
+│ d     ┆ e    ┆ f  │
+└───────┴──────┴────┘";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Junctions below a rowspan that ends where other spans begin in the next row.
+#[test]
+fn stacked_span_junctions() {
+    let mut table = Table::new();
+    table
+        .load_preset(presets::UTF8_FULL)
+        .set_style(TableComponent::MiddleSplitIntersection, '┬')
+        .add_row(vec![
+            Cell::new("big").set_rowspan(2).set_colspan(2),
+            Cell::new("a"),
+        ])
+        .add_row(vec!["a2"])
+        .add_row(vec![
+            Cell::new("p"),
+            Cell::new("q").set_rowspan(2).set_colspan(2),
+        ])
+        .add_row(vec!["p2"])
+        .add_row(vec!["x", "y", "z"]);
+
+    let expected = "
+┌─────┬────┬────┐
+│ big      ┆ a  │
+│          ├╌╌╌╌┤
+│          ┆ a2 │
+├╌╌╌╌╌┬╌╌╌╌┴╌╌╌╌┤
+│ p   ┆ q       │
+├╌╌╌╌╌┤         │
+│ p2  ┆         │
+├╌╌╌╌╌┼╌╌╌╌┬╌╌╌╌┤
+│ x   ┆ y  ┆ z  │
+└─────┴────┴────┘";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Rowspans that end in the last row are only merged with the bottom border inside of them.
+#[test]
+fn rowspan_ending_at_bottom_border() {
+    let mut table = Table::new();
+    table
+        .load_preset(presets::UTF8_FULL)
+        .add_row(vec![
+            Cell::new("a"),
+            Cell::new("r").set_rowspan(2),
+            Cell::new("c"),
+        ])
+        .add_row(vec!["a2", "c2"])
+        .add_row(vec![
+            Cell::new("x"),
+            Cell::new("wide").set_rowspan(2).set_colspan(2),
+        ])
+        .add_row(vec!["x2"]);
+
+    let expected = "
+┌────┬─────┬─────┐
+│ a  ┆ r   ┆ c   │
+├╌╌╌╌┤     ├╌╌╌╌╌┤
+│ a2 ┆     ┆ c2  │
+├╌╌╌╌┼╌╌╌╌╌┴╌╌╌╌╌┤
+│ x  ┆ wide      │
+├╌╌╌╌┤           │
+│ x2 ┆           │
+└────┴───────────┘";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Without vertical lines, a colspan only covers the columns it spans.
+#[test]
+fn colspan_without_vertical_lines() {
+    let mut table = Table::new();
+    table
+        .load_preset(presets::NOTHING)
+        .set_header(vec!["a", "b", "c"])
+        .add_row(vec![Cell::new("spans").set_colspan(2), Cell::new("c")]);
+
+    let expected = "
+ a     b    c
+ spans      c";
+    assert_table_line_width(&table, 14);
+    assert_eq!(expected, "\n".to_string() + &table.trim_fmt());
+}
+
+/// The content of a colspan may use the padding of the inner spanned columns.
+#[test]
+fn colspan_without_padding() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["a", "b", "c"])
+        .add_row(vec![Cell::new("spanning").set_colspan(2), Cell::new("c")]);
+    for column in table.column_iter_mut() {
+        column.set_padding((0, 0));
+    }
+
+    let expected = "
++-----+-----+-+
+|a    |b    |c|
++=============+
+|spanning   |c|
++-----------+-+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Cells that start in a hidden column are displayed in their visible columns.
+#[test]
+fn colspan_starting_in_hidden_column() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["a", "b", "c"])
+        .add_row(vec![Cell::new("spans").set_colspan(2), Cell::new("c")]);
+    table
+        .column_mut(0)
+        .unwrap()
+        .set_constraint(ColumnConstraint::Hidden);
+
+    let expected = "
++-----+---+
+| b   | c |
++=========+
+| spa | c |
+| ns  |   |
++-----+---+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn rowspan_starting_in_hidden_column() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["a", "b", "c"])
+        .add_row(vec![
+            Cell::new("rows").set_colspan(2).set_rowspan(2),
+            Cell::new("c1"),
+        ])
+        .add_row(vec!["c2"]);
+    table
+        .column_mut(0)
+        .unwrap()
+        .set_constraint(ColumnConstraint::Hidden);
+
+    let expected = "
++-----+----+
+| b   | c  |
++==========+
+| row | c1 |
+|     |----|
+| s   | c2 |
++-----+----+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// A rowspan in a hidden column still occupies its column in the following rows.
+#[test]
+fn rowspan_in_hidden_column() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["a", "b", "c"])
+        .add_row(vec![
+            Cell::new("hidden").set_rowspan(2),
+            Cell::new("b1"),
+            Cell::new("c1"),
+        ])
+        .add_row(vec!["b2", "c2"]);
+    table
+        .column_mut(0)
+        .unwrap()
+        .set_constraint(ColumnConstraint::Hidden);
+
+    let expected = "
++----+----+
+| b  | c  |
++=========+
+| b1 | c1 |
+|----+----|
+| b2 | c2 |
++----+----+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// The line below an ending rowspan isn't shifted by a colspan next to it.
+#[test]
+fn rowspan_ending_next_to_colspan() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["a", "b", "c", "d"])
+        .add_row(vec![
+            Cell::new("rows").set_colspan(2).set_rowspan(2),
+            Cell::new("c1"),
+            Cell::new("d1"),
+        ])
+        .add_row(vec![Cell::new("spans").set_colspan(2)])
+        .add_row(vec!["a3", "b3", "c3", "d3"]);
+
+    let expected = "
++------+-----+------+-----+
+| a    | b   | c    | d   |
++=========================+
+| rows       | c1   | d1  |
+|            |------+-----|
+|            | spans      |
+|------------+------------|
+| a3   | b3  | c3   | d3  |
++------+-----+------+-----+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// The occupancy grid shows which cell covers each position, once all spans are resolved.
+#[cfg(feature = "_integration_test")]
+#[test]
+fn occupancy_grid() {
+    use super_table::utils::spanning::occupancy_grid;
+
+    let mut table = Table::new();
+    table
+        .set_header(vec!["H1", "H2", "H3"])
+        .add_row(vec![
+            Cell::new("Tall").set_rowspan(2),
+            Cell::new("Wide").set_colspan(2),
+        ])
+        .add_row(vec![Cell::new("Rest").set_colspan_rest()])
+        .add_row(vec!["Short"]);
+
+    let expected = vec![
+        vec![Some((0, 0)), Some((0, 1)), Some((0, 2))],
+        vec![Some((1, 0)), Some((1, 1)), Some((1, 1))],
+        vec![Some((1, 0)), Some((2, 1)), Some((2, 1))],
+        vec![Some((3, 0)), None, None],
+    ];
+    assert_eq!(expected, occupancy_grid(&table));
+}
+
+/// Annotations span the whole table, but wrap instead of widening it.
+/// They ignore the padding of the columns and span the hidden columns summary as well.
+#[test]
+fn annotation_rows() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Host", "State", "Uptime"])
+        .add_row(vec!["db-1", "up", "12d"])
+        .add_annotation_row("Restarted twice after the kernel update")
+        .add_row(vec!["db-2", "down", "0d"])
+        .set_hidden_columns_summary(HiddenColumnsSummary::Header);
+    table.column_mut(0).unwrap().set_padding((3, 3));
+    table
+        .column_mut(2)
+        .unwrap()
+        .set_constraint(ColumnConstraint::Hidden);
+    assert!(table.row(1).unwrap().is_annotation());
+    assert!(!table.row(0).unwrap().is_annotation());
+
+    let expected = "
++----------+-------+----+
+|   Host   | State | +1 |
++=======================+
+|   db-1   | up    |    |
+|----------+-------+----|
+| Restarted twice after |
+| the kernel update     |
+|-----------------------|
+|   db-2   | down  |    |
++----------+-------+----+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Rowspans start in their last row, once the rows are reversed.
+#[test]
+fn reversed_rowspans() {
+    let mut table = Table::new();
+    table
+        .set_reversed(true)
+        .set_header(vec!["Day", "Time", "Event"])
+        .add_row(vec![
+            Cell::new("Mon").set_rowspan(2),
+            Cell::new("09:00"),
+            Cell::new("Boot"),
+        ])
+        .add_row(vec!["17:00", "Shutdown"])
+        .add_section_break()
+        .add_row(vec!["Tue", "08:30", "Boot"])
+        .add_row(vec![
+            Cell::new("Total").set_role(CellRole::Footer),
+            Cell::new("3 events")
+                .set_role(CellRole::Footer)
+                .set_colspan(2),
+        ]);
+
+    let expected = "
++-------+-------+----------+
+| Day   | Time  | Event    |
++==========================+
+| Tue   | 08:30 | Boot     |
++==========================+
+| Mon   | 17:00 | Shutdown |
+|       |-------+----------|
+|       | 09:00 | Boot     |
+|-------+-------+----------|
+| Total | 3 events         |
++-------+-------+----------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert_table_line_width(&table, 28);
+}
+
+/// Rowspans from rows, that are left out by a tail, are cut off at the first remaining row.
+#[test]
+fn tail_rowspans() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Day", "Time", "Event"])
+        .add_row(vec![
+            Cell::new("Mon").set_rowspan(3),
+            Cell::new("09:00"),
+            Cell::new("Boot"),
+        ])
+        .add_row(vec!["12:00", "Update"])
+        .add_row(vec!["17:00", "Shutdown"])
+        .add_row(vec!["Tue", "08:30", "Boot"]);
+
+    let mut tail = table.tail(3);
+    assert_eq!(tail.row_count(), 3);
+    assert_eq!(table.row_count(), 4);
+
+    let expected = "
++-----+-------+----------+
+| Day | Time  | Event    |
++========================+
+| Mon | 12:00 | Update   |
+|     |-------+----------|
+|     | 17:00 | Shutdown |
+|-----+-------+----------|
+| Tue | 08:30 | Boot     |
++-----+-------+----------+";
+    assert_eq!(expected, "\n".to_string() + &tail.to_string());
+
+    let expected = "
++-----+-------+----------+
+| Day | Time  | Event    |
++========================+
+| Tue | 08:30 | Boot     |
+|-----+-------+----------|
+| Mon | 17:00 | Shutdown |
+|     |-------+----------|
+|     | 12:00 | Update   |
++-----+-------+----------+";
+    assert_eq!(
+        expected,
+        "\n".to_string() + &tail.set_reversed(true).to_string()
+    );
+}
+
+/// Rows that are removed due to the maximum amount of retained rows keep their rowspans intact.
+#[test]
+fn max_retained_rows_with_rowspans() {
+    let mut table = Table::new();
+    table
+        .set_max_retained_rows(3)
+        .set_header(vec!["Host", "Time", "Load"])
+        .add_row(vec![
+            Cell::new("db-1").set_rowspan(3),
+            Cell::new("12:00"),
+            Cell::new("0.5"),
+        ])
+        .add_row(vec!["12:01", "0.7"])
+        .add_row(vec!["12:02", "0.9"]);
+    assert_eq!(table.row_count(), 3);
+
+    table.add_row(vec!["db-2", "12:02", "0.1"]);
+    assert_eq!(table.row_count(), 3);
+    assert_eq!(table.row(0).unwrap().cell_count(), 3);
+
+    let expected = "
++------+-------+------+
+| Host | Time  | Load |
++=====================+
+| db-1 | 12:01 | 0.7  |
+|      |-------+------|
+|      | 12:02 | 0.9  |
+|------+-------+------|
+| db-2 | 12:02 | 0.1  |
++------+-------+------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    table.set_max_retained_rows(1);
+    let expected = "
++------+-------+------+
+| Host | Time  | Load |
++=====================+
+| db-2 | 12:02 | 0.1  |
++------+-------+------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Columns are widened, if the widest character of an annotation doesn't fit into them.
+/// Rowspans, that reach into an annotation, leave less space for it.
+#[test]
+fn annotation_rows_with_wide_characters() {
+    let mut table = Table::new();
+    table
+        .set_margin(Margins {
+            left: 1,
+            right: 1,
+            top: 0,
+            bottom: 0,
+        })
+        .add_row(vec!["a"])
+        .add_annotation_row("你好 world");
+    table.column_mut(0).unwrap().set_padding((0, 0));
+
+    let expected = "
+ +----+ 
+ |a   | 
+ |----| 
+ | 你 | 
+ | 好 | 
+ | wo | 
+ | rl | 
+ | d  | 
+ +----+ ";
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    table.assert_rectangular();
+
+    let mut table = Table::new();
+    table
+        .set_header(vec![Cell::new("Host").set_rowspan(2), Cell::new("")])
+        .add_annotation_row("你好");
+    table.column_mut(1).unwrap().set_padding((0, 0));
+
+    let expected = "
++------+----+
+| Host |    |
++===========+
+|      | 你 |
+|      | 好 |
++------+----+";
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    table.assert_rectangular();
+}
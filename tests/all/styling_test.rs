@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use pretty_assertions::assert_eq;
 
 use super_table::presets::UTF8_FULL;
@@ -87,3 +89,409 @@ fn styled_text_only_table() {
 
     assert_eq!(expected, "\n".to_string() + &table.to_string());
 }
+
+#[test]
+fn audit_ignores_styling() {
+    let mut table = Table::new();
+    table.enforce_styling().add_row(vec![
+        Cell::new("Styled").fg(Color::Red),
+        Cell::new("★").bg(Color::Blue),
+    ]);
+
+    let issues = table.audit_alignment();
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].content, "★");
+    assert_eq!((issues[0].expected_width, issues[0].actual_width), (3, 4));
+}
+
+#[test]
+fn row_styling() {
+    let mut row = Row::from(vec![Cell::new("error"), Cell::new("ok").fg(Color::Green)]);
+    row.fg(Color::Red)
+        .bg(Color::Black)
+        .add_attribute(Attribute::Bold);
+    // Cells that are added later on are styled as well.
+    row.add_cell(Cell::new("later").add_attribute(Attribute::Italic));
+    let mut table = Table::new();
+    table.enforce_styling().add_row(row);
+
+    let mut expected = Table::new();
+    expected.enforce_styling().add_row(vec![
+        Cell::new("error")
+            .fg(Color::Red)
+            .bg(Color::Black)
+            .add_attribute(Attribute::Bold),
+        Cell::new("ok")
+            .fg(Color::Green)
+            .bg(Color::Black)
+            .add_attribute(Attribute::Bold),
+        Cell::new("later")
+            .fg(Color::Red)
+            .bg(Color::Black)
+            .add_attributes(vec![Attribute::Bold, Attribute::Italic]),
+    ]);
+    assert_eq!(expected.to_string(), table.to_string());
+
+    let cell = table.row(0).unwrap().cell_iter().nth(1).unwrap();
+    let style = cell.effective_style(&table).unwrap();
+    assert_eq!(style.fg, Some(Color::Green));
+    assert_eq!(style.bg, Some(Color::Black));
+    assert_eq!(style.attributes, vec![Attribute::Bold]);
+}
+
+#[test]
+fn attribute_policy() {
+    let styled_table = |policy: AttributePolicy| {
+        let mut table = Table::new();
+        table
+            .enforce_styling()
+            .set_attribute_policy(policy)
+            .add_row(vec![
+                Cell::new("italic").add_attribute(Attribute::Italic),
+                Cell::new("blinking")
+                    .fg(Color::Red)
+                    .add_attributes(vec![Attribute::SlowBlink, Attribute::Fraktur]),
+            ]);
+        table
+    };
+
+    let mut expected = Table::new();
+    expected.enforce_styling().add_row(vec![
+        Cell::new("italic").add_attribute(Attribute::Underlined),
+        Cell::new("blinking")
+            .fg(Color::Red)
+            .add_attribute(Attribute::Bold),
+    ]);
+    assert_eq!(
+        expected.to_string(),
+        styled_table(AttributePolicy::Fallback).to_string()
+    );
+
+    // Attributes are stripped for output that isn't a terminal, but colors are kept.
+    let mut expected = Table::new();
+    expected.enforce_styling().add_row(vec![
+        Cell::new("italic"),
+        Cell::new("blinking").fg(Color::Red),
+    ]);
+    let mut table = styled_table(AttributePolicy::Auto);
+    table.force_no_tty();
+    assert_eq!(expected.to_string(), table.to_string());
+    assert_eq!(
+        expected.to_string(),
+        styled_table(AttributePolicy::Strip).to_string()
+    );
+}
+
+#[test]
+fn null_style() {
+    let mut table = Table::new();
+    table
+        .enforce_styling()
+        .set_null_style(NullStyle::default())
+        .set_header(vec![Cell::from_value(CellValue::Null)])
+        .add_row(vec![
+            Cell::from_value(CellValue::Null),
+            Cell::new("n/a").set_value(CellValue::Null),
+            Cell::new(""),
+        ]);
+
+    // Only cells with a typed `NULL` value are styled, header cells are kept as they are.
+    let mut expected = Table::new();
+    expected
+        .enforce_styling()
+        .set_header(vec![""])
+        .add_row(vec![
+            Cell::new("NULL")
+                .add_attribute(Attribute::Dim)
+                .add_attribute(Attribute::Italic),
+            Cell::new("n/a")
+                .add_attribute(Attribute::Dim)
+                .add_attribute(Attribute::Italic),
+            Cell::new(""),
+        ]);
+    assert_eq!(expected.to_string(), table.to_string());
+}
+
+#[test]
+fn units_row_is_dimmed() {
+    let mut table = Table::new();
+    table
+        .enforce_styling()
+        .set_header(vec!["Latency"])
+        .set_units_row(vec!["[ms]"])
+        .add_row(vec!["12"]);
+
+    let lines: Vec<String> = table.lines().collect();
+    assert_eq!(lines[1], "| Latency |");
+    assert_eq!(lines[2], "|\u{1b}[2m [ms]    \u{1b}[0m|");
+}
+
+#[test]
+fn negative_style() {
+    let mut table = Table::new();
+    table
+        .enforce_styling()
+        .add_row(vec![Cell::new("-3"), Cell::new("-3")])
+        .add_row(vec![Cell::new("4").fg(Color::Blue), Cell::new("text")]);
+    table
+        .column_mut(0)
+        .unwrap()
+        .set_negative_style(Style::new().fg(Color::Red).add_attribute(Attribute::Bold));
+
+    let mut expected = Table::new();
+    expected
+        .enforce_styling()
+        .add_row(vec![
+            Cell::new("-3")
+                .fg(Color::Red)
+                .add_attribute(Attribute::Bold),
+            Cell::new("-3"),
+        ])
+        .add_row(vec![Cell::new("4").fg(Color::Blue), Cell::new("text")]);
+    assert_eq!(expected.to_string(), table.to_string());
+}
+
+#[test]
+fn bool_format_styles() {
+    let format = BoolFormat::check_marks()
+        .style_true(Style::new().fg(Color::Green))
+        .style_false(Style::new().fg(Color::Red));
+    let mut table = Table::new();
+    table
+        .enforce_styling()
+        .set_bool_format(BoolFormat::yes_no())
+        .add_row(vec![Cell::from_value(true), Cell::from_value(false)])
+        .add_row(vec![Cell::new("FALSE"), Cell::new("no bool")]);
+    table.column_mut(0).unwrap().set_bool_format(format);
+
+    let mut expected = Table::new();
+    expected
+        .enforce_styling()
+        .add_row(vec![Cell::new("✔").fg(Color::Green), Cell::new("no")])
+        .add_row(vec![Cell::new("✘").fg(Color::Red), Cell::new("no bool")]);
+    assert_eq!(expected.to_string(), table.to_string());
+}
+
+#[test]
+fn category_colors() {
+    let mut table = Table::new();
+    table
+        .enforce_styling()
+        .set_header(vec!["running", "Status"])
+        .add_row(vec!["running", "running"])
+        .add_row(vec![Cell::new("a"), Cell::new("failed").fg(Color::Blue)])
+        .add_row(vec!["b", "running "]);
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_category_colors(HashMap::from([
+            ("running".to_string(), Color::Green),
+            ("failed".to_string(), Color::Red),
+        ]));
+
+    // Only exact matches are colored and cells keep their own colors.
+    let mut expected = Table::new();
+    expected
+        .enforce_styling()
+        .set_header(vec!["running", "Status"])
+        .add_row(vec![
+            Cell::new("running"),
+            Cell::new("running").fg(Color::Green),
+        ])
+        .add_row(vec![Cell::new("a"), Cell::new("failed").fg(Color::Blue)])
+        .add_row(vec!["b", "running "]);
+    assert_eq!(expected.to_string(), table.to_string());
+    assert!(table.to_csv(CsvWriteOptions::new()).contains("a,failed\n"));
+}
+
+#[test]
+fn baseline_row() {
+    let mut table = Table::new();
+    table
+        .enforce_styling()
+        .set_header(vec!["Run", "Time"])
+        .add_row(vec!["main", "1.20s"])
+        .add_row(vec!["feature", "1.20s"])
+        .add_row(vec![Cell::new("fix"), Cell::new("0.98s")])
+        .add_row(vec![Cell::new("old"), Cell::new("1.40s").fg(Color::Red)]);
+    table.column_mut(1).unwrap().set_baseline_row(0);
+
+    // The baseline and cells with their own color are kept.
+    let mut expected = Table::new();
+    expected
+        .enforce_styling()
+        .set_header(vec!["Run", "Time"])
+        .add_row(vec!["main", "1.20s"])
+        .add_row(vec![
+            Cell::new("feature"),
+            Cell::new("1.20s").add_attribute(Attribute::Dim),
+        ])
+        .add_row(vec![Cell::new("fix"), Cell::new("0.98s").fg(Color::Yellow)])
+        .add_row(vec![Cell::new("old"), Cell::new("1.40s").fg(Color::Red)]);
+    assert_eq!(expected.to_string(), table.to_string());
+
+    table.column_mut(1).unwrap().remove_baseline_row();
+    assert_eq!(table.column(1).unwrap().baseline_row(), None);
+}
+
+/// Semantic styles are looked up in the style map, but the cell's own colors win.
+#[test]
+fn semantic_styles() {
+    let styles = StyleMap::new()
+        .add(
+            "error",
+            StyleTarget::Terminal(Style::new().fg(Color::Red).add_attribute(Attribute::Bold)),
+        )
+        .add("muted", StyleTarget::HtmlClass("muted".to_string()));
+    let mut table = Table::new();
+    table
+        .set_style_map(styles)
+        .set_header(vec![Cell::new("State").set_semantic_style("error")])
+        .add_row(vec![Cell::new("failed").set_semantic_style("error")])
+        .add_row(vec![
+            Cell::new("stopped")
+                .set_semantic_style("error")
+                .fg(Color::Yellow),
+        ])
+        .add_row(vec![Cell::new("idle").set_semantic_style("muted")])
+        .add_row(vec![Cell::new("unknown").set_semantic_style("missing")]);
+    table.force_no_tty().enforce_styling();
+
+    let expected = "
++---------+
+|\u{1b}[38;5;9m\u{1b}[1m State   \u{1b}[0m|
++=========+
+|\u{1b}[38;5;9m\u{1b}[1m failed  \u{1b}[0m|
+|---------|
+|\u{1b}[38;5;11m\u{1b}[1m stopped \u{1b}[0m|
+|---------|
+| idle    |
+|---------|
+| unknown |
++---------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // The stored cells aren't changed.
+    let cell = table.row(0).unwrap().cell(0).unwrap();
+    assert_eq!(cell.semantic_style(), Some("error"));
+    assert!(cell.effective_style(&table).unwrap().fg.is_none());
+}
+
+#[test]
+fn palette_remaps_colors() {
+    let mut table = Table::new();
+    table
+        .enforce_styling()
+        .set_header(vec!["Job", "Status"])
+        .add_row(vec![
+            Cell::new("backup").fg(Color::Grey),
+            Cell::new("failed"),
+        ])
+        .add_row(vec![
+            Cell::new("cleanup").bg(Color::DarkBlue),
+            Cell::new("ok"),
+        ]);
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_category_colors(HashMap::from([("failed".to_string(), Color::Red)]));
+    table.set_palette(palettes::OKABE_ITO);
+
+    // Colors from the content are replaced as well, while shades of grey are kept.
+    let mut expected = Table::new();
+    expected
+        .enforce_styling()
+        .set_header(vec!["Job", "Status"])
+        .add_row(vec![
+            Cell::new("backup").fg(Color::Grey),
+            Cell::new("failed").fg(palettes::OKABE_ITO.color(5)),
+        ])
+        .add_row(vec![
+            Cell::new("cleanup").bg(palettes::OKABE_ITO.color(4)),
+            Cell::new("ok"),
+        ]);
+    assert_eq!(expected.to_string(), table.to_string());
+
+    table.remove_palette();
+    assert_ne!(expected.to_string(), table.to_string());
+}
+
+#[test]
+fn auto_contrast() {
+    let mut row = Row::from(vec![Cell::new("row"), Cell::new("own").fg(Color::Red)]);
+    row.bg(Color::Rgb {
+        r: 250,
+        g: 240,
+        b: 200,
+    });
+    let mut table = Table::new();
+    table
+        .enforce_styling()
+        .set_auto_contrast(true)
+        .set_header(vec![
+            Cell::new("Header").bg(Color::DarkRed),
+            Cell::new("plain"),
+        ])
+        .add_row(row)
+        .add_row(vec![Cell::new("reset").bg(Color::Reset), Cell::new("none")]);
+
+    // Explicit foreground colors and backgrounds, that depend on the terminal, are kept.
+    let mut row = Row::from(vec![
+        Cell::new("row").fg(Color::Black),
+        Cell::new("own").fg(Color::Red),
+    ]);
+    row.bg(Color::Rgb {
+        r: 250,
+        g: 240,
+        b: 200,
+    });
+    let mut expected = Table::new();
+    expected
+        .enforce_styling()
+        .set_header(vec![
+            Cell::new("Header").bg(Color::DarkRed).fg(Color::White),
+            Cell::new("plain"),
+        ])
+        .add_row(row)
+        .add_row(vec![Cell::new("reset").bg(Color::Reset), Cell::new("none")]);
+    assert_eq!(expected.to_string(), table.to_string());
+}
+
+#[test]
+fn gradient() {
+    let mut table = Table::new();
+    table
+        .force_no_tty()
+        .enforce_styling()
+        .load_preset(presets::NOTHING)
+        .add_row(vec![Cell::new("abc").bg(Color::Blue).set_gradient(
+            Color::Rgb { r: 0, g: 0, b: 0 },
+            Color::Rgb {
+                r: 200,
+                g: 100,
+                b: 0,
+            },
+        )]);
+
+    // Without true color, the cell is displayed with its regular style.
+    if !table.supports_truecolor() {
+        let mut expected = Table::new();
+        expected
+            .force_no_tty()
+            .enforce_styling()
+            .load_preset(presets::NOTHING)
+            .add_row(vec![Cell::new("abc").bg(Color::Blue)]);
+        assert_eq!(expected.to_string(), table.to_string());
+    }
+
+    // Each character gets its own color, while the padding keeps the background.
+    table.enforce_truecolor();
+    let expected = concat!(
+        "\u{1b}[48;5;12m \u{1b}[49m",
+        "\u{1b}[48;5;12m\u{1b}[38;2;0;0;0ma\u{1b}[49m\u{1b}[39m",
+        "\u{1b}[48;5;12m\u{1b}[38;2;100;50;0mb\u{1b}[49m\u{1b}[39m",
+        "\u{1b}[48;5;12m\u{1b}[38;2;200;100;0mc\u{1b}[49m\u{1b}[39m",
+        "\u{1b}[48;5;12m \u{1b}[49m",
+    );
+    assert_eq!(expected, table.to_string());
+}
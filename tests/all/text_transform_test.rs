@@ -0,0 +1,80 @@
+use pretty_assertions::assert_eq;
+
+use super_table::*;
+
+#[test]
+fn text_transforms() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["first name", "status", "comment"])
+        .add_row(vec!["ada lovelace", "active", "Mixed CASE"])
+        .add_row(vec!["GRACE HOPPER", "inactive", "straße"]);
+    for column in table.column_iter_mut() {
+        column.set_header_text_transform(TextTransform::Uppercase);
+    }
+    table
+        .column_mut(0)
+        .unwrap()
+        .set_text_transform(TextTransform::TitleCase);
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_text_transform(TextTransform::Lowercase);
+    table
+        .column_mut(2)
+        .unwrap()
+        .set_text_transform(TextTransform::Uppercase);
+
+    println!("{table}");
+    // The width of the columns is calculated with the transformed content.
+    let expected = "
++--------------+----------+------------+
+| FIRST NAME   | STATUS   | COMMENT    |
++======================================+
+| Ada Lovelace | active   | MIXED CASE |
+|--------------+----------+------------|
+| Grace Hopper | inactive | STRASSE    |
++--------------+----------+------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // The stored content isn't touched.
+    let cell = table.row(1).unwrap().cell_iter().next().unwrap();
+    assert_eq!(cell.content(), "GRACE HOPPER");
+}
+
+#[test]
+fn text_transform_with_sort_indicator() {
+    let mut table = Table::new();
+    table.set_header(vec!["name"]).add_row(vec!["a"]);
+    table
+        .column_mut(0)
+        .unwrap()
+        .set_header_text_transform(TextTransform::TitleCase)
+        .set_sort_indicator(SortOrder::Ascending);
+
+    println!("{table}");
+    let expected = "
++--------+
+| Name ▲ |
++========+
+| a      |
++--------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[cfg(feature = "custom_styling")]
+#[test]
+fn text_transform_keeps_ansi_codes() {
+    let mut table = Table::new();
+    table.add_row(vec!["\u{1b}[31mred\u{1b}[0m text"]);
+    table
+        .column_mut(0)
+        .unwrap()
+        .set_text_transform(TextTransform::Uppercase);
+
+    let expected = "
++----------+
+| \u{1b}[31mRED\u{1b}[0m TEXT |
++----------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
@@ -197,3 +197,51 @@ fn table_with_composite_utf8_emojis() {
     assert_table_line_width(&table, 15);
     assert_eq!(expected, "\n".to_string() + &table.to_string());
 }
+
+/// The full content of truncated cells stays available, while the displayed content can be
+/// requested from the table.
+#[test]
+fn rendered_content_of_truncated_cells() {
+    let mut table = Table::new();
+    let mut row = Row::from(vec!["This is a very long line with a lot of text", "short"]);
+    row.max_height(2);
+    table
+        .set_header(vec!["Header1", "Header2"])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(25)
+        .add_row(row);
+
+    let cell = table.row(0).unwrap().cell_iter().next().unwrap();
+    assert_eq!(
+        cell.content(),
+        "This is a very long line with a lot of text"
+    );
+    assert_eq!(
+        table.rendered_content(0, 0).unwrap(),
+        "This is a\nvery lon..."
+    );
+    assert_eq!(table.rendered_content(0, 1).unwrap(), "short");
+    assert_eq!(table.rendered_content(0, 2), None);
+    assert_eq!(table.rendered_content(1, 0), None);
+}
+
+/// The truncation indicator is cut, if the column is narrower than the indicator.
+#[test]
+fn truncation_indicator_wider_than_column() {
+    let mut table = Table::new();
+    let mut row = Row::from(vec!["x", "some long content"]);
+    row.max_height(1);
+    table.add_row(row);
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_constraint(Absolute(Fixed(3)));
+
+    let expected = "
++---+---+
+| x | . |
++---+---+";
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    table.assert_rectangular();
+}
@@ -108,3 +108,117 @@ fn zwj_utf8_word_splitting() {
     println!("{expected}");
     assert_eq!(expected, "\n".to_string() + &table.to_string());
 }
+
+/// Content isn't split within a grapheme.
+/// Columns are widened to their widest grapheme, even if their constraint is narrower.
+#[test]
+fn wide_characters_in_narrow_column() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["a", "b"])
+        .add_row(vec!["新年快", "b"]);
+    table
+        .column_mut(0)
+        .unwrap()
+        .set_constraint(ColumnConstraint::Absolute(Width::Fixed(3)));
+
+    println!("{table}");
+    let expected = "
++----+---+
+| a  | b |
++========+
+| 新 | b |
+| 年 |   |
+| 快 |   |
++----+---+";
+    println!("{expected}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn audit_ambiguous_width() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Rating"])
+        .add_row(vec!["Wide characters like 你好 are fine", "★★★"])
+        .add_row(vec!["Plain", "→\nok"]);
+
+    let issues = table.audit_alignment();
+    assert_eq!(
+        issues,
+        vec![
+            render::AlignmentIssue {
+                row_index: 1,
+                column_index: 1,
+                line_index: 0,
+                expected_width: 8,
+                actual_width: 11,
+                content: "★★★".to_string(),
+            },
+            render::AlignmentIssue {
+                row_index: 2,
+                column_index: 1,
+                line_index: 0,
+                expected_width: 8,
+                actual_width: 9,
+                content: "→".to_string(),
+            },
+        ]
+    );
+
+    let mut table = Table::new();
+    table.add_row(vec!["Only ASCII", "and 你好"]);
+    assert!(table.audit_alignment().is_empty());
+}
+
+#[test]
+fn ambiguous_width_wide() {
+    let mut table = Table::new();
+    table
+        .set_ambiguous_width(AmbiguousWidth::Wide)
+        .set_header(vec!["Name", "Rating"])
+        .add_row(vec!["Plain", "★★★"])
+        .add_row(vec!["Arrow", "→"]);
+
+    let expected = "
++-------+--------+
+| Name  | Rating |
++================+
+| Plain | ★★★ |
+|-------+--------|
+| Arrow | →     |
++-------+--------+";
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert!(table.audit_alignment().is_empty());
+}
+
+#[test]
+fn ambiguous_width_wide_utf8_borders() {
+    let mut table = Table::new();
+    table
+        .load_preset(presets::UTF8_FULL)
+        .set_ambiguous_width(AmbiguousWidth::Wide)
+        .set_margin(Margins {
+            left: 1,
+            right: 1,
+            top: 1,
+            bottom: 0,
+        })
+        .set_header(vec!["Name", "Rating"])
+        .add_row(vec!["Plain", "★★★"])
+        .add_row(vec!["a", "b"]);
+
+    let expected = "
+                    
+ ┌───────┬────────┐ 
+ │ Name  ┆ Rating │ 
+ ╞═══════╪════════╡ 
+ │ Plain ┆ ★★★ │ 
+ ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌┤ 
+ │ a     ┆ b      │ 
+ └───────┴────────┘ ";
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    table.assert_rectangular();
+}